@@ -14,6 +14,14 @@ const logger = createLogger('map-config');
 
 const CURRENT_CONFIG_VERSION = 2;
 
+// Endpoint each provider's key is sent to; domain-restricted keys are only
+// released by the secure store for a URL their restrictions allow.
+const PROVIDER_REQUEST_URLS: Record<MapApiKey['provider'], string> = {
+  openstreetmap: 'https://tile.openstreetmap.org/',
+  google: 'https://maps.googleapis.com/',
+  mapbox: 'https://api.mapbox.com/',
+};
+
 export interface MapApiKey {
   id: string;
   provider: 'openstreetmap' | 'google' | 'mapbox';
@@ -298,7 +306,7 @@ class MapConfigurationService {
         let apiKey = legacyInConfig || await secretVaultApi.getMapApiKey(meta.provider, meta.id);
 
         if (!apiKey) {
-          apiKey = await mapKeysApi.get(meta.id).catch(() => null);
+          apiKey = await mapKeysApi.get(meta.id, PROVIDER_REQUEST_URLS[meta.provider]).catch(() => null);
           if (apiKey) {
             await secretVaultApi.setMapApiKey(meta.provider, meta.id, apiKey);
             shouldScrubLegacyStorage = true;
@@ -461,7 +461,7 @@ describe('useTargetList', () => {
   it('should load target list on mount', async () => {
     const mockTarget = { id: '1', name: 'M31', ra: 10.68, dec: 41.27, ra_string: '0h 42m 44s', dec_string: "+41° 16' 9\"", added_at: Date.now(), priority: 'medium' as const, status: 'planned' as const, tags: ['galaxy'], is_favorite: false, is_archived: false };
     const mockData = { targets: [mockTarget], available_tags: ['galaxy'], active_target_id: undefined };
-    const mockStats = { total: 1, planned: 1, in_progress: 0, completed: 0, favorites: 0, archived: 0, high_priority: 0, medium_priority: 1, low_priority: 0, by_tag: [['galaxy', 1]] as [string, number][] };
+    const mockStats = { total: 1, planned: 1, in_progress: 0, completed: 0, favorites: 0, archived: 0, high_priority: 0, medium_priority: 1, low_priority: 0, by_tag: [['galaxy', 1]] as [string, number][], captured_hours: 0, planned_hours: 0 };
     
     mockTargetListApi.load.mockResolvedValue(mockData);
     mockTargetListApi.getStats.mockResolvedValue(mockStats);
@@ -481,7 +481,7 @@ describe('useTargetList', () => {
     const mockData = { targets: [], available_tags: [], active_target_id: undefined };
     const mockTarget = { id: '1', name: 'M31', ra: 10.68, dec: 41.27, ra_string: '0h 42m 44s', dec_string: "+41° 16' 9\"", added_at: Date.now(), priority: 'medium' as const, status: 'planned' as const, tags: [], is_favorite: false, is_archived: false };
     const mockUpdatedData = { targets: [mockTarget], available_tags: [], active_target_id: undefined };
-    const mockStats = { total: 0, planned: 0, in_progress: 0, completed: 0, favorites: 0, archived: 0, high_priority: 0, medium_priority: 0, low_priority: 0, by_tag: [] as [string, number][] };
+    const mockStats = { total: 0, planned: 0, in_progress: 0, completed: 0, favorites: 0, archived: 0, high_priority: 0, medium_priority: 0, low_priority: 0, by_tag: [] as [string, number][], captured_hours: 0, planned_hours: 0 };
     
     mockTargetListApi.load.mockResolvedValue(mockData);
     mockTargetListApi.getStats.mockResolvedValue(mockStats);
@@ -504,7 +504,7 @@ describe('useTargetList', () => {
     const mockTarget = { id: '1', name: 'M31', ra: 10.68, dec: 41.27, ra_string: '0h 42m 44s', dec_string: "+41° 16' 9\"", added_at: Date.now(), priority: 'medium' as const, status: 'planned' as const, tags: [], is_favorite: false, is_archived: false };
     const mockData = { targets: [mockTarget], available_tags: [], active_target_id: undefined };
     const mockUpdatedData = { targets: [], available_tags: [], active_target_id: undefined };
-    const mockStats = { total: 1, planned: 1, in_progress: 0, completed: 0, favorites: 0, archived: 0, high_priority: 0, medium_priority: 1, low_priority: 0, by_tag: [] as [string, number][] };
+    const mockStats = { total: 1, planned: 1, in_progress: 0, completed: 0, favorites: 0, archived: 0, high_priority: 0, medium_priority: 1, low_priority: 0, by_tag: [] as [string, number][], captured_hours: 0, planned_hours: 0 };
     
     mockTargetListApi.load.mockResolvedValue(mockData);
     mockTargetListApi.getStats.mockResolvedValue(mockStats);
@@ -526,7 +526,7 @@ describe('useTargetList', () => {
   it('should toggle favorite', async () => {
     const mockTarget = { id: '1', name: 'M31', ra: 10.68, dec: 41.27, ra_string: '0h 42m 44s', dec_string: "+41° 16' 9\"", added_at: Date.now(), priority: 'medium' as const, status: 'planned' as const, tags: [], is_favorite: false, is_archived: false };
     const mockData = { targets: [mockTarget], available_tags: [], active_target_id: undefined };
-    const mockStats = { total: 1, planned: 1, in_progress: 0, completed: 0, favorites: 0, archived: 0, high_priority: 0, medium_priority: 1, low_priority: 0, by_tag: [] as [string, number][] };
+    const mockStats = { total: 1, planned: 1, in_progress: 0, completed: 0, favorites: 0, archived: 0, high_priority: 0, medium_priority: 1, low_priority: 0, by_tag: [] as [string, number][], captured_hours: 0, planned_hours: 0 };
     
     mockTargetListApi.load.mockResolvedValue(mockData);
     mockTargetListApi.getStats.mockResolvedValue(mockStats);
@@ -44,7 +44,16 @@ describe('mapKeysApi', () => {
     mockInvoke.mockResolvedValue('secret');
     const value = await mapKeysApi.get('google-1');
     expect(value).toBe('secret');
-    expect(mockInvoke).toHaveBeenCalledWith('get_map_api_key', { keyId: 'google-1' });
+    expect(mockInvoke).toHaveBeenCalledWith('get_map_api_key', { keyId: 'google-1', requestUrl: undefined });
+  });
+
+  it('passes the request URL when getting a key', async () => {
+    mockInvoke.mockResolvedValue('secret');
+    await mapKeysApi.get('google-1', 'https://maps.googleapis.com/maps/api/js');
+    expect(mockInvoke).toHaveBeenCalledWith('get_map_api_key', {
+      keyId: 'google-1',
+      requestUrl: 'https://maps.googleapis.com/maps/api/js',
+    });
   });
 
   it('deletes key', async () => {
@@ -122,7 +122,7 @@ describe('pathConfigApi', () => {
 
       const result = await pathConfigApi.migrateDataDir('/target/data');
 
-      expect(mockInvoke).toHaveBeenCalledWith('migrate_data_dir', { targetDir: '/target/data' });
+      expect(mockInvoke).toHaveBeenCalledWith('migrate_data_dir', { targetDir: '/target/data', overwrite: false });
       expect(result).toEqual(expected);
     });
 
@@ -295,6 +295,8 @@ describe('targetListApi', () => {
       medium_priority: 4,
       low_priority: 3,
       by_tag: [['galaxy', 5], ['nebula', 3]] as [string, number][],
+      captured_hours: 1.5,
+      planned_hours: 4,
     };
     mockInvoke.mockResolvedValue(mockStats);
 
@@ -185,21 +185,32 @@ describe('updater-api', () => {
   });
 
   describe('hasPendingUpdate', () => {
-    it('should return true when update is pending', async () => {
-      mockInvoke.mockResolvedValue(true);
+    it('should return the pending update state', async () => {
+      mockInvoke.mockResolvedValue({
+        pending: true,
+        rollback_available: true,
+        rollback_version: '1.0.0',
+      });
 
       const result = await hasPendingUpdate();
 
       expect(mockInvoke).toHaveBeenCalledWith('has_pending_update');
-      expect(result).toBe(true);
+      expect(result.pending).toBe(true);
+      expect(result.rollback_available).toBe(true);
+      expect(result.rollback_version).toBe('1.0.0');
     });
 
-    it('should return false when no update is pending', async () => {
-      mockInvoke.mockResolvedValue(false);
+    it('should report no pending update', async () => {
+      mockInvoke.mockResolvedValue({
+        pending: false,
+        rollback_available: false,
+        rollback_version: null,
+      });
 
       const result = await hasPendingUpdate();
 
-      expect(result).toBe(false);
+      expect(result.pending).toBe(false);
+      expect(result.rollback_version).toBeNull();
     });
   });
 
@@ -17,8 +17,10 @@ import type {
   ObservationLocation,
   ObservationLogData,
   ObservationSession,
+  ActiveSessionInfo,
   Observation,
   ObservationStats,
+  PeriodStats,
   CreatePlannedSessionPayload,
   ObservationQueryFilters,
   ObservationSearchHit,
@@ -174,6 +176,16 @@ export const locationsApi = {
     const invoke = await getInvoke();
     return invoke('get_current_location');
   },
+
+  async setHorizonProfile(points: [number, number][], locationId?: string): Promise<LocationsData> {
+    const invoke = await getInvoke();
+    return invoke('set_horizon_profile', { points, locationId });
+  },
+
+  async importHorizonProfile(path: string, locationId?: string): Promise<LocationsData> {
+    const invoke = await getInvoke();
+    return invoke('import_horizon_profile', { path, locationId });
+  },
 };
 
 // ============================================================================
@@ -225,6 +237,11 @@ export const observationLogApi = {
     return invoke('end_session', { sessionId });
   },
 
+  async getActiveSession(): Promise<ActiveSessionInfo | null> {
+    const invoke = await getInvoke();
+    return invoke('get_active_session');
+  },
+
   async deleteSession(sessionId: string): Promise<void> {
     const invoke = await getInvoke();
     return invoke('delete_session', { sessionId });
@@ -235,6 +252,11 @@ export const observationLogApi = {
     return invoke('get_observation_stats');
   },
 
+  async getStatsByPeriod(granularity: 'month' | 'year'): Promise<PeriodStats[]> {
+    const invoke = await getInvoke();
+    return invoke('get_observation_stats_by_period', { granularity });
+  },
+
   async search(
     queryOrFilters?: string | ObservationQueryFilters,
     filtersArg?: ObservationQueryFilters,
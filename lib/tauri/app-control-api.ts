@@ -11,6 +11,15 @@ import { createLogger } from '@/lib/logger';
 const logger = createLogger('app-control-api');
 
 export const TRAY_ACTIVATED_EVENT = 'skymap-tray-activated';
+export const ALL_OPERATIONS_CANCELLED_EVENT = 'all-operations-cancelled';
+
+/** What `cancelAllOperations` stopped */
+export interface CancelAllSummary {
+  http_requests: number;
+  solver_killed: boolean;
+  online_solves: number;
+  prefetches: number;
+}
 
 /**
  * Check if running in Tauri environment
@@ -62,6 +71,17 @@ export async function reloadWebview(): Promise<void> {
   await invoke('reload_webview');
 }
 
+/**
+ * Cancel every HTTP request, plate solve and prefetch in progress
+ */
+export async function cancelAllOperations(): Promise<CancelAllSummary | null> {
+  if (!isTauri()) {
+    logger.warn('cancelAllOperations is only available in Tauri environment');
+    return null;
+  }
+  return await invoke<CancelAllSummary>('cancel_all_operations');
+}
+
 /**
  * Check if running in development mode
  */
@@ -171,12 +171,14 @@ export const visibilityApi = {
     latitude: number,
     longitude: number,
     timestamp?: number,
-    minAltitude?: number
+    minAltitude?: number,
+    horizonProfile?: [number, number][]
   ): Promise<VisibilityInfo> {
     const invoke = await getInvoke();
     return invoke('calculate_visibility', { 
       ra, dec, latitude, longitude, timestamp, 
-      minAltitude: minAltitude ?? 0 
+      minAltitude: minAltitude ?? 0,
+      horizonProfile,
     });
   },
 
@@ -57,6 +57,12 @@ export interface CacheStats {
   surveys: SurveyCacheInfo[];
 }
 
+export interface SurveyCacheImport {
+  survey_id: string;
+  imported: number;
+  skipped: number;
+}
+
 // ============================================================================
 // Cache API
 // ============================================================================
@@ -190,6 +196,22 @@ export const cacheApi = {
     return invoke('clear_survey_cache', { surveyId });
   },
 
+  /**
+   * Pack a survey's cached tiles into a tar archive for offline sharing
+   */
+  async exportSurveyCache(survey: string, archivePath: string): Promise<number> {
+    const invoke = await getInvoke();
+    return invoke('export_survey_cache', { survey, archivePath });
+  },
+
+  /**
+   * Import a survey cache archive, keeping newer tiles already cached
+   */
+  async importSurveyCache(archivePath: string): Promise<SurveyCacheImport> {
+    const invoke = await getInvoke();
+    return invoke('import_survey_cache', { archivePath });
+  },
+
   /**
    * Clear all cached data
    */
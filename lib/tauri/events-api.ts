@@ -34,7 +34,12 @@ export type AstroEventType =
   | 'perihelion'
   | 'aphelion'
   | 'supermoon'
-  | 'blue_moon';
+  | 'blue_moon'
+  | 'sunrise'
+  | 'sunset'
+  | 'twilight'
+  | 'moonrise'
+  | 'moonset';
 
 export interface AstroEvent {
   id: string;
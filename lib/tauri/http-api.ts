@@ -59,6 +59,35 @@ export interface HttpClientConfig {
   follow_redirects: boolean;
   /** Maximum number of redirects to follow */
   max_redirects: number;
+  /** Log requests and keep recent summaries for getRequestLog */
+  log_requests?: boolean;
+}
+
+/**
+ * Summary of one logged request
+ */
+export interface RequestLogEntry {
+  method: string;
+  /** URL with API key query values redacted */
+  url: string;
+  status: number | null;
+  error: string | null;
+  duration_ms: number;
+  timestamp: number;
+}
+
+/**
+ * Sliding-window rate limit, used for per-domain overrides
+ */
+export interface RateLimitConfig {
+  /** Requests allowed per window */
+  max_requests: number;
+  /** Window length in seconds */
+  window_seconds: number;
+  /** Ban the caller once the limit is exceeded */
+  ban_on_exceed: boolean;
+  /** Ban length in seconds */
+  ban_duration_seconds: number | null;
 }
 
 /**
@@ -239,6 +268,30 @@ export const httpApi = {
     return invoke('set_http_config', { config: { ...currentConfig, ...config } });
   },
 
+  /**
+   * Get recent request summaries (requires log_requests)
+   */
+  async getRequestLog(): Promise<RequestLogEntry[]> {
+    const invoke = await getInvoke();
+    return invoke('get_request_log');
+  },
+
+  /**
+   * Get user-configured per-domain rate limits
+   */
+  async getDomainRateLimits(): Promise<Record<string, RateLimitConfig>> {
+    const invoke = await getInvoke();
+    return invoke('get_domain_rate_limits');
+  },
+
+  /**
+   * Persist a rate limit for a domain and its subdomains
+   */
+  async setDomainRateLimit(domain: string, config: RateLimitConfig): Promise<void> {
+    const invoke = await getInvoke();
+    return invoke('set_domain_rate_limit', { domain, config });
+  },
+
   /**
    * Execute a custom HTTP request
    */
@@ -88,6 +88,7 @@ export type {
   UpdateProgress,
   UpdateStatus,
   UpdateCheckOptions,
+  PendingUpdateState,
 } from './updater-api';
 
 // Updater Hooks
@@ -105,7 +106,10 @@ export {
   minimizeWindow,
   toggleMaximizeWindow,
   isWindowMaximized,
+  cancelAllOperations,
+  ALL_OPERATIONS_CANCELLED_EVENT,
 } from './app-control-api';
+export type { CancelAllSummary } from './app-control-api';
 
 // Mount API (desktop only)
 export { mountApi } from './mount-api';
@@ -50,9 +50,9 @@ export const mapKeysApi = {
     return invoke('list_map_api_keys_meta');
   },
 
-  async get(keyId: string): Promise<string | null> {
+  async get(keyId: string, requestUrl?: string): Promise<string | null> {
     const invoke = await getInvoke();
-    return invoke('get_map_api_key', { keyId });
+    return invoke('get_map_api_key', { keyId, requestUrl });
   },
 
   async remove(keyId: string): Promise<void> {
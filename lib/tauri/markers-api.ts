@@ -51,6 +51,16 @@ export interface MarkerInput {
   updated_at?: number;
 }
 
+/** Bundled catalog subset; unset fields match everything */
+export interface CatalogFilter {
+  /** "Messier" (or "M"), "NGC" or "IC" */
+  catalog?: string;
+  object_types?: string[];
+  max_magnitude?: number;
+  /** IAU abbreviation or full name */
+  constellation?: string;
+}
+
 export interface MarkerUpdateInput {
   name?: string;
   description?: string | null;
@@ -106,6 +116,11 @@ export const markersApi = {
     return invoke('remove_markers_by_group', { group });
   },
 
+  async addMarkersFromCatalog(filter: CatalogFilter, group: string): Promise<MarkersData> {
+    const invoke = await getInvoke();
+    return invoke('add_markers_from_catalog', { filter, group });
+  },
+
   async clearAll(): Promise<MarkersData> {
     const invoke = await getInvoke();
     return invoke('clear_all_markers');
@@ -80,10 +80,11 @@ export const pathConfigApi = {
 
   /**
    * Migrate data to a new directory
+   * Fails if the target already holds app data unless `overwrite` is set
    */
-  async migrateDataDir(targetDir: string): Promise<MigrationResult> {
+  async migrateDataDir(targetDir: string, overwrite = false): Promise<MigrationResult> {
     const invoke = await getInvoke();
-    return invoke('migrate_data_dir', { targetDir });
+    return invoke('migrate_data_dir', { targetDir, overwrite });
   },
 
   /**
@@ -81,6 +81,8 @@ export interface SolverConfig {
   auto_hints: boolean;
   retry_on_failure: boolean;
   max_retries: number;
+  /** Extra executables or directories searched before the built-in locations */
+  extra_solver_paths?: string[];
 }
 
 export interface SolveParameters {
@@ -192,6 +194,7 @@ export interface OnlineSolveConfig {
   crpix_center?: boolean;
   parity?: number;
   timeout_seconds?: number;
+  poll_interval_seconds?: number;
   publicly_visible?: boolean;
 }
 
@@ -309,6 +312,7 @@ export const DEFAULT_SOLVER_CONFIG: SolverConfig = {
   auto_hints: true,
   retry_on_failure: false,
   max_retries: 2,
+  extra_solver_paths: [],
 };
 
 // ============================================================================
@@ -497,6 +501,20 @@ export async function cancelOnlineSolve(operationId?: string): Promise<boolean>
   return invoke<boolean>('cancel_online_solve', { operationId: operationId ?? null });
 }
 
+/**
+ * Forget cached Astrometry.net session keys so the next online solve logs in again.
+ */
+export async function clearAstrometrySession(): Promise<void> {
+  return invoke<void>('clear_astrometry_session');
+}
+
+/**
+ * Write a solved WCS to a standalone FITS header file (.wcs).
+ */
+export async function writeWcsFile(wcs: OnlineWcsResult, path: string): Promise<void> {
+  return invoke<void>('write_wcs_file', { wcs, path });
+}
+
 // ============================================================================
 // Legacy Plate Solver API (backward compatibility)
 // ============================================================================
@@ -716,6 +734,8 @@ export const plateSolverApi = {
   // Online Solving API
   solveOnline,
   cancelOnlineSolve,
+  clearAstrometrySession,
+  writeWcsFile,
   // Legacy API
   plateSolve,
   getSolverIndexes,
@@ -28,6 +28,22 @@ export interface MosaicSettings {
   overlap: number;
 }
 
+export interface ExposurePlanInputs {
+  read_noise: number;
+  dark_current: number;
+  gain: number;
+  sqm: number;
+  /** arcsec per pixel */
+  pixel_scale: number;
+  filter_bandwidth_nm: number;
+  aperture_mm?: number;
+  quantum_efficiency?: number;
+  full_well?: number;
+  read_noise_limit_percent?: number;
+}
+
+export type ExposurePlanAdvanced = NonNullable<ExposurePlan['advanced']>;
+
 export interface ExposurePlan {
   single_exposure: number;
   total_exposure: number;
@@ -77,12 +93,15 @@ export interface TargetItem {
   dec: number;
   ra_string: string;
   dec_string: string;
+  constellation?: string;
+  object_type?: string;
   sensor_width?: number;
   sensor_height?: number;
   focal_length?: number;
   rotation_angle?: number;
   mosaic?: MosaicSettings;
   exposure_plan?: ExposurePlan;
+  captured_exposure_minutes?: number;
   notes?: string;
   added_at: number;
   priority: TargetPriority;
@@ -135,6 +154,8 @@ export interface TargetStats {
   medium_priority: number;
   low_priority: number;
   by_tag: [string, number][];
+  captured_hours: number;
+  planned_hours: number;
 }
 
 // ============================================================================
@@ -206,6 +227,11 @@ export const targetListApi = {
     return invoke('set_targets_priority_batch', { targetIds, priority });
   },
 
+  async recordProgress(targetId: string, minutes: number): Promise<TargetListData> {
+    const invoke = await getInvoke();
+    return invoke('record_target_progress', { targetId, minutes });
+  },
+
   async addTagToTargets(targetIds: string[], tag: string): Promise<TargetListData> {
     const invoke = await getInvoke();
     return invoke('add_tag_to_targets', { targetIds, tag });
@@ -241,6 +267,11 @@ export const targetListApi = {
     return invoke('get_target_stats');
   },
 
+  async calculateExposurePlanAdvanced(inputs: ExposurePlanInputs): Promise<ExposurePlanAdvanced> {
+    const invoke = await getInvoke();
+    return invoke('calculate_exposure_plan_advanced', { inputs });
+  },
+
   isAvailable: isTauri,
 };
 
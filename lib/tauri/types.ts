@@ -2,6 +2,8 @@
  * TypeScript types for Tauri Rust backend commands
  */
 
+import type { MoonPhase } from './astronomy-api';
+
 // ============================================================================
 // Equipment Types
 // ============================================================================
@@ -71,6 +73,9 @@ export interface Filter {
   name: string;
   filter_type: FilterType;
   bandwidth?: number;
+  center_nm?: number;
+  bandwidth_nm?: number;
+  peak_transmission?: number;
   notes?: string;
   created_at: string;
   updated_at: string;
@@ -99,6 +104,8 @@ export interface ObservationLocation {
   notes?: string;
   is_default: boolean;
   is_current: boolean;
+  /** Local horizon as [azimuth, altitude] points in degrees */
+  horizon_profile?: [number, number][];
   created_at: string;
   updated_at: string;
 }
@@ -257,14 +264,31 @@ export interface ObservationSession {
   execution_targets?: ObservationExecutionTarget[];
   weather_snapshot?: unknown;
   execution_summary?: ObservationExecutionSummary;
+  sky_snapshot?: SkySnapshot;
   created_at: string;
   updated_at: string;
 }
 
+export interface SkySnapshot {
+  latitude: number;
+  longitude: number;
+  moon: MoonPhase;
+  /** Astronomical dusk, Unix seconds */
+  darkness_start?: number;
+  /** Astronomical dawn, Unix seconds */
+  darkness_end?: number;
+}
+
 export interface ObservationLogData {
   sessions: ObservationSession[];
 }
 
+export interface ActiveSessionInfo {
+  session: ObservationSession;
+  elapsed_seconds: number;
+  observation_count: number;
+}
+
 export interface ObservationStats {
   total_sessions: number;
   total_observations: number;
@@ -274,6 +298,14 @@ export interface ObservationStats {
   monthly_counts: [string, number][];
 }
 
+/** Activity in one month (YYYY-MM) or year (YYYY) of the observation log */
+export interface PeriodStats {
+  period: string;
+  session_count: number;
+  integration_hours: number;
+  distinct_targets: number;
+}
+
 // ============================================================================
 // Target Import/Export Types
 // ============================================================================
@@ -34,6 +34,7 @@ export interface CacheEntryMeta {
   etag: string | null;
   access_count: number;
   last_access: number;
+  namespace?: string | null;
 }
 
 export interface UnifiedCacheStats {
@@ -53,11 +54,11 @@ export interface UnifiedCacheStats {
 
 export const unifiedCacheApi = {
   /**
-   * Get a cache entry
+   * Get a cache entry, optionally from a namespace
    */
-  async getEntry(key: string): Promise<UnifiedCacheResponse | null> {
+  async getEntry(key: string, namespace?: string): Promise<UnifiedCacheResponse | null> {
     const invoke = await getInvoke();
-    return invoke('get_unified_cache_entry', { key });
+    return invoke('get_unified_cache_entry', { key, namespace });
   },
 
   /**
@@ -67,7 +68,8 @@ export const unifiedCacheApi = {
     key: string,
     data: Uint8Array,
     contentType: string,
-    ttl?: number
+    ttl?: number,
+    namespace?: string
   ): Promise<void> {
     const invoke = await getInvoke();
     return invoke('put_unified_cache_entry', {
@@ -75,23 +77,26 @@ export const unifiedCacheApi = {
       data: Array.from(data),
       contentType,
       ttl,
+      namespace,
     });
   },
 
   /**
    * Delete a cache entry
    */
-  async deleteEntry(key: string): Promise<boolean> {
+  async deleteEntry(key: string, namespace?: string): Promise<boolean> {
     const invoke = await getInvoke();
-    return invoke('delete_unified_cache_entry', { key });
+    return invoke('delete_unified_cache_entry', { key, namespace });
   },
 
   /**
-   * Clear all cache entries
+   * Clear all cache entries, or only those in a namespace
    */
-  async clearCache(): Promise<number> {
+  async clearCache(namespace?: string): Promise<number> {
     const invoke = await getInvoke();
-    return invoke('clear_unified_cache');
+    return namespace === undefined
+      ? invoke('clear_unified_cache')
+      : invoke('clear_unified_cache', { namespace });
   },
 
   /**
@@ -103,11 +108,13 @@ export const unifiedCacheApi = {
   },
 
   /**
-   * List all cache keys
+   * List all cache keys (`namespace:key`), or the keys within a namespace
    */
-  async listKeys(): Promise<string[]> {
+  async listKeys(namespace?: string): Promise<string[]> {
     const invoke = await getInvoke();
-    return invoke('list_unified_cache_keys');
+    return namespace === undefined
+      ? invoke('list_unified_cache_keys')
+      : invoke('list_unified_cache_keys', { namespace });
   },
 
   /**
@@ -23,6 +23,12 @@ export type UpdateStatus =
   | { status: 'ready'; data: UpdateInfo }
   | { status: 'error'; data: string };
 
+export interface PendingUpdateState {
+  pending: boolean;
+  rollback_available: boolean;
+  rollback_version: string | null;
+}
+
 export interface UpdateCheckOptions {
   silent?: boolean;
 }
@@ -81,8 +87,8 @@ export async function clearPendingUpdate(): Promise<void> {
   await invoke('clear_pending_update');
 }
 
-export async function hasPendingUpdate(): Promise<boolean> {
-  return await invoke<boolean>('has_pending_update');
+export async function hasPendingUpdate(): Promise<PendingUpdateState> {
+  return await invoke<PendingUpdateState>('has_pending_update');
 }
 
 export async function onUpdateProgress(
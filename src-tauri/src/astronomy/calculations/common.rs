@@ -44,14 +44,25 @@ pub const _AZIMUTH_NORTH_ZERO_EAST_NINETY: bool = true;
 // Regex Patterns
 // ============================================================================
 
-/// Static compiled regex for RA parsing (HMS format)
+/// Static compiled regex for RA parsing: "12h 30m 45s", "12:30:45", "12 30 45"
 pub static RA_HMS_REGEX: Lazy<regex_lite::Regex> = Lazy::new(|| {
-    regex_lite::Regex::new(r"(\d+)[h:\s]+(\d+)[m:\s]+(\d+\.?\d*)s?").unwrap()
+    regex_lite::Regex::new(
+        r"^\s*(\d{1,2})\s*(?:h|:|\s)\s*(\d{1,2})\s*(?:m|:|\s)\s*(\d{1,2}(?:\.\d*)?)\s*s?\s*$",
+    )
+    .unwrap()
 });
 
-/// Static compiled regex for Dec parsing (DMS format)
+/// Static compiled regex for RA in decimal hours: "12.5h"
+pub static RA_DECIMAL_HOURS_REGEX: Lazy<regex_lite::Regex> = Lazy::new(|| {
+    regex_lite::Regex::new(r"^\s*([+-]?\d+(?:\.\d*)?)\s*h\s*$").unwrap()
+});
+
+/// Static compiled regex for Dec parsing: "+45° 30' 15\"", "-45:30:15", "-45 30 15"
 pub static DEC_DMS_REGEX: Lazy<regex_lite::Regex> = Lazy::new(|| {
-    regex_lite::Regex::new(r#"([+-]?\d+)[°:\s]+(\d+)[':\s]+(\d+\.?\d*)"?"#).unwrap()
+    regex_lite::Regex::new(
+        r#"^\s*([+-]?)\s*(\d{1,2})\s*(?:°|d|:|\s)\s*(\d{1,2})\s*(?:'|m|:|\s)\s*(\d{1,2}(?:\.\d*)?)\s*(?:"|s|'')?\s*$"#,
+    )
+    .unwrap()
 });
 
 // ============================================================================
@@ -8,8 +8,10 @@ use super::common::{
     atmospheric_refraction, calculate_obliquity, normalize_degrees, DEG_TO_RAD,
     EQ_TO_GAL_MATRIX, GAL_TO_EQ_MATRIX, RAD_TO_DEG,
 };
-use super::time::{calculate_hour_angle, calculate_lst, datetime_to_jd};
-use super::types::{EclipticCoords, EquatorialCoords, GalacticCoords, HorizontalCoords};
+use super::time::{calculate_hour_angle, calculate_lst, datetime_to_jd, SkyContext};
+use super::types::{
+    CoordinateGrid, EclipticCoords, EquatorialCoords, GalacticCoords, GridLine, HorizontalCoords,
+};
 
 // ============================================================================
 // Coordinate Conversions
@@ -27,12 +29,55 @@ pub fn equatorial_to_horizontal(
     timestamp: Option<i64>,
     apply_refraction: Option<bool>,
 ) -> HorizontalCoords {
+    let lst = lst_at(timestamp, longitude);
+    horizontal_at_lst(ra, dec, latitude, lst, apply_refraction.unwrap_or(true))
+}
+
+/// Convert many equatorial positions to horizontal coordinates at one instant,
+/// computing local sidereal time once. Refraction is applied as in
+/// [`equatorial_to_horizontal`].
+#[tauri::command]
+pub fn batch_equatorial_to_horizontal(
+    coords: Vec<EquatorialCoords>,
+    latitude: f64,
+    longitude: f64,
+    timestamp: Option<i64>,
+) -> Vec<HorizontalCoords> {
+    let lst = lst_at(timestamp, longitude);
+    coords
+        .iter()
+        .map(|c| horizontal_at_lst(c.ra, c.dec, latitude, lst, true))
+        .collect()
+}
+
+/// [`equatorial_to_horizontal`] using a precomputed [`SkyContext`]
+pub fn equatorial_to_horizontal_with_context(
+    ra: f64,
+    dec: f64,
+    latitude: f64,
+    context: &SkyContext,
+    apply_refraction: bool,
+) -> HorizontalCoords {
+    horizontal_at_lst(ra, dec, latitude, context.lst, apply_refraction)
+}
+
+/// Local sidereal time (degrees) for a timestamp, defaulting to now
+fn lst_at(timestamp: Option<i64>, longitude: f64) -> f64 {
     let dt = timestamp
         .map(|ts| DateTime::from_timestamp(ts, 0).unwrap_or_else(Utc::now))
         .unwrap_or_else(Utc::now);
 
-    let jd = datetime_to_jd(&dt);
-    let lst = calculate_lst(jd, longitude);
+    calculate_lst(datetime_to_jd(&dt), longitude)
+}
+
+/// Horizontal coordinates of an equatorial position for a given local sidereal time
+pub(crate) fn horizontal_at_lst(
+    ra: f64,
+    dec: f64,
+    latitude: f64,
+    lst: f64,
+    apply_refraction: bool,
+) -> HorizontalCoords {
     let ha = calculate_hour_angle(lst, ra);
 
     let ha_rad = ha * DEG_TO_RAD;
@@ -50,7 +95,7 @@ pub fn equatorial_to_horizontal(
     };
 
     let alt_deg = alt * RAD_TO_DEG;
-    let corrected_alt = if apply_refraction.unwrap_or(true) {
+    let corrected_alt = if apply_refraction {
         alt_deg + atmospheric_refraction(alt_deg)
     } else {
         alt_deg
@@ -228,6 +273,147 @@ pub fn angular_separation(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> f64 {
     cos_sep.clamp(-1.0, 1.0).acos() * RAD_TO_DEG
 }
 
+/// Precess equatorial coordinates (degrees) between two epochs given as
+/// Julian years, using the rigorous IAU 1976 angles (Meeus, ch. 21)
+pub fn precess_equatorial(ra: f64, dec: f64, from_epoch: f64, to_epoch: f64) -> (f64, f64) {
+    if from_epoch == to_epoch {
+        return (ra, dec);
+    }
+    let big_t = (from_epoch - 2000.0) / 100.0;
+    let t = (to_epoch - from_epoch) / 100.0;
+    let (t2, t3) = (t * t, t * t * t);
+    let arcsec = DEG_TO_RAD / 3600.0;
+
+    let linear = 2306.2181 + 1.39656 * big_t - 0.000139 * big_t * big_t;
+    let zeta = (linear * t + (0.30188 - 0.000344 * big_t) * t2 + 0.017998 * t3) * arcsec;
+    let z = (linear * t + (1.09468 + 0.000066 * big_t) * t2 + 0.018203 * t3) * arcsec;
+    let theta = ((2004.3109 - 0.85330 * big_t - 0.000217 * big_t * big_t) * t
+        - (0.42665 + 0.000217 * big_t) * t2
+        - 0.041833 * t3)
+        * arcsec;
+
+    let ra_rad = ra * DEG_TO_RAD;
+    let dec_rad = dec * DEG_TO_RAD;
+    let a = dec_rad.cos() * (ra_rad + zeta).sin();
+    let b = theta.cos() * dec_rad.cos() * (ra_rad + zeta).cos() - theta.sin() * dec_rad.sin();
+    let c = theta.sin() * dec_rad.cos() * (ra_rad + zeta).cos() + theta.cos() * dec_rad.sin();
+
+    let ra_out = ((a.atan2(b) + z) * RAD_TO_DEG).rem_euclid(360.0);
+    let dec_out = c.clamp(-1.0, 1.0).asin() * RAD_TO_DEG;
+    (ra_out, dec_out)
+}
+
+/// Angular separation between positions referred to different epochs
+/// (Julian years, e.g. 2000.0 for a catalog and the current year for JNow);
+/// the second position is precessed to the first's epoch before comparing
+#[tauri::command]
+pub fn angular_separation_epoch(ra1: f64, dec1: f64, epoch1: f64, ra2: f64, dec2: f64, epoch2: f64) -> f64 {
+    let (ra2, dec2) = precess_equatorial(ra2, dec2, epoch2, epoch1);
+    angular_separation(ra1, dec1, ra2, dec2)
+}
+
+// ============================================================================
+// Coordinate Grid
+// ============================================================================
+
+/// Largest angular gap between consecutive points of a grid polyline
+const GRID_DENSIFY_DEG: f64 = 1.0;
+/// Upper bound on grid lines per direction, guarding against tiny steps
+const MAX_GRID_LINES: usize = 3600;
+
+/// RA/Dec grid polylines for the box `ra_min..ra_max`, `dec_min..dec_max` (degrees).
+/// `ra_min > ra_max` means the box wraps through RA 0h; lines crossing it are
+/// split there. Meridians run into the poles, where parallels degenerate to a
+/// point and are left out; parallels are densified by true arc length so
+/// near-polar circles don't carry needless points.
+#[tauri::command]
+pub fn generate_coordinate_grid(
+    ra_min: f64,
+    ra_max: f64,
+    dec_min: f64,
+    dec_max: f64,
+    ra_step: f64,
+    dec_step: f64,
+) -> Result<CoordinateGrid, String> {
+    if !(ra_step > 0.0 && dec_step > 0.0) {
+        return Err("Grid steps must be positive".to_string());
+    }
+    if !(-90.0..=90.0).contains(&dec_min) || !(-90.0..=90.0).contains(&dec_max) || dec_min >= dec_max {
+        return Err(format!("Invalid declination range {}..{}", dec_min, dec_max));
+    }
+    let ra_start = ra_min.rem_euclid(360.0);
+    let mut ra_span = ra_max - ra_min;
+    if ra_span <= 0.0 || ra_span > 360.0 {
+        ra_span = ra_span.rem_euclid(360.0);
+        if ra_span == 0.0 {
+            ra_span = 360.0;
+        }
+    }
+    let full_circle = ra_span >= 360.0;
+
+    let meridian_values = grid_values(ra_start, ra_start + ra_span, ra_step)?;
+    let meridians = meridian_values
+        .into_iter()
+        // On a full circle the last meridian repeats the first
+        .filter(|&ra| !(full_circle && ra >= ra_start + 360.0 - 1e-9))
+        .map(|ra| {
+            let ra = ra.rem_euclid(360.0);
+            let points = densify(dec_min, dec_max, GRID_DENSIFY_DEG)
+                .map(|dec| EquatorialCoords { ra, dec })
+                .collect();
+            GridLine { value: ra, segments: vec![points] }
+        })
+        .collect();
+
+    let parallels = grid_values(dec_min, dec_max, dec_step)?
+        .into_iter()
+        .filter(|dec| dec.abs() < 90.0)
+        .map(|dec| {
+            let arc_step = GRID_DENSIFY_DEG / (dec * DEG_TO_RAD).cos();
+            let ras = densify(ra_start, ra_start + ra_span, arc_step);
+            GridLine { value: dec, segments: split_at_ra_wrap(ras, dec) }
+        })
+        .collect();
+
+    Ok(CoordinateGrid { meridians, parallels })
+}
+
+/// Multiples of `step` within `[start, end]`
+fn grid_values(start: f64, end: f64, step: f64) -> Result<Vec<f64>, String> {
+    let first = (start / step - 1e-9).ceil() as i64;
+    let last = (end / step + 1e-9).floor() as i64;
+    let count = (last - first + 1).max(0) as usize;
+    if count > MAX_GRID_LINES {
+        return Err(format!("Grid step {} would produce {} lines", step, count));
+    }
+    Ok((first..=last).map(|k| k as f64 * step).collect())
+}
+
+/// Evenly spaced values from `start` to `end` inclusive, at most `max_step` apart
+fn densify(start: f64, end: f64, max_step: f64) -> impl Iterator<Item = f64> {
+    let n = ((end - start) / max_step).ceil().max(1.0) as usize;
+    (0..=n).map(move |i| start + (end - start) * i as f64 / n as f64)
+}
+
+/// Turn unwrapped RA values (possibly past 360°) into polylines, breaking
+/// at RA 0h so no segment jumps across the whole map
+fn split_at_ra_wrap(ras: impl Iterator<Item = f64>, dec: f64) -> Vec<Vec<EquatorialCoords>> {
+    let mut segments = vec![Vec::new()];
+    let mut previous: Option<f64> = None;
+    for ra in ras {
+        if previous.is_some_and(|p| p <= 360.0) && ra > 360.0 {
+            if previous != Some(360.0) {
+                segments.last_mut().unwrap().push(EquatorialCoords { ra: 360.0, dec });
+            }
+            segments.push(vec![EquatorialCoords { ra: 0.0, dec }]);
+        }
+        let wrapped = if ra > 360.0 { ra - 360.0 } else { ra };
+        segments.last_mut().unwrap().push(EquatorialCoords { ra: wrapped, dec });
+        previous = Some(ra);
+    }
+    segments
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -386,6 +572,65 @@ mod tests {
         }
     }
 
+    // ------------------------------------------------------------------------
+    // Coordinate Grid Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_coordinate_grid_full_sky_line_counts() {
+        let grid = generate_coordinate_grid(0.0, 360.0, -90.0, 90.0, 30.0, 30.0).unwrap();
+        // 0h..22h every 2h; 0h is not repeated at 360°
+        assert_eq!(grid.meridians.len(), 12);
+        // -60..60 every 30°, the poles being single points
+        let decs: Vec<f64> = grid.parallels.iter().map(|line| line.value).collect();
+        assert_eq!(decs, vec![-60.0, -30.0, 0.0, 30.0, 60.0]);
+
+        for meridian in &grid.meridians {
+            let points = &meridian.segments[0];
+            assert_eq!(points.first().unwrap().dec, -90.0);
+            assert_eq!(points.last().unwrap().dec, 90.0);
+            assert!(points.windows(2).all(|w| w[1].dec - w[0].dec <= GRID_DENSIFY_DEG + 1e-9));
+        }
+
+        // Parallels are densified by arc length: fewer points near the pole
+        let equator = grid.parallels.iter().find(|l| l.value == 0.0).unwrap();
+        let high = grid.parallels.iter().find(|l| l.value == 60.0).unwrap();
+        assert_eq!(equator.segments.len(), 1);
+        assert!(high.segments[0].len() < equator.segments[0].len());
+    }
+
+    #[test]
+    fn test_coordinate_grid_box_counts() {
+        let grid = generate_coordinate_grid(10.0, 50.0, 20.0, 40.0, 10.0, 5.0).unwrap();
+        let ras: Vec<f64> = grid.meridians.iter().map(|line| line.value).collect();
+        assert_eq!(ras, vec![10.0, 20.0, 30.0, 40.0, 50.0]);
+        assert_eq!(grid.parallels.len(), 5);
+    }
+
+    #[test]
+    fn test_coordinate_grid_splits_at_ra_zero() {
+        let grid = generate_coordinate_grid(350.0, 10.0, -10.0, 10.0, 5.0, 10.0).unwrap();
+        let ras: Vec<f64> = grid.meridians.iter().map(|line| line.value).collect();
+        assert_eq!(ras, vec![350.0, 355.0, 0.0, 5.0, 10.0]);
+
+        let equator = grid.parallels.iter().find(|l| l.value == 0.0).unwrap();
+        assert_eq!(equator.segments.len(), 2);
+        let (east, west) = (&equator.segments[0], &equator.segments[1]);
+        assert_eq!(east.first().unwrap().ra, 350.0);
+        assert_eq!(east.last().unwrap().ra, 360.0);
+        assert_eq!(west.first().unwrap().ra, 0.0);
+        assert!((west.last().unwrap().ra - 10.0).abs() < 1e-9);
+        assert!(east.iter().chain(west.iter()).all(|p| (0.0..=360.0).contains(&p.ra)));
+    }
+
+    #[test]
+    fn test_coordinate_grid_rejects_bad_input() {
+        assert!(generate_coordinate_grid(0.0, 360.0, -90.0, 90.0, 0.0, 10.0).is_err());
+        assert!(generate_coordinate_grid(0.0, 360.0, 40.0, 20.0, 10.0, 10.0).is_err());
+        assert!(generate_coordinate_grid(0.0, 360.0, -90.0, 91.0, 10.0, 10.0).is_err());
+        assert!(generate_coordinate_grid(0.0, 360.0, -90.0, 90.0, 1e-6, 10.0).is_err());
+    }
+
     // ------------------------------------------------------------------------
     // Angular Separation Tests
     // ------------------------------------------------------------------------
@@ -409,4 +654,64 @@ mod tests {
         let sep = angular_separation(0.0, 0.0, 90.0, 0.0);
         assert!(approx_eq(sep, 90.0, 0.01), "Should be 90°, got {}", sep);
     }
+
+    #[test]
+    fn test_precess_equatorial_meeus_example() {
+        // Meeus example 21.b: theta Persei from J2000.0 to 2028 Nov 13.19 TD
+        let epoch = 2000.0 + (2462088.69 - 2451545.0) / 365.25;
+        let (ra, dec) = precess_equatorial(41.054063, 49.227750, 2000.0, epoch);
+        assert!(approx_eq(ra, 41.547214, 1e-5), "ra {}", ra);
+        assert!(approx_eq(dec, 49.348483, 1e-5), "dec {}", dec);
+
+        // Precessing back returns the original position
+        let (ra_back, dec_back) = precess_equatorial(ra, dec, epoch, 2000.0);
+        assert!(angular_separation(ra_back, dec_back, 41.054063, 49.227750) < 1e-6);
+    }
+
+    #[test]
+    fn test_angular_separation_epoch_same_epoch_matches() {
+        for (ra1, dec1, ra2, dec2) in [(10.68, 41.27, 83.82, -5.39), (37.95, 89.26, 250.0, 88.0), (0.0, 0.0, 359.0, 1.0)] {
+            let plain = angular_separation(ra1, dec1, ra2, dec2);
+            let epoch = angular_separation_epoch(ra1, dec1, 2025.0, ra2, dec2, 2025.0);
+            assert!(approx_eq(plain, epoch, EPSILON), "{} vs {}", plain, epoch);
+        }
+    }
+
+    #[test]
+    fn test_angular_separation_epoch_near_pole() {
+        // Polaris J2000 compared with identical numbers read out at epoch 2050
+        let (ra, dec) = (37.95, 89.26);
+        assert_eq!(angular_separation(ra, dec, ra, dec), 0.0);
+        let mixed = angular_separation_epoch(ra, dec, 2000.0, ra, dec, 2050.0);
+        assert!(mixed > 0.2, "separation {}", mixed);
+
+        // The same star correctly precessed to 2050 is recognised as coincident
+        let (ra_2050, dec_2050) = precess_equatorial(ra, dec, 2000.0, 2050.0);
+        let matched = angular_separation_epoch(ra, dec, 2000.0, ra_2050, dec_2050, 2050.0);
+        assert!(matched < ARCSEC_IN_DEGREES * 0.01, "separation {}", matched);
+    }
+
+    // ------------------------------------------------------------------------
+    // Batch Conversion Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_batch_equatorial_to_horizontal_matches_single() {
+        let ts = Some(1704067200);
+        let coords: Vec<EquatorialCoords> = (0..50)
+            .map(|i| EquatorialCoords { ra: i as f64 * 7.3, dec: -80.0 + i as f64 * 3.3 })
+            .collect();
+
+        let batch = batch_equatorial_to_horizontal(coords.clone(), 45.0, -75.0, ts);
+        assert_eq!(batch.len(), coords.len());
+        for (c, h) in coords.iter().zip(&batch) {
+            let single = equatorial_to_horizontal(c.ra, c.dec, 45.0, -75.0, ts, None);
+            assert!(approx_eq(h.alt, single.alt, 1e-12) && approx_eq(h.az, single.az, 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_batch_equatorial_to_horizontal_empty() {
+        assert!(batch_equatorial_to_horizontal(Vec::new(), 45.0, 0.0, Some(0)).is_empty());
+    }
 }
@@ -1,60 +1,95 @@
 //! Coordinate formatting and parsing
 //! RA/Dec format conversion between degrees and HMS/DMS strings
 
-use super::common::{DEC_DMS_REGEX, HOURS_TO_DEG, RA_HMS_REGEX};
+use super::common::{DEC_DMS_REGEX, HOURS_TO_DEG, RA_DECIMAL_HOURS_REGEX, RA_HMS_REGEX};
 
 // ============================================================================
 // Coordinate Formatting
 // ============================================================================
 
+/// Decimal places used by the default formatters
+const DEFAULT_SECONDS_DECIMALS: u8 = 2;
+
+/// Largest supported number of decimal places on seconds
+const MAX_SECONDS_DECIMALS: u8 = 9;
+
+/// Split a value in units of `1 / scale` seconds into (units, minutes, seconds)
+/// and format the seconds with `decimals` places
+fn split_sexagesimal(total: i64, scale: i64, decimals: u8) -> (i64, i64, String) {
+    let per_minute = 60 * scale;
+    let per_unit = 60 * per_minute;
+    let units = total / per_unit;
+    let minutes = (total % per_unit) / per_minute;
+    let sub_minute = total % per_minute;
+
+    let seconds = if decimals == 0 {
+        format!("{:02}", sub_minute)
+    } else {
+        format!(
+            "{:02}.{:0width$}",
+            sub_minute / scale,
+            sub_minute % scale,
+            width = decimals as usize
+        )
+    };
+    (units, minutes, seconds)
+}
+
 /// Format RA as HMS string
 #[tauri::command]
 pub fn format_ra_hms(ra_deg: f64) -> String {
-    let ra_hours = ra_deg / 15.0;
-    let h = ra_hours.floor() as i32;
-    let m_float = (ra_hours - h as f64) * 60.0;
-    let m = m_float.floor() as i32;
-    let s = (m_float - m as f64) * 60.0;
+    format_ra_hms_precision(ra_deg, DEFAULT_SECONDS_DECIMALS)
+}
 
-    format!("{:02}h {:02}m {:05.2}s", h, m, s)
+/// Format RA as HMS string with `decimals` places on the seconds.
+/// Rounding carries into minutes and hours, and 24h wraps to 00h.
+#[tauri::command]
+pub fn format_ra_hms_precision(ra_deg: f64, decimals: u8) -> String {
+    let decimals = decimals.min(MAX_SECONDS_DECIMALS);
+    let scale = 10_i64.pow(decimals as u32);
+    let day = 24 * 3600 * scale;
+    let total = ((ra_deg / HOURS_TO_DEG * 3600.0 * scale as f64).round() as i64).rem_euclid(day);
+
+    let (h, m, s) = split_sexagesimal(total, scale, decimals);
+    format!("{:02}h {:02}m {}s", h, m, s)
 }
 
 /// Format Dec as DMS string
 #[tauri::command]
 pub fn format_dec_dms(dec_deg: f64) -> String {
+    format_dec_dms_precision(dec_deg, DEFAULT_SECONDS_DECIMALS)
+}
+
+/// Format Dec as DMS string with `decimals` places on the seconds.
+/// Rounding carries into arcminutes and degrees.
+#[tauri::command]
+pub fn format_dec_dms_precision(dec_deg: f64, decimals: u8) -> String {
+    let decimals = decimals.min(MAX_SECONDS_DECIMALS);
+    let scale = 10_i64.pow(decimals as u32);
     let sign = if dec_deg >= 0.0 { "+" } else { "-" };
-    let dec_abs = dec_deg.abs();
-    let d = dec_abs.floor() as i32;
-    let m_float = (dec_abs - d as f64) * 60.0;
-    let m = m_float.floor() as i32;
-    let s = (m_float - m as f64) * 60.0;
+    let total = (dec_deg.abs() * 3600.0 * scale as f64).round() as i64;
 
-    format!("{}{}° {:02}' {:05.2}\"", sign, d, m, s)
+    let (d, m, s) = split_sexagesimal(total, scale, decimals);
+    format!("{}{}° {:02}' {}\"", sign, d, m, s)
 }
 
-/// Parse RA from HMS string
+/// Parse a numeric regex capture group
+fn capture_f64(caps: &regex_lite::Captures, index: usize) -> Result<f64, String> {
+    caps.get(index)
+        .ok_or_else(|| format!("Missing component {}", index))?
+        .as_str()
+        .parse()
+        .map_err(|e: std::num::ParseFloatError| e.to_string())
+}
+
+/// Parse RA from "12h 30m 45s", "12:30:45", "12 30 45", decimal hours
+/// ("12.5h"), or decimal degrees
 #[tauri::command]
 pub fn parse_ra_hms(ra_str: String) -> Result<f64, String> {
-    // Try various formats using static compiled regex
     if let Some(caps) = RA_HMS_REGEX.captures(&ra_str) {
-        let h: f64 = caps
-            .get(1)
-            .unwrap()
-            .as_str()
-            .parse()
-            .map_err(|e: std::num::ParseFloatError| e.to_string())?;
-        let m: f64 = caps
-            .get(2)
-            .unwrap()
-            .as_str()
-            .parse()
-            .map_err(|e: std::num::ParseFloatError| e.to_string())?;
-        let s: f64 = caps
-            .get(3)
-            .unwrap()
-            .as_str()
-            .parse()
-            .map_err(|e: std::num::ParseFloatError| e.to_string())?;
+        let h = capture_f64(&caps, 1)?;
+        let m = capture_f64(&caps, 2)?;
+        let s = capture_f64(&caps, 3)?;
 
         // Validate component ranges
         if h >= 24.0 || m >= 60.0 || s >= 60.0 {
@@ -65,46 +100,40 @@ pub fn parse_ra_hms(ra_str: String) -> Result<f64, String> {
         return Ok(deg);
     }
 
+    if let Some(caps) = RA_DECIMAL_HOURS_REGEX.captures(&ra_str) {
+        let hours = capture_f64(&caps, 1)?;
+        if !(0.0..24.0).contains(&hours) {
+            return Err(format!("RA out of range [0, 24h): {}h", hours));
+        }
+        return Ok(hours * HOURS_TO_DEG);
+    }
+
     // Try decimal degrees
     let deg = ra_str.trim().parse::<f64>().map_err(|e| e.to_string())?;
-    if deg < 0.0 || deg >= 360.0 {
+    if !(0.0..360.0).contains(&deg) {
         return Err(format!("RA out of range [0, 360): {}", deg));
     }
     Ok(deg)
 }
 
-/// Parse Dec from DMS string
+/// Parse Dec from "+45° 30' 15\"", "+45:30:15", "+45 30 15", or decimal degrees
 #[tauri::command]
 pub fn parse_dec_dms(dec_str: String) -> Result<f64, String> {
-    // Try various formats using static compiled regex
     if let Some(caps) = DEC_DMS_REGEX.captures(&dec_str) {
-        let d_str = caps.get(1).unwrap().as_str();
-        let d: f64 = d_str
-            .parse()
-            .map_err(|e: std::num::ParseFloatError| e.to_string())?;
-        let m: f64 = caps
-            .get(2)
-            .unwrap()
-            .as_str()
-            .parse()
-            .map_err(|e: std::num::ParseFloatError| e.to_string())?;
-        let s: f64 = caps
-            .get(3)
-            .unwrap()
-            .as_str()
-            .parse()
-            .map_err(|e: std::num::ParseFloatError| e.to_string())?;
+        let d = capture_f64(&caps, 2)?;
+        let m = capture_f64(&caps, 3)?;
+        let s = capture_f64(&caps, 4)?;
 
         // Validate component ranges
         if m >= 60.0 || s >= 60.0 {
             return Err(format!("Invalid DMS components: {} {}' {}\"", d, m, s));
         }
 
-        // Use string sign check to handle -0° correctly (IEEE 754: -0.0 < 0.0 is false)
-        let sign = if d_str.starts_with('-') { -1.0 } else { 1.0 };
-        let result = sign * (d.abs() + m / 60.0 + s / 3600.0);
+        // The sign is captured separately so -0° 30' stays negative
+        let sign = if &caps[1] == "-" { -1.0 } else { 1.0 };
+        let result = sign * (d + m / 60.0 + s / 3600.0);
 
-        if result < -90.0 || result > 90.0 {
+        if !(-90.0..=90.0).contains(&result) {
             return Err(format!("Dec out of range [-90, 90]: {}", result));
         }
         return Ok(result);
@@ -112,7 +141,7 @@ pub fn parse_dec_dms(dec_str: String) -> Result<f64, String> {
 
     // Try decimal degrees
     let deg = dec_str.trim().parse::<f64>().map_err(|e| e.to_string())?;
-    if deg < -90.0 || deg > 90.0 {
+    if !(-90.0..=90.0).contains(&deg) {
         return Err(format!("Dec out of range [-90, 90]: {}", deg));
     }
     Ok(deg)
@@ -185,4 +214,84 @@ mod tests {
         // -30° 15' 30" = -(30 + 15/60 + 30/3600) ≈ -30.2583°
         assert!(approx_eq(dec, -30.2583, 0.01), "Parsed Dec should be ~-30.26°, got {}", dec);
     }
+
+    // ------------------------------------------------------------------------
+    // Formatting Precision Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_format_precision_decimals() {
+        // 5h 35m 17.2956s, -5° 23' 27.9936"
+        let ra = (5.0 + 35.0 / 60.0 + 17.2956 / 3600.0) * 15.0;
+        let dec = -(5.0 + 23.0 / 60.0 + 27.9936 / 3600.0);
+
+        assert_eq!(format_ra_hms_precision(ra, 0), "05h 35m 17s");
+        assert_eq!(format_ra_hms_precision(ra, 1), "05h 35m 17.3s");
+        assert_eq!(format_ra_hms_precision(ra, 3), "05h 35m 17.296s");
+        assert_eq!(format_dec_dms_precision(dec, 0), "-5° 23' 28\"");
+        assert_eq!(format_dec_dms_precision(dec, 1), "-5° 23' 28.0\"");
+        assert_eq!(format_dec_dms_precision(dec, 3), "-5° 23' 27.994\"");
+
+        // Default wrappers keep two decimals
+        assert_eq!(format_ra_hms(ra), "05h 35m 17.30s");
+        assert_eq!(format_dec_dms(dec), "-5° 23' 27.99\"");
+    }
+
+    #[test]
+    fn test_format_precision_rounding_carry() {
+        // 1h 59m 59.999s rounds up to 2h 00m
+        let ra = (1.0 + 59.0 / 60.0 + 59.999 / 3600.0) * 15.0;
+        assert_eq!(format_ra_hms_precision(ra, 2), "02h 00m 00.00s");
+        assert_eq!(format_ra_hms_precision(ra, 3), "01h 59m 59.999s");
+
+        // 23h 59m 59.999s wraps to 00h
+        let ra_wrap = (23.0 + 59.0 / 60.0 + 59.999 / 3600.0) * 15.0;
+        assert_eq!(format_ra_hms_precision(ra_wrap, 1), "00h 00m 00.0s");
+
+        // +10° 59' 59.999" rounds up to +11° 00'
+        let dec = 10.0 + 59.0 / 60.0 + 59.999 / 3600.0;
+        assert_eq!(format_dec_dms_precision(dec, 2), "+11° 00' 00.00\"");
+        assert_eq!(format_dec_dms_precision(-dec, 0), "-11° 00' 00\"");
+        assert_eq!(format_dec_dms_precision(dec, 3), "+10° 59' 59.999\"");
+    }
+
+    // ------------------------------------------------------------------------
+    // Additional Parse Format Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_ra_alternate_formats() {
+        let expected = (12.0 + 30.0 / 60.0 + 45.0 / 3600.0) * 15.0;
+        for input in ["12:30:45", "12 30 45", "12h30m45s", " 12:30:45.0 "] {
+            let ra = parse_ra_hms(input.to_string()).unwrap();
+            assert!(approx_eq(ra, expected, EPSILON), "{} -> {}", input, ra);
+        }
+
+        assert!(approx_eq(parse_ra_hms("12.5h".to_string()).unwrap(), 187.5, EPSILON));
+        assert!(approx_eq(parse_ra_hms("+6h".to_string()).unwrap(), 90.0, EPSILON));
+        assert!(approx_eq(parse_ra_hms("187.5".to_string()).unwrap(), 187.5, EPSILON));
+    }
+
+    #[test]
+    fn test_parse_dec_alternate_formats() {
+        let expected = -(45.0 + 30.0 / 60.0 + 15.0 / 3600.0);
+        for input in ["-45 30 15", "-45:30:15", "-45d30m15s", "- 45° 30' 15\""] {
+            let dec = parse_dec_dms(input.to_string()).unwrap();
+            assert!(approx_eq(dec, expected, EPSILON), "{} -> {}", input, dec);
+        }
+
+        assert!(approx_eq(parse_dec_dms("+05 23 28".to_string()).unwrap(), 5.0 + 23.0 / 60.0 + 28.0 / 3600.0, EPSILON));
+        assert!(approx_eq(parse_dec_dms("-00 30 00".to_string()).unwrap(), -0.5, EPSILON));
+        assert!(approx_eq(parse_dec_dms("-12.75".to_string()).unwrap(), -12.75, EPSILON));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_input() {
+        for input in ["24:00:00", "12:60:00", "12:30:60", "-1.5h", "25h", "12:30", "abc 12:30:45", "12:30:45 xyz", "360"] {
+            assert!(parse_ra_hms(input.to_string()).is_err(), "RA {:?} should be rejected", input);
+        }
+        for input in ["+91 00 00", "+45 60 00", "-45:30", "45° 30' 15\" N", "90.5", "12h 30m 45s"] {
+            assert!(parse_dec_dms(input.to_string()).is_err(), "Dec {:?} should be rejected", input);
+        }
+    }
 }
@@ -0,0 +1,141 @@
+//! Local horizon profiles
+//! Interpolated obstruction altitude by azimuth, and Horizon (.hrz) file parsing
+
+use super::common::normalize_degrees;
+
+// ============================================================================
+// Horizon Profiles
+// ============================================================================
+
+/// Validate `(azimuth, altitude)` points in degrees and merge them with
+/// [`merge_horizon_points`]
+pub fn normalize_horizon_profile(points: Vec<(f64, f64)>) -> Result<Vec<(f64, f64)>, String> {
+    for &(az, alt) in &points {
+        if !az.is_finite() || !alt.is_finite() {
+            return Err("Horizon points must be finite numbers".to_string());
+        }
+        if !(-90.0..=90.0).contains(&alt) {
+            return Err(format!("Horizon altitude out of range: {}", alt));
+        }
+    }
+    Ok(merge_horizon_points(points))
+}
+
+/// Wrap azimuths into 0..360 and sort by azimuth. A repeated azimuth keeps
+/// its highest altitude, so a profile never understates an obstruction.
+pub fn merge_horizon_points(points: impl IntoIterator<Item = (f64, f64)>) -> Vec<(f64, f64)> {
+    let mut profile: Vec<(f64, f64)> = points
+        .into_iter()
+        .map(|(az, alt)| (normalize_degrees(az), alt))
+        .collect();
+    profile.sort_by(|a, b| a.0.total_cmp(&b.0));
+    profile.dedup_by(|next, kept| {
+        let duplicate = next.0 == kept.0;
+        if duplicate {
+            kept.1 = kept.1.max(next.1);
+        }
+        duplicate
+    });
+    profile
+}
+
+/// Local horizon altitude at `azimuth`, interpolated linearly between the
+/// neighbouring points of a normalized profile and wrapping through north.
+/// An empty profile is a flat 0° horizon.
+pub fn horizon_altitude_at(profile: &[(f64, f64)], azimuth: f64) -> f64 {
+    let (first, last) = match profile {
+        [] => return 0.0,
+        [(_, alt)] => return *alt,
+        [first, .., last] => (*first, *last),
+    };
+
+    let az = normalize_degrees(azimuth);
+    let (lo, hi) = match profile.iter().position(|&(a, _)| a >= az) {
+        Some(i) if profile[i].0 == az => return profile[i].1,
+        Some(0) => ((last.0 - 360.0, last.1), first),
+        Some(i) => (profile[i - 1], profile[i]),
+        None => (last, (first.0 + 360.0, first.1)),
+    };
+    lo.1 + (hi.1 - lo.1) * (az - lo.0) / (hi.0 - lo.0)
+}
+
+/// Parse a Horizon (.hrz) or CSV file: one `azimuth altitude` pair per line,
+/// separated by whitespace, commas or semicolons. Comment lines (`#`, `;`,
+/// `//`) and lines that don't start with two numbers, such as a CSV header,
+/// are skipped.
+pub fn parse_horizon_file(content: &str) -> Result<Vec<(f64, f64)>, String> {
+    let points: Vec<(f64, f64)> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with(';') && !line.starts_with("//"))
+        .filter_map(|line| {
+            let mut fields = line
+                .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+                .filter(|field| !field.is_empty());
+            let az = fields.next()?.parse::<f64>().ok()?;
+            let alt = fields.next()?.parse::<f64>().ok()?;
+            Some((az, alt))
+        })
+        .collect();
+
+    if points.is_empty() {
+        return Err("No horizon points found".to_string());
+    }
+    normalize_horizon_profile(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_sorts_and_wraps() {
+        let profile = normalize_horizon_profile(vec![(180.0, 5.0), (-90.0, 10.0), (360.0, 2.0), (0.0, 3.0)]).unwrap();
+        assert_eq!(profile, vec![(0.0, 3.0), (180.0, 5.0), (270.0, 10.0)]);
+    }
+
+    #[test]
+    fn test_normalize_keeps_highest_altitude_for_repeated_azimuth() {
+        let profile = normalize_horizon_profile(vec![(90.0, 30.0), (450.0, 10.0), (90.0, 20.0)]).unwrap();
+        assert_eq!(profile, vec![(90.0, 30.0)]);
+    }
+
+    #[test]
+    fn test_normalize_rejects_bad_points() {
+        assert!(normalize_horizon_profile(vec![(10.0, f64::NAN)]).is_err());
+        assert!(normalize_horizon_profile(vec![(10.0, 95.0)]).is_err());
+    }
+
+    #[test]
+    fn test_altitude_interpolates_between_points() {
+        let profile = vec![(0.0, 0.0), (90.0, 20.0), (180.0, 10.0)];
+        assert_eq!(horizon_altitude_at(&profile, 90.0), 20.0);
+        assert!((horizon_altitude_at(&profile, 45.0) - 10.0).abs() < 1e-9);
+        assert!((horizon_altitude_at(&profile, 135.0) - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_altitude_wraps_through_north() {
+        let profile = vec![(10.0, 10.0), (350.0, 0.0)];
+        assert!((horizon_altitude_at(&profile, 0.0) - 5.0).abs() < 1e-9);
+        assert!((horizon_altitude_at(&profile, 355.0) - 2.5).abs() < 1e-9);
+        assert!((horizon_altitude_at(&profile, 5.0) - 7.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_altitude_of_trivial_profiles() {
+        assert_eq!(horizon_altitude_at(&[], 123.0), 0.0);
+        assert_eq!(horizon_altitude_at(&[(40.0, 12.0)], 300.0), 12.0);
+    }
+
+    #[test]
+    fn test_parse_hrz_and_csv() {
+        let hrz = "# Backyard horizon\n0 5\n90 25.5\n\n180\t10\n270 8\n";
+        assert_eq!(parse_horizon_file(hrz).unwrap(), vec![(0.0, 5.0), (90.0, 25.5), (180.0, 10.0), (270.0, 8.0)]);
+
+        let csv = "azimuth,altitude\n0,5\n120, 15\n";
+        assert_eq!(parse_horizon_file(csv).unwrap(), vec![(0.0, 5.0), (120.0, 15.0)]);
+
+        assert!(parse_horizon_file("azimuth,altitude\n").is_err());
+    }
+}
@@ -1,8 +1,12 @@
 //! Imaging calculations
-//! Field of view and mosaic coverage calculations
+//! Field of view, mosaic coverage and angular size calculations
 
-use super::common::RAD_TO_DEG;
-use super::types::{FOVResult, MosaicCoverage};
+use super::common::{atmospheric_refraction, timestamp_to_jd, DEG_TO_RAD, RAD_TO_DEG};
+use super::coordinates::angular_separation;
+use super::time::{calculate_hour_angle, calculate_lst};
+use super::types::{
+    DriftRate, EquatorialCoords, FOVResult, FrameFill, MosaicCoverage, MosaicPanel,
+};
 
 // ============================================================================
 // Imaging Calculations
@@ -70,6 +74,279 @@ pub fn calculate_mosaic_coverage(
     }
 }
 
+/// Deproject gnomonic standard coordinates (xi east, eta north; radians)
+/// around a tangent point given in degrees
+fn tangent_to_equatorial(ra0: f64, dec0: f64, xi: f64, eta: f64) -> EquatorialCoords {
+    let (sin_dec0, cos_dec0) = (dec0 * DEG_TO_RAD).sin_cos();
+    let denom = cos_dec0 - eta * sin_dec0;
+    let ra_offset = xi.atan2(denom);
+    let dec_rad = (sin_dec0 + eta * cos_dec0).atan2((xi * xi + denom * denom).sqrt());
+
+    EquatorialCoords {
+        ra: (ra0 + ra_offset * RAD_TO_DEG).rem_euclid(360.0),
+        dec: dec_rad * RAD_TO_DEG,
+    }
+}
+
+/// Rotate a tangent-plane offset from north through east
+fn rotate_offset(x: f64, y: f64, rotation_deg: f64) -> (f64, f64) {
+    let (sin_rot, cos_rot) = (rotation_deg * DEG_TO_RAD).sin_cos();
+    (x * cos_rot - y * sin_rot, x * sin_rot + y * cos_rot)
+}
+
+/// Calculate the sky footprint of a sensor as four corner coordinates.
+///
+/// Corners are deprojected through a gnomonic (TAN) projection centred on the
+/// target, in the order top-left, top-right, bottom-right, bottom-left with
+/// north up and east left at zero rotation. `rotation_deg` turns the frame
+/// from north through east.
+#[tauri::command]
+pub fn calculate_fov_footprint(
+    ra: f64,            // deg
+    dec: f64,           // deg
+    sensor_width: f64,  // mm
+    sensor_height: f64, // mm
+    focal_length: f64,  // mm
+    rotation_deg: f64,
+) -> Result<Vec<EquatorialCoords>, String> {
+    validate_focal_length(focal_length)?;
+    // Tangent-plane half extents: a sensor edge at x mm projects to x / f exactly
+    let half_w = sensor_width / (2.0 * focal_length);
+    let half_h = sensor_height / (2.0 * focal_length);
+
+    Ok([(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)]
+        .iter()
+        .map(|&(sx, sy)| {
+            // Standard coordinates: xi towards east, eta towards north
+            let (xi, eta) = rotate_offset(sx * half_w, sy * half_h, rotation_deg);
+            tangent_to_equatorial(ra, dec, xi, eta)
+        })
+        .collect())
+}
+
+/// Reject focal lengths that would divide by zero or flip the field
+fn validate_focal_length(focal_length: f64) -> Result<(), String> {
+    if focal_length.is_finite() && focal_length > 0.0 {
+        Ok(())
+    } else {
+        Err(format!("Focal length must be positive, got {}", focal_length))
+    }
+}
+
+/// Largest mosaic `calculate_mosaic_panels` lays out
+const MAX_MOSAIC_PANELS: u32 = 10_000;
+
+/// Calculate the center of every panel in a mosaic.
+///
+/// Panels are laid out on the tangent plane around the mosaic center, spaced
+/// by the panel size less the overlap, then deprojected so RA spacing widens
+/// with declination. Row 0 is the northern edge and column 0 the eastern edge
+/// at zero rotation; panels are indexed row by row.
+#[allow(clippy::too_many_arguments)]
+#[tauri::command]
+pub fn calculate_mosaic_panels(
+    ra: f64,
+    dec: f64,
+    sensor_width: f64,
+    sensor_height: f64,
+    focal_length: f64,
+    rows: u32,
+    cols: u32,
+    overlap_percent: f64,
+    rotation_deg: f64,
+) -> Result<Vec<MosaicPanel>, String> {
+    validate_focal_length(focal_length)?;
+    let panel_count = rows
+        .checked_mul(cols)
+        .filter(|count| *count <= MAX_MOSAIC_PANELS)
+        .ok_or_else(|| format!("Mosaic of {}x{} panels exceeds {} panels", rows, cols, MAX_MOSAIC_PANELS))?;
+
+    let overlap_factor = 1.0 - overlap_percent.clamp(0.0, 99.0) / 100.0;
+    let step_x = sensor_width / focal_length * overlap_factor;
+    let step_y = sensor_height / focal_length * overlap_factor;
+    let mid_col = (cols.max(1) - 1) as f64 / 2.0;
+    let mid_row = (rows.max(1) - 1) as f64 / 2.0;
+
+    let mut panels = Vec::with_capacity(panel_count as usize);
+    for row in 0..rows {
+        for col in 0..cols {
+            let x = (mid_col - col as f64) * step_x;
+            let y = (mid_row - row as f64) * step_y;
+            let (xi, eta) = rotate_offset(x, y, rotation_deg);
+            let center = tangent_to_equatorial(ra, dec, xi, eta);
+            panels.push(MosaicPanel {
+                index: row * cols + col,
+                row,
+                col,
+                ra: center.ra,
+                dec: center.dec,
+            });
+        }
+    }
+    Ok(panels)
+}
+
+/// Serpentine (boustrophedon) slew order for mosaic panels
+///
+/// Returns positions into `panels`, walking each row in alternating column
+/// direction. Both starting directions are tried and the one with less total
+/// angular travel wins; ties keep row 0 running left to right.
+#[tauri::command]
+pub fn mosaic_slew_order(panels: Vec<MosaicPanel>) -> Vec<usize> {
+    let mut sorted: Vec<usize> = (0..panels.len()).collect();
+    sorted.sort_by_key(|&i| (panels[i].row, panels[i].col));
+
+    let mut rows: Vec<Vec<usize>> = Vec::new();
+    for i in sorted {
+        match rows.last_mut() {
+            Some(row) if panels[row[0]].row == panels[i].row => row.push(i),
+            _ => rows.push(vec![i]),
+        }
+    }
+
+    let serpentine = |reverse_first: bool| -> Vec<usize> {
+        rows.iter()
+            .enumerate()
+            .flat_map(|(n, row)| {
+                let reverse = (n % 2 == 1) != reverse_first;
+                let mut row = row.clone();
+                if reverse {
+                    row.reverse();
+                }
+                row
+            })
+            .collect()
+    };
+    let travel = |order: &[usize]| -> f64 {
+        order
+            .windows(2)
+            .map(|w| angular_separation(panels[w[0]].ra, panels[w[0]].dec, panels[w[1]].ra, panels[w[1]].dec))
+            .sum()
+    };
+
+    let forward = serpentine(false);
+    let backward = serpentine(true);
+    if travel(&backward) < travel(&forward) - 1e-9 {
+        backward
+    } else {
+        forward
+    }
+}
+
+// ============================================================================
+// Angular Size
+// ============================================================================
+
+/// Largest angle an object can subtend (arcmin), reached at zero distance
+const MAX_ANGULAR_SIZE_ARCMIN: f64 = 180.0 * 60.0;
+
+/// Angular size in arcminutes of an object with the given physical size and
+/// distance (any matching units, e.g. light years).
+///
+/// Uses the exact `2·atan(s / 2d)` form, which reduces to `s / d` in the
+/// small-angle regime. A non-positive distance yields 180°.
+pub fn angular_size(physical_size_ly: f64, distance_ly: f64) -> f64 {
+    if physical_size_ly <= 0.0 {
+        return 0.0;
+    }
+    if distance_ly <= 0.0 {
+        return MAX_ANGULAR_SIZE_ARCMIN;
+    }
+    2.0 * (physical_size_ly / (2.0 * distance_ly)).atan() * RAD_TO_DEG * 60.0
+}
+
+/// Physical size (in the units of `distance_ly`) of an object subtending
+/// `angular_size_arcmin` at the given distance; the inverse of [`angular_size`].
+pub fn physical_size(angular_size_arcmin: f64, distance_ly: f64) -> f64 {
+    if angular_size_arcmin <= 0.0 || distance_ly <= 0.0 {
+        return 0.0;
+    }
+    let half_angle = (angular_size_arcmin.min(MAX_ANGULAR_SIZE_ARCMIN) / 60.0) * DEG_TO_RAD / 2.0;
+    2.0 * distance_ly * half_angle.tan()
+}
+
+/// Compare an object's angular size against a field of view (arcmin)
+#[tauri::command]
+pub fn calculate_frame_fill(
+    physical_size_ly: f64,
+    distance_ly: f64,
+    fov_width_arcmin: f64,
+    fov_height_arcmin: f64,
+) -> FrameFill {
+    let size = angular_size(physical_size_ly, distance_ly);
+    let fraction = |extent: f64| if extent > 0.0 { size / extent } else { 0.0 };
+
+    let frame_area = fov_width_arcmin * fov_height_arcmin;
+    let area_fraction = if frame_area > 0.0 {
+        (std::f64::consts::PI * (size / 2.0).powi(2) / frame_area).min(1.0)
+    } else {
+        0.0
+    };
+
+    FrameFill {
+        angular_size_arcmin: size,
+        width_fraction: fraction(fov_width_arcmin),
+        height_fraction: fraction(fov_height_arcmin),
+        area_fraction,
+    }
+}
+
+/// Sidereal rate of the sky in arcseconds per (solar) second
+const SIDEREAL_RATE_ARCSEC: f64 = 15.041067;
+
+/// Apparent drift of a star through a fixed (untracked) camera, decomposed
+/// along altitude and azimuth with the parallactic angle. Near the horizon
+/// differential refraction slows the apparent altitude motion.
+#[tauri::command]
+pub fn calculate_drift_rate(
+    ra: f64,
+    dec: f64,
+    latitude: f64,
+    longitude: f64,
+    timestamp: i64,
+    pixel_scale: f64,        // arcsec/pixel
+    trail_tolerance_px: f64, // acceptable trail length in pixels
+) -> DriftRate {
+    let lst = calculate_lst(timestamp_to_jd(timestamp), longitude);
+    let ha = calculate_hour_angle(lst, ra) * DEG_TO_RAD;
+    let (dec_rad, lat_rad) = (dec * DEG_TO_RAD, latitude * DEG_TO_RAD);
+
+    let altitude = (dec_rad.sin() * lat_rad.sin() + dec_rad.cos() * lat_rad.cos() * ha.cos())
+        .asin()
+        * RAD_TO_DEG;
+    let parallactic = ha
+        .sin()
+        .atan2(lat_rad.tan() * dec_rad.cos() - dec_rad.sin() * ha.cos());
+
+    let ra_rate = SIDEREAL_RATE_ARCSEC * dec_rad.cos();
+
+    // Rising stars (east, negative parallactic angle) gain altitude
+    let geometric_alt_rate = -ra_rate * parallactic.sin();
+    let azimuth_rate = ra_rate * parallactic.cos();
+
+    // Apparent altitude is h + R(h), so its rate scales by 1 + dR/dh
+    let refraction_slope = if altitude > 0.0 {
+        (atmospheric_refraction(altitude + 0.01) - atmospheric_refraction(altitude - 0.01)) / 0.02
+    } else {
+        0.0
+    };
+    let altitude_rate = geometric_alt_rate * (1.0 + refraction_slope);
+
+    let total = altitude_rate.hypot(azimuth_rate);
+    let max_exposure = (total > 1e-6 && pixel_scale > 0.0 && trail_tolerance_px > 0.0)
+        .then(|| trail_tolerance_px * pixel_scale / total);
+
+    DriftRate {
+        ra_arcsec_per_sec: ra_rate,
+        altitude_arcsec_per_sec: altitude_rate,
+        azimuth_arcsec_per_sec: azimuth_rate,
+        total_arcsec_per_sec: total,
+        parallactic_angle: parallactic * RAD_TO_DEG,
+        altitude,
+        max_exposure_seconds: max_exposure,
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -126,4 +403,239 @@ mod tests {
         assert!(mosaic.total_width_deg > mosaic.panel_width_deg);
         assert!(mosaic.total_height_deg > mosaic.panel_height_deg);
     }
+
+    // ------------------------------------------------------------------------
+    // FOV Footprint Tests
+    // ------------------------------------------------------------------------
+
+
+    /// Signed RA difference in degrees, wrapped to [-180, 180)
+    fn ra_delta(ra: f64, center: f64) -> f64 {
+        (ra - center + 180.0).rem_euclid(360.0) - 180.0
+    }
+
+    #[test]
+    fn test_footprint_unrotated_is_symmetric() {
+        let corners = calculate_fov_footprint(180.0, 30.0, 36.0, 24.0, 500.0, 0.0).unwrap();
+        assert_eq!(corners.len(), 4);
+
+        let distances: Vec<f64> = corners
+            .iter()
+            .map(|c| angular_separation(180.0, 30.0, c.ra, c.dec))
+            .collect();
+        for d in &distances {
+            assert!(approx_eq(*d, distances[0], 1e-9), "Corner distances differ: {:?}", distances);
+        }
+
+        // Left/right corners mirror in RA; top corners share a declination
+        assert!(approx_eq(ra_delta(corners[0].ra, 180.0), -ra_delta(corners[1].ra, 180.0), 1e-9));
+        assert!(approx_eq(ra_delta(corners[3].ra, 180.0), -ra_delta(corners[2].ra, 180.0), 1e-9));
+        assert!(approx_eq(corners[0].dec, corners[1].dec, 1e-9));
+        assert!(corners[0].dec > 30.0 && corners[3].dec < 30.0);
+        // East is left: the top-left corner has the larger RA
+        assert!(ra_delta(corners[0].ra, 180.0) > 0.0);
+    }
+
+    #[test]
+    fn test_footprint_rotation_90_swaps_extents() {
+        let extents = |rotation: f64| {
+            let corners = calculate_fov_footprint(90.0, 0.0, 36.0, 24.0, 1000.0, rotation).unwrap();
+            let ras: Vec<f64> = corners.iter().map(|c| ra_delta(c.ra, 90.0)).collect();
+            let decs: Vec<f64> = corners.iter().map(|c| c.dec).collect();
+            let span = |v: &[f64]| {
+                v.iter().cloned().fold(f64::MIN, f64::max) - v.iter().cloned().fold(f64::MAX, f64::min)
+            };
+            (span(&ras), span(&decs))
+        };
+
+        let (ra_span, dec_span) = extents(0.0);
+        let (ra_span_rot, dec_span_rot) = extents(90.0);
+        assert!(ra_span > dec_span);
+        assert!(approx_eq(ra_span, dec_span_rot, 1e-3), "{} vs {}", ra_span, dec_span_rot);
+        assert!(approx_eq(dec_span, ra_span_rot, 1e-3), "{} vs {}", dec_span, ra_span_rot);
+    }
+
+    #[test]
+    fn test_footprint_rejects_non_positive_focal_length() {
+        assert!(calculate_fov_footprint(0.0, 0.0, 36.0, 24.0, 0.0, 0.0).is_err());
+        assert!(calculate_fov_footprint(0.0, 0.0, 36.0, 24.0, -500.0, 0.0).is_err());
+        assert!(calculate_fov_footprint(0.0, 0.0, 36.0, 24.0, f64::NAN, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_footprint_near_pole() {
+        let corners = calculate_fov_footprint(45.0, 89.9, 36.0, 24.0, 200.0, 15.0).unwrap();
+        let expected = angular_separation(45.0, 89.9, corners[0].ra, corners[0].dec);
+        for c in &corners {
+            assert!(c.ra.is_finite() && c.dec.is_finite());
+            assert!((0.0..360.0).contains(&c.ra));
+            assert!(c.dec <= 90.0);
+            assert!(approx_eq(angular_separation(45.0, 89.9, c.ra, c.dec), expected, 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_footprint_wraps_ra() {
+        let corners = calculate_fov_footprint(0.2, 10.0, 36.0, 24.0, 200.0, 0.0).unwrap();
+        assert!(corners.iter().all(|c| (0.0..360.0).contains(&c.ra)));
+        // East corners sit just above 0°, west corners just below 360°
+        assert!(corners[0].ra < 10.0);
+        assert!(corners[1].ra > 350.0);
+    }
+
+    // ------------------------------------------------------------------------
+    // Mosaic Panel Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_mosaic_panels_single_is_center() {
+        let panels = calculate_mosaic_panels(83.8, -5.4, 36.0, 24.0, 500.0, 1, 1, 20.0, 30.0).unwrap();
+        assert_eq!(panels.len(), 1);
+        assert_eq!(panels[0].index, 0);
+        assert!(approx_eq(panels[0].ra, 83.8, 1e-9));
+        assert!(approx_eq(panels[0].dec, -5.4, 1e-9));
+    }
+
+    #[test]
+    fn test_mosaic_panels_2x2_symmetric() {
+        let panels = calculate_mosaic_panels(180.0, 45.0, 36.0, 24.0, 500.0, 2, 2, 20.0, 0.0).unwrap();
+        assert_eq!(panels.len(), 4);
+        assert_eq!(panels.iter().map(|p| p.index).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        let distances: Vec<f64> = panels
+            .iter()
+            .map(|p| angular_separation(180.0, 45.0, p.ra, p.dec))
+            .collect();
+        for d in &distances {
+            assert!(approx_eq(*d, distances[0], 1e-9));
+        }
+
+        // Row 0 is north, column 0 is east
+        assert!(panels[0].dec > 45.0 && panels[2].dec < 45.0);
+        assert!(approx_eq(panels[0].dec, panels[1].dec, 1e-9));
+        assert!(approx_eq(ra_delta(panels[0].ra, 180.0), -ra_delta(panels[1].ra, 180.0), 1e-9));
+        assert!(ra_delta(panels[0].ra, 180.0) > 0.0);
+    }
+
+    #[test]
+    fn test_mosaic_panel_spacing_uses_overlap() {
+        let panels = calculate_mosaic_panels(10.0, 0.0, 36.0, 24.0, 1000.0, 1, 2, 25.0, 0.0).unwrap();
+        let spacing = angular_separation(panels[0].ra, panels[0].dec, panels[1].ra, panels[1].dec);
+        let expected = (36.0_f64 / 1000.0 * 0.75) * RAD_TO_DEG;
+        assert!(approx_eq(spacing, expected, 1e-3), "{} vs {}", spacing, expected);
+    }
+
+    #[test]
+    fn test_mosaic_panels_reject_oversized_grid_and_bad_focal_length() {
+        assert!(calculate_mosaic_panels(0.0, 0.0, 36.0, 24.0, 500.0, u32::MAX, 2, 10.0, 0.0).is_err());
+        assert!(calculate_mosaic_panels(0.0, 0.0, 36.0, 24.0, 500.0, 200, 200, 10.0, 0.0).is_err());
+        assert!(calculate_mosaic_panels(0.0, 0.0, 36.0, 24.0, 0.0, 2, 2, 10.0, 0.0).is_err());
+        assert!(calculate_mosaic_panels(0.0, 0.0, 36.0, 24.0, -500.0, 2, 2, 10.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_mosaic_slew_order_3x3_serpentine() {
+        let panels = calculate_mosaic_panels(120.0, 30.0, 36.0, 24.0, 500.0, 3, 3, 10.0, 0.0).unwrap();
+        assert_eq!(mosaic_slew_order(panels), vec![0, 1, 2, 5, 4, 3, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_mosaic_slew_order_ignores_input_order() {
+        let mut panels = calculate_mosaic_panels(120.0, 30.0, 36.0, 24.0, 500.0, 2, 3, 10.0, 0.0).unwrap();
+        panels.reverse();
+        let order: Vec<u32> = mosaic_slew_order(panels.clone())
+            .into_iter()
+            .map(|i| panels[i].index)
+            .collect();
+        assert_eq!(order, vec![0, 1, 2, 5, 4, 3]);
+    }
+
+    #[test]
+    fn test_mosaic_slew_order_empty() {
+        assert!(mosaic_slew_order(Vec::new()).is_empty());
+    }
+
+    // ------------------------------------------------------------------------
+    // Angular Size Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_angular_size_of_moon() {
+        // 3474 km at 384,400 km: only the ratio matters
+        let size = angular_size(3474.0, 384_400.0);
+        assert!(approx_eq(size, 31.07, 0.05), "Moon size unexpected: {}", size);
+        assert!(approx_eq(physical_size(size, 384_400.0), 3474.0, 1e-6));
+    }
+
+    #[test]
+    fn test_angular_size_degenerate_inputs() {
+        assert_eq!(angular_size(10.0, 0.0), 180.0 * 60.0);
+        assert_eq!(angular_size(0.0, 100.0), 0.0);
+        assert_eq!(physical_size(30.0, 0.0), 0.0);
+        // Small-angle regime: s/d radians
+        let size = angular_size(1.0, 1.0e6);
+        assert!(approx_eq(size, 1.0e-6 * RAD_TO_DEG * 60.0, 1e-12));
+    }
+
+    #[test]
+    fn test_frame_fill() {
+        // ~110,000 ly at 2.5 Mly is about 2.5° (M31)
+        let fill = calculate_frame_fill(110_000.0, 2_500_000.0, 300.0, 200.0);
+        assert!(approx_eq(fill.width_fraction, fill.angular_size_arcmin / 300.0, EPSILON));
+        assert!(fill.height_fraction > 0.7 && fill.height_fraction < 0.8);
+        assert!(fill.area_fraction > 0.0 && fill.area_fraction <= 1.0);
+
+        let empty = calculate_frame_fill(1.0, 1.0, 0.0, 0.0);
+        assert_eq!(empty.width_fraction, 0.0);
+        assert_eq!(empty.area_fraction, 0.0);
+    }
+
+    // ------------------------------------------------------------------------
+    // Drift Rate Tests
+    // ------------------------------------------------------------------------
+
+    /// RA on the meridian at the given time and longitude
+    fn meridian_ra(timestamp: i64, longitude: f64) -> f64 {
+        calculate_lst(timestamp_to_jd(timestamp), longitude)
+    }
+
+    #[test]
+    fn test_drift_rate_maximal_at_equator() {
+        let ts = 1704067200;
+        let ra = meridian_ra(ts, 0.0);
+        let rate = |dec: f64| calculate_drift_rate(ra, dec, 45.0, 0.0, ts, 1.0, 1.0);
+
+        let equator = rate(0.0);
+        let mid = rate(60.0);
+        let pole = rate(89.5);
+        assert!((equator.total_arcsec_per_sec - SIDEREAL_RATE_ARCSEC).abs() < 0.01);
+        assert!((mid.total_arcsec_per_sec - SIDEREAL_RATE_ARCSEC / 2.0).abs() < 0.01);
+        assert!(pole.total_arcsec_per_sec < 0.2);
+        assert!(equator.max_exposure_seconds.unwrap() < pole.max_exposure_seconds.unwrap());
+
+        // On the meridian all motion is azimuthal
+        assert!(equator.altitude_arcsec_per_sec.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_drift_rate_max_exposure() {
+        let ts = 1704067200;
+        let drift = calculate_drift_rate(meridian_ra(ts, 0.0), 0.0, 45.0, 0.0, ts, 2.0, 3.0);
+        // 3 px at 2"/px over ~15"/s
+        assert!((drift.max_exposure_seconds.unwrap() - 6.0 / SIDEREAL_RATE_ARCSEC).abs() < 0.01);
+        assert!(calculate_drift_rate(0.0, 90.0, 45.0, 0.0, ts, 2.0, 3.0).max_exposure_seconds.is_none());
+    }
+
+    #[test]
+    fn test_drift_rate_refraction_near_horizon() {
+        // Equatorial star rising in the east (hour angle -85 degrees) from the equator
+        let ts = 1704067200;
+        let ra = meridian_ra(ts, 0.0) + 85.0;
+        let drift = calculate_drift_rate(ra, 0.0, 0.0, 0.0, ts, 1.0, 1.0);
+
+        assert!(drift.altitude > 0.0 && drift.altitude < 10.0, "alt {}", drift.altitude);
+        assert!(drift.altitude_arcsec_per_sec > 0.0, "should be rising");
+        // Refraction flattens the apparent rise below the geometric rate
+        assert!(drift.total_arcsec_per_sec < drift.ra_arcsec_per_sec);
+    }
 }
@@ -4,12 +4,14 @@
 //! Submodules:
 //! - `types`: Coordinate and result types
 //! - `common`: Constants, regex patterns, and helper functions
-//! - `time`: Julian Date, GMST, LST, hour angle
+//! - `time`: Julian Date, GMST, LST, hour angle, HJD/BJD
 //! - `coordinates`: Coordinate conversions (equatorial, horizontal, galactic, ecliptic)
 //! - `visibility`: Target visibility calculations
+//! - `horizon`: Local horizon profiles and .hrz parsing
 //! - `twilight`: Twilight and sunrise/sunset calculations
 //! - `moon`: Moon phase and position
 //! - `sun`: Sun position
+//! - `planets`: Naked-eye planet positions
 //! - `imaging`: FOV and mosaic coverage
 //! - `formatting`: RA/Dec formatting and parsing
 
@@ -18,26 +20,46 @@ pub mod common;
 pub mod time;
 pub mod coordinates;
 pub mod visibility;
+pub mod horizon;
 pub mod twilight;
 pub mod moon;
 pub mod sun;
+pub mod planets;
 pub mod imaging;
 pub mod formatting;
 
 // Re-export all public types
 pub use types::{
-    EclipticCoords, EquatorialCoords, FOVResult, GalacticCoords, GeoLocation, HorizontalCoords,
-    MoonPhase, MoonPosition, MosaicCoverage, SunPosition, TwilightTimes, VisibilityInfo,
+    AltitudeSample, BestTime, CoordinateGrid, DayVisibility, DriftRate, EclipticCoords, EffectiveDarkness, EquatorialCoords, FOVResult, FrameFill, GalacticCoords, GeoLocation, GridLine, HorizontalCoords, ImagingConditions,
+    MoonPhase, MoonPosition, MosaicCoverage, MosaicPanel, Nutation, HourAngleInfo, PhotographyTimes, SunPosition, TwilightTimes, VisibilityInfo,
 };
 
+// Shared sidereal time context and its helpers
+pub use coordinates::equatorial_to_horizontal_with_context;
+pub use time::SkyContext;
+pub use visibility::{calculate_visibility_with_context, calculate_visibility_with_horizon};
+pub use horizon::{horizon_altitude_at, normalize_horizon_profile, parse_horizon_file};
+pub use coordinates::precess_equatorial;
+
 // Re-export all Tauri commands
 pub use coordinates::{
-    angular_separation, ecliptic_to_equatorial, equatorial_to_ecliptic, equatorial_to_galactic,
-    equatorial_to_horizontal, galactic_to_equatorial, horizontal_to_equatorial,
+    angular_separation, angular_separation_epoch, batch_equatorial_to_horizontal, ecliptic_to_equatorial, equatorial_to_ecliptic, equatorial_to_galactic,
+    equatorial_to_horizontal, galactic_to_equatorial, generate_coordinate_grid, horizontal_to_equatorial,
+};
+pub use formatting::{
+    format_dec_dms, format_dec_dms_precision, format_ra_hms, format_ra_hms_precision, parse_dec_dms,
+    parse_ra_hms,
+};
+pub use imaging::{
+    angular_size, calculate_drift_rate, calculate_fov, calculate_fov_footprint, calculate_frame_fill,
+    calculate_mosaic_coverage, calculate_mosaic_panels, mosaic_slew_order, physical_size,
+};
+pub use moon::{calculate_moon_altitude_curve, calculate_moon_phase, calculate_moon_position};
+pub use sun::{calculate_sun_altitude_curve, calculate_sun_position};
+pub use time::{calculate_nutation, calculate_obliquity_command, get_target_hour_angle, utc_to_bjd, utc_to_hjd};
+pub use twilight::{
+    calculate_effective_darkness, calculate_photography_times, calculate_twilight, get_imaging_conditions,
+};
+pub use visibility::{
+    calculate_best_observation_time, calculate_visibility, calculate_visibility_calendar,
 };
-pub use formatting::{format_dec_dms, format_ra_hms, parse_dec_dms, parse_ra_hms};
-pub use imaging::{calculate_fov, calculate_mosaic_coverage};
-pub use moon::{calculate_moon_phase, calculate_moon_position};
-pub use sun::calculate_sun_position;
-pub use twilight::calculate_twilight;
-pub use visibility::calculate_visibility;
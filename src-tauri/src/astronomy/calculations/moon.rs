@@ -2,12 +2,15 @@
 //! Moon phase and position calculations
 
 use chrono::{DateTime, Utc};
-use std::f64::consts::PI;
 
 use super::common::{normalize_degrees, DEG_TO_RAD};
 use super::coordinates::{ecliptic_to_equatorial, equatorial_to_horizontal};
-use super::time::datetime_to_jd;
-use super::types::{MoonPhase, MoonPosition};
+use super::sun::sun_equatorial;
+use super::time::{datetime_to_jd, day_sample_times};
+use super::types::{AltitudeSample, MoonPhase, MoonPosition};
+
+/// Kilometres per astronomical unit
+const AU_KM: f64 = 149597870.7;
 
 // ============================================================================
 // Moon Calculations
@@ -35,8 +38,7 @@ pub fn calculate_moon_phase(timestamp: Option<i64>) -> MoonPhase {
 
     let age = phase * SYNODIC_MONTH;
 
-    // Illumination (simplified)
-    let illumination = (1.0 - (2.0 * PI * phase).cos()) / 2.0 * 100.0;
+    let illumination = moon_illuminated_fraction(jd, dt.timestamp()) * 100.0;
 
     let is_waxing = phase < 0.5;
 
@@ -62,6 +64,24 @@ pub fn calculate_moon_phase(timestamp: Option<i64>) -> MoonPhase {
     }
 }
 
+/// Illuminated fraction of the Moon's disk from the true Sun-Moon geometry
+/// (Meeus, ch. 48): elongation from both positions, then the phase angle
+fn moon_illuminated_fraction(jd: f64, timestamp: i64) -> f64 {
+    let (sun_ra, sun_dec, sun_distance_au) = sun_equatorial(jd);
+    let moon = calculate_moon_position(0.0, 0.0, Some(timestamp));
+
+    let (sun_ra, sun_dec) = (sun_ra * DEG_TO_RAD, sun_dec * DEG_TO_RAD);
+    let (moon_ra, moon_dec) = (moon.ra * DEG_TO_RAD, moon.dec * DEG_TO_RAD);
+    let cos_elongation = sun_dec.sin() * moon_dec.sin()
+        + sun_dec.cos() * moon_dec.cos() * (sun_ra - moon_ra).cos();
+    let elongation = cos_elongation.clamp(-1.0, 1.0).acos();
+
+    let sun_distance = sun_distance_au * AU_KM;
+    let phase_angle = (sun_distance * elongation.sin())
+        .atan2(moon.distance - sun_distance * elongation.cos());
+    (1.0 + phase_angle.cos()) / 2.0
+}
+
 /// Calculate moon position with improved accuracy
 /// Uses simplified lunar theory with major perturbation terms
 #[tauri::command]
@@ -167,6 +187,23 @@ pub fn calculate_moon_position(
     }
 }
 
+/// Moon altitude every `step_minutes` across the UTC day `date`
+#[tauri::command]
+pub fn calculate_moon_altitude_curve(
+    date: String,
+    latitude: f64,
+    longitude: f64,
+    step_minutes: u32,
+) -> Result<Vec<AltitudeSample>, String> {
+    Ok(day_sample_times(&date, step_minutes)?
+        .into_iter()
+        .map(|(timestamp, _)| AltitudeSample {
+            timestamp,
+            altitude: calculate_moon_position(latitude, longitude, Some(timestamp)).altitude,
+        })
+        .collect())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -185,6 +222,40 @@ mod tests {
         assert!(phase.illumination < 10.0, "New moon illumination should be low, got {}", phase.illumination);
     }
 
+    #[test]
+    fn test_moon_illumination_at_quarters_and_full() {
+        // January 2024 lunation: first quarter 18th 03:52, full 25th 17:54,
+        // last quarter Feb 2nd 23:18 (UTC)
+        let first = Utc.with_ymd_and_hms(2024, 1, 18, 3, 52, 0).unwrap();
+        let full = Utc.with_ymd_and_hms(2024, 1, 25, 17, 54, 0).unwrap();
+        let last = Utc.with_ymd_and_hms(2024, 2, 2, 23, 18, 0).unwrap();
+
+        let first = calculate_moon_phase(Some(first.timestamp()));
+        assert!((first.illumination - 50.0).abs() < 1.0, "first quarter {}", first.illumination);
+        assert!(first.is_waxing);
+
+        let full = calculate_moon_phase(Some(full.timestamp()));
+        assert!(full.illumination > 99.0, "full moon {}", full.illumination);
+
+        let last = calculate_moon_phase(Some(last.timestamp()));
+        assert!((last.illumination - 50.0).abs() < 1.0, "last quarter {}", last.illumination);
+        assert!(!last.is_waxing);
+    }
+
+    #[test]
+    fn test_moon_altitude_curve_matches_position() {
+        let curve = calculate_moon_altitude_curve("2024-01-25".to_string(), 40.0, 0.0, 30).unwrap();
+        assert_eq!(curve.len(), 48);
+        for sample in curve.iter().step_by(8) {
+            let position = calculate_moon_position(40.0, 0.0, Some(sample.timestamp));
+            assert_eq!(sample.altitude, position.altitude);
+        }
+        // Full moon: up around midnight, down around noon
+        assert!(curve[0].altitude > 45.0);
+        assert!(curve[24].altitude < 0.0);
+        assert!(calculate_moon_altitude_curve("2024-01-25".to_string(), 40.0, 0.0, 0).is_err());
+    }
+
     #[test]
     fn test_moon_phase_range() {
         let phase = calculate_moon_phase(None);
@@ -0,0 +1,152 @@
+//! Planet positions
+//! Low-precision geocentric positions of the naked-eye planets from mean
+//! Keplerian elements (Standish, JPL, valid 1800-2050; accuracy ~1')
+
+use super::common::{calculate_obliquity, normalize_degrees, DEG_TO_RAD, RAD_TO_DEG};
+
+/// Mean orbital elements at J2000 and their rates per Julian century:
+/// (a AU, e, inclination, mean longitude, longitude of perihelion, longitude of ascending node)
+type OrbitalElements = [(f64, f64); 6];
+
+const EARTH_MOON_BARYCENTER: OrbitalElements = [
+    (1.00000261, 0.00000562),
+    (0.01671123, -0.00004392),
+    (-0.00001531, -0.01294668),
+    (100.46457166, 35999.37244981),
+    (102.93768193, 0.32327364),
+    (0.0, 0.0),
+];
+
+/// Naked-eye planets and their elements
+pub(crate) const BRIGHT_PLANETS: [(&str, OrbitalElements); 5] = [
+    (
+        "Mercury",
+        [
+            (0.38709927, 0.00000037),
+            (0.20563593, 0.00001906),
+            (7.00497902, -0.00594749),
+            (252.2503235, 149472.67411175),
+            (77.45779628, 0.16047689),
+            (48.33076593, -0.12534081),
+        ],
+    ),
+    (
+        "Venus",
+        [
+            (0.72333566, 0.0000039),
+            (0.00677672, -0.00004107),
+            (3.39467605, -0.0007889),
+            (181.9790995, 58517.81538729),
+            (131.60246718, 0.00268329),
+            (76.67984255, -0.27769418),
+        ],
+    ),
+    (
+        "Mars",
+        [
+            (1.52371034, 0.00001847),
+            (0.0933941, 0.00007882),
+            (1.84969142, -0.00813131),
+            (-4.55343205, 19140.30268499),
+            (-23.94362959, 0.44441088),
+            (49.55953891, -0.29257343),
+        ],
+    ),
+    (
+        "Jupiter",
+        [
+            (5.202887, -0.00011607),
+            (0.04838624, -0.00013253),
+            (1.30439695, -0.00183714),
+            (34.39644051, 3034.74612775),
+            (14.72847983, 0.21252668),
+            (100.47390909, 0.20469106),
+        ],
+    ),
+    (
+        "Saturn",
+        [
+            (9.53667594, -0.0012506),
+            (0.05386179, -0.00050991),
+            (2.48599187, 0.00193609),
+            (49.95424423, 1222.49362201),
+            (92.59887831, -0.41897216),
+            (113.66242448, -0.28867794),
+        ],
+    ),
+];
+
+/// Heliocentric ecliptic position (AU) from mean elements at a Julian Date
+fn heliocentric_position(elements: &OrbitalElements, jd: f64) -> [f64; 3] {
+    let t = (jd - 2451545.0) / 36525.0;
+    let [a, e, i, l, peri, node] = elements.map(|(value, rate)| value + rate * t);
+
+    let mean_anomaly = normalize_degrees(l - peri) * DEG_TO_RAD;
+    let mut ecc_anomaly = mean_anomaly + e * mean_anomaly.sin();
+    for _ in 0..10 {
+        ecc_anomaly -= (ecc_anomaly - e * ecc_anomaly.sin() - mean_anomaly)
+            / (1.0 - e * ecc_anomaly.cos());
+    }
+
+    let x_orb = a * (ecc_anomaly.cos() - e);
+    let y_orb = a * (1.0 - e * e).sqrt() * ecc_anomaly.sin();
+
+    let (w, o, i) = ((peri - node) * DEG_TO_RAD, node * DEG_TO_RAD, i * DEG_TO_RAD);
+    [
+        (w.cos() * o.cos() - w.sin() * o.sin() * i.cos()) * x_orb
+            + (-w.sin() * o.cos() - w.cos() * o.sin() * i.cos()) * y_orb,
+        (w.cos() * o.sin() + w.sin() * o.cos() * i.cos()) * x_orb
+            + (-w.sin() * o.sin() + w.cos() * o.cos() * i.cos()) * y_orb,
+        w.sin() * i.sin() * x_orb + w.cos() * i.sin() * y_orb,
+    ]
+}
+
+/// Geocentric equatorial position of a planet: (RA degrees, Dec degrees, distance AU)
+pub(crate) fn planet_equatorial(elements: &OrbitalElements, jd: f64) -> (f64, f64, f64) {
+    let planet = heliocentric_position(elements, jd);
+    let earth = heliocentric_position(&EARTH_MOON_BARYCENTER, jd);
+    let [x, y, z] = [planet[0] - earth[0], planet[1] - earth[1], planet[2] - earth[2]];
+
+    let eps = calculate_obliquity(jd) * DEG_TO_RAD;
+    let y_eq = y * eps.cos() - z * eps.sin();
+    let z_eq = y * eps.sin() + z * eps.cos();
+
+    let distance = (x * x + y * y + z * z).sqrt();
+    let ra = normalize_degrees(y_eq.atan2(x) * RAD_TO_DEG);
+    let dec = (z_eq / distance).asin() * RAD_TO_DEG;
+    (ra, dec, distance)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn planet(name: &str) -> &'static OrbitalElements {
+        &BRIGHT_PLANETS.iter().find(|(n, _)| *n == name).unwrap().1
+    }
+
+    #[test]
+    fn test_jupiter_position_2024() {
+        // 2024-01-01 00:00 UTC: Jupiter stationary in Aries at RA 2h 13m, Dec +12.1
+        let (ra, dec, distance) = planet_equatorial(planet("Jupiter"), 2460310.5);
+        assert!((ra - 33.3).abs() < 0.5, "ra {}", ra);
+        assert!((dec - 12.1).abs() < 0.5, "dec {}", dec);
+        assert!((distance - 4.48).abs() < 0.05, "distance {}", distance);
+    }
+
+    #[test]
+    fn test_venus_stays_near_sun() {
+        // Venus never strays more than ~47 degrees from the Sun
+        for day in 0..20 {
+            let jd = 2460310.5 + day as f64 * 30.0;
+            let (ra, dec, _) = planet_equatorial(planet("Venus"), jd);
+            let (sun_ra, sun_dec, _) = super::super::sun::sun_equatorial(jd);
+            let separation = super::super::coordinates::angular_separation(ra, dec, sun_ra, sun_dec);
+            assert!(separation < 48.0, "separation {} on day {}", separation, day);
+        }
+    }
+}
@@ -4,9 +4,9 @@
 use chrono::{DateTime, Utc};
 
 use super::common::{calculate_obliquity, normalize_degrees, DEG_TO_RAD, RAD_TO_DEG};
-use super::coordinates::equatorial_to_horizontal;
-use super::time::datetime_to_jd;
-use super::types::SunPosition;
+use super::coordinates::{equatorial_to_horizontal, horizontal_at_lst};
+use super::time::{calculate_lst, datetime_to_jd, day_sample_times};
+use super::types::{AltitudeSample, SunPosition};
 
 // ============================================================================
 // Sun Calculations
@@ -25,6 +25,44 @@ pub fn calculate_sun_position(
         .unwrap_or_else(Utc::now);
 
     let jd = datetime_to_jd(&dt);
+    let (ra_deg, dec_deg, _) = sun_equatorial(jd);
+
+    // Convert to horizontal
+    let hor = equatorial_to_horizontal(ra_deg, dec_deg, latitude, longitude, Some(dt.timestamp()), None);
+
+    SunPosition {
+        ra: ra_deg,
+        dec: dec_deg,
+        altitude: hor.alt,
+        azimuth: hor.az,
+    }
+}
+
+/// Sun altitude every `step_minutes` across the UTC day `date`, for
+/// plotting darkness curves in one call
+#[tauri::command]
+pub fn calculate_sun_altitude_curve(
+    date: String,
+    latitude: f64,
+    longitude: f64,
+    step_minutes: u32,
+) -> Result<Vec<AltitudeSample>, String> {
+    Ok(day_sample_times(&date, step_minutes)?
+        .into_iter()
+        .map(|(timestamp, jd)| {
+            let (ra, dec, _) = sun_equatorial(jd);
+            let lst = calculate_lst(jd, longitude);
+            AltitudeSample {
+                timestamp,
+                altitude: horizontal_at_lst(ra, dec, latitude, lst, true).alt,
+            }
+        })
+        .collect())
+}
+
+/// Geocentric apparent RA/Dec of the Sun (degrees) and its distance (AU)
+/// for a Julian Date
+pub(crate) fn sun_equatorial(jd: f64) -> (f64, f64, f64) {
     let t = (jd - 2451545.0) / 36525.0;
     let t2 = t * t;
     // Geometric mean longitude of the Sun (in degrees)
@@ -50,7 +88,7 @@ pub fn calculate_sun_position(
     let v_rad = v * DEG_TO_RAD;
 
     // Sun's radius vector (AU)
-    let _r = (1.000001018 * (1.0 - e * e)) / (1.0 + e * v_rad.cos());
+    let r = (1.000001018 * (1.0 - e * e)) / (1.0 + e * v_rad.cos());
 
     // Apparent longitude (corrected for nutation and aberration)
     let omega = 125.04 - 1934.136 * t; // longitude of Moon's ascending node
@@ -67,18 +105,7 @@ pub fn calculate_sun_position(
     let ra = (obliquity_rad.cos() * sun_lon_rad.sin()).atan2(sun_lon_rad.cos());
     let dec = (obliquity_rad.sin() * sun_lon_rad.sin()).asin();
 
-    let ra_deg = normalize_degrees(ra * RAD_TO_DEG);
-    let dec_deg = dec * RAD_TO_DEG;
-
-    // Convert to horizontal
-    let hor = equatorial_to_horizontal(ra_deg, dec_deg, latitude, longitude, Some(dt.timestamp()), None);
-
-    SunPosition {
-        ra: ra_deg,
-        dec: dec_deg,
-        altitude: hor.alt,
-        azimuth: hor.az,
-    }
+    (normalize_degrees(ra * RAD_TO_DEG), dec * RAD_TO_DEG, r)
 }
 
 // ============================================================================
@@ -124,6 +151,43 @@ mod tests {
             "Sun Dec on equinox should be near 0°, got {}", sun.dec);
     }
 
+    #[test]
+    fn test_sun_altitude_curve_sample_count() {
+        let curve = calculate_sun_altitude_curve("2024-03-20".to_string(), 40.0, 0.0, 10).unwrap();
+        assert_eq!(curve.len(), 144);
+        let midnight = Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap().timestamp();
+        assert_eq!(curve[0].timestamp, midnight);
+        assert_eq!(curve[1].timestamp - curve[0].timestamp, 600);
+
+        // Steps that don't divide the day still cover it
+        assert_eq!(calculate_sun_altitude_curve("2024-03-20".to_string(), 40.0, 0.0, 7).unwrap().len(), 206);
+        assert!(calculate_sun_altitude_curve("2024-03-20".to_string(), 40.0, 0.0, 0).is_err());
+        assert!(calculate_sun_altitude_curve("20-03-2024".to_string(), 40.0, 0.0, 10).is_err());
+    }
+
+    #[test]
+    fn test_sun_altitude_curve_minimum_at_solar_midnight() {
+        // At 75°W solar midnight falls near 05:00 UTC
+        let curve = calculate_sun_altitude_curve("2024-06-15".to_string(), 40.0, -75.0, 5).unwrap();
+        let lowest = curve
+            .iter()
+            .min_by(|a, b| a.altitude.partial_cmp(&b.altitude).unwrap())
+            .unwrap();
+
+        let twilight = super::super::twilight::calculate_twilight("2024-06-15".to_string(), 40.0, -75.0).unwrap();
+        let solar_midnight = twilight.solar_noon.unwrap() - 12 * 3600;
+        assert!(
+            (lowest.timestamp - solar_midnight).abs() <= 15 * 60,
+            "lowest at {}, solar midnight {}",
+            lowest.timestamp,
+            solar_midnight
+        );
+
+        // Samples agree with the single-instant calculation
+        let single = calculate_sun_position(40.0, -75.0, Some(lowest.timestamp));
+        assert!((single.altitude - lowest.altitude).abs() < 0.05);
+    }
+
     #[test]
     fn test_sun_altitude_noon() {
         // At solar noon, sun should be at or near highest altitude
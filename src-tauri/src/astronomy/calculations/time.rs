@@ -1,9 +1,11 @@
 //! Time calculations
-//! Julian Date, GMST, LST, and hour angle calculations
+//! Julian Date, GMST, LST, hour angle, and heliocentric/barycentric time corrections
 
 use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
 
-use super::common::normalize_degrees;
+use super::common::{calculate_obliquity, normalize_degrees, timestamp_to_jd, DEG_TO_RAD};
+use super::sun::sun_equatorial;
+use super::types::{HourAngleInfo, Nutation};
 
 // ============================================================================
 // Time Calculations
@@ -70,6 +72,227 @@ pub fn calculate_hour_angle(lst: f64, ra: f64) -> f64 {
     normalize_degrees(lst - ra)
 }
 
+/// Degrees of time as "HH:MM:SS", rounded to the second (24h wraps to 00h),
+/// with a leading "-" for negative values
+fn format_time_hms(degrees: f64) -> String {
+    let total = (degrees.abs() / 15.0 * 3600.0).round() as i64 % (24 * 3600);
+    let sign = if degrees < 0.0 && total > 0 { "-" } else { "" };
+    format!("{}{:02}:{:02}:{:02}", sign, total / 3600, total / 60 % 60, total % 60)
+}
+
+/// Local Sidereal Time and hour angle for a target of right ascension `ra`
+/// (degrees) seen from east-positive `longitude`
+#[tauri::command]
+pub fn get_target_hour_angle(ra: f64, longitude: f64, timestamp: i64) -> HourAngleInfo {
+    let sky = SkyContext::new(timestamp, longitude);
+    let mut hour_angle = calculate_hour_angle(sky.lst, ra);
+    if hour_angle > 180.0 {
+        hour_angle -= 360.0;
+    }
+
+    HourAngleInfo {
+        lst_degrees: sky.lst,
+        lst_hms: format_time_hms(sky.lst),
+        hour_angle_degrees: hour_angle,
+        hour_angle_hms: format_time_hms(hour_angle),
+        meridian_side: if hour_angle < 0.0 { "east" } else { "west" }.to_string(),
+    }
+}
+
+/// Evenly spaced (timestamp, Julian Date) pairs covering the UTC day `date`
+/// ("YYYY-MM-DD"), starting at midnight, `step_minutes` apart
+pub(crate) fn day_sample_times(date: &str, step_minutes: u32) -> Result<Vec<(i64, f64)>, String> {
+    if step_minutes == 0 || step_minutes > 1440 {
+        return Err("step_minutes must be between 1 and 1440".to_string());
+    }
+    let naive_date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date format: {}", e))?;
+    let midnight = naive_date.and_time(chrono::NaiveTime::MIN).and_utc().timestamp();
+    let midnight_jd = date_to_jd(&naive_date);
+
+    let count = 1440usize.div_ceil(step_minutes as usize);
+    Ok((0..count)
+        .map(|i| {
+            let minutes = i as u32 * step_minutes;
+            (midnight + minutes as i64 * 60, midnight_jd + minutes as f64 / 1440.0)
+        })
+        .collect())
+}
+
+/// Sidereal time for one instant and site, computed once and shared by
+/// per-object calculations such as rendering a whole target list
+#[derive(Debug, Clone, Copy)]
+pub struct SkyContext {
+    pub timestamp: i64,
+    pub longitude: f64,
+    pub jd: f64,
+    /// Greenwich Mean Sidereal Time in degrees
+    pub gmst: f64,
+    /// Local Sidereal Time in degrees
+    pub lst: f64,
+    /// Start of the UTC day containing `timestamp`
+    pub midnight_timestamp: i64,
+    /// GMST at `midnight_timestamp`, used for transit times
+    pub midnight_gmst: f64,
+}
+
+impl SkyContext {
+    /// Build the context for a Unix timestamp (seconds) and east-positive longitude
+    pub fn new(timestamp: i64, longitude: f64) -> Self {
+        let dt = DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now);
+        let jd = datetime_to_jd(&dt);
+        let gmst = calculate_gmst(jd);
+
+        let midnight = dt.date_naive().and_time(chrono::NaiveTime::MIN).and_utc();
+
+        Self {
+            timestamp: dt.timestamp(),
+            longitude,
+            jd,
+            gmst,
+            lst: normalize_degrees(gmst + longitude),
+            midnight_timestamp: midnight.timestamp(),
+            midnight_gmst: calculate_gmst(datetime_to_jd(&midnight)),
+        }
+    }
+}
+
+// ============================================================================
+// Heliocentric / Barycentric Corrections
+// ============================================================================
+
+/// Light travel time across one astronomical unit, in days
+const AU_LIGHT_TIME_DAYS: f64 = 499.004784 / 86400.0;
+
+/// Unit vector for equatorial coordinates in degrees
+fn unit_vector(ra: f64, dec: f64) -> [f64; 3] {
+    let (ra_rad, dec_rad) = (ra * DEG_TO_RAD, dec * DEG_TO_RAD);
+    [dec_rad.cos() * ra_rad.cos(), dec_rad.cos() * ra_rad.sin(), dec_rad.sin()]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Heliocentric position of the Earth (AU, equatorial frame)
+fn earth_heliocentric(jd: f64) -> [f64; 3] {
+    let (sun_ra, sun_dec, r) = sun_equatorial(jd);
+    unit_vector(sun_ra, sun_dec).map(|c| -c * r)
+}
+
+/// Approximate barycentric position of the Sun (AU, equatorial frame).
+/// Only Jupiter and Saturn on mean circular orbits are considered, which
+/// covers the bulk of the Sun's ~0.01 AU reflex motion.
+fn sun_barycentric(jd: f64) -> [f64; 3] {
+    let t = (jd - 2451545.0) / 36525.0;
+    // (mean longitude at J2000, degrees per century, semi-major axis AU, mass ratio)
+    let planets = [
+        (34.39644, 3034.746128, 5.202887, 1.0 / 1047.3486),
+        (49.954244, 1222.493622, 9.536676, 1.0 / 3497.898),
+    ];
+
+    let (mut x, mut y) = (0.0, 0.0);
+    for (l0, rate, a, mass) in planets {
+        let l = normalize_degrees(l0 + rate * t) * DEG_TO_RAD;
+        x -= mass * a * l.cos();
+        y -= mass * a * l.sin();
+    }
+
+    // Rotate from the ecliptic plane into the equatorial frame
+    let eps = calculate_obliquity(jd) * DEG_TO_RAD;
+    [x, y * eps.cos(), y * eps.sin()]
+}
+
+/// Heliocentric Julian Date for an observation of a J2000 target (degrees).
+/// The light-travel-time correction is applied to the UTC Julian Date, so the
+/// result stays on the UTC time scale.
+#[tauri::command]
+pub fn utc_to_hjd(timestamp: i64, ra: f64, dec: f64) -> f64 {
+    let jd = timestamp_to_jd(timestamp);
+    let earth = earth_heliocentric(jd);
+    jd + dot(earth, unit_vector(ra, dec)) * AU_LIGHT_TIME_DAYS
+}
+
+/// Approximate Barycentric Julian Date for an observation of a J2000 target
+/// (degrees). Like [`utc_to_hjd`], but measured from the solar system
+/// barycentre; no TDB conversion or relativistic terms are applied.
+#[tauri::command]
+pub fn utc_to_bjd(timestamp: i64, ra: f64, dec: f64) -> f64 {
+    let jd = timestamp_to_jd(timestamp);
+    let helio = earth_heliocentric(jd);
+    let offset = sun_barycentric(jd);
+    let earth = [helio[0] + offset[0], helio[1] + offset[1], helio[2] + offset[2]];
+    jd + dot(earth, unit_vector(ra, dec)) * AU_LIGHT_TIME_DAYS
+}
+
+// ============================================================================
+// Obliquity and Nutation
+// ============================================================================
+
+/// Leading terms of the IAU 1980 nutation series (Meeus, table 22.A):
+/// multipliers of (D, M, M', F, Ω), then Δψ = a + b·T and Δε = c + d·T in 0.0001"
+const NUTATION_TERMS: [([f64; 5], f64, f64, f64, f64); 18] = [
+    ([0.0, 0.0, 0.0, 0.0, 1.0], -171996.0, -174.2, 92025.0, 8.9),
+    ([-2.0, 0.0, 0.0, 2.0, 2.0], -13187.0, -1.6, 5736.0, -3.1),
+    ([0.0, 0.0, 0.0, 2.0, 2.0], -2274.0, -0.2, 977.0, -0.5),
+    ([0.0, 0.0, 0.0, 0.0, 2.0], 2062.0, 0.2, -895.0, 0.5),
+    ([0.0, 1.0, 0.0, 0.0, 0.0], 1426.0, -3.4, 54.0, -0.1),
+    ([0.0, 0.0, 1.0, 0.0, 0.0], 712.0, 0.1, -7.0, 0.0),
+    ([-2.0, 1.0, 0.0, 2.0, 2.0], -517.0, 1.2, 224.0, -0.6),
+    ([0.0, 0.0, 0.0, 2.0, 1.0], -386.0, -0.4, 200.0, 0.0),
+    ([0.0, 0.0, 1.0, 2.0, 2.0], -301.0, 0.0, 129.0, -0.1),
+    ([-2.0, -1.0, 0.0, 2.0, 2.0], 217.0, -0.5, -95.0, 0.3),
+    ([-2.0, 0.0, 1.0, 0.0, 0.0], -158.0, 0.0, 0.0, 0.0),
+    ([-2.0, 0.0, 0.0, 2.0, 1.0], 129.0, 0.1, -70.0, 0.0),
+    ([0.0, 0.0, -1.0, 2.0, 2.0], 123.0, 0.0, -53.0, 0.0),
+    ([2.0, 0.0, 0.0, 0.0, 0.0], 63.0, 0.0, 0.0, 0.0),
+    ([0.0, 0.0, 1.0, 0.0, 1.0], 63.0, 0.1, -33.0, 0.0),
+    ([2.0, 0.0, -1.0, 2.0, 2.0], -59.0, 0.0, 26.0, 0.0),
+    ([0.0, 0.0, -1.0, 0.0, 1.0], -58.0, -0.1, 32.0, 0.0),
+    ([0.0, 0.0, 1.0, 2.0, 1.0], -51.0, 0.0, 27.0, 0.0),
+];
+
+/// Nutation (Δψ, Δε) in arcseconds at a Julian Date
+pub(crate) fn nutation_at_jd(jd: f64) -> (f64, f64) {
+    let t = (jd - 2451545.0) / 36525.0;
+    let (t2, t3) = (t * t, t * t * t);
+
+    // Mean elongation of the Moon, anomalies of Sun and Moon, Moon's argument
+    // of latitude and longitude of its ascending node (degrees)
+    let args = [
+        297.85036 + 445267.11148 * t - 0.0019142 * t2 + t3 / 189474.0,
+        357.52772 + 35999.05034 * t - 0.0001603 * t2 - t3 / 300000.0,
+        134.96298 + 477198.867398 * t + 0.0086972 * t2 + t3 / 56250.0,
+        93.27191 + 483202.017538 * t - 0.0036825 * t2 + t3 / 327270.0,
+        125.04452 - 1934.136261 * t + 0.0020708 * t2 + t3 / 450000.0,
+    ];
+
+    let (mut dpsi, mut deps) = (0.0, 0.0);
+    for (multipliers, a, b, c, d) in NUTATION_TERMS {
+        let angle: f64 = multipliers.iter().zip(args).map(|(m, arg)| m * arg).sum();
+        let angle = normalize_degrees(angle) * DEG_TO_RAD;
+        dpsi += (a + b * t) * angle.sin();
+        deps += (c + d * t) * angle.cos();
+    }
+    (dpsi * 0.0001, deps * 0.0001)
+}
+
+/// Mean obliquity of the ecliptic in degrees at a Unix timestamp
+#[tauri::command]
+pub fn calculate_obliquity_command(timestamp: i64) -> f64 {
+    calculate_obliquity(timestamp_to_jd(timestamp))
+}
+
+/// Nutation in longitude and obliquity (IAU 1980 leading terms, ~0.5" accuracy)
+#[tauri::command]
+pub fn calculate_nutation(timestamp: i64) -> Nutation {
+    let (longitude_arcsec, obliquity_arcsec) = nutation_at_jd(timestamp_to_jd(timestamp));
+    Nutation {
+        longitude_arcsec,
+        obliquity_arcsec,
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -109,6 +332,31 @@ mod tests {
         assert!(approx_eq(jd, 2451544.5, 0.001), "Expected ~2451544.5, got {}", jd);
     }
 
+    #[test]
+    fn test_obliquity_at_j2000() {
+        // 2000-01-01 12:00 UTC
+        let obliquity = calculate_obliquity_command(946728000);
+        assert!(approx_eq(obliquity, 23.4393, 0.0001), "obliquity {}", obliquity);
+    }
+
+    #[test]
+    fn test_nutation_meeus_example() {
+        // Meeus example 22.a: 1987-04-10 0h, Δψ = -3.788", Δε = +9.443"
+        let nutation = calculate_nutation(545011200);
+        assert!(approx_eq(nutation.longitude_arcsec, -3.788, 0.5), "dpsi {}", nutation.longitude_arcsec);
+        assert!(approx_eq(nutation.obliquity_arcsec, 9.443, 0.5), "deps {}", nutation.obliquity_arcsec);
+    }
+
+    #[test]
+    fn test_nutation_bounded() {
+        // One full 18.6-year node cycle in 100-day steps
+        for step in 0..70 {
+            let nutation = calculate_nutation(946728000 + step * 100 * 86400);
+            assert!(nutation.longitude_arcsec.abs() < 20.0, "dpsi {}", nutation.longitude_arcsec);
+            assert!(nutation.obliquity_arcsec.abs() < 10.5, "deps {}", nutation.obliquity_arcsec);
+        }
+    }
+
     #[test]
     fn test_calculate_gmst() {
         // At J2000.0 (JD 2451545.0), GMST ≈ 280.46°
@@ -132,4 +380,76 @@ mod tests {
         let ha = calculate_hour_angle(lst, ra);
         assert!(approx_eq(ha, 60.0, EPSILON), "HA should be 60°, got {}", ha);
     }
+
+    // ------------------------------------------------------------------------
+    // Target Hour Angle Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_target_on_meridian_has_zero_hour_angle() {
+        let ts = Utc.with_ymd_and_hms(2024, 3, 20, 22, 0, 0).unwrap().timestamp();
+        let longitude = -71.0;
+        let lst = SkyContext::new(ts, longitude).lst;
+
+        let info = get_target_hour_angle(lst, longitude, ts);
+        assert!(approx_eq(info.lst_degrees, lst, EPSILON));
+        assert!(info.hour_angle_degrees.abs() < 1e-9, "HA should be ~0, got {}", info.hour_angle_degrees);
+        assert_eq!(info.hour_angle_hms, "00:00:00");
+        assert_eq!(info.meridian_side, "west");
+    }
+
+    #[test]
+    fn test_target_an_hour_east_of_meridian() {
+        let ts = Utc.with_ymd_and_hms(2024, 3, 20, 22, 0, 0).unwrap().timestamp();
+        let longitude = 139.7;
+        let lst = SkyContext::new(ts, longitude).lst;
+
+        let info = get_target_hour_angle(normalize_degrees(lst + 15.0), longitude, ts);
+        assert!(approx_eq(info.hour_angle_degrees, -15.0, 1e-9), "HA should be -15°, got {}", info.hour_angle_degrees);
+        assert_eq!(info.hour_angle_hms, "-01:00:00");
+        assert_eq!(info.meridian_side, "east");
+    }
+
+    #[test]
+    fn test_format_time_hms() {
+        assert_eq!(format_time_hms(0.0), "00:00:00");
+        assert_eq!(format_time_hms(187.5), "12:30:00");
+        assert_eq!(format_time_hms(-0.25), "-00:01:00");
+        assert_eq!(format_time_hms(359.99999), "00:00:00");
+    }
+
+    // ------------------------------------------------------------------------
+    // HJD / BJD Tests
+    // ------------------------------------------------------------------------
+
+    const MAX_CORRECTION_DAYS: f64 = 8.4 / 1440.0;
+
+    #[test]
+    fn test_hjd_correction_is_bounded() {
+        let ts = Utc.with_ymd_and_hms(2024, 7, 4, 0, 0, 0).unwrap().timestamp();
+        let jd = datetime_to_jd(&DateTime::from_timestamp(ts, 0).unwrap());
+        for (ra, dec) in [(0.0, 0.0), (90.0, 23.4), (200.0, -60.0), (310.0, 89.0)] {
+            let hjd = utc_to_hjd(ts, ra, dec);
+            let bjd = utc_to_bjd(ts, ra, dec);
+            assert!((hjd - jd).abs() <= MAX_CORRECTION_DAYS, "HJD correction too large");
+            assert!((bjd - jd).abs() <= MAX_CORRECTION_DAYS, "BJD correction too large");
+            // Barycentre and Sun are at most ~0.01 AU (~5 s) apart
+            assert!((bjd - hjd).abs() * 86400.0 < 6.0);
+        }
+    }
+
+    #[test]
+    fn test_hjd_sign_near_ecliptic() {
+        // At the March equinox the Sun sits near RA 0h
+        let ts = Utc.with_ymd_and_hms(2024, 3, 20, 3, 0, 0).unwrap().timestamp();
+        let jd = datetime_to_jd(&DateTime::from_timestamp(ts, 0).unwrap());
+
+        // Target behind the Sun: light reaches the Sun first, so HJD < JD
+        let conjunction = (utc_to_hjd(ts, 0.0, 0.0) - jd) * 1440.0;
+        assert!(conjunction < -8.0, "Expected ~-8.2 min, got {}", conjunction);
+
+        // Target at opposition: light reaches Earth first, so HJD > JD
+        let opposition = (utc_to_hjd(ts, 180.0, 0.0) - jd) * 1440.0;
+        assert!(opposition > 8.0, "Expected ~+8.2 min, got {}", opposition);
+    }
 }
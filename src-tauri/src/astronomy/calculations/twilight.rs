@@ -1,14 +1,16 @@
 //! Twilight calculations
-//! Sunrise, sunset, and twilight times with iterative precision
+//! Sunrise, sunset, twilight, and golden/blue hour times with iterative precision
 
-use chrono::NaiveDate;
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
 
 use super::common::{
     calculate_obliquity, jd_to_timestamp, normalize_degrees, timestamp_to_jd, DEG_TO_RAD,
     RAD_TO_DEG,
 };
+use super::moon::{calculate_moon_phase, calculate_moon_position};
+use super::sun::calculate_sun_position;
 use super::time::date_to_jd;
-use super::types::TwilightTimes;
+use super::types::{EffectiveDarkness, ImagingConditions, PhotographyTimes, TwilightTimes};
 
 // ============================================================================
 // Twilight Calculations
@@ -87,6 +89,123 @@ pub fn calculate_twilight(
     })
 }
 
+/// Calculate golden-hour and blue-hour bounds for a date.
+/// Bounds the sun never crosses (e.g. polar day) are `None`.
+#[tauri::command]
+pub fn calculate_photography_times(
+    date: String,
+    latitude: f64,
+    longitude: f64,
+) -> Result<PhotographyTimes, String> {
+    let naive_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date format: {}", e))?;
+
+    let jd_noon = date_to_jd(&naive_date) + 0.5;
+
+    const GOLDEN_HOUR_HIGH_ALT: f64 = 6.0;
+    const GOLDEN_BLUE_BOUNDARY_ALT: f64 = -4.0;
+    const BLUE_HOUR_LOW_ALT: f64 = -6.0;
+
+    let (golden_high_rise, golden_high_set) =
+        calculate_sun_rise_set_times(jd_noon, latitude, longitude, GOLDEN_HOUR_HIGH_ALT);
+    let (boundary_rise, boundary_set) =
+        calculate_sun_rise_set_times(jd_noon, latitude, longitude, GOLDEN_BLUE_BOUNDARY_ALT);
+    let (blue_low_rise, blue_low_set) =
+        calculate_sun_rise_set_times(jd_noon, latitude, longitude, BLUE_HOUR_LOW_ALT);
+
+    Ok(PhotographyTimes {
+        date,
+        morning_blue_hour_start: blue_low_rise,
+        morning_blue_hour_end: boundary_rise,
+        morning_golden_hour_start: boundary_rise,
+        morning_golden_hour_end: golden_high_rise,
+        evening_golden_hour_start: golden_high_set,
+        evening_golden_hour_end: boundary_set,
+        evening_blue_hour_start: boundary_set,
+        evening_blue_hour_end: blue_low_set,
+    })
+}
+
+/// Largest fraction of the sky darkness a full Moon at the zenith removes
+const MOON_MAX_BRIGHTENING: f64 = 0.8;
+
+/// Sky darkness from the Sun's depression combined with the Moon's altitude
+/// and illumination, as a single 0-100 score for deep-sky planning
+#[tauri::command]
+pub fn calculate_effective_darkness(latitude: f64, longitude: f64, timestamp: i64) -> EffectiveDarkness {
+    let sun_altitude = calculate_sun_position(latitude, longitude, Some(timestamp)).altitude;
+    let moon_altitude = calculate_moon_position(latitude, longitude, Some(timestamp)).altitude;
+    let moon_illumination = calculate_moon_phase(Some(timestamp)).illumination;
+
+    let twilight_phase = match sun_altitude {
+        alt if alt > -0.833 => "day",
+        alt if alt > -6.0 => "civil",
+        alt if alt > -12.0 => "nautical",
+        alt if alt > -18.0 => "astronomical",
+        _ => "night",
+    };
+
+    // 0 at sunset, 1 once the Sun is 18° down
+    let sun_darkness = (-sun_altitude / 18.0).clamp(0.0, 1.0);
+    // Moonlight grows with phase and with altitude (less airmass extinction)
+    let moon_brightening = if moon_altitude > 0.0 {
+        (moon_illumination / 100.0) * (moon_altitude * DEG_TO_RAD).sin().sqrt()
+    } else {
+        0.0
+    };
+    let darkness_score = 100.0 * sun_darkness * (1.0 - MOON_MAX_BRIGHTENING * moon_brightening);
+
+    EffectiveDarkness {
+        timestamp,
+        sun_altitude,
+        moon_altitude,
+        moon_illumination,
+        twilight_phase: twilight_phase.to_string(),
+        darkness_score,
+    }
+}
+
+/// Twilight times for the evening and following morning of the night
+/// containing `timestamp`; the night is taken to run from local solar noon
+/// to noon
+pub(crate) fn night_twilight(
+    latitude: f64,
+    longitude: f64,
+    timestamp: i64,
+) -> Option<(TwilightTimes, TwilightTimes)> {
+    // Shift to local solar time, then back half a day so the date rolls over at noon
+    let offset = (longitude * 240.0) as i64 - 12 * 3600;
+    let local = Utc.timestamp_opt(timestamp + offset, 0).single()?;
+    let evening = local.date_naive();
+    let morning = evening + Duration::days(1);
+
+    Some((
+        calculate_twilight(evening.format("%Y-%m-%d").to_string(), latitude, longitude).ok()?,
+        calculate_twilight(morning.format("%Y-%m-%d").to_string(), latitude, longitude).ok()?,
+    ))
+}
+
+/// Sun, Moon, darkness window and current darkness at a site in one call
+#[tauri::command]
+pub fn get_imaging_conditions(latitude: f64, longitude: f64, timestamp: i64) -> ImagingConditions {
+    let darkness = calculate_effective_darkness(latitude, longitude, timestamp);
+    let moon_phase = calculate_moon_phase(Some(timestamp));
+    let (darkness_start, darkness_end) = night_twilight(latitude, longitude, timestamp)
+        .map_or((None, None), |(evening, morning)| (evening.astronomical_dusk, morning.astronomical_dawn));
+
+    ImagingConditions {
+        timestamp,
+        sun_altitude: darkness.sun_altitude,
+        is_astronomically_dark: darkness.twilight_phase == "night",
+        twilight_phase: darkness.twilight_phase,
+        moon_altitude: darkness.moon_altitude,
+        moon_phase,
+        darkness_start,
+        darkness_end,
+        darkness_score: darkness.darkness_score,
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -222,6 +341,45 @@ mod tests {
         assert!(twilight.is_polar_day || twilight.is_polar_night == false);
     }
 
+    #[test]
+    fn test_effective_darkness_moonless_vs_full_moon() {
+        // 2024-01-11 new moon, local midnight at 40°N 0°E: Sun and Moon far below
+        let moonless = calculate_effective_darkness(40.0, 0.0, 1704931200);
+        assert_eq!(moonless.twilight_phase, "night");
+        assert!(moonless.moon_altitude < 0.0, "moon {}", moonless.moon_altitude);
+        assert!((moonless.darkness_score - 100.0).abs() < 1e-9);
+
+        // 2024-01-25 full moon riding high at local midnight
+        let full = calculate_effective_darkness(40.0, 0.0, 1706140800);
+        assert_eq!(full.twilight_phase, "night");
+        assert!(full.moon_altitude > 45.0, "moon {}", full.moon_altitude);
+        assert!(full.moon_illumination > 95.0);
+        assert!(full.darkness_score < 40.0, "score {}", full.darkness_score);
+
+        // Daytime is never dark
+        let noon = calculate_effective_darkness(40.0, 0.0, 1704974400);
+        assert_eq!(noon.twilight_phase, "day");
+        assert_eq!(noon.darkness_score, 0.0);
+    }
+
+    #[test]
+    fn test_imaging_conditions_day_and_moonless_night() {
+        // Noon at 40°N 0°E on 2024-01-11: daylight
+        let day = get_imaging_conditions(40.0, 0.0, 1704974400);
+        assert!(!day.is_astronomically_dark);
+        assert_eq!(day.twilight_phase, "day");
+        assert!(day.sun_altitude > 0.0);
+
+        // Midnight before, at new moon: dark with the Moon below the horizon
+        let night = get_imaging_conditions(40.0, 0.0, 1704931200);
+        assert!(night.is_astronomically_dark);
+        assert!(night.moon_altitude < 0.0);
+        assert!(night.moon_phase.illumination < 5.0);
+        assert!(night.darkness_score > 99.0);
+        let (start, end) = (night.darkness_start.unwrap(), night.darkness_end.unwrap());
+        assert!(start < night.timestamp && night.timestamp < end);
+    }
+
     #[test]
     fn test_twilight_invalid_date() {
         let result = calculate_twilight("invalid-date".to_string(), 45.0, 0.0);
@@ -282,4 +440,43 @@ mod tests {
         assert!(dec_summer > 20.0, "Summer sun dec should be > 20°, got {}", dec_summer);
         assert!(dec_winter < -20.0, "Winter sun dec should be < -20°, got {}", dec_winter);
     }
+
+    #[test]
+    fn test_photography_times_ordering() {
+        let times = calculate_photography_times("2024-09-15".to_string(), 45.0, 7.0).unwrap();
+
+        let morning = [
+            times.morning_blue_hour_start.unwrap(),
+            times.morning_blue_hour_end.unwrap(),
+            times.morning_golden_hour_start.unwrap(),
+            times.morning_golden_hour_end.unwrap(),
+        ];
+        let evening = [
+            times.evening_golden_hour_start.unwrap(),
+            times.evening_golden_hour_end.unwrap(),
+            times.evening_blue_hour_start.unwrap(),
+            times.evening_blue_hour_end.unwrap(),
+        ];
+
+        // Morning: blue hour precedes golden hour
+        assert!(morning[0] < morning[1], "Blue hour should have positive length");
+        assert_eq!(morning[1], morning[2]);
+        assert!(morning[2] < morning[3], "Golden hour should have positive length");
+
+        // Evening: golden hour precedes blue hour
+        assert!(evening[0] < evening[1], "Golden hour should have positive length");
+        assert_eq!(evening[1], evening[2]);
+        assert!(evening[2] < evening[3], "Blue hour should have positive length");
+        assert!(morning[3] < evening[0]);
+
+        // Golden hour brackets sunrise
+        let twilight = calculate_twilight("2024-09-15".to_string(), 45.0, 7.0).unwrap();
+        let sunrise = twilight.sunrise.unwrap();
+        assert!(morning[2] < sunrise && sunrise < morning[3]);
+    }
+
+    #[test]
+    fn test_photography_times_invalid_date() {
+        assert!(calculate_photography_times("2024-13-01".to_string(), 45.0, 0.0).is_err());
+    }
 }
@@ -43,6 +43,20 @@ pub struct EclipticCoords {
     pub lat: f64, // Ecliptic latitude in degrees
 }
 
+/// One RA or Dec grid line; split into several polylines where it crosses RA 0h
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridLine {
+    pub value: f64, // RA of a meridian or Dec of a parallel, in degrees
+    pub segments: Vec<Vec<EquatorialCoords>>,
+}
+
+/// RA/Dec grid lines covering a region of sky
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoordinateGrid {
+    pub meridians: Vec<GridLine>,
+    pub parallels: Vec<GridLine>,
+}
+
 // ============================================================================
 // Visibility Types
 // ============================================================================
@@ -62,6 +76,38 @@ pub struct VisibilityInfo {
     pub hours_visible: f64,
 }
 
+/// Best time to image a target during one night
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BestTime {
+    /// Highest point within astronomical darkness; `None` if the target
+    /// never clears the minimum altitude while it is dark
+    pub timestamp: Option<i64>,
+    pub altitude: f64,
+    pub airmass: Option<f64>,
+    pub dark_start: Option<i64>,
+    pub dark_end: Option<i64>,
+    pub transit_in_darkness: bool,
+}
+
+/// Altitude of a body at one instant, for plotting curves
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AltitudeSample {
+    pub timestamp: i64,
+    pub altitude: f64,
+}
+
+/// Per-day visibility of a target during astronomical darkness
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayVisibility {
+    pub date: String,
+    /// Length of astronomical darkness starting that evening
+    pub dark_hours: f64,
+    /// Hours of darkness with the target above the minimum altitude
+    pub hours_visible: f64,
+    /// Highest altitude reached during darkness
+    pub max_altitude: Option<f64>,
+}
+
 // ============================================================================
 // Twilight Types
 // ============================================================================
@@ -83,6 +129,51 @@ pub struct TwilightTimes {
     pub is_polar_night: bool,
 }
 
+/// Golden- and blue-hour bounds for a given date and location.
+/// Golden hour spans sun altitudes -4° to +6°, blue hour -6° to -4°.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhotographyTimes {
+    pub date: String,
+    pub morning_blue_hour_start: Option<i64>,
+    pub morning_blue_hour_end: Option<i64>,
+    pub morning_golden_hour_start: Option<i64>,
+    pub morning_golden_hour_end: Option<i64>,
+    pub evening_golden_hour_start: Option<i64>,
+    pub evening_golden_hour_end: Option<i64>,
+    pub evening_blue_hour_start: Option<i64>,
+    pub evening_blue_hour_end: Option<i64>,
+}
+
+/// Combined Sun and Moon sky darkness at one instant.
+/// `darkness_score` runs from 0 (daylight) to 100 (moonless astronomical night).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectiveDarkness {
+    pub timestamp: i64,
+    pub sun_altitude: f64,
+    pub moon_altitude: f64,
+    pub moon_illumination: f64,
+    /// "day", "civil", "nautical", "astronomical" or "night"
+    pub twilight_phase: String,
+    pub darkness_score: f64,
+}
+
+/// Summary of current imaging conditions at a site
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagingConditions {
+    pub timestamp: i64,
+    pub sun_altitude: f64,
+    /// "day", "civil", "nautical", "astronomical" or "night"
+    pub twilight_phase: String,
+    pub moon_altitude: f64,
+    pub moon_phase: MoonPhase,
+    /// Astronomical dusk starting the current or upcoming night
+    pub darkness_start: Option<i64>,
+    /// Astronomical dawn ending that night
+    pub darkness_end: Option<i64>,
+    pub is_astronomically_dark: bool,
+    pub darkness_score: f64,
+}
+
 // ============================================================================
 // Celestial Body Types
 // ============================================================================
@@ -107,6 +198,28 @@ pub struct MoonPosition {
     pub distance: f64, // km
 }
 
+/// Nutation in longitude (Δψ) and obliquity (Δε), arcseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Nutation {
+    pub longitude_arcsec: f64,
+    pub obliquity_arcsec: f64,
+}
+
+/// Sidereal time and hour angle of a target at one instant and site
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourAngleInfo {
+    /// Local Sidereal Time in degrees
+    pub lst_degrees: f64,
+    /// Local Sidereal Time as "HH:MM:SS"
+    pub lst_hms: String,
+    /// Hour angle in degrees, -180..180; negative before transit
+    pub hour_angle_degrees: f64,
+    /// Hour angle as signed "±HH:MM:SS"
+    pub hour_angle_hms: String,
+    /// "east" before transit, "west" from transit on
+    pub meridian_side: String,
+}
+
 /// Sun position
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SunPosition {
@@ -131,6 +244,35 @@ pub struct FOVResult {
     pub f_ratio: f64,
 }
 
+/// How much of a field of view an object of known physical size fills
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameFill {
+    pub angular_size_arcmin: f64,
+    /// Object size relative to the FOV width
+    pub width_fraction: f64,
+    /// Object size relative to the FOV height
+    pub height_fraction: f64,
+    /// Area of the object's disc relative to the frame, capped at 1.0
+    pub area_fraction: f64,
+}
+
+/// Apparent sidereal drift of a star for an untracked camera
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriftRate {
+    /// Drift along RA on the sky (15.04"/s at the equator, shrinking with cos Dec)
+    pub ra_arcsec_per_sec: f64,
+    /// Apparent altitude component, including differential refraction
+    pub altitude_arcsec_per_sec: f64,
+    /// Azimuthal component measured on the sky
+    pub azimuth_arcsec_per_sec: f64,
+    pub total_arcsec_per_sec: f64,
+    /// Parallactic angle in degrees
+    pub parallactic_angle: f64,
+    pub altitude: f64,
+    /// Longest exposure before trails exceed the pixel tolerance; `None` at the pole
+    pub max_exposure_seconds: Option<f64>,
+}
+
 /// Mosaic coverage result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MosaicCoverage {
@@ -140,3 +282,13 @@ pub struct MosaicCoverage {
     pub panel_width_deg: f64,
     pub panel_height_deg: f64,
 }
+
+/// Single mosaic panel and its center coordinates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MosaicPanel {
+    pub index: u32,
+    pub row: u32,
+    pub col: u32,
+    pub ra: f64,
+    pub dec: f64,
+}
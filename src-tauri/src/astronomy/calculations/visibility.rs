@@ -1,18 +1,33 @@
 //! Visibility calculations
 //! Target visibility with rise/set/transit times
 
-use chrono::{DateTime, Utc};
+use chrono::{Duration, NaiveDate, Utc};
 
 use super::common::{normalize_degrees, DEG_TO_RAD, HOURS_TO_DEG, RAD_TO_DEG};
-use super::coordinates::equatorial_to_horizontal;
-use super::time::{calculate_gmst, datetime_to_jd};
-use super::types::VisibilityInfo;
+use super::coordinates::{equatorial_to_horizontal, equatorial_to_horizontal_with_context};
+use super::horizon::{horizon_altitude_at, normalize_horizon_profile};
+use super::time::SkyContext;
+use super::twilight::calculate_twilight;
+use super::types::{BestTime, DayVisibility, VisibilityInfo};
+
+/// Length of a sidereal day in seconds
+const SIDEREAL_DAY_SECONDS: f64 = 86164.0905;
+
+/// Sampling step for the visibility calendar
+const CALENDAR_STEP_SECONDS: i64 = 600;
+/// Longest calendar accepted, a year of nights
+const MAX_CALENDAR_DAYS: u32 = 366;
+
+/// Sampling step when searching for crossings of a local horizon profile
+const HORIZON_STEP_SECONDS: i64 = 300;
 
 // ============================================================================
 // Visibility Calculations
 // ============================================================================
 
-/// Calculate target visibility with precise rise/set/transit times
+/// Calculate target visibility with precise rise/set/transit times. With a
+/// horizon profile of `(azimuth, altitude)` points, rise/set and `is_visible`
+/// use the local horizon altitude instead of 0°. An invalid profile is an error.
 #[tauri::command]
 pub fn calculate_visibility(
     ra: f64,
@@ -21,14 +36,33 @@ pub fn calculate_visibility(
     longitude: f64,
     timestamp: Option<i64>,
     min_altitude: Option<f64>,
+    horizon_profile: Option<Vec<(f64, f64)>>,
+) -> Result<VisibilityInfo, String> {
+    let timestamp = timestamp.unwrap_or_else(|| Utc::now().timestamp());
+    let context = SkyContext::new(timestamp, longitude);
+    let profile = horizon_profile
+        .map(normalize_horizon_profile)
+        .transpose()?
+        .unwrap_or_default();
+    if profile.is_empty() {
+        Ok(calculate_visibility_with_context(ra, dec, latitude, &context, min_altitude))
+    } else {
+        Ok(calculate_visibility_with_horizon(ra, dec, latitude, &context, min_altitude, &profile))
+    }
+}
+
+/// [`calculate_visibility`] using a precomputed [`SkyContext`]
+pub fn calculate_visibility_with_context(
+    ra: f64,
+    dec: f64,
+    latitude: f64,
+    context: &SkyContext,
+    min_altitude: Option<f64>,
 ) -> VisibilityInfo {
     let min_alt = min_altitude.unwrap_or(0.0);
-    let dt = timestamp
-        .map(|ts| DateTime::from_timestamp(ts, 0).unwrap_or_else(Utc::now))
-        .unwrap_or_else(Utc::now);
 
     // Current position
-    let current = equatorial_to_horizontal(ra, dec, latitude, longitude, Some(dt.timestamp()), None);
+    let current = equatorial_to_horizontal_with_context(ra, dec, latitude, context, true);
 
     // Transit altitude (when object crosses meridian)
     let transit_alt = 90.0 - (latitude - dec).abs();
@@ -49,7 +83,7 @@ pub fn calculate_visibility(
     // Calculate rise/set times using sidereal time
     let (rise_time, set_time, transit_time, hours_visible) = if is_circumpolar {
         // Object is always above horizon, calculate transit time only
-        let transit_ts = calculate_transit_time(ra, longitude, &dt);
+        let transit_ts = calculate_transit_time(ra, context);
         (None, None, transit_ts, 24.0)
     } else if never_rises {
         (None, None, None, 0.0)
@@ -58,7 +92,7 @@ pub fn calculate_visibility(
         let hours = h0 / HOURS_TO_DEG * 2.0; // Total hours visible
 
         // Calculate transit time (when HA = 0)
-        let transit_ts = calculate_transit_time(ra, longitude, &dt);
+        let transit_ts = calculate_transit_time(ra, context);
 
         // Rise time = transit - h0 (in hours converted to seconds)
         // Set time = transit + h0
@@ -84,18 +118,83 @@ pub fn calculate_visibility(
     }
 }
 
-/// Calculate the transit time (meridian crossing) for an object
-fn calculate_transit_time(ra: f64, longitude: f64, dt: &DateTime<Utc>) -> Option<i64> {
-    // Get the date at midnight UTC
-    let midnight = dt.date_naive().and_hms_opt(0, 0, 0)?;
-    let midnight_utc = DateTime::<Utc>::from_naive_utc_and_offset(midnight, Utc);
-    let jd_midnight = datetime_to_jd(&midnight_utc);
+/// [`calculate_visibility_with_context`] against a normalized local horizon
+/// profile. Rise and set are the crossings of the profile either side of the
+/// target's highest clearance above it during the sidereal day around transit.
+pub fn calculate_visibility_with_horizon(
+    ra: f64,
+    dec: f64,
+    latitude: f64,
+    context: &SkyContext,
+    min_altitude: Option<f64>,
+    profile: &[(f64, f64)],
+) -> VisibilityInfo {
+    let flat = calculate_visibility_with_context(ra, dec, latitude, context, min_altitude);
+    let clearance = |ts: i64| {
+        let pos = equatorial_to_horizontal(ra, dec, latitude, context.longitude, Some(ts), None);
+        pos.alt - horizon_altitude_at(profile, pos.az)
+    };
+
+    let local_horizon = horizon_altitude_at(profile, flat.current_azimuth);
+    let is_visible = flat.current_altitude >= min_altitude.unwrap_or(0.0).max(local_horizon);
+
+    let transit = calculate_transit_time(ra, context).unwrap_or(context.midnight_timestamp);
+    let half_day = (SIDEREAL_DAY_SECONDS / 2.0) as i64;
+    let samples: Vec<(i64, f64)> = (transit - half_day..=transit + half_day)
+        .step_by(HORIZON_STEP_SECONDS as usize)
+        .map(|ts| (ts, clearance(ts)))
+        .collect();
+    let (peak, peak_clearance) = samples
+        .iter()
+        .copied()
+        .fold((transit, f64::MIN), |best, s| if s.1 > best.1 { s } else { best });
+
+    let (rise_time, set_time, hours_visible, is_circumpolar, never_rises) = if peak_clearance <= 0.0 {
+        (None, None, 0.0, false, true)
+    } else if samples.iter().all(|&(_, c)| c > 0.0) {
+        (None, None, 24.0, true, false)
+    } else {
+        let rise = horizon_crossing(&clearance, peak, -HORIZON_STEP_SECONDS);
+        let set = horizon_crossing(&clearance, peak, HORIZON_STEP_SECONDS);
+        (Some(rise), Some(set), (set - rise) as f64 / 3600.0, false, false)
+    };
+
+    VisibilityInfo {
+        is_visible,
+        rise_time,
+        set_time,
+        transit_time: calculate_transit_time(ra, context),
+        is_circumpolar,
+        never_rises,
+        hours_visible,
+        ..flat
+    }
+}
 
-    // Calculate GMST at midnight
-    let gmst_midnight = calculate_gmst(jd_midnight);
+/// Walk from `start` (above the horizon) in `step`-second increments until
+/// `clearance` drops to zero or below, then bisect to the crossing second
+fn horizon_crossing(clearance: &impl Fn(i64) -> f64, start: i64, step: i64) -> i64 {
+    let mut above = start;
+    let mut below = start + step;
+    while clearance(below) > 0.0 && (below - start).abs() < SIDEREAL_DAY_SECONDS as i64 {
+        above = below;
+        below += step;
+    }
+    while (below - above).abs() > 1 {
+        let mid = above + (below - above) / 2;
+        if clearance(mid) > 0.0 {
+            above = mid;
+        } else {
+            below = mid;
+        }
+    }
+    above
+}
 
-    // LST at midnight for this longitude
-    let lst_midnight = normalize_degrees(gmst_midnight + longitude);
+/// Calculate the transit time (meridian crossing) for an object
+fn calculate_transit_time(ra: f64, context: &SkyContext) -> Option<i64> {
+    // LST at midnight UTC for this longitude
+    let lst_midnight = normalize_degrees(context.midnight_gmst + context.longitude);
 
     // Hour angle at midnight
     let ha_midnight = normalize_degrees(lst_midnight - ra);
@@ -117,7 +216,142 @@ fn calculate_transit_time(ra: f64, longitude: f64, dt: &DateTime<Utc>) -> Option
     let sidereal_correction = seconds_to_transit * (1.0 - 86164.0905 / 86400.0);
     let adjusted_seconds = seconds_to_transit - sidereal_correction;
 
-    Some(midnight_utc.timestamp() + adjusted_seconds as i64)
+    Some(context.midnight_timestamp + adjusted_seconds as i64)
+}
+
+/// Find the best moment to image a target on the night starting at `date`
+/// (YYYY-MM-DD): its highest point between astronomical dusk and the next
+/// astronomical dawn. If the target transits in daylight, the darkness
+/// boundary closest to transit wins instead.
+#[tauri::command]
+pub fn calculate_best_observation_time(
+    ra: f64,
+    dec: f64,
+    latitude: f64,
+    longitude: f64,
+    date: String,
+    min_altitude: f64,
+) -> Result<BestTime, String> {
+    let evening = calculate_twilight(date.clone(), latitude, longitude)?;
+    let next_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date format: {}", e))?
+        + Duration::days(1);
+    let morning = calculate_twilight(next_date.format("%Y-%m-%d").to_string(), latitude, longitude)?;
+
+    let (dark_start, dark_end) = match (evening.astronomical_dusk, morning.astronomical_dawn) {
+        (Some(start), Some(end)) if end > start => (start, end),
+        _ => {
+            // No astronomical darkness (polar day or midsummer at high latitude)
+            return Ok(BestTime {
+                timestamp: None,
+                altitude: 90.0 - (latitude - dec).abs(),
+                airmass: None,
+                dark_start: evening.astronomical_dusk,
+                dark_end: morning.astronomical_dawn,
+                transit_in_darkness: false,
+            });
+        }
+    };
+
+    let altitude_at = |ts: i64| equatorial_to_horizontal(ra, dec, latitude, longitude, Some(ts), None).alt;
+
+    // Shift the transit onto the sidereal day nearest the middle of the night
+    let context = SkyContext::new(dark_start, longitude);
+    let visibility = calculate_visibility_with_context(ra, dec, latitude, &context, Some(min_altitude));
+    let midpoint = (dark_start + dark_end) / 2;
+    let transit = visibility.transit_time.map(|t| {
+        let days = ((midpoint - t) as f64 / SIDEREAL_DAY_SECONDS).round();
+        t + (days * SIDEREAL_DAY_SECONDS) as i64
+    });
+
+    let transit_in_darkness = transit.is_some_and(|t| (dark_start..=dark_end).contains(&t));
+    let candidates: Vec<i64> = match transit {
+        Some(t) if transit_in_darkness => vec![t],
+        _ => vec![dark_start, dark_end],
+    };
+    let (best_ts, best_alt) = candidates
+        .into_iter()
+        .map(|ts| (ts, altitude_at(ts)))
+        .fold((dark_start, f64::MIN), |best, c| if c.1 > best.1 { c } else { best });
+
+    Ok(BestTime {
+        timestamp: (best_alt >= min_altitude).then_some(best_ts),
+        altitude: best_alt,
+        airmass: calculate_airmass(best_alt),
+        dark_start: Some(dark_start),
+        dark_end: Some(dark_end),
+        transit_in_darkness,
+    })
+}
+
+/// Hours a target spends above `min_altitude` during astronomical darkness
+/// for each night from `start` (YYYY-MM-DD), for planning heatmaps
+#[tauri::command]
+pub fn calculate_visibility_calendar(
+    ra: f64,
+    dec: f64,
+    latitude: f64,
+    longitude: f64,
+    start: String,
+    days: u32,
+    min_altitude: f64,
+) -> Result<Vec<DayVisibility>, String> {
+    if days > MAX_CALENDAR_DAYS {
+        return Err(format!("days must be at most {}, got {}", MAX_CALENDAR_DAYS, days));
+    }
+    let start_date = NaiveDate::parse_from_str(&start, "%Y-%m-%d")
+        .map_err(|e| format!("Invalid date format: {}", e))?;
+
+    let mut evening = calculate_twilight(start.clone(), latitude, longitude)?;
+    let mut calendar = Vec::with_capacity(days as usize);
+
+    for day in 0..days {
+        let date = start_date + Duration::days(day as i64);
+        let next = (date + Duration::days(1)).format("%Y-%m-%d").to_string();
+        let morning = calculate_twilight(next, latitude, longitude)?;
+
+        let entry = match (evening.astronomical_dusk, morning.astronomical_dawn) {
+            (Some(dusk), Some(dawn)) if dawn > dusk => {
+                // Sample the middle of each step across the night
+                let altitudes: Vec<f64> = (dusk..dawn)
+                    .step_by(CALENDAR_STEP_SECONDS as usize)
+                    .map(|ts| {
+                        let mid = (ts + CALENDAR_STEP_SECONDS / 2).min(dawn);
+                        equatorial_to_horizontal(ra, dec, latitude, longitude, Some(mid), None).alt
+                    })
+                    .collect();
+                let visible_steps = altitudes.iter().filter(|alt| **alt >= min_altitude).count();
+                let dark_hours = (dawn - dusk) as f64 / 3600.0;
+
+                DayVisibility {
+                    date: date.format("%Y-%m-%d").to_string(),
+                    dark_hours,
+                    hours_visible: (visible_steps as f64 * CALENDAR_STEP_SECONDS as f64 / 3600.0)
+                        .min(dark_hours),
+                    max_altitude: altitudes.into_iter().reduce(f64::max),
+                }
+            }
+            _ => DayVisibility {
+                date: date.format("%Y-%m-%d").to_string(),
+                dark_hours: 0.0,
+                hours_visible: 0.0,
+                max_altitude: None,
+            },
+        };
+
+        calendar.push(entry);
+        evening = morning;
+    }
+
+    Ok(calendar)
+}
+
+/// Relative airmass (Kasten & Young 1989); `None` below the horizon
+fn calculate_airmass(altitude: f64) -> Option<f64> {
+    if altitude <= 0.0 {
+        return None;
+    }
+    Some(1.0 / ((altitude * DEG_TO_RAD).sin() + 0.50572 * (altitude + 6.07995).powf(-1.6364)))
 }
 
 // ============================================================================
@@ -135,7 +369,7 @@ mod tests {
     #[test]
     fn test_visibility_circumpolar() {
         // Polaris (Dec ~89°) from North pole (lat 90°) should be circumpolar
-        let vis = calculate_visibility(0.0, 89.0, 80.0, 0.0, None, None);
+        let vis = calculate_visibility(0.0, 89.0, 80.0, 0.0, None, None, None).unwrap();
         assert!(vis.is_circumpolar, "High dec star from high latitude should be circumpolar");
         assert!(!vis.never_rises);
         assert!(approx_eq(vis.hours_visible, 24.0, 0.1));
@@ -144,7 +378,7 @@ mod tests {
     #[test]
     fn test_visibility_never_rises() {
         // Southern star (Dec -80°) from Northern location (lat 60°) should never rise
-        let vis = calculate_visibility(0.0, -80.0, 60.0, 0.0, None, None);
+        let vis = calculate_visibility(0.0, -80.0, 60.0, 0.0, None, None, None).unwrap();
         assert!(vis.never_rises, "Southern star should never rise from far north");
         assert!(!vis.is_circumpolar);
         assert!(approx_eq(vis.hours_visible, 0.0, 0.1));
@@ -153,7 +387,7 @@ mod tests {
     #[test]
     fn test_visibility_transit_altitude() {
         // Transit altitude = 90 - |lat - dec|
-        let vis = calculate_visibility(0.0, 30.0, 45.0, 0.0, None, None);
+        let vis = calculate_visibility(0.0, 30.0, 45.0, 0.0, None, None, None).unwrap();
         let lat: f64 = 45.0;
         let dec: f64 = 30.0;
         let expected_transit = 90.0 - (lat - dec).abs(); // 75°
@@ -164,7 +398,7 @@ mod tests {
     #[test]
     fn test_visibility_rise_set_times() {
         // Normal visibility case: object that rises and sets
-        let vis = calculate_visibility(0.0, 20.0, 45.0, 0.0, None, None);
+        let vis = calculate_visibility(0.0, 20.0, 45.0, 0.0, None, None, None).unwrap();
         
         // Should have rise and set times
         assert!(vis.rise_time.is_some(), "Rise time should be present for normal object");
@@ -182,7 +416,7 @@ mod tests {
     #[test]
     fn test_visibility_circumpolar_has_transit() {
         // Circumpolar objects should have transit time but no rise/set
-        let vis = calculate_visibility(0.0, 85.0, 80.0, 0.0, None, None);
+        let vis = calculate_visibility(0.0, 85.0, 80.0, 0.0, None, None, None).unwrap();
         
         assert!(vis.is_circumpolar);
         assert!(vis.transit_time.is_some(), "Circumpolar object should have transit time");
@@ -193,7 +427,7 @@ mod tests {
     #[test]
     fn test_visibility_never_rises_no_times() {
         // Objects that never rise should have no times
-        let vis = calculate_visibility(0.0, -85.0, 80.0, 0.0, None, None);
+        let vis = calculate_visibility(0.0, -85.0, 80.0, 0.0, None, None, None).unwrap();
         
         assert!(vis.never_rises);
         assert!(vis.rise_time.is_none(), "Never-rises object should not have rise time");
@@ -211,10 +445,173 @@ mod tests {
         ];
         
         for (ra, dec, lat) in test_cases {
-            let vis = calculate_visibility(ra, dec, lat, 0.0, None, None);
+            let vis = calculate_visibility(ra, dec, lat, 0.0, None, None, None).unwrap();
             assert!(vis.hours_visible >= 0.0 && vis.hours_visible <= 24.0,
                 "Hours visible out of range: {} for ra={}, dec={}, lat={}", 
                 vis.hours_visible, ra, dec, lat);
         }
     }
+
+    // ------------------------------------------------------------------------
+    // Horizon Profile Tests
+    // ------------------------------------------------------------------------
+
+    const HORIZON_TEST_TS: i64 = 1768435200; // 2026-01-15 00:00 UTC
+
+    fn visibility_with_profile(dec: f64, profile: Option<Vec<(f64, f64)>>) -> VisibilityInfo {
+        calculate_visibility(115.0, dec, 40.0, 0.0, Some(HORIZON_TEST_TS), None, profile).unwrap()
+    }
+
+    #[test]
+    fn test_flat_profile_matches_analytic_rise_and_set() {
+        let analytic = visibility_with_profile(10.0, None);
+        let flat = visibility_with_profile(10.0, Some(vec![(0.0, 0.0)]));
+
+        assert!((flat.rise_time.unwrap() - analytic.rise_time.unwrap()).abs() < 300);
+        assert!((flat.set_time.unwrap() - analytic.set_time.unwrap()).abs() < 300);
+        assert!(approx_eq(flat.hours_visible, analytic.hours_visible, 0.15));
+    }
+
+    #[test]
+    fn test_eastern_obstruction_delays_rise() {
+        let flat = visibility_with_profile(10.0, None);
+        let obstructed = visibility_with_profile(
+            10.0,
+            Some(vec![(0.0, 0.0), (45.0, 20.0), (135.0, 20.0), (180.0, 0.0)]),
+        );
+
+        let delay = obstructed.rise_time.unwrap() - flat.rise_time.unwrap();
+        assert!(delay > 3600, "rise delayed by only {}s", delay);
+        assert!((obstructed.set_time.unwrap() - flat.set_time.unwrap()).abs() < 300);
+        assert!(obstructed.hours_visible < flat.hours_visible - 1.0);
+    }
+
+    #[test]
+    fn test_profile_can_hide_a_low_target() {
+        // Dec -40° peaks ~10° up from latitude 40°N, behind a 20° southern wall
+        let vis = visibility_with_profile(-40.0, Some(vec![(90.0, 20.0), (270.0, 20.0)]));
+        assert!(vis.never_rises);
+        assert!(!vis.is_visible);
+        assert_eq!(vis.hours_visible, 0.0);
+    }
+
+    #[test]
+    fn test_invalid_profile_is_rejected() {
+        let profile = Some(vec![(0.0, 0.0), (90.0, f64::NAN)]);
+        assert!(calculate_visibility(115.0, 10.0, 40.0, 0.0, Some(HORIZON_TEST_TS), None, profile).is_err());
+        let profile = Some(vec![(0.0, 95.0)]);
+        assert!(calculate_visibility(115.0, 10.0, 40.0, 0.0, Some(HORIZON_TEST_TS), None, profile).is_err());
+    }
+
+    // ------------------------------------------------------------------------
+    // Best Observation Time Tests
+    // ------------------------------------------------------------------------
+
+    // Local sidereal time at Greenwich midnight after 2026-01-15 is ~7.7h, so
+    // RA 115° transits near midnight and RA 295° near noon.
+
+    #[test]
+    fn test_best_time_midnight_transit() {
+        let best = calculate_best_observation_time(115.0, 20.0, 40.0, 0.0, "2026-01-15".to_string(), 30.0)
+            .unwrap();
+        assert!(best.transit_in_darkness);
+        let ts = best.timestamp.expect("target should be observable");
+        assert!(ts > best.dark_start.unwrap() && ts < best.dark_end.unwrap());
+        assert!(approx_eq(best.altitude, 70.0, 0.5), "altitude {}", best.altitude);
+        assert!(approx_eq(best.airmass.unwrap(), 1.064, 0.01));
+
+        // Within an hour of local midnight (2026-01-16T00:00Z)
+        let midnight = 1_768_521_600;
+        assert!((ts - midnight).abs() < 3600, "best time {} too far from midnight", ts);
+    }
+
+    #[test]
+    fn test_best_time_noon_transit_uses_dark_boundary() {
+        let best = calculate_best_observation_time(295.0, 20.0, 40.0, 0.0, "2026-01-15".to_string(), 0.0)
+            .unwrap();
+        assert!(!best.transit_in_darkness);
+        let ts = best.timestamp.expect("target is above the horizon at a darkness boundary");
+        assert!(ts == best.dark_start.unwrap() || ts == best.dark_end.unwrap());
+        assert!(best.altitude > 0.0 && best.altitude < 70.0);
+
+        // Too low for a 30° floor at either boundary
+        let high_floor = calculate_best_observation_time(295.0, 20.0, 40.0, 0.0, "2026-01-15".to_string(), 30.0)
+            .unwrap();
+        assert!(high_floor.timestamp.is_none());
+    }
+
+    #[test]
+    fn test_best_time_without_darkness() {
+        // Midsummer at 60°N never reaches astronomical darkness
+        let best = calculate_best_observation_time(100.0, 20.0, 60.0, 0.0, "2026-06-21".to_string(), 0.0)
+            .unwrap();
+        assert!(best.timestamp.is_none());
+        assert!(!best.transit_in_darkness);
+    }
+
+    #[test]
+    fn test_airmass() {
+        assert!(approx_eq(calculate_airmass(90.0).unwrap(), 1.0, 0.001));
+        assert!(calculate_airmass(30.0).unwrap() > 1.9);
+        assert!(calculate_airmass(-5.0).is_none());
+    }
+
+    #[test]
+    fn test_visibility_calendar_summer_vs_winter_objects() {
+        let total = |ra: f64, dec: f64, start: &str| -> f64 {
+            calculate_visibility_calendar(ra, dec, 45.0, 0.0, start.to_string(), 7, 30.0)
+                .unwrap()
+                .iter()
+                .map(|d| d.hours_visible)
+                .sum()
+        };
+
+        // M13 is a summer object, M42 a winter one
+        let (m13_ra, m13_dec) = (250.42, 36.46);
+        let (m42_ra, m42_dec) = (83.82, -5.39);
+        assert!(total(m13_ra, m13_dec, "2024-07-01") > total(m13_ra, m13_dec, "2024-01-01") + 5.0);
+        assert!(total(m42_ra, m42_dec, "2024-01-01") > total(m42_ra, m42_dec, "2024-07-01") + 5.0);
+    }
+
+    #[test]
+    fn test_visibility_calendar_days_and_bounds() {
+        let calendar = calculate_visibility_calendar(83.82, -5.39, 45.0, 0.0, "2024-01-30".to_string(), 3, 0.0).unwrap();
+        let dates: Vec<&str> = calendar.iter().map(|d| d.date.as_str()).collect();
+        assert_eq!(dates, ["2024-01-30", "2024-01-31", "2024-02-01"]);
+        for day in &calendar {
+            assert!(day.dark_hours > 10.0 && day.dark_hours < 14.0);
+            assert!(day.hours_visible > 0.0 && day.hours_visible <= day.dark_hours);
+        }
+
+        // No astronomical darkness at 60N around the June solstice
+        let midsummer = calculate_visibility_calendar(0.0, 60.0, 60.0, 0.0, "2024-06-21".to_string(), 1, 0.0).unwrap();
+        assert_eq!(midsummer[0].dark_hours, 0.0);
+        assert!(midsummer[0].max_altitude.is_none());
+
+        assert!(calculate_visibility_calendar(0.0, 0.0, 45.0, 0.0, "bad".to_string(), 1, 0.0).is_err());
+        assert!(calculate_visibility_calendar(0.0, 0.0, 45.0, 0.0, "2024-01-01".to_string(), 367, 0.0).is_err());
+        assert!(calculate_visibility_calendar(0.0, 0.0, 45.0, 0.0, "2024-01-01".to_string(), u32::MAX, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_context_matches_reference_values() {
+        // Meeus, Astronomical Algorithms, example 12.a: GMST at 1987-04-10 0h UT
+        let midnight = SkyContext::new(545011200, 0.0);
+        assert!(approx_eq(midnight.midnight_gmst, 197.693195, 1e-4), "{}", midnight.midnight_gmst);
+
+        // Example 13.b: Venus from the US Naval Observatory at 19:21 UT
+        let usno = SkyContext::new(545080860, -77.065556);
+        let venus = equatorial_to_horizontal_with_context(347.3193375, -6.719892, 38.921389, &usno, false);
+        assert!(approx_eq(venus.alt, 15.1249, 0.01), "alt {}", venus.alt);
+        assert!(approx_eq(venus.az, 248.0337, 0.01), "az {}", venus.az);
+
+        // On the equator, a star on the meridian at 0h UT transits then and,
+        // with 34' of refraction, stays up 12h04.5m
+        let vis = calculate_visibility_with_context(197.693195, 0.0, 0.0, &midnight, None);
+        let transit = vis.transit_time.unwrap();
+        assert!((transit - 545011200).abs() <= 60, "transit {}", transit);
+        assert!(approx_eq(vis.hours_visible, 12.0756, 0.01), "hours {}", vis.hours_visible);
+        assert!((transit - vis.rise_time.unwrap() - 21736).abs() <= 120);
+        assert!((vis.set_time.unwrap() - transit - 21736).abs() <= 120);
+    }
 }
@@ -0,0 +1,1008 @@
+//! Catalog lookups
+//! Identifies the IAU constellation containing a position using the
+//! Roman (1987) boundary table, which is defined at equinox B1875.0, and
+//! resolves deep-sky objects from a bundled Messier/NGC/IC catalog.
+
+use serde::{Deserialize, Serialize};
+
+use super::calculations::angular_separation;
+use super::calculations::common::{DEG_TO_RAD, RAD_TO_DEG};
+
+// ============================================================================
+// Boundary Data
+// ============================================================================
+
+/// Julian Date of the B1875.0 epoch the boundaries were drawn for
+const B1875_JD: f64 = 2405889.258550475;
+
+/// Julian Date of J2000.0
+const J2000_JD: f64 = 2451545.0;
+
+/// Constellation boundary strips: (RA low h, RA high h, Dec low deg, abbreviation).
+/// Rows are ordered by descending lower declination; the first row whose RA
+/// range contains the point and whose lower declination is at or below the
+/// point identifies the constellation (Roman 1987, CDS VI/42).
+const CONSTELLATION_BOUNDARIES: &[(f64, f64, f64, &str)] = &[
+    (0.0, 8.0, 88.0, "UMi"),
+    (23.0, 24.0, 88.0, "UMi"),
+    (8.0, 14.5, 86.5, "UMi"),
+    (21.0, 23.0, 86.166667, "UMi"),
+    (18.0, 21.0, 86.0, "UMi"),
+    (5.0, 8.0, 85.0, "Cep"),
+    (9.166667, 10.666667, 82.0, "Cam"),
+    (3.508333, 5.0, 80.0, "Cep"),
+    (10.666667, 11.5, 80.0, "Cam"),
+    (13.583333, 14.5, 80.0, "Cam"),
+    (17.5, 18.0, 80.0, "UMi"),
+    (20.166667, 21.0, 80.0, "Dra"),
+    (0.333333, 3.508333, 77.0, "Cep"),
+    (11.5, 13.583333, 77.0, "Cam"),
+    (16.533333, 17.5, 75.0, "UMi"),
+    (20.166667, 20.666667, 75.0, "Cep"),
+    (7.966667, 9.166667, 73.5, "Cam"),
+    (9.166667, 11.333333, 73.5, "Dra"),
+    (13.0, 14.0, 70.0, "UMi"),
+    (15.666667, 16.533333, 70.0, "UMi"),
+    (3.1, 3.416667, 68.0, "Cas"),
+    (20.416667, 20.666667, 67.0, "Dra"),
+    (11.333333, 12.0, 66.5, "Dra"),
+    (0.0, 0.333333, 66.0, "Cep"),
+    (14.0, 15.666667, 66.0, "UMi"),
+    (23.583333, 24.0, 66.0, "Cep"),
+    (12.0, 13.5, 64.0, "Dra"),
+    (13.5, 14.416667, 63.0, "Dra"),
+    (23.166667, 23.583333, 63.0, "Cep"),
+    (6.1, 7.0, 62.0, "Cam"),
+    (20.0, 20.416667, 61.5, "Dra"),
+    (20.536667, 20.6, 60.916667, "Cep"),
+    (7.0, 7.966667, 60.0, "Cam"),
+    (7.966667, 8.416667, 60.0, "UMa"),
+    (19.766667, 20.0, 59.5, "Dra"),
+    (20.0, 20.536667, 59.5, "Cep"),
+    (22.866667, 23.166667, 59.083333, "Cep"),
+    (1.908333, 2.433333, 58.5, "Cas"),
+    (19.416667, 19.766667, 58.0, "Dra"),
+    (1.7, 1.908333, 57.5, "Cas"),
+    (2.433333, 3.1, 57.0, "Cas"),
+    (3.1, 3.166667, 57.0, "Cam"),
+    (22.316667, 22.866667, 56.25, "Cep"),
+    (5.0, 6.1, 56.0, "Cam"),
+    (14.033333, 14.416667, 55.5, "UMa"),
+    (14.416667, 15.25, 55.5, "Dra"),
+    (19.083333, 19.416667, 55.5, "Dra"),
+    (3.166667, 3.333333, 55.0, "Cam"),
+    (22.133333, 22.316667, 55.0, "Cep"),
+    (20.6, 21.966667, 54.833333, "Cep"),
+    (1.366667, 1.7, 54.0, "Cas"),
+    (6.1, 6.5, 54.0, "Lyn"),
+    (12.083333, 13.5, 53.0, "UMa"),
+    (15.25, 15.75, 53.0, "Dra"),
+    (21.966667, 22.133333, 52.75, "Cep"),
+    (3.333333, 5.0, 52.5, "Cam"),
+    (22.866667, 23.333333, 52.5, "Cas"),
+    (15.75, 17.0, 51.5, "Dra"),
+    (2.041667, 2.516667, 50.5, "Per"),
+    (17.0, 18.233333, 50.5, "Dra"),
+    (1.116667, 1.366667, 50.0, "Cas"),
+    (1.366667, 1.666667, 50.0, "Per"),
+    (6.5, 6.8, 50.0, "Lyn"),
+    (23.333333, 23.583333, 50.0, "Cas"),
+    (13.5, 14.033333, 48.5, "UMa"),
+    (0.0, 0.166667, 48.0, "Cas"),
+    (0.866667, 1.116667, 48.0, "Cas"),
+    (23.583333, 24.0, 48.0, "Cas"),
+    (18.175, 18.233333, 47.5, "Her"),
+    (18.233333, 19.083333, 47.5, "Dra"),
+    (19.083333, 19.166667, 47.5, "Cyg"),
+    (1.666667, 2.041667, 47.0, "Per"),
+    (8.416667, 9.166667, 47.0, "UMa"),
+    (0.166667, 0.866667, 46.0, "Cas"),
+    (12.0, 12.083333, 45.0, "UMa"),
+    (6.8, 7.366667, 44.5, "Lyn"),
+    (21.908333, 21.966667, 44.0, "Cyg"),
+    (21.875, 21.908333, 43.75, "Cyg"),
+    (19.166667, 19.4, 43.5, "Cyg"),
+    (9.166667, 10.166667, 42.0, "UMa"),
+    (10.166667, 10.783333, 40.0, "UMa"),
+    (15.433333, 15.75, 40.0, "Boo"),
+    (15.75, 16.333333, 40.0, "Her"),
+    (9.25, 9.583333, 39.75, "Lyn"),
+    (2.0, 2.516667, 36.75, "And"),
+    (2.516667, 2.566667, 36.75, "Per"),
+    (19.358333, 19.4, 36.5, "Lyr"),
+    (4.5, 4.691667, 36.0, "Per"),
+    (21.733333, 21.875, 36.0, "Cyg"),
+    (21.875, 22.0, 36.0, "Lac"),
+    (6.533333, 7.366667, 35.5, "Aur"),
+    (7.366667, 7.75, 35.5, "Lyn"),
+    (1.408333, 2.0, 35.0, "And"),
+    (22.0, 22.816667, 35.0, "Lac"),
+    (22.816667, 22.866667, 34.5, "Lac"),
+    (22.866667, 23.5, 34.5, "And"),
+    (2.566667, 2.716667, 34.0, "Per"),
+    (10.783333, 11.0, 34.0, "UMa"),
+    (12.0, 12.333333, 34.0, "CVn"),
+    (7.75, 9.25, 33.5, "Lyn"),
+    (9.25, 9.883333, 33.5, "LMi"),
+    (0.716667, 1.408333, 33.0, "And"),
+    (15.183333, 15.433333, 33.0, "Boo"),
+    (23.5, 23.75, 32.083333, "And"),
+    (12.333333, 13.25, 32.0, "CVn"),
+    (23.75, 24.0, 31.333333, "And"),
+    (13.958333, 14.033333, 30.75, "CVn"),
+    (2.416667, 2.716667, 30.666667, "Tri"),
+    (2.716667, 4.5, 30.666667, "Per"),
+    (4.5, 4.75, 30.0, "Aur"),
+    (18.175, 18.366667, 30.0, "Lyr"),
+    (19.258333, 19.358333, 30.0, "Lyr"),
+    (11.0, 12.0, 29.0, "UMa"),
+    (19.666667, 20.916667, 29.0, "Cyg"),
+    (4.75, 5.883333, 28.5, "Aur"),
+    (9.883333, 10.5, 28.5, "LMi"),
+    (13.25, 13.958333, 28.5, "CVn"),
+    (0.0, 0.066667, 28.0, "And"),
+    (1.408333, 1.666667, 28.0, "Tri"),
+    (5.883333, 6.533333, 28.0, "Aur"),
+    (7.883333, 8.0, 28.0, "Gem"),
+    (20.916667, 21.733333, 28.0, "Cyg"),
+    (19.258333, 19.666667, 27.5, "Cyg"),
+    (1.916667, 2.416667, 27.25, "Tri"),
+    (16.166667, 16.333333, 27.0, "CrB"),
+    (15.083333, 15.183333, 26.0, "Boo"),
+    (15.183333, 16.166667, 26.0, "CrB"),
+    (18.366667, 18.866667, 26.0, "Lyr"),
+    (10.75, 11.0, 25.5, "LMi"),
+    (18.866667, 19.258333, 25.5, "Lyr"),
+    (1.666667, 1.916667, 25.0, "Tri"),
+    (0.716667, 0.85, 23.75, "Psc"),
+    (10.5, 10.75, 23.5, "LMi"),
+    (21.25, 21.416667, 23.5, "Vul"),
+    (5.7, 5.883333, 22.833333, "Tau"),
+    (0.066667, 0.141667, 22.0, "And"),
+    (15.916667, 16.033333, 22.0, "Ser"),
+    (5.883333, 6.216667, 21.5, "Gem"),
+    (19.833333, 20.25, 21.25, "Vul"),
+    (18.866667, 19.25, 21.083333, "Vul"),
+    (0.141667, 0.85, 21.0, "And"),
+    (20.25, 20.566667, 20.5, "Vul"),
+    (7.808333, 7.883333, 20.0, "Gem"),
+    (20.566667, 21.25, 19.5, "Vul"),
+    (19.25, 19.833333, 19.166667, "Vul"),
+    (3.283333, 3.366667, 19.0, "Ari"),
+    (18.866667, 19.0, 18.5, "Sge"),
+    (5.7, 5.766667, 18.0, "Ori"),
+    (6.216667, 6.308333, 17.5, "Gem"),
+    (19.0, 19.833333, 16.166667, "Sge"),
+    (4.966667, 5.333333, 16.0, "Tau"),
+    (15.916667, 16.083333, 16.0, "Her"),
+    (19.833333, 20.25, 15.75, "Sge"),
+    (4.616667, 4.966667, 15.5, "Tau"),
+    (5.333333, 5.6, 15.5, "Tau"),
+    (12.833333, 13.5, 15.0, "Com"),
+    (17.25, 18.25, 14.333333, "Her"),
+    (11.866667, 12.833333, 14.0, "Com"),
+    (7.5, 7.808333, 13.5, "Gem"),
+    (16.75, 17.25, 12.833333, "Her"),
+    (0.0, 0.141667, 12.5, "Peg"),
+    (5.6, 5.766667, 12.5, "Tau"),
+    (7.0, 7.5, 12.5, "Gem"),
+    (21.116667, 21.333333, 12.5, "Peg"),
+    (6.308333, 6.933333, 12.0, "Gem"),
+    (18.25, 18.866667, 12.0, "Her"),
+    (20.875, 21.05, 11.833333, "Del"),
+    (21.05, 21.116667, 11.833333, "Peg"),
+    (11.516667, 11.866667, 11.0, "Leo"),
+    (6.241667, 6.308333, 10.0, "Ori"),
+    (6.933333, 7.0, 10.0, "Gem"),
+    (7.808333, 7.925, 10.0, "Cnc"),
+    (23.833333, 24.0, 10.0, "Peg"),
+    (1.666667, 3.283333, 9.916667, "Ari"),
+    (20.141667, 20.3, 8.5, "Del"),
+    (13.5, 15.083333, 8.0, "Boo"),
+    (22.75, 23.833333, 7.5, "Peg"),
+    (7.925, 9.25, 7.0, "Cnc"),
+    (9.25, 10.75, 7.0, "Leo"),
+    (18.25, 18.662222, 6.25, "Oph"),
+    (18.662222, 18.866667, 6.25, "Aql"),
+    (20.833333, 20.875, 6.0, "Del"),
+    (7.0, 7.016667, 5.5, "CMi"),
+    (18.25, 18.425, 4.5, "Ser"),
+    (16.083333, 16.75, 4.0, "Her"),
+    (18.25, 18.425, 3.0, "Oph"),
+    (21.466667, 21.666667, 2.75, "Peg"),
+    (0.333333, 2.0, 2.0, "Psc"),
+    (18.583333, 18.866667, 2.0, "Ser"),
+    (20.3, 20.833333, 2.0, "Del"),
+    (20.833333, 21.333333, 2.0, "Equ"),
+    (21.333333, 21.466667, 2.0, "Peg"),
+    (22.0, 22.75, 2.0, "Peg"),
+    (21.666667, 22.0, 1.75, "Peg"),
+    (7.016667, 7.2, 1.5, "CMi"),
+    (3.583333, 4.616667, 0.0, "Tau"),
+    (4.616667, 4.666667, 0.0, "Ori"),
+    (7.2, 8.083333, 0.0, "CMi"),
+    (14.666667, 15.083333, 0.0, "Vir"),
+    (17.833333, 18.25, 0.0, "Oph"),
+    (2.65, 3.283333, -1.75, "Cet"),
+    (3.283333, 3.583333, -1.75, "Tau"),
+    (15.083333, 16.266667, -3.25, "Ser"),
+    (4.666667, 5.083333, -4.0, "Ori"),
+    (5.833333, 6.241667, -4.0, "Ori"),
+    (17.833333, 17.966667, -4.0, "Ser"),
+    (18.25, 18.583333, -4.0, "Ser"),
+    (18.583333, 18.866667, -4.0, "Aql"),
+    (22.75, 23.833333, -4.0, "Psc"),
+    (10.75, 11.516667, -6.0, "Leo"),
+    (11.516667, 11.833333, -6.0, "Vir"),
+    (0.0, 0.333333, -7.0, "Psc"),
+    (23.833333, 24.0, -7.0, "Psc"),
+    (14.25, 14.666667, -8.0, "Vir"),
+    (15.916667, 16.266667, -8.0, "Oph"),
+    (20.0, 20.533333, -9.0, "Aql"),
+    (21.333333, 21.866667, -9.0, "Aqr"),
+    (17.166667, 17.583333, -10.0, "Oph"),
+    (17.666667, 17.966667, -10.0, "Oph"),
+    (4.916667, 5.083333, -11.0, "Eri"),
+    (5.083333, 5.833333, -11.0, "Ori"),
+    (5.833333, 8.083333, -11.0, "Mon"),
+    (8.083333, 8.366667, -11.0, "Hya"),
+    (9.583333, 10.75, -11.0, "Sex"),
+    (11.833333, 12.833333, -11.0, "Vir"),
+    (17.583333, 17.666667, -11.666667, "Oph"),
+    (18.866667, 20.0, -12.033333, "Aql"),
+    (4.833333, 4.916667, -14.5, "Eri"),
+    (20.533333, 21.333333, -15.0, "Aqr"),
+    (17.166667, 18.25, -16.0, "Ser"),
+    (18.25, 18.866667, -16.0, "Sct"),
+    (8.366667, 8.583333, -17.0, "Hya"),
+    (16.266667, 16.375, -18.25, "Oph"),
+    (8.583333, 9.083333, -19.0, "Hya"),
+    (10.75, 10.833333, -19.0, "Crt"),
+    (16.266667, 16.375, -19.25, "Sco"),
+    (15.666667, 15.916667, -20.0, "Lib"),
+    (12.583333, 12.833333, -22.0, "Crv"),
+    (12.833333, 14.25, -22.0, "Vir"),
+    (9.083333, 9.75, -24.0, "Hya"),
+    (1.666667, 2.65, -24.383333, "Cet"),
+    (2.65, 3.75, -24.383333, "Eri"),
+    (10.833333, 11.833333, -24.5, "Crt"),
+    (11.833333, 12.583333, -24.5, "Crv"),
+    (14.25, 14.916667, -24.5, "Lib"),
+    (16.266667, 16.75, -24.583333, "Oph"),
+    (0.0, 1.666667, -25.5, "Cet"),
+    (21.333333, 21.866667, -25.5, "Cap"),
+    (21.866667, 23.833333, -25.5, "Aqr"),
+    (23.833333, 24.0, -25.5, "Cet"),
+    (9.75, 10.25, -26.5, "Hya"),
+    (4.7, 4.833333, -27.25, "Eri"),
+    (4.833333, 6.116667, -27.25, "Lep"),
+    (20.0, 21.333333, -28.0, "Cap"),
+    (10.25, 10.583333, -29.166667, "Hya"),
+    (12.583333, 14.916667, -29.5, "Hya"),
+    (14.916667, 15.666667, -29.5, "Lib"),
+    (15.666667, 16.0, -29.5, "Sco"),
+    (4.583333, 4.7, -30.0, "Eri"),
+    (16.75, 17.6, -30.0, "Oph"),
+    (17.6, 17.833333, -30.0, "Sgr"),
+    (10.583333, 10.833333, -31.166667, "Hya"),
+    (6.116667, 7.366667, -33.0, "CMa"),
+    (12.25, 12.583333, -33.0, "Hya"),
+    (10.833333, 12.25, -35.0, "Hya"),
+    (3.5, 3.75, -36.0, "For"),
+    (8.366667, 9.366667, -36.75, "Pyx"),
+    (4.266667, 4.583333, -37.0, "Eri"),
+    (17.833333, 19.166667, -37.0, "Sgr"),
+    (21.333333, 23.0, -37.0, "PsA"),
+    (23.0, 23.333333, -37.0, "Scl"),
+    (3.0, 3.5, -39.583333, "For"),
+    (9.366667, 11.0, -39.75, "Ant"),
+    (0.0, 1.666667, -40.0, "Scl"),
+    (1.666667, 3.0, -40.0, "For"),
+    (3.866667, 4.266667, -40.0, "Eri"),
+    (23.333333, 24.0, -40.0, "Scl"),
+    (14.166667, 14.916667, -42.0, "Cen"),
+    (15.666667, 16.0, -42.0, "Lup"),
+    (16.0, 16.420833, -42.0, "Sco"),
+    (4.833333, 5.0, -43.0, "Cae"),
+    (5.0, 6.583333, -43.0, "Col"),
+    (8.0, 8.366667, -43.0, "Pup"),
+    (3.416667, 3.866667, -44.0, "Eri"),
+    (16.420833, 17.833333, -45.5, "Sco"),
+    (17.833333, 19.166667, -45.5, "CrA"),
+    (19.166667, 20.333333, -45.5, "Sgr"),
+    (20.333333, 21.333333, -45.5, "Mic"),
+    (3.0, 3.416667, -46.0, "Eri"),
+    (4.5, 4.833333, -46.5, "Cae"),
+    (15.333333, 15.666667, -48.0, "Lup"),
+    (1.833333, 2.333333, -48.166667, "Phe"),
+    (2.666667, 3.0, -49.0, "Eri"),
+    (4.083333, 4.266667, -49.0, "Hor"),
+    (4.266667, 4.5, -49.0, "Cae"),
+    (21.333333, 22.0, -50.0, "Gru"),
+    (6.0, 8.0, -50.75, "Pup"),
+    (8.0, 8.166667, -50.75, "Vel"),
+    (2.416667, 2.666667, -51.0, "Eri"),
+    (3.833333, 4.083333, -51.0, "Hor"),
+    (1.583333, 1.833333, -51.5, "Phe"),
+    (6.0, 6.166667, -52.5, "Car"),
+    (8.166667, 8.45, -53.0, "Vel"),
+    (3.5, 3.833333, -53.166667, "Hor"),
+    (3.833333, 4.0, -53.166667, "Dor"),
+    (1.333333, 1.583333, -53.5, "Phe"),
+    (2.166667, 2.416667, -54.0, "Eri"),
+    (4.5, 5.0, -54.0, "Pic"),
+    (15.05, 15.333333, -54.0, "Lup"),
+    (8.45, 8.833333, -54.5, "Vel"),
+    (6.166667, 6.5, -55.0, "Car"),
+    (11.833333, 12.833333, -55.0, "Cen"),
+    (14.166667, 15.05, -55.0, "Lup"),
+    (15.05, 15.333333, -55.0, "Nor"),
+    (4.0, 4.333333, -56.5, "Dor"),
+    (8.833333, 11.0, -56.5, "Vel"),
+    (11.0, 11.25, -56.5, "Cen"),
+    (17.5, 18.0, -57.0, "Ara"),
+    (18.0, 20.333333, -57.0, "Tel"),
+    (22.0, 23.333333, -57.0, "Gru"),
+    (3.2, 3.5, -57.5, "Hor"),
+    (5.0, 5.5, -57.5, "Pic"),
+    (6.5, 6.833333, -58.0, "Car"),
+    (0.0, 1.333333, -58.5, "Phe"),
+    (1.333333, 2.166667, -58.5, "Eri"),
+    (23.333333, 24.0, -58.5, "Phe"),
+    (4.333333, 4.583333, -59.0, "Dor"),
+    (15.333333, 16.420833, -60.0, "Nor"),
+    (20.333333, 21.333333, -60.0, "Ind"),
+    (5.5, 6.0, -61.0, "Pic"),
+    (15.166667, 15.333333, -61.0, "Cir"),
+    (16.420833, 16.583333, -61.0, "Ara"),
+    (14.916667, 15.166667, -63.583333, "Cir"),
+    (16.583333, 16.75, -63.583333, "Ara"),
+    (6.0, 6.833333, -64.0, "Pic"),
+    (6.833333, 9.033333, -64.0, "Car"),
+    (11.25, 11.833333, -64.0, "Cen"),
+    (11.833333, 12.833333, -64.0, "Cru"),
+    (12.833333, 14.533333, -64.0, "Cen"),
+    (13.5, 13.666667, -65.0, "Cir"),
+    (16.75, 16.833333, -65.0, "Ara"),
+    (2.166667, 3.2, -67.5, "Hor"),
+    (3.2, 4.583333, -67.5, "Ret"),
+    (14.75, 14.916667, -67.5, "Cir"),
+    (16.833333, 17.5, -67.5, "Ara"),
+    (17.5, 18.0, -67.5, "Pav"),
+    (22.0, 23.333333, -67.5, "Tuc"),
+    (4.583333, 6.583333, -70.0, "Dor"),
+    (13.666667, 14.75, -70.0, "Cir"),
+    (14.75, 17.0, -70.0, "TrA"),
+    (0.0, 0.75, -75.0, "Tuc"),
+    (3.5, 4.583333, -75.0, "Hyi"),
+    (6.583333, 9.033333, -75.0, "Vol"),
+    (9.033333, 11.25, -75.0, "Car"),
+    (11.25, 13.666667, -75.0, "Mus"),
+    (18.0, 21.333333, -75.0, "Pav"),
+    (21.333333, 23.333333, -75.0, "Ind"),
+    (23.333333, 24.0, -75.0, "Tuc"),
+    (0.75, 1.333333, -76.0, "Tuc"),
+    (0.0, 3.5, -82.5, "Hyi"),
+    (7.666667, 13.666667, -82.5, "Cha"),
+    (13.666667, 18.0, -82.5, "Aps"),
+    (3.5, 7.666667, -85.0, "Men"),
+    (0.0, 24.0, -90.0, "Oct"),
+];
+
+/// IAU abbreviations and full constellation names
+const CONSTELLATION_NAMES: &[(&str, &str)] = &[
+    ("And", "Andromeda"),
+    ("Ant", "Antlia"),
+    ("Aps", "Apus"),
+    ("Aql", "Aquila"),
+    ("Aqr", "Aquarius"),
+    ("Ara", "Ara"),
+    ("Ari", "Aries"),
+    ("Aur", "Auriga"),
+    ("Boo", "Boötes"),
+    ("Cae", "Caelum"),
+    ("Cam", "Camelopardalis"),
+    ("Cap", "Capricornus"),
+    ("Car", "Carina"),
+    ("Cas", "Cassiopeia"),
+    ("Cen", "Centaurus"),
+    ("Cep", "Cepheus"),
+    ("Cet", "Cetus"),
+    ("Cha", "Chamaeleon"),
+    ("Cir", "Circinus"),
+    ("CMa", "Canis Major"),
+    ("CMi", "Canis Minor"),
+    ("Cnc", "Cancer"),
+    ("Col", "Columba"),
+    ("Com", "Coma Berenices"),
+    ("CrA", "Corona Australis"),
+    ("CrB", "Corona Borealis"),
+    ("Crt", "Crater"),
+    ("Cru", "Crux"),
+    ("Crv", "Corvus"),
+    ("CVn", "Canes Venatici"),
+    ("Cyg", "Cygnus"),
+    ("Del", "Delphinus"),
+    ("Dor", "Dorado"),
+    ("Dra", "Draco"),
+    ("Equ", "Equuleus"),
+    ("Eri", "Eridanus"),
+    ("For", "Fornax"),
+    ("Gem", "Gemini"),
+    ("Gru", "Grus"),
+    ("Her", "Hercules"),
+    ("Hor", "Horologium"),
+    ("Hya", "Hydra"),
+    ("Hyi", "Hydrus"),
+    ("Ind", "Indus"),
+    ("Lac", "Lacerta"),
+    ("Leo", "Leo"),
+    ("Lep", "Lepus"),
+    ("Lib", "Libra"),
+    ("LMi", "Leo Minor"),
+    ("Lup", "Lupus"),
+    ("Lyn", "Lynx"),
+    ("Lyr", "Lyra"),
+    ("Men", "Mensa"),
+    ("Mic", "Microscopium"),
+    ("Mon", "Monoceros"),
+    ("Mus", "Musca"),
+    ("Nor", "Norma"),
+    ("Oct", "Octans"),
+    ("Oph", "Ophiuchus"),
+    ("Ori", "Orion"),
+    ("Pav", "Pavo"),
+    ("Peg", "Pegasus"),
+    ("Per", "Perseus"),
+    ("Phe", "Phoenix"),
+    ("Pic", "Pictor"),
+    ("PsA", "Piscis Austrinus"),
+    ("Psc", "Pisces"),
+    ("Pup", "Puppis"),
+    ("Pyx", "Pyxis"),
+    ("Ret", "Reticulum"),
+    ("Scl", "Sculptor"),
+    ("Sco", "Scorpius"),
+    ("Sct", "Scutum"),
+    ("Ser", "Serpens"),
+    ("Sex", "Sextans"),
+    ("Sge", "Sagitta"),
+    ("Sgr", "Sagittarius"),
+    ("Tau", "Taurus"),
+    ("Tel", "Telescopium"),
+    ("TrA", "Triangulum Australe"),
+    ("Tri", "Triangulum"),
+    ("Tuc", "Tucana"),
+    ("UMa", "Ursa Major"),
+    ("UMi", "Ursa Minor"),
+    ("Vel", "Vela"),
+    ("Vir", "Virgo"),
+    ("Vol", "Volans"),
+    ("Vul", "Vulpecula"),
+];
+
+// ============================================================================
+// Lookup
+// ============================================================================
+
+/// Precess J2000 equatorial coordinates (degrees) to the B1875.0 equinox
+/// using the IAU 1976 precession angles. Returns (RA hours, Dec degrees).
+fn precess_j2000_to_b1875(ra: f64, dec: f64) -> (f64, f64) {
+    let t = (B1875_JD - J2000_JD) / 36525.0;
+    let arcsec = DEG_TO_RAD / 3600.0;
+    let zeta = (2306.2181 * t + 0.30188 * t * t + 0.017998 * t * t * t) * arcsec;
+    let z = (2306.2181 * t + 1.09468 * t * t + 0.018203 * t * t * t) * arcsec;
+    let theta = (2004.3109 * t - 0.42665 * t * t - 0.041833 * t * t * t) * arcsec;
+
+    let ra_rad = ra * DEG_TO_RAD;
+    let dec_rad = dec * DEG_TO_RAD;
+
+    let a = dec_rad.cos() * (ra_rad + zeta).sin();
+    let b = theta.cos() * dec_rad.cos() * (ra_rad + zeta).cos() - theta.sin() * dec_rad.sin();
+    let c = theta.sin() * dec_rad.cos() * (ra_rad + zeta).cos() + theta.cos() * dec_rad.sin();
+
+    let ra_1875 = ((a.atan2(b) + z) * RAD_TO_DEG).rem_euclid(360.0) / 15.0;
+    let dec_1875 = c.clamp(-1.0, 1.0).asin() * RAD_TO_DEG;
+    (ra_1875, dec_1875)
+}
+
+/// IAU abbreviation of the constellation containing J2000 coordinates (degrees)
+pub fn constellation_abbreviation(ra: f64, dec: f64) -> &'static str {
+    let (ra_h, dec_deg) = precess_j2000_to_b1875(ra.rem_euclid(360.0), dec.clamp(-90.0, 90.0));
+    CONSTELLATION_BOUNDARIES
+        .iter()
+        .find(|(ra_low, ra_high, dec_low, _)| {
+            dec_deg >= *dec_low && ra_h >= *ra_low && ra_h < *ra_high
+        })
+        .map(|(_, _, _, abbr)| *abbr)
+        .unwrap_or("Oct")
+}
+
+/// Full constellation name for an IAU abbreviation
+pub fn constellation_name(abbreviation: &str) -> Option<&'static str> {
+    CONSTELLATION_NAMES
+        .iter()
+        .find(|(abbr, _)| abbr.eq_ignore_ascii_case(abbreviation))
+        .map(|(_, name)| *name)
+}
+
+/// Name of the constellation containing J2000 coordinates (degrees)
+#[tauri::command]
+pub fn constellation_for_coords(ra: f64, dec: f64) -> String {
+    let abbr = constellation_abbreviation(ra, dec);
+    constellation_name(abbr).unwrap_or(abbr).to_string()
+}
+
+// ============================================================================
+// Deep-Sky Object Catalog
+// ============================================================================
+
+/// Bundled catalog row; sizes are in arcminutes
+struct CatalogEntry {
+    designation: &'static str,
+    common_name: Option<&'static str>,
+    object_type: &'static str,
+    constellation: &'static str,
+    ra: f64,
+    dec: f64,
+    magnitude: f64,
+    size_major: f64,
+    size_minor: Option<f64>,
+    aliases: &'static [&'static str],
+}
+
+/// Messier catalog plus the most popular NGC and IC objects (J2000, degrees)
+const DSO_CATALOG: &[CatalogEntry] = &[
+    CatalogEntry { designation: "M1", common_name: Some("Crab Nebula"), object_type: "SupernovaRemnant", constellation: "Tau", ra: 83.6287, dec: 22.0145, magnitude: 8.4, size_major: 6.0, size_minor: Some(4.0), aliases: &["NGC 1952"] },
+    CatalogEntry { designation: "M2", common_name: None, object_type: "GlobularCluster", constellation: "Aqr", ra: 323.3626, dec: -0.8232, magnitude: 6.3, size_major: 16.0, size_minor: None, aliases: &["NGC 7089"] },
+    CatalogEntry { designation: "M3", common_name: None, object_type: "GlobularCluster", constellation: "CVn", ra: 205.5484, dec: 28.3773, magnitude: 6.2, size_major: 18.0, size_minor: None, aliases: &["NGC 5272"] },
+    CatalogEntry { designation: "M4", common_name: None, object_type: "GlobularCluster", constellation: "Sco", ra: 245.8967, dec: -26.5256, magnitude: 5.6, size_major: 36.0, size_minor: None, aliases: &["NGC 6121"] },
+    CatalogEntry { designation: "M5", common_name: None, object_type: "GlobularCluster", constellation: "Ser", ra: 229.6385, dec: 2.081, magnitude: 5.6, size_major: 23.0, size_minor: None, aliases: &["NGC 5904"] },
+    CatalogEntry { designation: "M6", common_name: Some("Butterfly Cluster"), object_type: "OpenCluster", constellation: "Sco", ra: 265.0833, dec: -32.2167, magnitude: 4.2, size_major: 20.0, size_minor: None, aliases: &["NGC 6405"] },
+    CatalogEntry { designation: "M7", common_name: Some("Ptolemy Cluster"), object_type: "OpenCluster", constellation: "Sco", ra: 268.4667, dec: -34.7833, magnitude: 3.3, size_major: 80.0, size_minor: None, aliases: &["NGC 6475"] },
+    CatalogEntry { designation: "M8", common_name: Some("Lagoon Nebula"), object_type: "EmissionNebula", constellation: "Sgr", ra: 270.9208, dec: -24.3833, magnitude: 5.0, size_major: 90.0, size_minor: Some(40.0), aliases: &["NGC 6523"] },
+    CatalogEntry { designation: "M9", common_name: None, object_type: "GlobularCluster", constellation: "Oph", ra: 259.7981, dec: -18.5161, magnitude: 7.7, size_major: 12.0, size_minor: None, aliases: &["NGC 6333"] },
+    CatalogEntry { designation: "M10", common_name: None, object_type: "GlobularCluster", constellation: "Oph", ra: 254.2877, dec: -4.0994, magnitude: 6.6, size_major: 20.0, size_minor: None, aliases: &["NGC 6254"] },
+    CatalogEntry { designation: "M11", common_name: Some("Wild Duck Cluster"), object_type: "OpenCluster", constellation: "Sct", ra: 282.7667, dec: -6.2667, magnitude: 5.8, size_major: 14.0, size_minor: None, aliases: &["NGC 6705"] },
+    CatalogEntry { designation: "M12", common_name: None, object_type: "GlobularCluster", constellation: "Oph", ra: 251.8092, dec: -1.9478, magnitude: 6.7, size_major: 16.0, size_minor: None, aliases: &["NGC 6218"] },
+    CatalogEntry { designation: "M13", common_name: Some("Great Hercules Cluster"), object_type: "GlobularCluster", constellation: "Her", ra: 250.4218, dec: 36.4617, magnitude: 5.8, size_major: 20.0, size_minor: None, aliases: &["NGC 6205"] },
+    CatalogEntry { designation: "M14", common_name: None, object_type: "GlobularCluster", constellation: "Oph", ra: 264.4004, dec: -3.2458, magnitude: 7.6, size_major: 11.0, size_minor: None, aliases: &["NGC 6402"] },
+    CatalogEntry { designation: "M15", common_name: None, object_type: "GlobularCluster", constellation: "Peg", ra: 322.493, dec: 12.167, magnitude: 6.2, size_major: 18.0, size_minor: None, aliases: &["NGC 7078"] },
+    CatalogEntry { designation: "M16", common_name: Some("Eagle Nebula"), object_type: "EmissionNebula", constellation: "Ser", ra: 274.7, dec: -13.8167, magnitude: 6.0, size_major: 35.0, size_minor: None, aliases: &["NGC 6611"] },
+    CatalogEntry { designation: "M17", common_name: Some("Omega Nebula"), object_type: "EmissionNebula", constellation: "Sgr", ra: 275.1958, dec: -16.1833, magnitude: 6.0, size_major: 46.0, size_minor: Some(37.0), aliases: &["NGC 6618", "Swan Nebula"] },
+    CatalogEntry { designation: "M18", common_name: None, object_type: "OpenCluster", constellation: "Sgr", ra: 274.9, dec: -17.1333, magnitude: 6.9, size_major: 9.0, size_minor: None, aliases: &["NGC 6613"] },
+    CatalogEntry { designation: "M19", common_name: None, object_type: "GlobularCluster", constellation: "Oph", ra: 255.6571, dec: -26.2681, magnitude: 6.8, size_major: 17.0, size_minor: None, aliases: &["NGC 6273"] },
+    CatalogEntry { designation: "M20", common_name: Some("Trifid Nebula"), object_type: "EmissionNebula", constellation: "Sgr", ra: 270.6208, dec: -23.0333, magnitude: 6.3, size_major: 28.0, size_minor: None, aliases: &["NGC 6514"] },
+    CatalogEntry { designation: "M21", common_name: None, object_type: "OpenCluster", constellation: "Sgr", ra: 270.9833, dec: -22.5, magnitude: 5.9, size_major: 13.0, size_minor: None, aliases: &["NGC 6531"] },
+    CatalogEntry { designation: "M22", common_name: None, object_type: "GlobularCluster", constellation: "Sgr", ra: 279.0996, dec: -23.9047, magnitude: 5.1, size_major: 32.0, size_minor: None, aliases: &["NGC 6656"] },
+    CatalogEntry { designation: "M23", common_name: None, object_type: "OpenCluster", constellation: "Sgr", ra: 269.2667, dec: -18.9833, magnitude: 5.5, size_major: 27.0, size_minor: None, aliases: &["NGC 6494"] },
+    CatalogEntry { designation: "M24", common_name: Some("Sagittarius Star Cloud"), object_type: "StarCluster", constellation: "Sgr", ra: 274.5, dec: -18.4833, magnitude: 4.6, size_major: 90.0, size_minor: None, aliases: &["IC 4715"] },
+    CatalogEntry { designation: "M25", common_name: None, object_type: "OpenCluster", constellation: "Sgr", ra: 277.8667, dec: -19.25, magnitude: 4.6, size_major: 32.0, size_minor: None, aliases: &["IC 4725"] },
+    CatalogEntry { designation: "M26", common_name: None, object_type: "OpenCluster", constellation: "Sct", ra: 281.3167, dec: -9.3833, magnitude: 8.0, size_major: 15.0, size_minor: None, aliases: &["NGC 6694"] },
+    CatalogEntry { designation: "M27", common_name: Some("Dumbbell Nebula"), object_type: "PlanetaryNebula", constellation: "Vul", ra: 299.9017, dec: 22.7211, magnitude: 7.4, size_major: 8.0, size_minor: Some(5.7), aliases: &["NGC 6853"] },
+    CatalogEntry { designation: "M28", common_name: None, object_type: "GlobularCluster", constellation: "Sgr", ra: 276.1369, dec: -24.8697, magnitude: 6.8, size_major: 11.0, size_minor: None, aliases: &["NGC 6626"] },
+    CatalogEntry { designation: "M29", common_name: None, object_type: "OpenCluster", constellation: "Cyg", ra: 305.9917, dec: 38.5167, magnitude: 6.6, size_major: 7.0, size_minor: None, aliases: &["NGC 6913"] },
+    CatalogEntry { designation: "M30", common_name: None, object_type: "GlobularCluster", constellation: "Cap", ra: 325.0921, dec: -23.1797, magnitude: 7.2, size_major: 12.0, size_minor: None, aliases: &["NGC 7099"] },
+    CatalogEntry { designation: "M31", common_name: Some("Andromeda Galaxy"), object_type: "Galaxy", constellation: "And", ra: 10.6847, dec: 41.2689, magnitude: 3.4, size_major: 190.0, size_minor: Some(60.0), aliases: &["NGC 224"] },
+    CatalogEntry { designation: "M32", common_name: None, object_type: "Galaxy", constellation: "And", ra: 10.6743, dec: 40.8658, magnitude: 8.1, size_major: 8.0, size_minor: Some(6.0), aliases: &["NGC 221"] },
+    CatalogEntry { designation: "M33", common_name: Some("Triangulum Galaxy"), object_type: "Galaxy", constellation: "Tri", ra: 23.4621, dec: 30.6602, magnitude: 5.7, size_major: 73.0, size_minor: Some(45.0), aliases: &["NGC 598"] },
+    CatalogEntry { designation: "M34", common_name: None, object_type: "OpenCluster", constellation: "Per", ra: 40.5167, dec: 42.7667, magnitude: 5.2, size_major: 35.0, size_minor: None, aliases: &["NGC 1039"] },
+    CatalogEntry { designation: "M35", common_name: None, object_type: "OpenCluster", constellation: "Gem", ra: 92.25, dec: 24.3333, magnitude: 5.1, size_major: 28.0, size_minor: None, aliases: &["NGC 2168"] },
+    CatalogEntry { designation: "M36", common_name: None, object_type: "OpenCluster", constellation: "Aur", ra: 84.0833, dec: 34.1333, magnitude: 6.0, size_major: 12.0, size_minor: None, aliases: &["NGC 1960"] },
+    CatalogEntry { designation: "M37", common_name: None, object_type: "OpenCluster", constellation: "Aur", ra: 88.075, dec: 32.55, magnitude: 5.6, size_major: 24.0, size_minor: None, aliases: &["NGC 2099"] },
+    CatalogEntry { designation: "M38", common_name: None, object_type: "OpenCluster", constellation: "Aur", ra: 82.1667, dec: 35.8333, magnitude: 6.4, size_major: 21.0, size_minor: None, aliases: &["NGC 1912"] },
+    CatalogEntry { designation: "M39", common_name: None, object_type: "OpenCluster", constellation: "Cyg", ra: 322.9167, dec: 48.4333, magnitude: 4.6, size_major: 32.0, size_minor: None, aliases: &["NGC 7092"] },
+    CatalogEntry { designation: "M40", common_name: Some("Winnecke 4"), object_type: "DoubleStar", constellation: "UMa", ra: 185.55, dec: 58.0833, magnitude: 8.4, size_major: 0.8, size_minor: None, aliases: &["WNC 4"] },
+    CatalogEntry { designation: "M41", common_name: None, object_type: "OpenCluster", constellation: "CMa", ra: 101.5, dec: -20.7333, magnitude: 4.5, size_major: 38.0, size_minor: None, aliases: &["NGC 2287"] },
+    CatalogEntry { designation: "M42", common_name: Some("Orion Nebula"), object_type: "EmissionNebula", constellation: "Ori", ra: 83.8221, dec: -5.3911, magnitude: 4.0, size_major: 85.0, size_minor: Some(60.0), aliases: &["NGC 1976"] },
+    CatalogEntry { designation: "M43", common_name: Some("De Mairan's Nebula"), object_type: "EmissionNebula", constellation: "Ori", ra: 83.8875, dec: -5.2667, magnitude: 9.0, size_major: 20.0, size_minor: None, aliases: &["NGC 1982"] },
+    CatalogEntry { designation: "M44", common_name: Some("Beehive Cluster"), object_type: "OpenCluster", constellation: "Cnc", ra: 130.0333, dec: 19.6667, magnitude: 3.1, size_major: 95.0, size_minor: None, aliases: &["NGC 2632", "Praesepe"] },
+    CatalogEntry { designation: "M45", common_name: Some("Pleiades"), object_type: "OpenCluster", constellation: "Tau", ra: 56.6, dec: 24.1167, magnitude: 1.6, size_major: 110.0, size_minor: None, aliases: &["Seven Sisters"] },
+    CatalogEntry { designation: "M46", common_name: None, object_type: "OpenCluster", constellation: "Pup", ra: 115.4333, dec: -14.8167, magnitude: 6.1, size_major: 27.0, size_minor: None, aliases: &["NGC 2437"] },
+    CatalogEntry { designation: "M47", common_name: None, object_type: "OpenCluster", constellation: "Pup", ra: 114.15, dec: -14.4833, magnitude: 4.4, size_major: 30.0, size_minor: None, aliases: &["NGC 2422"] },
+    CatalogEntry { designation: "M48", common_name: None, object_type: "OpenCluster", constellation: "Hya", ra: 123.4333, dec: -5.8, magnitude: 5.8, size_major: 54.0, size_minor: None, aliases: &["NGC 2548"] },
+    CatalogEntry { designation: "M49", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 187.4449, dec: 8.0003, magnitude: 8.4, size_major: 10.0, size_minor: Some(8.0), aliases: &["NGC 4472"] },
+    CatalogEntry { designation: "M50", common_name: None, object_type: "OpenCluster", constellation: "Mon", ra: 105.6833, dec: -8.35, magnitude: 5.9, size_major: 16.0, size_minor: None, aliases: &["NGC 2323"] },
+    CatalogEntry { designation: "M51", common_name: Some("Whirlpool Galaxy"), object_type: "Galaxy", constellation: "CVn", ra: 202.4696, dec: 47.1952, magnitude: 8.4, size_major: 11.0, size_minor: Some(7.0), aliases: &["NGC 5194"] },
+    CatalogEntry { designation: "M52", common_name: None, object_type: "OpenCluster", constellation: "Cas", ra: 351.2, dec: 61.5833, magnitude: 6.9, size_major: 13.0, size_minor: None, aliases: &["NGC 7654"] },
+    CatalogEntry { designation: "M53", common_name: None, object_type: "GlobularCluster", constellation: "Com", ra: 198.2303, dec: 18.1681, magnitude: 7.6, size_major: 13.0, size_minor: None, aliases: &["NGC 5024"] },
+    CatalogEntry { designation: "M54", common_name: None, object_type: "GlobularCluster", constellation: "Sgr", ra: 283.7637, dec: -30.4783, magnitude: 7.6, size_major: 12.0, size_minor: None, aliases: &["NGC 6715"] },
+    CatalogEntry { designation: "M55", common_name: None, object_type: "GlobularCluster", constellation: "Sgr", ra: 294.9988, dec: -30.9647, magnitude: 6.3, size_major: 19.0, size_minor: None, aliases: &["NGC 6809"] },
+    CatalogEntry { designation: "M56", common_name: None, object_type: "GlobularCluster", constellation: "Lyr", ra: 289.1479, dec: 30.1833, magnitude: 8.3, size_major: 8.8, size_minor: None, aliases: &["NGC 6779"] },
+    CatalogEntry { designation: "M57", common_name: Some("Ring Nebula"), object_type: "PlanetaryNebula", constellation: "Lyr", ra: 283.3962, dec: 33.0286, magnitude: 8.8, size_major: 1.4, size_minor: Some(1.0), aliases: &["NGC 6720"] },
+    CatalogEntry { designation: "M58", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 189.9973, dec: 11.8203, magnitude: 9.7, size_major: 5.9, size_minor: Some(4.7), aliases: &["NGC 4579"] },
+    CatalogEntry { designation: "M59", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 190.5092, dec: 11.6467, magnitude: 9.6, size_major: 5.4, size_minor: Some(3.7), aliases: &["NGC 4621"] },
+    CatalogEntry { designation: "M60", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 190.9166, dec: 11.5525, magnitude: 8.8, size_major: 7.4, size_minor: Some(6.0), aliases: &["NGC 4649"] },
+    CatalogEntry { designation: "M61", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 185.4787, dec: 4.4736, magnitude: 9.7, size_major: 6.5, size_minor: Some(5.8), aliases: &["NGC 4303"] },
+    CatalogEntry { designation: "M62", common_name: None, object_type: "GlobularCluster", constellation: "Oph", ra: 255.3033, dec: -30.1136, magnitude: 6.5, size_major: 15.0, size_minor: None, aliases: &["NGC 6266"] },
+    CatalogEntry { designation: "M63", common_name: Some("Sunflower Galaxy"), object_type: "Galaxy", constellation: "CVn", ra: 198.9554, dec: 42.0294, magnitude: 8.6, size_major: 12.6, size_minor: Some(7.2), aliases: &["NGC 5055"] },
+    CatalogEntry { designation: "M64", common_name: Some("Black Eye Galaxy"), object_type: "Galaxy", constellation: "Com", ra: 194.1826, dec: 21.6817, magnitude: 8.5, size_major: 10.3, size_minor: Some(5.4), aliases: &["NGC 4826"] },
+    CatalogEntry { designation: "M65", common_name: None, object_type: "Galaxy", constellation: "Leo", ra: 169.733, dec: 13.0922, magnitude: 9.3, size_major: 10.0, size_minor: Some(3.3), aliases: &["NGC 3623"] },
+    CatalogEntry { designation: "M66", common_name: None, object_type: "Galaxy", constellation: "Leo", ra: 170.0629, dec: 12.9914, magnitude: 8.9, size_major: 9.1, size_minor: Some(4.2), aliases: &["NGC 3627"] },
+    CatalogEntry { designation: "M67", common_name: None, object_type: "OpenCluster", constellation: "Cnc", ra: 132.825, dec: 11.8, magnitude: 6.9, size_major: 30.0, size_minor: None, aliases: &["NGC 2682"] },
+    CatalogEntry { designation: "M68", common_name: None, object_type: "GlobularCluster", constellation: "Hya", ra: 189.8667, dec: -26.7444, magnitude: 7.8, size_major: 11.0, size_minor: None, aliases: &["NGC 4590"] },
+    CatalogEntry { designation: "M69", common_name: None, object_type: "GlobularCluster", constellation: "Sgr", ra: 279.0983, dec: -32.3481, magnitude: 7.6, size_major: 9.8, size_minor: None, aliases: &["NGC 6637"] },
+    CatalogEntry { designation: "M70", common_name: None, object_type: "GlobularCluster", constellation: "Sgr", ra: 281.2754, dec: -32.2908, magnitude: 7.9, size_major: 8.0, size_minor: None, aliases: &["NGC 6681"] },
+    CatalogEntry { designation: "M71", common_name: None, object_type: "GlobularCluster", constellation: "Sge", ra: 298.4438, dec: 18.7792, magnitude: 8.2, size_major: 7.2, size_minor: None, aliases: &["NGC 6838"] },
+    CatalogEntry { designation: "M72", common_name: None, object_type: "GlobularCluster", constellation: "Aqr", ra: 313.3646, dec: -12.5372, magnitude: 9.3, size_major: 6.6, size_minor: None, aliases: &["NGC 6981"] },
+    CatalogEntry { designation: "M73", common_name: None, object_type: "Asterism", constellation: "Aqr", ra: 314.75, dec: -12.6333, magnitude: 9.0, size_major: 2.8, size_minor: None, aliases: &["NGC 6994"] },
+    CatalogEntry { designation: "M74", common_name: None, object_type: "Galaxy", constellation: "Psc", ra: 24.174, dec: 15.7833, magnitude: 9.4, size_major: 10.5, size_minor: Some(9.5), aliases: &["NGC 628"] },
+    CatalogEntry { designation: "M75", common_name: None, object_type: "GlobularCluster", constellation: "Sgr", ra: 301.52, dec: -21.9211, magnitude: 8.5, size_major: 6.8, size_minor: None, aliases: &["NGC 6864"] },
+    CatalogEntry { designation: "M76", common_name: Some("Little Dumbbell"), object_type: "PlanetaryNebula", constellation: "Per", ra: 25.5817, dec: 51.5747, magnitude: 10.1, size_major: 2.7, size_minor: Some(1.8), aliases: &["NGC 650", "NGC 651"] },
+    CatalogEntry { designation: "M77", common_name: None, object_type: "Galaxy", constellation: "Cet", ra: 40.6696, dec: -0.0133, magnitude: 8.9, size_major: 7.3, size_minor: Some(6.3), aliases: &["NGC 1068"] },
+    CatalogEntry { designation: "M78", common_name: None, object_type: "ReflectionNebula", constellation: "Ori", ra: 86.6833, dec: 0.0833, magnitude: 8.3, size_major: 8.0, size_minor: Some(6.0), aliases: &["NGC 2068"] },
+    CatalogEntry { designation: "M79", common_name: None, object_type: "GlobularCluster", constellation: "Lep", ra: 81.0463, dec: -24.5247, magnitude: 7.7, size_major: 9.6, size_minor: None, aliases: &["NGC 1904"] },
+    CatalogEntry { designation: "M80", common_name: None, object_type: "GlobularCluster", constellation: "Sco", ra: 244.26, dec: -22.9758, magnitude: 7.3, size_major: 10.0, size_minor: None, aliases: &["NGC 6093"] },
+    CatalogEntry { designation: "M81", common_name: Some("Bode's Galaxy"), object_type: "Galaxy", constellation: "UMa", ra: 148.8882, dec: 69.0653, magnitude: 6.9, size_major: 26.9, size_minor: Some(14.1), aliases: &["NGC 3031"] },
+    CatalogEntry { designation: "M82", common_name: Some("Cigar Galaxy"), object_type: "Galaxy", constellation: "UMa", ra: 148.9685, dec: 69.6797, magnitude: 8.4, size_major: 11.2, size_minor: Some(4.3), aliases: &["NGC 3034"] },
+    CatalogEntry { designation: "M83", common_name: Some("Southern Pinwheel"), object_type: "Galaxy", constellation: "Hya", ra: 204.2538, dec: -29.8657, magnitude: 7.5, size_major: 12.9, size_minor: Some(11.5), aliases: &["NGC 5236"] },
+    CatalogEntry { designation: "M84", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 186.2655, dec: 12.8869, magnitude: 9.1, size_major: 6.5, size_minor: Some(5.6), aliases: &["NGC 4374"] },
+    CatalogEntry { designation: "M85", common_name: None, object_type: "Galaxy", constellation: "Com", ra: 186.3499, dec: 18.1914, magnitude: 9.1, size_major: 7.3, size_minor: Some(5.5), aliases: &["NGC 4382"] },
+    CatalogEntry { designation: "M86", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 186.5489, dec: 12.9461, magnitude: 8.9, size_major: 9.8, size_minor: Some(6.3), aliases: &["NGC 4406"] },
+    CatalogEntry { designation: "M87", common_name: Some("Virgo A"), object_type: "Galaxy", constellation: "Vir", ra: 187.7059, dec: 12.3911, magnitude: 8.6, size_major: 8.3, size_minor: Some(6.6), aliases: &["NGC 4486"] },
+    CatalogEntry { designation: "M88", common_name: None, object_type: "Galaxy", constellation: "Com", ra: 188.9963, dec: 14.4203, magnitude: 9.6, size_major: 6.9, size_minor: Some(3.7), aliases: &["NGC 4501"] },
+    CatalogEntry { designation: "M89", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 188.9159, dec: 12.5564, magnitude: 9.8, size_major: 5.1, size_minor: Some(4.7), aliases: &["NGC 4552"] },
+    CatalogEntry { designation: "M90", common_name: None, object_type: "Galaxy", constellation: "Vir", ra: 189.2095, dec: 13.1628, magnitude: 9.5, size_major: 9.5, size_minor: Some(4.4), aliases: &["NGC 4569"] },
+    CatalogEntry { designation: "M91", common_name: None, object_type: "Galaxy", constellation: "Com", ra: 188.8642, dec: 14.4961, magnitude: 10.2, size_major: 5.4, size_minor: Some(4.3), aliases: &["NGC 4548"] },
+    CatalogEntry { designation: "M92", common_name: None, object_type: "GlobularCluster", constellation: "Her", ra: 259.2807, dec: 43.1364, magnitude: 6.4, size_major: 14.0, size_minor: None, aliases: &["NGC 6341"] },
+    CatalogEntry { designation: "M93", common_name: None, object_type: "OpenCluster", constellation: "Pup", ra: 116.1333, dec: -23.85, magnitude: 6.2, size_major: 22.0, size_minor: None, aliases: &["NGC 2447"] },
+    CatalogEntry { designation: "M94", common_name: None, object_type: "Galaxy", constellation: "CVn", ra: 192.7215, dec: 41.1203, magnitude: 8.2, size_major: 14.4, size_minor: Some(12.1), aliases: &["NGC 4736"] },
+    CatalogEntry { designation: "M95", common_name: None, object_type: "Galaxy", constellation: "Leo", ra: 160.9898, dec: 11.7039, magnitude: 9.7, size_major: 7.4, size_minor: Some(5.0), aliases: &["NGC 3351"] },
+    CatalogEntry { designation: "M96", common_name: None, object_type: "Galaxy", constellation: "Leo", ra: 161.6905, dec: 11.8194, magnitude: 9.2, size_major: 7.6, size_minor: Some(5.2), aliases: &["NGC 3368"] },
+    CatalogEntry { designation: "M97", common_name: Some("Owl Nebula"), object_type: "PlanetaryNebula", constellation: "UMa", ra: 168.6987, dec: 55.0192, magnitude: 9.9, size_major: 3.4, size_minor: Some(3.3), aliases: &["NGC 3587"] },
+    CatalogEntry { designation: "M98", common_name: None, object_type: "Galaxy", constellation: "Com", ra: 183.4514, dec: 14.9003, magnitude: 10.1, size_major: 9.8, size_minor: Some(2.8), aliases: &["NGC 4192"] },
+    CatalogEntry { designation: "M99", common_name: None, object_type: "Galaxy", constellation: "Com", ra: 184.7068, dec: 14.4167, magnitude: 9.9, size_major: 5.4, size_minor: Some(4.7), aliases: &["NGC 4254"] },
+    CatalogEntry { designation: "M100", common_name: None, object_type: "Galaxy", constellation: "Com", ra: 185.7289, dec: 15.8222, magnitude: 9.3, size_major: 7.4, size_minor: Some(6.3), aliases: &["NGC 4321"] },
+    CatalogEntry { designation: "M101", common_name: Some("Pinwheel Galaxy"), object_type: "Galaxy", constellation: "UMa", ra: 210.8024, dec: 54.3489, magnitude: 7.9, size_major: 28.8, size_minor: Some(26.9), aliases: &["NGC 5457"] },
+    CatalogEntry { designation: "M102", common_name: Some("Spindle Galaxy"), object_type: "Galaxy", constellation: "Dra", ra: 226.6232, dec: 55.7636, magnitude: 9.9, size_major: 6.5, size_minor: Some(3.1), aliases: &["NGC 5866"] },
+    CatalogEntry { designation: "M103", common_name: None, object_type: "OpenCluster", constellation: "Cas", ra: 23.3333, dec: 60.65, magnitude: 7.4, size_major: 6.0, size_minor: None, aliases: &["NGC 581"] },
+    CatalogEntry { designation: "M104", common_name: Some("Sombrero Galaxy"), object_type: "Galaxy", constellation: "Vir", ra: 189.9977, dec: -11.623, magnitude: 8.0, size_major: 8.9, size_minor: Some(4.0), aliases: &["NGC 4594"] },
+    CatalogEntry { designation: "M105", common_name: None, object_type: "Galaxy", constellation: "Leo", ra: 161.9564, dec: 12.5819, magnitude: 9.3, size_major: 5.4, size_minor: Some(4.8), aliases: &["NGC 3379"] },
+    CatalogEntry { designation: "M106", common_name: None, object_type: "Galaxy", constellation: "CVn", ra: 184.7397, dec: 47.3039, magnitude: 8.4, size_major: 18.6, size_minor: Some(7.2), aliases: &["NGC 4258"] },
+    CatalogEntry { designation: "M107", common_name: None, object_type: "GlobularCluster", constellation: "Oph", ra: 248.1325, dec: -13.0536, magnitude: 7.9, size_major: 13.0, size_minor: None, aliases: &["NGC 6171"] },
+    CatalogEntry { designation: "M108", common_name: Some("Surfboard Galaxy"), object_type: "Galaxy", constellation: "UMa", ra: 167.879, dec: 55.6742, magnitude: 10.0, size_major: 8.7, size_minor: Some(2.2), aliases: &["NGC 3556"] },
+    CatalogEntry { designation: "M109", common_name: None, object_type: "Galaxy", constellation: "UMa", ra: 179.3999, dec: 53.3747, magnitude: 9.8, size_major: 7.6, size_minor: Some(4.7), aliases: &["NGC 3992"] },
+    CatalogEntry { designation: "M110", common_name: None, object_type: "Galaxy", constellation: "And", ra: 10.0917, dec: 41.685, magnitude: 8.5, size_major: 21.9, size_minor: Some(11.0), aliases: &["NGC 205"] },
+    CatalogEntry { designation: "NGC 869", common_name: Some("Double Cluster"), object_type: "OpenCluster", constellation: "Per", ra: 34.75, dec: 57.133, magnitude: 5.3, size_major: 30.0, size_minor: None, aliases: &["h Persei"] },
+    CatalogEntry { designation: "NGC 884", common_name: Some("Double Cluster"), object_type: "OpenCluster", constellation: "Per", ra: 35.083, dec: 57.133, magnitude: 6.1, size_major: 30.0, size_minor: None, aliases: &["χ Persei"] },
+    CatalogEntry { designation: "NGC 253", common_name: Some("Sculptor Galaxy"), object_type: "Galaxy", constellation: "Scl", ra: 11.888, dec: -25.288, magnitude: 7.1, size_major: 27.5, size_minor: Some(6.8), aliases: &[] },
+    CatalogEntry { designation: "NGC 2024", common_name: Some("Flame Nebula"), object_type: "EmissionNebula", constellation: "Ori", ra: 85.417, dec: -1.917, magnitude: 2.0, size_major: 30.0, size_minor: Some(30.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 2237", common_name: Some("Rosette Nebula"), object_type: "EmissionNebula", constellation: "Mon", ra: 98.0, dec: 4.95, magnitude: 9.0, size_major: 80.0, size_minor: Some(60.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 2264", common_name: Some("Cone Nebula"), object_type: "EmissionNebula", constellation: "Mon", ra: 100.25, dec: 9.883, magnitude: 3.9, size_major: 20.0, size_minor: None, aliases: &[] },
+    CatalogEntry { designation: "NGC 2359", common_name: Some("Thor's Helmet"), object_type: "EmissionNebula", constellation: "CMa", ra: 109.275, dec: -13.233, magnitude: 11.5, size_major: 10.0, size_minor: None, aliases: &[] },
+    CatalogEntry { designation: "NGC 2403", common_name: None, object_type: "Galaxy", constellation: "Cam", ra: 114.214, dec: 65.603, magnitude: 8.4, size_major: 21.9, size_minor: Some(12.3), aliases: &[] },
+    CatalogEntry { designation: "NGC 2841", common_name: None, object_type: "Galaxy", constellation: "UMa", ra: 140.511, dec: 50.976, magnitude: 9.2, size_major: 8.1, size_minor: Some(3.5), aliases: &[] },
+    CatalogEntry { designation: "NGC 2903", common_name: None, object_type: "Galaxy", constellation: "Leo", ra: 143.042, dec: 21.501, magnitude: 9.0, size_major: 12.6, size_minor: Some(6.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 3115", common_name: Some("Spindle Galaxy"), object_type: "Galaxy", constellation: "Sex", ra: 151.308, dec: -7.718, magnitude: 9.1, size_major: 7.2, size_minor: Some(2.5), aliases: &[] },
+    CatalogEntry { designation: "NGC 3242", common_name: Some("Ghost of Jupiter"), object_type: "PlanetaryNebula", constellation: "Hya", ra: 156.158, dec: -18.633, magnitude: 7.3, size_major: 1.35, size_minor: None, aliases: &[] },
+    CatalogEntry { designation: "NGC 3628", common_name: Some("Hamburger Galaxy"), object_type: "Galaxy", constellation: "Leo", ra: 170.071, dec: 13.589, magnitude: 9.5, size_major: 14.8, size_minor: Some(3.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 4038", common_name: Some("Antennae Galaxies"), object_type: "Galaxy", constellation: "Crv", ra: 180.471, dec: -18.868, magnitude: 10.3, size_major: 5.2, size_minor: Some(3.1), aliases: &[] },
+    CatalogEntry { designation: "NGC 4244", common_name: Some("Silver Needle Galaxy"), object_type: "Galaxy", constellation: "CVn", ra: 184.374, dec: 37.807, magnitude: 10.2, size_major: 16.6, size_minor: Some(1.9), aliases: &[] },
+    CatalogEntry { designation: "NGC 4361", common_name: None, object_type: "PlanetaryNebula", constellation: "Crv", ra: 186.163, dec: -18.783, magnitude: 10.3, size_major: 1.85, size_minor: None, aliases: &[] },
+    CatalogEntry { designation: "NGC 4565", common_name: Some("Needle Galaxy"), object_type: "Galaxy", constellation: "Com", ra: 189.087, dec: 25.988, magnitude: 9.6, size_major: 15.9, size_minor: Some(1.9), aliases: &[] },
+    CatalogEntry { designation: "NGC 4631", common_name: Some("Whale Galaxy"), object_type: "Galaxy", constellation: "CVn", ra: 190.533, dec: 32.541, magnitude: 9.2, size_major: 15.5, size_minor: Some(2.7), aliases: &[] },
+    CatalogEntry { designation: "NGC 4656", common_name: Some("Hockey Stick Galaxy"), object_type: "Galaxy", constellation: "CVn", ra: 190.992, dec: 32.17, magnitude: 10.5, size_major: 15.3, size_minor: Some(2.4), aliases: &[] },
+    CatalogEntry { designation: "NGC 4725", common_name: None, object_type: "Galaxy", constellation: "Com", ra: 192.611, dec: 25.501, magnitude: 9.4, size_major: 10.7, size_minor: Some(7.6), aliases: &[] },
+    CatalogEntry { designation: "NGC 5128", common_name: Some("Centaurus A"), object_type: "Galaxy", constellation: "Cen", ra: 201.365, dec: -43.019, magnitude: 6.8, size_major: 25.7, size_minor: Some(20.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 5139", common_name: Some("Omega Centauri"), object_type: "GlobularCluster", constellation: "Cen", ra: 201.697, dec: -47.479, magnitude: 3.9, size_major: 36.3, size_minor: None, aliases: &[] },
+    CatalogEntry { designation: "NGC 6543", common_name: Some("Cat's Eye Nebula"), object_type: "PlanetaryNebula", constellation: "Dra", ra: 269.639, dec: 66.633, magnitude: 8.1, size_major: 0.35, size_minor: None, aliases: &[] },
+    CatalogEntry { designation: "NGC 6826", common_name: Some("Blinking Planetary"), object_type: "PlanetaryNebula", constellation: "Cyg", ra: 296.2, dec: 50.525, magnitude: 8.8, size_major: 0.43, size_minor: None, aliases: &[] },
+    CatalogEntry { designation: "NGC 6888", common_name: Some("Crescent Nebula"), object_type: "EmissionNebula", constellation: "Cyg", ra: 303.062, dec: 38.35, magnitude: 7.4, size_major: 18.0, size_minor: Some(12.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 6960", common_name: Some("Veil Nebula West"), object_type: "SupernovaRemnant", constellation: "Cyg", ra: 312.75, dec: 30.717, magnitude: 7.0, size_major: 70.0, size_minor: Some(6.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 6992", common_name: Some("Veil Nebula East"), object_type: "SupernovaRemnant", constellation: "Cyg", ra: 314.583, dec: 31.717, magnitude: 7.0, size_major: 60.0, size_minor: Some(8.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 7000", common_name: Some("North America Nebula"), object_type: "EmissionNebula", constellation: "Cyg", ra: 314.75, dec: 44.333, magnitude: 4.0, size_major: 120.0, size_minor: Some(100.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 7023", common_name: Some("Iris Nebula"), object_type: "ReflectionNebula", constellation: "Cep", ra: 315.375, dec: 68.167, magnitude: 7.1, size_major: 18.0, size_minor: Some(18.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 7129", common_name: None, object_type: "ReflectionNebula", constellation: "Cep", ra: 325.758, dec: 66.117, magnitude: 11.5, size_major: 7.0, size_minor: Some(7.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 7293", common_name: Some("Helix Nebula"), object_type: "PlanetaryNebula", constellation: "Aqr", ra: 337.411, dec: -20.837, magnitude: 7.6, size_major: 16.0, size_minor: None, aliases: &[] },
+    CatalogEntry { designation: "NGC 7331", common_name: None, object_type: "Galaxy", constellation: "Peg", ra: 339.267, dec: 34.416, magnitude: 9.5, size_major: 10.5, size_minor: Some(3.7), aliases: &[] },
+    CatalogEntry { designation: "NGC 7635", common_name: Some("Bubble Nebula"), object_type: "EmissionNebula", constellation: "Cas", ra: 350.2, dec: 61.2, magnitude: 10.0, size_major: 15.0, size_minor: Some(8.0), aliases: &[] },
+    CatalogEntry { designation: "NGC 7822", common_name: None, object_type: "EmissionNebula", constellation: "Cep", ra: 0.75, dec: 67.417, magnitude: 7.0, size_major: 60.0, size_minor: Some(30.0), aliases: &[] },
+    CatalogEntry { designation: "IC 434", common_name: Some("Horsehead Nebula"), object_type: "DarkNebula", constellation: "Ori", ra: 85.25, dec: -2.433, magnitude: 6.8, size_major: 60.0, size_minor: Some(10.0), aliases: &[] },
+    CatalogEntry { designation: "IC 1396", common_name: Some("Elephant Trunk Nebula"), object_type: "EmissionNebula", constellation: "Cep", ra: 324.75, dec: 57.5, magnitude: 3.5, size_major: 170.0, size_minor: Some(140.0), aliases: &[] },
+    CatalogEntry { designation: "IC 1805", common_name: Some("Heart Nebula"), object_type: "EmissionNebula", constellation: "Cas", ra: 38.208, dec: 61.45, magnitude: 6.5, size_major: 60.0, size_minor: Some(60.0), aliases: &[] },
+    CatalogEntry { designation: "IC 1848", common_name: Some("Soul Nebula"), object_type: "EmissionNebula", constellation: "Cas", ra: 43.0, dec: 60.433, magnitude: 6.5, size_major: 60.0, size_minor: Some(30.0), aliases: &[] },
+    CatalogEntry { designation: "IC 2118", common_name: Some("Witch Head Nebula"), object_type: "ReflectionNebula", constellation: "Eri", ra: 80.083, dec: -7.183, magnitude: 13.0, size_major: 180.0, size_minor: Some(60.0), aliases: &[] },
+    CatalogEntry { designation: "IC 2177", common_name: Some("Seagull Nebula"), object_type: "EmissionNebula", constellation: "Mon", ra: 109.333, dec: -10.7, magnitude: 7.0, size_major: 120.0, size_minor: Some(40.0), aliases: &[] },
+    CatalogEntry { designation: "IC 4592", common_name: Some("Blue Horsehead"), object_type: "ReflectionNebula", constellation: "Sco", ra: 243.167, dec: -19.367, magnitude: 4.0, size_major: 90.0, size_minor: Some(60.0), aliases: &[] },
+    CatalogEntry { designation: "IC 4603", common_name: None, object_type: "ReflectionNebula", constellation: "Oph", ra: 244.667, dec: -20.633, magnitude: 10.0, size_major: 15.0, size_minor: Some(10.0), aliases: &[] },
+    CatalogEntry { designation: "IC 4604", common_name: Some("Rho Ophiuchi"), object_type: "ReflectionNebula", constellation: "Oph", ra: 246.417, dec: -23.45, magnitude: 4.6, size_major: 60.0, size_minor: Some(60.0), aliases: &[] },
+    CatalogEntry { designation: "IC 5067", common_name: Some("Pelican Nebula"), object_type: "EmissionNebula", constellation: "Cyg", ra: 312.75, dec: 44.367, magnitude: 8.0, size_major: 60.0, size_minor: Some(50.0), aliases: &[] },
+    CatalogEntry { designation: "IC 5146", common_name: Some("Cocoon Nebula"), object_type: "EmissionNebula", constellation: "Cyg", ra: 328.375, dec: 47.267, magnitude: 7.2, size_major: 12.0, size_minor: Some(12.0), aliases: &[] },
+];
+
+/// Deep-sky object returned by catalog searches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogObject {
+    /// Designation without spaces, e.g. "M42" or "NGC7000"
+    pub id: String,
+    pub designation: String,
+    pub name: String,
+    pub object_type: String,
+    pub constellation: String,
+    pub ra: f64,
+    pub dec: f64,
+    pub magnitude: f64,
+    pub size_major_arcmin: f64,
+    pub size_minor_arcmin: Option<f64>,
+    pub aliases: Vec<String>,
+}
+
+impl From<&CatalogEntry> for CatalogObject {
+    fn from(entry: &CatalogEntry) -> Self {
+        Self {
+            id: entry.designation.replace(' ', ""),
+            designation: entry.designation.to_string(),
+            name: entry.common_name.unwrap_or(entry.designation).to_string(),
+            object_type: entry.object_type.to_string(),
+            constellation: entry.constellation.to_string(),
+            ra: entry.ra,
+            dec: entry.dec,
+            magnitude: entry.magnitude,
+            size_major_arcmin: entry.size_major,
+            size_minor_arcmin: entry.size_minor,
+            aliases: entry.aliases.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+}
+
+/// Lowercase and strip everything but letters and digits, so "NGC 7000",
+/// "ngc7000" and "Messier 42" / "M 42" compare equal to their designations
+fn normalize_designation(text: &str) -> String {
+    let normalized: String = text
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    match normalized.strip_prefix("messier") {
+        Some(number) if number.chars().all(|c| c.is_ascii_digit()) => format!("m{}", number),
+        _ => normalized,
+    }
+}
+
+/// Match rank of an entry for a normalized query; lower is better
+fn match_rank(entry: &CatalogEntry, query: &str) -> Option<u8> {
+    let designation = normalize_designation(entry.designation);
+    let name = entry.common_name.map(normalize_designation).unwrap_or_default();
+    let aliases: Vec<String> = entry.aliases.iter().map(|a| normalize_designation(a)).collect();
+
+    if designation == query || aliases.iter().any(|a| a == query) {
+        Some(0)
+    } else if name == query {
+        Some(1)
+    } else if designation.starts_with(query) || name.starts_with(query) {
+        Some(2)
+    } else if name.contains(query) || aliases.iter().any(|a| a.contains(query)) {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+/// Every object in the bundled catalog
+pub(crate) fn catalog_objects() -> Vec<CatalogObject> {
+    DSO_CATALOG.iter().map(CatalogObject::from).collect()
+}
+
+/// Look up a catalog object by id or designation
+pub fn find_catalog_object(object_id: &str) -> Option<CatalogObject> {
+    let query = normalize_designation(object_id);
+    DSO_CATALOG
+        .iter()
+        .find(|entry| normalize_designation(entry.designation) == query)
+        .map(CatalogObject::from)
+}
+
+/// Search the bundled catalog by designation, cross-identification, or
+/// common name. Exact designations rank first, then names, then partial
+/// matches; ties are broken by brightness.
+#[tauri::command]
+pub fn search_catalog(query: String, limit: usize) -> Vec<CatalogObject> {
+    let query = normalize_designation(&query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<(u8, &CatalogEntry)> = DSO_CATALOG
+        .iter()
+        .filter_map(|entry| match_rank(entry, &query).map(|rank| (rank, entry)))
+        .collect();
+    matches.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.magnitude.total_cmp(&b.1.magnitude)));
+
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, entry)| CatalogObject::from(entry))
+        .collect()
+}
+
+/// Criteria for selecting a subset of the bundled catalog; unset fields match
+/// everything
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CatalogFilter {
+    /// Catalog prefix such as "Messier" (or "M"), "NGC" or "IC"; matches
+    /// cross-identifications too, so "NGC" includes M42 as NGC 1976
+    pub catalog: Option<String>,
+    /// Object types as stored in the catalog, e.g. "Galaxy"
+    pub object_types: Option<Vec<String>>,
+    /// Faintest magnitude to include
+    pub max_magnitude: Option<f64>,
+    /// IAU abbreviation or full constellation name
+    pub constellation: Option<String>,
+}
+
+/// Whether `designation` is a numbered entry of the normalized `catalog`
+fn in_catalog(designation: &str, catalog: &str) -> bool {
+    normalize_designation(designation)
+        .strip_prefix(catalog)
+        .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn matches_filter(entry: &CatalogEntry, filter: &CatalogFilter) -> bool {
+    if let Some(catalog) = &filter.catalog {
+        let catalog = match normalize_designation(catalog).as_str() {
+            "messier" => "m".to_string(),
+            other => other.to_string(),
+        };
+        let listed = in_catalog(entry.designation, &catalog)
+            || entry.aliases.iter().any(|alias| in_catalog(alias, &catalog));
+        if !listed {
+            return false;
+        }
+    }
+    if let Some(types) = &filter.object_types {
+        if !types.iter().any(|t| t.eq_ignore_ascii_case(entry.object_type)) {
+            return false;
+        }
+    }
+    if filter.max_magnitude.is_some_and(|max| entry.magnitude > max) {
+        return false;
+    }
+    if let Some(constellation) = &filter.constellation {
+        let name = constellation_name(entry.constellation).unwrap_or(entry.constellation);
+        if !constellation.eq_ignore_ascii_case(entry.constellation) && !constellation.eq_ignore_ascii_case(name) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Catalog objects matching every criterion of `filter`, in catalog order
+pub fn filter_catalog(filter: &CatalogFilter) -> Vec<CatalogObject> {
+    DSO_CATALOG
+        .iter()
+        .filter(|entry| matches_filter(entry, filter))
+        .map(CatalogObject::from)
+        .collect()
+}
+
+/// Identify catalog objects within `radius_arcmin` of a position, nearest
+/// first. Intended for naming the center of a plate-solved field.
+#[tauri::command]
+pub fn identify_object(ra: f64, dec: f64, radius_arcmin: f64) -> Vec<CatalogObject> {
+    let radius_deg = radius_arcmin / 60.0;
+
+    let mut matches: Vec<(f64, &CatalogEntry)> = DSO_CATALOG
+        .iter()
+        .map(|entry| (angular_separation(ra, dec, entry.ra, entry.dec), entry))
+        .filter(|(separation, _)| *separation <= radius_deg)
+        .collect();
+    matches.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    matches
+        .into_iter()
+        .map(|(_, entry)| CatalogObject::from(entry))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ------------------------------------------------------------------------
+    // Lookup Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_m31_is_in_andromeda() {
+        assert_eq!(constellation_for_coords(10.6847, 41.2690), "Andromeda");
+    }
+
+    #[test]
+    fn test_galactic_center_is_in_sagittarius() {
+        assert_eq!(constellation_for_coords(266.4168, -29.0078), "Sagittarius");
+    }
+
+    #[test]
+    fn test_bright_stars_and_poles() {
+        assert_eq!(constellation_abbreviation(101.2872, -16.7161), "CMa"); // Sirius
+        assert_eq!(constellation_abbreviation(279.2347, 38.7837), "Lyr"); // Vega
+        assert_eq!(constellation_abbreviation(186.6496, -63.0991), "Cru"); // Acrux
+        assert_eq!(constellation_abbreviation(0.0, 90.0), "UMi");
+        assert_eq!(constellation_abbreviation(0.0, -90.0), "Oct");
+    }
+
+    #[test]
+    fn test_every_boundary_has_a_name() {
+        for (_, _, _, abbr) in CONSTELLATION_BOUNDARIES {
+            assert!(constellation_name(abbr).is_some(), "missing name for {}", abbr);
+        }
+        assert_eq!(CONSTELLATION_NAMES.len(), 88);
+    }
+
+    // ------------------------------------------------------------------------
+    // Catalog Search Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_search_m42_resolves_orion_nebula() {
+        let results = search_catalog("M42".to_string(), 5);
+        let m42 = &results[0];
+        assert_eq!(m42.id, "M42");
+        assert_eq!(m42.name, "Orion Nebula");
+        assert!((m42.ra - 83.82).abs() < 0.05 && (m42.dec + 5.39).abs() < 0.05);
+        assert_eq!(constellation_for_coords(m42.ra, m42.dec), "Orion");
+    }
+
+    #[test]
+    fn test_search_by_common_name_and_alias() {
+        assert_eq!(search_catalog("Ring Nebula".to_string(), 1)[0].id, "M57");
+        assert_eq!(search_catalog("ngc 6720".to_string(), 1)[0].id, "M57");
+        assert_eq!(search_catalog("NGC 7000".to_string(), 1)[0].name, "North America Nebula");
+        assert_eq!(search_catalog("messier 31".to_string(), 1)[0].id, "M31");
+    }
+
+    #[test]
+    fn test_search_limit_and_empty_query() {
+        assert_eq!(search_catalog("nebula".to_string(), 3).len(), 3);
+        assert!(search_catalog("  ".to_string(), 10).is_empty());
+        assert!(search_catalog("xyzzy".to_string(), 10).is_empty());
+        assert!(find_catalog_object("IC 434").is_some());
+        assert!(find_catalog_object("M111").is_none());
+    }
+
+    #[test]
+    fn test_identify_object_at_m31() {
+        let results = identify_object(10.6847, 41.2690, 60.0);
+        assert_eq!(results[0].id, "M31");
+        // M32 and M110 are companions within a degree
+        assert!(results.iter().any(|o| o.id == "M32"));
+    }
+
+    #[test]
+    fn test_identify_object_empty_field() {
+        assert!(identify_object(0.0, -60.0, 30.0).is_empty());
+    }
+
+    // ------------------------------------------------------------------------
+    // Filter Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_filter_by_catalog() {
+        let messier = filter_catalog(&CatalogFilter { catalog: Some("Messier".to_string()), ..Default::default() });
+        assert_eq!(messier.len(), 110);
+        assert_eq!(filter_catalog(&CatalogFilter { catalog: Some("m".to_string()), ..Default::default() }).len(), 110);
+
+        // NGC includes Messier objects through their cross-identifications
+        let ngc = filter_catalog(&CatalogFilter { catalog: Some("NGC".to_string()), ..Default::default() });
+        assert!(ngc.iter().any(|o| o.id == "M42"));
+        assert!(!ngc.iter().any(|o| o.id.starts_with("IC")));
+    }
+
+    #[test]
+    fn test_filter_by_type_magnitude_and_constellation() {
+        let filter = CatalogFilter {
+            object_types: Some(vec!["galaxy".to_string()]),
+            max_magnitude: Some(9.0),
+            constellation: Some("Ursa Major".to_string()),
+            ..Default::default()
+        };
+        let results = filter_catalog(&filter);
+        assert!(results.iter().any(|o| o.id == "M81"));
+        assert!(results.iter().all(|o| o.object_type == "Galaxy" && o.magnitude <= 9.0 && o.constellation == "UMa"));
+
+        let by_abbreviation = CatalogFilter { constellation: Some("uma".to_string()), ..filter };
+        assert_eq!(filter_catalog(&by_abbreviation).len(), results.len());
+    }
+}
@@ -4,7 +4,14 @@
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::calculations::{calculate_moon_phase, calculate_moon_position, calculate_sun_position};
+use super::calculations::common::timestamp_to_jd;
+use super::calculations::planets::{planet_equatorial, BRIGHT_PLANETS};
+use super::calculations::twilight::night_twilight;
+use super::calculations::{
+    calculate_best_observation_time, calculate_moon_phase, calculate_moon_position,
+    calculate_sun_position, calculate_twilight, equatorial_to_ecliptic, equatorial_to_horizontal,
+};
+use super::catalog::catalog_objects;
 
 // ============================================================================
 // Types
@@ -35,6 +42,12 @@ pub enum AstroEventType {
     Aphelion,
     Supermoon,
     BlueMoon,
+    // Daily sky events
+    Sunrise,
+    Sunset,
+    Twilight,
+    Moonrise,
+    Moonset,
 }
 
 /// Astronomical event
@@ -63,7 +76,7 @@ pub struct MoonPhaseEvent {
 }
 
 /// Meteor shower info
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MeteorShowerInfo {
     pub name: String,
     pub peak_date: String,
@@ -74,6 +87,12 @@ pub struct MeteorShowerInfo {
     pub radiant_dec: f64,
     pub parent_body: Option<String>,
     pub description: String,
+    /// Moon illumination (0-100%) at local midnight on the peak night
+    pub moon_illumination_at_peak: f64,
+    /// "excellent", "good", "fair" or "poor" depending on moonlight
+    pub favorability: String,
+    /// Radiant altitude at local midnight on the peak night; requires a location
+    pub radiant_altitude_at_midnight: Option<f64>,
 }
 
 // ============================================================================
@@ -175,14 +194,107 @@ fn check_supermoon(timestamp: i64) -> bool {
     moon_pos.distance < threshold
 }
 
+/// Step used to bracket elongation crossings (seconds)
+const QUARTER_SCAN_STEP_SECS: i64 = 6 * 3600;
+
+/// Moon–Sun elongation in ecliptic longitude (degrees, 0-360)
+fn moon_sun_elongation(timestamp: i64) -> f64 {
+    let moon = calculate_moon_position(0.0, 0.0, Some(timestamp));
+    let sun = calculate_sun_position(0.0, 0.0, Some(timestamp));
+    let moon_lon = equatorial_to_ecliptic(moon.ra, moon.dec, Some(timestamp)).lon;
+    let sun_lon = equatorial_to_ecliptic(sun.ra, sun.dec, Some(timestamp)).lon;
+    normalize_degrees(moon_lon - sun_lon)
+}
+
+/// Bisect a bracketed crossing of `target` elongation down to one second
+fn bisect_elongation(mut low: i64, mut high: i64, target: f64) -> i64 {
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if normalize_angle_diff(moon_sun_elongation(mid), target) < 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    high
+}
+
+/// Find the exact instants of the principal moon phases in a month.
+/// Each quarter is where the Moon–Sun elongation crosses 0°, 90°, 180°
+/// or 270°, bracketed on a 6-hour grid and refined by bisection.
+#[tauri::command]
+pub fn get_moon_quarter_times(year: i32, month: u32) -> Vec<MoonPhaseEvent> {
+    const QUARTERS: [(f64, &str); 4] = [
+        (0.0, "New Moon"),
+        (90.0, "First Quarter"),
+        (180.0, "Full Moon"),
+        (270.0, "Last Quarter"),
+    ];
+
+    let Some(first_day) = NaiveDate::from_ymd_opt(year, month, 1) else {
+        return Vec::new();
+    };
+    let next_month = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    };
+    let Some(next_month) = next_month else {
+        return Vec::new();
+    };
+
+    let start = first_day.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+    let end = next_month.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+
+    let mut events = Vec::new();
+    let mut t0 = start;
+    let mut e0 = moon_sun_elongation(t0);
+
+    while t0 < end {
+        let t1 = (t0 + QUARTER_SCAN_STEP_SECS).min(end);
+        let e1 = moon_sun_elongation(t1);
+
+        for (target, name) in QUARTERS {
+            let before = normalize_angle_diff(e0, target);
+            let after = normalize_angle_diff(e1, target);
+            // The elongation only increases, so a crossing is a sign change near the target
+            if before < 0.0 && after >= 0.0 && after - before < 90.0 {
+                let timestamp = bisect_elongation(t0, t1, target);
+                if timestamp >= end {
+                    continue;
+                }
+                let date = DateTime::from_timestamp(timestamp, 0)
+                    .map(|dt| dt.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default();
+                events.push(MoonPhaseEvent {
+                    phase_type: name.to_string(),
+                    date,
+                    timestamp,
+                    illumination: calculate_moon_phase(Some(timestamp)).illumination,
+                    is_supermoon: target == 180.0 && check_supermoon(timestamp),
+                });
+            }
+        }
+
+        t0 = t1;
+        e0 = e1;
+    }
+
+    events
+}
+
 // ============================================================================
 // Meteor Showers
 // ============================================================================
 
 /// Get major meteor showers for a year
 #[tauri::command]
-pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
-    vec![
+pub fn get_meteor_showers(
+    year: i32,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+) -> Vec<MeteorShowerInfo> {
+    let mut showers = vec![
         MeteorShowerInfo {
             name: "Quadrantids".to_string(),
             peak_date: format!("{}-01-03", year),
@@ -193,6 +305,7 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: 49.0,
             parent_body: Some("2003 EH1".to_string()),
             description: "One of the best annual meteor showers with bright meteors".to_string(),
+            ..Default::default()
         },
         MeteorShowerInfo {
             name: "Lyrids".to_string(),
@@ -204,6 +317,7 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: 34.0,
             parent_body: Some("C/1861 G1 Thatcher".to_string()),
             description: "Medium strength shower with occasional bright fireballs".to_string(),
+            ..Default::default()
         },
         MeteorShowerInfo {
             name: "Eta Aquariids".to_string(),
@@ -215,6 +329,7 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: -1.0,
             parent_body: Some("1P/Halley".to_string()),
             description: "Fast meteors from Halley's Comet debris".to_string(),
+            ..Default::default()
         },
         MeteorShowerInfo {
             name: "Delta Aquariids".to_string(),
@@ -226,6 +341,7 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: -16.0,
             parent_body: Some("96P/Machholz".to_string()),
             description: "Best viewed from southern latitudes".to_string(),
+            ..Default::default()
         },
         MeteorShowerInfo {
             name: "Perseids".to_string(),
@@ -237,6 +353,7 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: 58.0,
             parent_body: Some("109P/Swift-Tuttle".to_string()),
             description: "Most popular meteor shower with many bright meteors".to_string(),
+            ..Default::default()
         },
         MeteorShowerInfo {
             name: "Orionids".to_string(),
@@ -248,6 +365,7 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: 16.0,
             parent_body: Some("1P/Halley".to_string()),
             description: "Fast meteors from Halley's Comet debris".to_string(),
+            ..Default::default()
         },
         MeteorShowerInfo {
             name: "Leonids".to_string(),
@@ -259,6 +377,7 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: 22.0,
             parent_body: Some("55P/Tempel-Tuttle".to_string()),
             description: "Can produce meteor storms every 33 years".to_string(),
+            ..Default::default()
         },
         MeteorShowerInfo {
             name: "Geminids".to_string(),
@@ -270,6 +389,7 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: 33.0,
             parent_body: Some("3200 Phaethon".to_string()),
             description: "King of meteor showers with many bright, colorful meteors".to_string(),
+            ..Default::default()
         },
         MeteorShowerInfo {
             name: "Ursids".to_string(),
@@ -281,81 +401,160 @@ pub fn get_meteor_showers(year: i32) -> Vec<MeteorShowerInfo> {
             radiant_dec: 76.0,
             parent_body: Some("8P/Tuttle".to_string()),
             description: "Minor shower near the winter solstice".to_string(),
+            ..Default::default()
         },
-    ]
+    ];
+
+    for shower in &mut showers {
+        rate_meteor_shower_peak(shower, latitude, longitude);
+    }
+
+    showers
+}
+
+/// Timestamp of local midnight starting the peak date, approximating the
+/// site's local time from its longitude
+fn peak_midnight_timestamp(peak_date: &str, longitude: Option<f64>) -> Option<i64> {
+    let date = NaiveDate::parse_from_str(peak_date, "%Y-%m-%d").ok()?;
+    let utc_midnight = date.and_hms_opt(0, 0, 0)?.and_utc().timestamp();
+    let offset_secs = (longitude.unwrap_or(0.0) / 15.0 * 3600.0).round() as i64;
+    Some(utc_midnight - offset_secs)
+}
+
+/// Rate a shower's peak by moonlight
+fn meteor_favorability(moon_illumination: f64) -> &'static str {
+    match moon_illumination {
+        i if i < 25.0 => "excellent",
+        i if i < 50.0 => "good",
+        i if i < 75.0 => "fair",
+        _ => "poor",
+    }
+}
+
+/// Fill in moon interference and radiant altitude for a shower's peak night
+fn rate_meteor_shower_peak(
+    shower: &mut MeteorShowerInfo,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+) {
+    let Some(midnight) = peak_midnight_timestamp(&shower.peak_date, longitude) else {
+        return;
+    };
+
+    let illumination = calculate_moon_phase(Some(midnight)).illumination;
+    shower.moon_illumination_at_peak = illumination;
+    shower.favorability = meteor_favorability(illumination).to_string();
+
+    if let (Some(lat), Some(lon)) = (latitude, longitude) {
+        let radiant = equatorial_to_horizontal(
+            shower.radiant_ra,
+            shower.radiant_dec,
+            lat,
+            lon,
+            Some(midnight),
+            Some(false),
+        );
+        shower.radiant_altitude_at_midnight = Some(radiant.alt);
+    }
 }
 
 // ============================================================================
 // Seasonal Events
 // ============================================================================
 
+/// Equinox and solstice instants: (month, solar longitude, northern season, southern season)
+const SEASON_MARKERS: [(u32, f64, &str, &str); 4] = [
+    (3, 0.0, "spring", "autumn"),
+    (6, 90.0, "summer", "winter"),
+    (9, 180.0, "autumn", "spring"),
+    (12, 270.0, "winter", "summer"),
+];
+
+/// Galactic center position (J2000, degrees)
+const GALACTIC_CENTER_RA: f64 = 266.417;
+const GALACTIC_CENTER_DEC: f64 = -29.008;
+
+/// Minimum altitude of the galactic core during darkness for it to be in season
+const GALACTIC_CORE_MIN_ALTITUDE: f64 = 15.0;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July", "August", "September",
+    "October", "November", "December",
+];
+
+/// Months (1-12) in which the galactic core clears the minimum altitude during
+/// astronomical darkness at mid-month, as a (first, last) range
+fn galactic_core_season(year: i32, latitude: f64) -> Option<(u32, u32)> {
+    let months: Vec<u32> = (1..=12)
+        .filter(|month| {
+            calculate_best_observation_time(
+                GALACTIC_CENTER_RA,
+                GALACTIC_CENTER_DEC,
+                latitude,
+                0.0,
+                format!("{}-{:02}-15", year, month),
+                GALACTIC_CORE_MIN_ALTITUDE,
+            )
+            .is_ok_and(|best| best.timestamp.is_some())
+        })
+        .collect();
+
+    Some((*months.first()?, *months.last()?))
+}
+
 /// Get equinoxes and solstices for a year with dynamically calculated dates
-/// Uses solar longitude calculation to determine precise event times
+/// Uses solar longitude calculation to determine precise event times. Names
+/// follow the observer's hemisphere (northern when `latitude` is omitted),
+/// and with a latitude each event carries the galactic core season.
 #[tauri::command]
-pub fn get_seasonal_events(year: i32) -> Vec<AstroEvent> {
-    let mut events = Vec::new();
-
-    // Calculate vernal equinox (sun longitude = 0°)
-    if let Some((ts, date_str, time_str)) = find_solar_longitude_event(year, 3, 0.0) {
-        events.push(AstroEvent {
-            id: format!("vernal-equinox-{}", year),
-            event_type: AstroEventType::Equinox,
-            name: "Vernal Equinox".to_string(),
-            description: "First day of spring in Northern Hemisphere".to_string(),
-            date: date_str,
-            time: Some(time_str),
-            timestamp: ts,
-            magnitude: None,
-            visibility: Some("Global".to_string()),
-            details: None,
+pub fn get_seasonal_events(year: i32, latitude: Option<f64>) -> Vec<AstroEvent> {
+    let southern = latitude.is_some_and(|lat| lat < 0.0);
+    let hemisphere = if southern { "Southern" } else { "Northern" };
+
+    let details = latitude.map(|lat| {
+        let core_season = galactic_core_season(year, lat).map(|(first, last)| {
+            serde_json::json!({
+                "start_month": first,
+                "end_month": last,
+                "description": format!(
+                    "Galactic core well placed from {} to {}",
+                    MONTH_NAMES[first as usize - 1],
+                    MONTH_NAMES[last as usize - 1]
+                ),
+            })
         });
-    }
+        serde_json::json!({
+            "hemisphere": hemisphere.to_lowercase(),
+            "galactic_core_season": core_season,
+        })
+    });
 
-    // Calculate summer solstice (sun longitude = 90°)
-    if let Some((ts, date_str, time_str)) = find_solar_longitude_event(year, 6, 90.0) {
-        events.push(AstroEvent {
-            id: format!("summer-solstice-{}", year),
-            event_type: AstroEventType::Solstice,
-            name: "Summer Solstice".to_string(),
-            description: "Longest day in Northern Hemisphere".to_string(),
-            date: date_str,
-            time: Some(time_str),
-            timestamp: ts,
-            magnitude: None,
-            visibility: Some("Global".to_string()),
-            details: None,
-        });
-    }
+    let mut events = Vec::new();
 
-    // Calculate autumnal equinox (sun longitude = 180°)
-    if let Some((ts, date_str, time_str)) = find_solar_longitude_event(year, 9, 180.0) {
-        events.push(AstroEvent {
-            id: format!("autumnal-equinox-{}", year),
-            event_type: AstroEventType::Equinox,
-            name: "Autumnal Equinox".to_string(),
-            description: "First day of autumn in Northern Hemisphere".to_string(),
-            date: date_str,
-            time: Some(time_str),
-            timestamp: ts,
-            magnitude: None,
-            visibility: Some("Global".to_string()),
-            details: None,
-        });
-    }
+    for (month, solar_longitude, northern_season, southern_season) in SEASON_MARKERS {
+        let Some((ts, date_str, time_str)) = find_solar_longitude_event(year, month, solar_longitude) else {
+            continue;
+        };
+
+        let season = if southern { southern_season } else { northern_season };
+        let (event_type, name, description) = match season {
+            "spring" => (AstroEventType::Equinox, "Vernal Equinox", "First day of spring in"),
+            "autumn" => (AstroEventType::Equinox, "Autumnal Equinox", "First day of autumn in"),
+            "summer" => (AstroEventType::Solstice, "Summer Solstice", "Longest day in"),
+            _ => (AstroEventType::Solstice, "Winter Solstice", "Shortest day in"),
+        };
 
-    // Calculate winter solstice (sun longitude = 270°)
-    if let Some((ts, date_str, time_str)) = find_solar_longitude_event(year, 12, 270.0) {
         events.push(AstroEvent {
-            id: format!("winter-solstice-{}", year),
-            event_type: AstroEventType::Solstice,
-            name: "Winter Solstice".to_string(),
-            description: "Shortest day in Northern Hemisphere".to_string(),
+            id: format!("{}-{}", name.to_lowercase().replace(' ', "-"), year),
+            event_type,
+            name: name.to_string(),
+            description: format!("{} {} Hemisphere", description, hemisphere),
             date: date_str,
             time: Some(time_str),
             timestamp: ts,
             magnitude: None,
             visibility: Some("Global".to_string()),
-            details: None,
+            details: details.clone(),
         });
     }
 
@@ -508,7 +707,7 @@ pub fn get_astro_events(start_date: String, end_date: String) -> Result<Vec<Astr
 
     for year in start_year..=end_year {
         // Add seasonal events
-        for event in get_seasonal_events(year) {
+        for event in get_seasonal_events(year, None) {
             let event_date = NaiveDate::parse_from_str(&event.date, "%Y-%m-%d").ok();
             if let Some(date) = event_date {
                 if date >= start && date <= end {
@@ -518,7 +717,7 @@ pub fn get_astro_events(start_date: String, end_date: String) -> Result<Vec<Astr
         }
 
         // Add meteor showers
-        for shower in get_meteor_showers(year) {
+        for shower in get_meteor_showers(year, None, None) {
             let peak_date = NaiveDate::parse_from_str(&shower.peak_date, "%Y-%m-%d").ok();
             if let Some(date) = peak_date {
                 if date >= start && date <= end {
@@ -619,12 +818,113 @@ pub fn get_astro_events(start_date: String, end_date: String) -> Result<Vec<Astr
     Ok(events)
 }
 
-/// Get astronomical events for a single local day
+/// Sampling step when searching for moonrise and moonset
+const MOON_SCAN_STEP_SECS: i64 = 600;
+
+/// Build an instant event on `date` from a timestamp
+fn daily_instant_event(
+    id: String,
+    event_type: AstroEventType,
+    name: &str,
+    description: String,
+    date: NaiveDate,
+    timestamp: i64,
+) -> AstroEvent {
+    AstroEvent {
+        id,
+        event_type,
+        name: name.to_string(),
+        description,
+        date: date.to_string(),
+        time: DateTime::from_timestamp(timestamp, 0).map(|dt| dt.format("%H:%M").to_string()),
+        timestamp,
+        magnitude: None,
+        visibility: None,
+        details: None,
+    }
+}
+
+/// Moonrise (`true`) and moonset (`false`) times within a window, found by
+/// scanning the Moon's altitude and bisecting each horizon crossing
+fn moon_horizon_crossings(latitude: f64, longitude: f64, start: i64, end: i64) -> Vec<(i64, bool)> {
+    let altitude = |ts: i64| calculate_moon_position(latitude, longitude, Some(ts)).altitude;
+
+    let mut crossings = Vec::new();
+    let mut prev = (start, altitude(start));
+    let mut ts = start + MOON_SCAN_STEP_SECS;
+    while ts <= end {
+        let alt = altitude(ts);
+        if (prev.1 < 0.0) != (alt < 0.0) {
+            let rising = alt >= 0.0;
+            let (mut lo, mut hi) = (prev.0, ts);
+            while hi - lo > 30 {
+                let mid = (lo + hi) / 2;
+                if (altitude(mid) >= 0.0) == rising {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+            crossings.push((hi, rising));
+        }
+        prev = (ts, alt);
+        ts += MOON_SCAN_STEP_SECS;
+    }
+    crossings
+}
+
+/// Sun rise/set, twilight boundaries and moonrise/moonset for a local day at a site
+fn daily_sky_events(date: NaiveDate, latitude: f64, longitude: f64) -> Result<Vec<AstroEvent>, String> {
+    let twilight = calculate_twilight(date.to_string(), latitude, longitude)?;
+
+    let solar = [
+        (twilight.astronomical_dawn, AstroEventType::Twilight, "Astronomical Dawn", "Sun reaches -18° before sunrise"),
+        (twilight.nautical_dawn, AstroEventType::Twilight, "Nautical Dawn", "Sun reaches -12° before sunrise"),
+        (twilight.civil_dawn, AstroEventType::Twilight, "Civil Dawn", "Sun reaches -6° before sunrise"),
+        (twilight.sunrise, AstroEventType::Sunrise, "Sunrise", "Sun rises above the horizon"),
+        (twilight.sunset, AstroEventType::Sunset, "Sunset", "Sun sets below the horizon"),
+        (twilight.civil_dusk, AstroEventType::Twilight, "Civil Dusk", "Sun reaches -6° after sunset"),
+        (twilight.nautical_dusk, AstroEventType::Twilight, "Nautical Dusk", "Sun reaches -12° after sunset"),
+        (twilight.astronomical_dusk, AstroEventType::Twilight, "Astronomical Dusk", "Sun reaches -18° after sunset"),
+    ];
+
+    let mut events: Vec<AstroEvent> = solar
+        .into_iter()
+        .filter_map(|(ts, event_type, name, description)| {
+            let id = format!("daily-{}-{}", name.to_lowercase().replace(' ', "-"), date);
+            ts.map(|ts| daily_instant_event(id, event_type, name, description.to_string(), date, ts))
+        })
+        .collect();
+
+    // Local mean day for the site's longitude
+    let day_start = date.and_hms_opt(0, 0, 0)
+        .ok_or_else(|| "Failed to build selected date timestamp".to_string())?
+        .and_utc()
+        .timestamp()
+        - (longitude / 15.0 * 3600.0) as i64;
+
+    for (ts, rising) in moon_horizon_crossings(latitude, longitude, day_start, day_start + 86400) {
+        let (event_type, name, description) = if rising {
+            (AstroEventType::Moonrise, "Moonrise", "Moon rises above the horizon")
+        } else {
+            (AstroEventType::Moonset, "Moonset", "Moon sets below the horizon")
+        };
+        let id = format!("daily-{}-{}-{}", name.to_lowercase(), date, ts);
+        events.push(daily_instant_event(id, event_type, name, description.to_string(), date, ts));
+    }
+
+    Ok(events)
+}
+
+/// Get astronomical events for a single local day. With a site location the
+/// day also includes sunrise/sunset, twilight boundaries and moonrise/moonset.
 #[tauri::command]
 pub fn get_daily_astro_events(
     date: String,
     timezone: String,
     include_ongoing: bool,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
 ) -> Result<Vec<AstroEvent>, String> {
     let selected_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
         .map_err(|e| format!("Invalid date: {}", e))?;
@@ -643,7 +943,7 @@ pub fn get_daily_astro_events(
         let year = selected_date.year();
 
         for y in (year - 1)..=(year + 1) {
-            for shower in get_meteor_showers(y) {
+            for shower in get_meteor_showers(y, None, None) {
                 let start = NaiveDate::parse_from_str(&shower.active_start, "%Y-%m-%d").ok();
                 let end = NaiveDate::parse_from_str(&shower.active_end, "%Y-%m-%d").ok();
                 if let (Some(active_start), Some(active_end)) = (start, end) {
@@ -685,6 +985,10 @@ pub fn get_daily_astro_events(
         }
     }
 
+    if let (Some(lat), Some(lon)) = (latitude, longitude) {
+        events.extend(daily_sky_events(selected_date, lat, lon)?);
+    }
+
     for event in events.iter_mut() {
         let details = event.details.get_or_insert_with(|| serde_json::json!({}));
         if let Some(obj) = details.as_object_mut() {
@@ -698,7 +1002,144 @@ pub fn get_daily_astro_events(
     Ok(events)
 }
 
-/// Get tonight's astronomical highlights
+/// Minimum altitude for a planet to count as observable tonight
+const HIGHLIGHT_MIN_ALTITUDE: f64 = 10.0;
+
+/// Minimum altitude for a deep-sky target to be suggested
+const DSO_HIGHLIGHT_MIN_ALTITUDE: f64 = 30.0;
+
+/// How close to the middle of the night a deep-sky target must transit
+const DSO_TRANSIT_WINDOW_SECS: i64 = 5400;
+
+/// Number of deep-sky targets suggested per night
+const DSO_HIGHLIGHT_COUNT: usize = 3;
+
+/// Sampling step when scanning the night for altitudes
+const NIGHT_SCAN_STEP_SECS: i64 = 900;
+
+/// Format a timestamp as "HH:MM UTC"
+fn format_utc_time(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format("%H:%M UTC").to_string())
+        .unwrap_or_default()
+}
+
+/// Darkness window of the night containing `dt`: astronomical dusk to
+/// dawn, falling back to nautical twilight when it never gets fully dark
+pub(crate) fn tonight_dark_window(latitude: f64, longitude: f64, dt: DateTime<Utc>) -> Option<(i64, i64)> {
+    let (evening, morning) = night_twilight(latitude, longitude, dt.timestamp())?;
+
+    [
+        (evening.astronomical_dusk, morning.astronomical_dawn),
+        (evening.nautical_dusk, morning.nautical_dawn),
+    ]
+    .into_iter()
+    .find_map(|window| match window {
+        (Some(start), Some(end)) if end > start => Some((start, end)),
+        _ => None,
+    })
+}
+
+/// Highest altitude a position reaches within a window, with its timestamp
+fn peak_altitude_between(
+    ra: f64,
+    dec: f64,
+    latitude: f64,
+    longitude: f64,
+    (start, end): (i64, i64),
+) -> (i64, f64) {
+    let mut best = (start, f64::MIN);
+    let mut ts = start;
+    while ts <= end {
+        let alt = equatorial_to_horizontal(ra, dec, latitude, longitude, Some(ts), None).alt;
+        if alt > best.1 {
+            best = (ts, alt);
+        }
+        ts += NIGHT_SCAN_STEP_SECS;
+    }
+    best
+}
+
+/// Bright planets that clear the minimum altitude during darkness, highest first
+fn planet_highlights(latitude: f64, longitude: f64, window: (i64, i64)) -> Vec<String> {
+    let jd = timestamp_to_jd((window.0 + window.1) / 2);
+
+    let mut visible: Vec<(f64, String)> = BRIGHT_PLANETS
+        .iter()
+        .filter_map(|(name, elements)| {
+            let (ra, dec, _) = planet_equatorial(elements, jd);
+            let (ts, alt) = peak_altitude_between(ra, dec, latitude, longitude, window);
+            (alt >= HIGHLIGHT_MIN_ALTITUDE).then(|| {
+                (alt, format!("{}: up to {:.0}° at {}", name, alt, format_utc_time(ts)))
+            })
+        })
+        .collect();
+    visible.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    visible.into_iter().map(|(_, text)| text).collect()
+}
+
+/// Meteor shower active on `date` whose radiant rises at the site, if any
+fn meteor_shower_highlight(latitude: f64, longitude: f64, date: NaiveDate) -> Option<String> {
+    let parse = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+
+    (date.year()..=date.year() + 1)
+        .flat_map(|year| get_meteor_showers(year, Some(latitude), Some(longitude)))
+        .filter(|shower| {
+            let active = matches!(
+                (parse(&shower.active_start), parse(&shower.active_end)),
+                (Some(start), Some(end)) if (start..=end).contains(&date)
+            );
+            active && shower.radiant_altitude_at_midnight.is_some_and(|alt| alt > 0.0)
+        })
+        .filter_map(|shower| {
+            let days_from_peak = (parse(&shower.peak_date)? - date).num_days().abs();
+            Some((days_from_peak, shower))
+        })
+        .min_by_key(|(days, _)| *days)
+        .map(|(days, shower)| {
+            if days <= 1 {
+                format!(
+                    "{} peak (ZHR {}, Moon {:.0}% - {})",
+                    shower.name, shower.zhr, shower.moon_illumination_at_peak, shower.favorability
+                )
+            } else {
+                format!("{} active (peak {})", shower.name, shower.peak_date)
+            }
+        })
+}
+
+/// Brightest catalog objects transiting high in the middle of the night
+fn deep_sky_highlights(latitude: f64, longitude: f64, window: (i64, i64)) -> Vec<String> {
+    let midpoint = (window.0 + window.1) / 2;
+
+    let mut candidates: Vec<_> = catalog_objects()
+        .into_iter()
+        .filter_map(|object| {
+            let (ts, alt) = peak_altitude_between(object.ra, object.dec, latitude, longitude, window);
+            let near_transit = (ts - midpoint).abs() <= DSO_TRANSIT_WINDOW_SECS;
+            (near_transit && alt >= DSO_HIGHLIGHT_MIN_ALTITUDE).then_some((object, ts, alt))
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.0.magnitude.total_cmp(&b.0.magnitude));
+
+    candidates
+        .into_iter()
+        .take(DSO_HIGHLIGHT_COUNT)
+        .map(|(object, ts, alt)| {
+            let label = if object.name == object.designation {
+                object.designation
+            } else {
+                format!("{} ({})", object.designation, object.name)
+            };
+            format!("{}: transits near {} at {:.0}°", label, format_utc_time(ts), alt)
+        })
+        .collect()
+}
+
+/// Get tonight's astronomical highlights for a site: the Moon, current sky
+/// conditions, then planets, meteor showers and deep-sky targets that are
+/// actually observable during tonight's darkness
 #[tauri::command]
 pub fn get_tonight_highlights(
     latitude: f64,
@@ -743,6 +1184,19 @@ pub fn get_tonight_highlights(
         highlights.push("Daytime - wait for sunset".to_string());
     }
 
+    if let Some(window) = tonight_dark_window(latitude, longitude, dt) {
+        highlights.push(format!(
+            "Darkness from {} to {}",
+            format_utc_time(window.0),
+            format_utc_time(window.1)
+        ));
+        highlights.extend(planet_highlights(latitude, longitude, window));
+
+        let night_of = DateTime::from_timestamp(window.0, 0).unwrap_or(dt).date_naive();
+        highlights.extend(meteor_shower_highlight(latitude, longitude, night_of));
+        highlights.extend(deep_sky_highlights(latitude, longitude, window));
+    }
+
     highlights
 }
 
@@ -783,6 +1237,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_moon_quarter_times_match_ephemeris() {
+        use chrono::TimeZone;
+
+        // Published new moons (UTC): 2024-01-11 11:57, 2024-02-09 22:59, 2024-12-01 06:21
+        let published = [
+            (2024, 1, Utc.with_ymd_and_hms(2024, 1, 11, 11, 57, 0).unwrap()),
+            (2024, 2, Utc.with_ymd_and_hms(2024, 2, 9, 22, 59, 0).unwrap()),
+            (2024, 12, Utc.with_ymd_and_hms(2024, 12, 1, 6, 21, 0).unwrap()),
+        ];
+
+        for (year, month, expected) in published {
+            let events = get_moon_quarter_times(year, month);
+            let new_moon = events
+                .iter()
+                .find(|e| e.phase_type == "New Moon")
+                .expect("new moon should be found");
+            let error_minutes = (new_moon.timestamp - expected.timestamp()).abs() as f64 / 60.0;
+            assert!(error_minutes < 10.0, "{}-{} new moon off by {} min", year, month, error_minutes);
+            assert!(new_moon.illumination < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_moon_quarter_times_cycle_in_order() {
+        let events = get_moon_quarter_times(2024, 3);
+        assert!(events.len() >= 4 && events.len() <= 5, "got {} events", events.len());
+        assert!(events.windows(2).all(|w| w[0].timestamp < w[1].timestamp));
+        for event in &events {
+            assert!(event.date.starts_with("2024-03-"));
+        }
+
+        // Full moon of 2024-03-25 07:00 UTC
+        let full = events.iter().find(|e| e.phase_type == "Full Moon").unwrap();
+        assert_eq!(full.date, "2024-03-25");
+        assert!(full.illumination > 99.0);
+    }
+
     #[test]
     fn test_get_moon_phases_december_edge_case() {
         // December is special because it needs to handle year boundary
@@ -798,7 +1290,7 @@ mod tests {
 
     #[test]
     fn test_get_meteor_showers_returns_showers() {
-        let showers = get_meteor_showers(2024);
+        let showers = get_meteor_showers(2024, None, None);
         assert!(!showers.is_empty(), "Should return meteor showers");
         
         // Verify major showers are present
@@ -810,7 +1302,7 @@ mod tests {
 
     #[test]
     fn test_meteor_shower_data_validity() {
-        let showers = get_meteor_showers(2024);
+        let showers = get_meteor_showers(2024, None, None);
         
         for shower in &showers {
             // ZHR should be positive
@@ -830,8 +1322,8 @@ mod tests {
 
     #[test]
     fn test_meteor_showers_different_years() {
-        let showers_2024 = get_meteor_showers(2024);
-        let showers_2025 = get_meteor_showers(2025);
+        let showers_2024 = get_meteor_showers(2024, None, None);
+        let showers_2025 = get_meteor_showers(2025, None, None);
         
         // Same number of showers
         assert_eq!(showers_2024.len(), showers_2025.len());
@@ -841,19 +1333,50 @@ mod tests {
         assert!(showers_2025[0].peak_date.starts_with("2025-"));
     }
 
+    #[test]
+    fn test_meteor_shower_moon_interference() {
+        // Perseids 2022 peaked with a full Moon on Aug 12
+        let perseids = get_meteor_showers(2022, None, None)
+            .into_iter()
+            .find(|s| s.name == "Perseids")
+            .unwrap();
+        assert!(perseids.moon_illumination_at_peak > 90.0);
+        assert_eq!(perseids.favorability, "poor");
+
+        // Geminids 2023 peaked a day after new Moon
+        let geminids = get_meteor_showers(2023, None, None)
+            .into_iter()
+            .find(|s| s.name == "Geminids")
+            .unwrap();
+        assert!(geminids.moon_illumination_at_peak < 10.0);
+        assert_eq!(geminids.favorability, "excellent");
+        assert!(geminids.radiant_altitude_at_midnight.is_none());
+    }
+
+    #[test]
+    fn test_meteor_shower_radiant_altitude() {
+        // Geminid radiant (dec +33) is near transit at midnight in mid-December
+        let geminids = get_meteor_showers(2023, Some(40.0), Some(-75.0))
+            .into_iter()
+            .find(|s| s.name == "Geminids")
+            .unwrap();
+        let altitude = geminids.radiant_altitude_at_midnight.unwrap();
+        assert!(altitude > 60.0 && altitude <= 90.0, "altitude {}", altitude);
+    }
+
     // ------------------------------------------------------------------------
     // Seasonal Events Tests
     // ------------------------------------------------------------------------
 
     #[test]
     fn test_get_seasonal_events_returns_four() {
-        let events = get_seasonal_events(2024);
+        let events = get_seasonal_events(2024, None);
         assert_eq!(events.len(), 4, "Should return 4 seasonal events");
     }
 
     #[test]
     fn test_seasonal_events_types() {
-        let events = get_seasonal_events(2024);
+        let events = get_seasonal_events(2024, None);
         
         let equinoxes: Vec<_> = events.iter()
             .filter(|e| matches!(e.event_type, AstroEventType::Equinox))
@@ -868,7 +1391,7 @@ mod tests {
 
     #[test]
     fn test_seasonal_events_dates() {
-        let events = get_seasonal_events(2024);
+        let events = get_seasonal_events(2024, None);
         
         // Check approximate dates
         let dates: Vec<&str> = events.iter().map(|e| e.date.as_str()).collect();
@@ -882,7 +1405,7 @@ mod tests {
     #[test]
     fn test_seasonal_events_have_times() {
         // Dynamic calculation should provide precise times
-        let events = get_seasonal_events(2024);
+        let events = get_seasonal_events(2024, None);
         
         for event in &events {
             assert!(event.time.is_some(), "Event {} should have time", event.name);
@@ -895,7 +1418,7 @@ mod tests {
     #[test]
     fn test_seasonal_events_accurate_dates() {
         // Verify dynamically calculated dates are within expected ranges
-        let events = get_seasonal_events(2024);
+        let events = get_seasonal_events(2024, None);
         
         for event in &events {
             let date = &event.date;
@@ -932,7 +1455,7 @@ mod tests {
 
     #[test]
     fn test_seasonal_events_ordered_chronologically() {
-        let events = get_seasonal_events(2024);
+        let events = get_seasonal_events(2024, None);
         
         // Should be in order: vernal, summer, autumnal, winter
         assert!(events.len() == 4);
@@ -945,8 +1468,8 @@ mod tests {
 
     #[test]
     fn test_seasonal_events_different_years() {
-        let events_2024 = get_seasonal_events(2024);
-        let events_2025 = get_seasonal_events(2025);
+        let events_2024 = get_seasonal_events(2024, None);
+        let events_2025 = get_seasonal_events(2025, None);
         
         // Both years should have 4 events
         assert_eq!(events_2024.len(), 4);
@@ -958,6 +1481,43 @@ mod tests {
         assert!(first_2025 > last_2024, "2025 events should be after 2024");
     }
 
+    #[test]
+    fn test_seasonal_events_southern_hemisphere() {
+        let north = get_seasonal_events(2024, Some(45.0));
+        let south = get_seasonal_events(2024, Some(-33.0));
+
+        // Same instants, flipped labels
+        assert_eq!(north.len(), south.len());
+        for (n, s) in north.iter().zip(&south) {
+            assert_eq!(n.timestamp, s.timestamp);
+        }
+        let december = south.iter().find(|e| e.date.starts_with("2024-12-")).unwrap();
+        assert_eq!(december.name, "Summer Solstice");
+        assert!(december.description.contains("Southern"));
+        let march = south.iter().find(|e| e.date.starts_with("2024-03-")).unwrap();
+        assert_eq!(march.name, "Autumnal Equinox");
+        assert_eq!(get_seasonal_events(2024, None)[1].name, "Summer Solstice");
+    }
+
+    #[test]
+    fn test_galactic_core_season_depends_on_latitude() {
+        let north = galactic_core_season(2024, 45.0).unwrap();
+        let south = galactic_core_season(2024, -33.0).unwrap();
+        assert_ne!(north, south);
+
+        // The core rides higher and longer in southern skies
+        assert!(south.1 - south.0 > north.1 - north.0, "{:?} vs {:?}", south, north);
+        // Mid-northern latitudes: pre-dawn in spring through late summer evenings
+        assert!((3..=5).contains(&north.0) && (7..=9).contains(&north.1), "{:?}", north);
+
+        // Never clears 15° from far northern latitudes
+        assert!(galactic_core_season(2024, 65.0).is_none());
+
+        let details = get_seasonal_events(2024, Some(-33.0))[0].details.clone().unwrap();
+        assert_eq!(details["hemisphere"], "southern");
+        assert!(details["galactic_core_season"]["description"].is_string());
+    }
+
     // ------------------------------------------------------------------------
     // Supermoon Detection Tests
     // ------------------------------------------------------------------------
@@ -1084,6 +1644,8 @@ mod tests {
             "2026-08-12".to_string(),
             "Etc/UTC".to_string(),
             true,
+            None,
+            None,
         );
         assert!(result.is_ok());
         let events = result.unwrap();
@@ -1099,6 +1661,8 @@ mod tests {
             "2024-08-10".to_string(),
             "Etc/UTC".to_string(),
             true,
+            None,
+            None,
         );
         assert!(result.is_ok());
         let events = result.unwrap();
@@ -1113,6 +1677,48 @@ mod tests {
         assert!(has_window, "Expected at least one window-style daily event");
     }
 
+    #[test]
+    fn test_get_daily_astro_events_sun_and_moon() {
+        // London on the 2024 March equinox
+        let events = get_daily_astro_events(
+            "2024-03-20".to_string(),
+            "Europe/London".to_string(),
+            false,
+            Some(51.5),
+            Some(0.0),
+        )
+        .unwrap();
+
+        let at = |name: &str| events.iter().find(|e| e.name == name).map(|e| e.timestamp).unwrap();
+        let sunrise = at("Sunrise");
+        let sunset = at("Sunset");
+        // ~06:03 and ~18:15 UTC
+        assert!((sunrise - 1710914580).abs() < 600, "sunrise {}", sunrise);
+        assert!((sunset - 1710958500).abs() < 600, "sunset {}", sunset);
+
+        let order = [
+            "Astronomical Dawn", "Nautical Dawn", "Civil Dawn", "Sunrise",
+            "Sunset", "Civil Dusk", "Nautical Dusk", "Astronomical Dusk",
+        ];
+        for pair in order.windows(2) {
+            assert!(at(pair[0]) < at(pair[1]), "{} should precede {}", pair[0], pair[1]);
+        }
+
+        // Events come back sorted, and the equinox itself is included
+        assert!(events.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+        assert!(events.iter().any(|e| matches!(e.event_type, AstroEventType::Equinox)));
+        assert!(events.iter().any(|e| matches!(e.event_type, AstroEventType::Moonrise | AstroEventType::Moonset)));
+    }
+
+    #[test]
+    fn test_moon_horizon_crossings_alternate() {
+        let crossings = moon_horizon_crossings(51.5, 0.0, 1710892800, 1710892800 + 3 * 86400);
+        assert!(crossings.len() >= 4, "{:?}", crossings);
+        for pair in crossings.windows(2) {
+            assert_ne!(pair[0].1, pair[1].1, "rises and sets should alternate");
+        }
+    }
+
     // ------------------------------------------------------------------------
     // Tonight Highlights Tests
     // ------------------------------------------------------------------------
@@ -1153,6 +1759,39 @@ mod tests {
         assert!(!highlights_south.is_empty());
     }
 
+    #[test]
+    fn test_tonight_highlights_planet_filtering() {
+        // 2023-11-18: Mars at solar conjunction, Jupiter two weeks past opposition
+        let highlights = get_tonight_highlights(45.0, 0.0, Some(1700344800));
+
+        assert!(highlights.iter().any(|h| h.starts_with("Jupiter:")), "{:?}", highlights);
+        assert!(!highlights.iter().any(|h| h.starts_with("Mars:")), "{:?}", highlights);
+        assert!(highlights.iter().any(|h| h.starts_with("Darkness from")));
+    }
+
+    #[test]
+    fn test_tonight_highlights_meteor_and_deep_sky() {
+        // 2023-12-14 20:00 UTC: Geminid peak under a new Moon
+        let highlights = get_tonight_highlights(45.0, 0.0, Some(1702584000));
+
+        let geminids = highlights.iter().find(|h| h.starts_with("Geminids")).unwrap();
+        assert!(geminids.contains("excellent"), "{}", geminids);
+        assert!(highlights.iter().any(|h| h.contains("transits near")), "{:?}", highlights);
+    }
+
+    #[test]
+    fn test_tonight_dark_window_follows_local_night() {
+        // Morning and evening of the same night resolve to the same window
+        let evening = tonight_dark_window(45.0, 0.0, DateTime::from_timestamp(1700344800, 0).unwrap());
+        let morning = tonight_dark_window(45.0, 0.0, DateTime::from_timestamp(1700370000, 0).unwrap());
+        assert!(evening.is_some());
+        assert_eq!(evening, morning);
+
+        // No darkness at all during the Arctic summer
+        let midsummer = DateTime::from_timestamp(1718884800, 0).unwrap();
+        assert!(tonight_dark_window(70.0, 20.0, midsummer).is_none());
+    }
+
     // ------------------------------------------------------------------------
     // AstroEventType Tests
     // ------------------------------------------------------------------------
@@ -1197,6 +1836,9 @@ mod tests {
             radiant_dec: 45.0,
             parent_body: Some("Test Comet".to_string()),
             description: "A test shower".to_string(),
+            moon_illumination_at_peak: 12.5,
+            favorability: "excellent".to_string(),
+            radiant_altitude_at_midnight: None,
         };
         
         let json = serde_json::to_string(&info).unwrap();
@@ -4,28 +4,44 @@
 //! Submodules:
 //! - `calculations`: Core astronomical calculations (coordinates, time, visibility, imaging)
 //! - `events`: Astronomical events (moon phases, meteor showers, seasonal events)
+//! - `catalog`: Catalog lookups (constellation boundaries)
 
 pub mod calculations;
+pub mod catalog;
 pub mod events;
 
 // Re-export commonly used items
 pub use calculations::{
     // Coordinate types
     EquatorialCoords, EclipticCoords, GalacticCoords, GeoLocation, HorizontalCoords,
+    CoordinateGrid, GridLine,
     // Result types
-    FOVResult, MoonPhase, MoonPosition, MosaicCoverage, SunPosition, TwilightTimes, VisibilityInfo,
+    AltitudeSample, BestTime, DayVisibility, DriftRate, EffectiveDarkness, FOVResult, FrameFill, ImagingConditions, MoonPhase, MoonPosition, MosaicCoverage, MosaicPanel, Nutation, HourAngleInfo, PhotographyTimes,
+    SunPosition, TwilightTimes,
+    VisibilityInfo,
     // Tauri commands
-    angular_separation, calculate_fov, calculate_moon_phase, calculate_moon_position,
-    calculate_mosaic_coverage, calculate_sun_position, calculate_twilight, calculate_visibility,
+    angular_separation, angular_separation_epoch, batch_equatorial_to_horizontal, calculate_drift_rate, calculate_fov, calculate_fov_footprint, calculate_frame_fill,
+    calculate_moon_phase, calculate_moon_altitude_curve, calculate_sun_altitude_curve,
+    calculate_moon_position, calculate_mosaic_coverage, calculate_mosaic_panels, mosaic_slew_order,
+    calculate_best_observation_time, calculate_sun_position, calculate_photography_times, calculate_twilight,
+    calculate_effective_darkness, get_imaging_conditions, calculate_visibility, calculate_visibility_calendar,
     ecliptic_to_equatorial, equatorial_to_ecliptic, equatorial_to_galactic, equatorial_to_horizontal,
-    format_dec_dms, format_ra_hms, galactic_to_equatorial, horizontal_to_equatorial,
-    parse_dec_dms, parse_ra_hms,
+    format_dec_dms, format_dec_dms_precision, format_ra_hms, format_ra_hms_precision,
+    galactic_to_equatorial, generate_coordinate_grid, horizontal_to_equatorial,
+    parse_dec_dms, parse_ra_hms, utc_to_bjd, utc_to_hjd, calculate_nutation, calculate_obliquity_command,
+    get_target_hour_angle,
+};
+
+pub use catalog::{
+    constellation_for_coords, filter_catalog, find_catalog_object, identify_object, search_catalog,
+    CatalogFilter, CatalogObject,
 };
 
 pub use events::{
     // Types
     AstroEvent, AstroEventType, MeteorShowerInfo, MoonPhaseEvent,
     // Tauri commands
-    get_astro_events, get_meteor_showers, get_moon_phases_for_month, get_seasonal_events,
+    get_astro_events, get_meteor_showers, get_moon_phases_for_month, get_moon_quarter_times,
+    get_seasonal_events,
     get_daily_astro_events, get_tonight_highlights,
 };
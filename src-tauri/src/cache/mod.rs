@@ -10,10 +10,11 @@ pub mod unified;
 
 // Re-export types and commands from offline cache
 pub use offline::{
-    CacheData, CacheRegion, CacheStats, CacheStatus, CreateRegionArgs, SurveyCacheInfo, TileMetadata,
+    CacheData, CacheRegion, CacheStats, CacheStatus, CreateRegionArgs, SurveyArchiveManifest,
+    SurveyCacheImport, SurveyCacheInfo, TileMetadata,
     clear_all_cache, clear_survey_cache, create_cache_region, delete_cache_region,
-    get_cache_directory, get_cache_stats, is_tile_cached, list_cache_regions,
-    load_cached_tile, save_cached_tile, update_cache_region,
+    export_survey_cache, get_cache_directory, get_cache_stats, import_survey_cache, is_tile_cached,
+    list_cache_regions, load_cached_tile, save_cached_tile, update_cache_region,
 };
 
 // Re-export types and commands from unified cache
@@ -5,13 +5,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
 use tauri::AppHandle;
 #[cfg(not(desktop))]
 use tauri::Manager;
 
-use crate::data::StorageError;
+use crate::data::{write_atomic, write_json_atomic, StorageError};
 use crate::network::security::limits;
 use crate::utils::generate_id;
 
@@ -120,7 +120,15 @@ fn get_tiles_dir(app: &AppHandle, survey_id: &str) -> Result<PathBuf, StorageErr
 fn get_tile_path(app: &AppHandle, survey_id: &str, zoom: u8, x: u64, y: u64) -> Result<PathBuf, StorageError> {
     let zoom_dir = get_tiles_dir(app, survey_id)?.join(zoom.to_string());
     if !zoom_dir.exists() { fs::create_dir_all(&zoom_dir)?; }
-    Ok(zoom_dir.join(format!("{}_{}.jpg", x, y)))
+    Ok(zoom_dir.join(tile_file_name(x, y)))
+}
+
+fn tile_file_name(x: u64, y: u64) -> String {
+    format!("{}_{}.jpg", x, y)
+}
+
+fn tile_key(survey_id: &str, zoom: u8, x: u64, y: u64) -> String {
+    format!("{}_{}_{}_{}", survey_id, zoom, x, y)
 }
 
 fn load_cache_data_from_disk(app: &AppHandle) -> Result<CacheData, StorageError> {
@@ -130,7 +138,7 @@ fn load_cache_data_from_disk(app: &AppHandle) -> Result<CacheData, StorageError>
 }
 
 fn save_cache_data_to_disk(app: &AppHandle, data: &CacheData) -> Result<(), StorageError> {
-    fs::write(&get_cache_meta_path(app)?, serde_json::to_string_pretty(data)?)?;
+    write_json_atomic(&get_cache_meta_path(app)?, data)?;
     Ok(())
 }
 
@@ -246,21 +254,10 @@ pub async fn save_cached_tile(app: AppHandle, survey_id: String, zoom: u8, x: u6
 
     // Use atomic write: write to temp file then rename
     let tile_path = get_tile_path(&app, &survey_id, zoom, x, y)?;
-    let temp_path = tile_path.with_extension("tmp");
-    
-    // Ensure parent directory exists
-    if let Some(parent) = tile_path.parent() {
-        fs::create_dir_all(parent)?;
-    }
-    
-    // Write to temp file first
-    fs::write(&temp_path, &data)?;
-    
-    // Atomic rename (on most filesystems this is atomic)
-    fs::rename(&temp_path, &tile_path)?;
+    write_atomic(&tile_path, &data)?;
     
     // Update metadata in the same loaded instance (fixes race condition)
-    cache_data.tiles.insert(format!("{}_{}_{}_{}", survey_id, zoom, x, y), TileMetadata {
+    cache_data.tiles.insert(tile_key(&survey_id, zoom, x, y), TileMetadata {
         survey_id, zoom, x, y, size_bytes: data.len() as u64, cached_at: Utc::now(),
     });
     update_cache_data(&app, cache_data)?;
@@ -333,6 +330,213 @@ fn count_files_recursive(dir: &PathBuf) -> Result<u64, StorageError> {
     Ok(count)
 }
 
+// ============================================================================
+// Survey Cache Archives
+// ============================================================================
+
+/// Version written to and required in archive manifests
+const SURVEY_ARCHIVE_VERSION: u32 = 1;
+const SURVEY_ARCHIVE_MANIFEST: &str = "manifest.json";
+
+/// Describes the tiles packed in a survey cache archive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurveyArchiveManifest {
+    pub version: u32,
+    pub survey_id: String,
+    pub exported_at: DateTime<Utc>,
+    pub tiles: Vec<TileMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurveyCacheImport {
+    pub survey_id: String,
+    pub imported: usize,
+    /// Tiles already cached at least as recently as the archived copy
+    pub skipped: usize,
+}
+
+/// Path of a tile inside an archive
+fn archive_tile_path(zoom: u8, x: u64, y: u64) -> String {
+    format!("tiles/{}/{}", zoom, tile_file_name(x, y))
+}
+
+/// Survey ids become directory names, so keep them to a safe character set
+fn validate_survey_id(survey_id: &str) -> Result<(), StorageError> {
+    let valid = !survey_id.is_empty()
+        && survey_id != "."
+        && survey_id != ".."
+        && survey_id.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    if valid {
+        Ok(())
+    } else {
+        Err(StorageError::Other(format!("Invalid survey id: {}", survey_id)))
+    }
+}
+
+/// Pack a survey's cached tiles and their metadata into a tar archive;
+/// returns the number of tiles written
+fn export_survey_archive(
+    cache_dir: &Path,
+    data: &CacheData,
+    survey_id: &str,
+    archive_path: &Path,
+) -> Result<usize, StorageError> {
+    validate_survey_id(survey_id)?;
+    let tiles_dir = cache_dir.join("tiles").join(survey_id);
+    let mut tiles: Vec<TileMetadata> = data
+        .tiles
+        .values()
+        .filter(|t| t.survey_id == survey_id)
+        .filter(|t| tiles_dir.join(t.zoom.to_string()).join(tile_file_name(t.x, t.y)).is_file())
+        .cloned()
+        .collect();
+    if tiles.is_empty() {
+        return Err(StorageError::Other(format!("No cached tiles for survey {}", survey_id)));
+    }
+    tiles.sort_by_key(|t| (t.zoom, t.x, t.y));
+
+    let manifest = SurveyArchiveManifest {
+        version: SURVEY_ARCHIVE_VERSION,
+        survey_id: survey_id.to_string(),
+        exported_at: Utc::now(),
+        tiles,
+    };
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest)?;
+
+    let mut builder = tar::Builder::new(fs::File::create(archive_path)?);
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest_bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(manifest.exported_at.timestamp().max(0) as u64);
+    builder.append_data(&mut header, SURVEY_ARCHIVE_MANIFEST, manifest_bytes.as_slice())?;
+
+    for tile in &manifest.tiles {
+        let source = tiles_dir.join(tile.zoom.to_string()).join(tile_file_name(tile.x, tile.y));
+        builder.append_path_with_name(&source, archive_tile_path(tile.zoom, tile.x, tile.y))?;
+    }
+    builder.into_inner()?.sync_all()?;
+
+    Ok(manifest.tiles.len())
+}
+
+/// Read and validate an archive's manifest: it must come first, carry the
+/// supported version and a safe survey id, and every listed tile must be
+/// present in the archive with the recorded size
+fn read_survey_archive_manifest(archive_path: &Path) -> Result<SurveyArchiveManifest, StorageError> {
+    let mut archive = tar::Archive::new(fs::File::open(archive_path)?);
+    let mut entries = archive.entries()?;
+
+    let mut first = entries
+        .next()
+        .ok_or_else(|| StorageError::Other("Survey archive is empty".to_string()))??;
+    if first.path()?.to_string_lossy() != SURVEY_ARCHIVE_MANIFEST {
+        return Err(StorageError::Other("Survey archive has no manifest".to_string()));
+    }
+    let manifest: SurveyArchiveManifest = serde_json::from_reader(&mut first)?;
+    if manifest.version != SURVEY_ARCHIVE_VERSION {
+        return Err(StorageError::Other(format!(
+            "Unsupported survey archive version {}",
+            manifest.version
+        )));
+    }
+    validate_survey_id(&manifest.survey_id)?;
+
+    let mut sizes: HashMap<String, u64> = HashMap::new();
+    for entry in entries {
+        let entry = entry?;
+        sizes.insert(entry.path()?.to_string_lossy().to_string(), entry.size());
+    }
+    for tile in &manifest.tiles {
+        if tile.survey_id != manifest.survey_id {
+            return Err(StorageError::Other(format!(
+                "Tile survey {} does not match archive survey {}",
+                tile.survey_id, manifest.survey_id
+            )));
+        }
+        let path = archive_tile_path(tile.zoom, tile.x, tile.y);
+        match sizes.get(&path) {
+            Some(&size) if size == tile.size_bytes => {}
+            Some(_) => return Err(StorageError::Other(format!("Tile {} has the wrong size", path))),
+            None => return Err(StorageError::Other(format!("Tile {} is missing from the archive", path))),
+        }
+    }
+
+    Ok(manifest)
+}
+
+/// Merge an archive's tiles into `cache_dir` and `data`. Tiles already cached
+/// at least as recently as the archived copy are kept.
+fn import_survey_archive(
+    cache_dir: &Path,
+    data: &mut CacheData,
+    archive_path: &Path,
+) -> Result<SurveyCacheImport, StorageError> {
+    let manifest = read_survey_archive_manifest(archive_path)?;
+    let tiles_dir = cache_dir.join("tiles").join(&manifest.survey_id);
+
+    let mut wanted: HashMap<String, TileMetadata> = HashMap::new();
+    let mut skipped = 0;
+    for tile in manifest.tiles {
+        let key = tile_key(&tile.survey_id, tile.zoom, tile.x, tile.y);
+        let on_disk = tiles_dir.join(tile.zoom.to_string()).join(tile_file_name(tile.x, tile.y)).is_file();
+        let newer_local = data.tiles.get(&key).is_some_and(|t| t.cached_at >= tile.cached_at);
+        if on_disk && newer_local {
+            skipped += 1;
+        } else {
+            wanted.insert(archive_tile_path(tile.zoom, tile.x, tile.y), tile);
+        }
+    }
+
+    let added: u64 = wanted.values().map(|t| t.size_bytes).sum();
+    let current: u64 = data.tiles.values().map(|t| t.size_bytes).sum();
+    if current + added > limits::MAX_CACHE_TOTAL_SIZE as u64 {
+        return Err(StorageError::Other(format!("Cache size limit reached ({} bytes)", limits::MAX_CACHE_TOTAL_SIZE)));
+    }
+
+    let mut imported = 0;
+    let mut archive = tar::Archive::new(fs::File::open(archive_path)?);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        // Only paths named by the validated manifest are extracted
+        let Some(tile) = wanted.remove(entry.path()?.to_string_lossy().as_ref()) else {
+            continue;
+        };
+        let zoom_dir = tiles_dir.join(tile.zoom.to_string());
+        fs::create_dir_all(&zoom_dir)?;
+        let tile_path = zoom_dir.join(tile_file_name(tile.x, tile.y));
+        let temp_path = tile_path.with_extension("tmp");
+        std::io::copy(&mut entry, &mut fs::File::create(&temp_path)?)?;
+        fs::rename(&temp_path, &tile_path)?;
+
+        data.tiles.insert(tile_key(&tile.survey_id, tile.zoom, tile.x, tile.y), tile);
+        imported += 1;
+    }
+
+    Ok(SurveyCacheImport {
+        survey_id: manifest.survey_id,
+        imported,
+        skipped,
+    })
+}
+
+/// Export one survey's cached tiles to a tar archive for offline sharing
+#[tauri::command]
+pub async fn export_survey_cache(app: AppHandle, survey: String, archive_path: String) -> Result<usize, StorageError> {
+    let cache_dir = get_cache_dir(&app)?;
+    let data = get_cache_data(&app)?;
+    export_survey_archive(&cache_dir, &data, &survey, Path::new(&archive_path))
+}
+
+/// Import a survey cache archive, keeping any newer tiles already cached
+#[tauri::command]
+pub async fn import_survey_cache(app: AppHandle, archive_path: String) -> Result<SurveyCacheImport, StorageError> {
+    let cache_dir = get_cache_dir(&app)?;
+    let mut data = get_cache_data(&app)?;
+    let result = import_survey_archive(&cache_dir, &mut data, Path::new(&archive_path))?;
+    update_cache_data(&app, data)?;
+    Ok(result)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -341,6 +545,129 @@ fn count_files_recursive(dir: &PathBuf) -> Result<u64, StorageError> {
 mod tests {
     use super::*;
 
+    // ------------------------------------------------------------------------
+    // Survey Archive Tests
+    // ------------------------------------------------------------------------
+
+    fn archive_test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("skymap_test_survey_archive_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn seed_tile(cache_dir: &Path, data: &mut CacheData, survey: &str, zoom: u8, x: u64, y: u64, bytes: &[u8]) {
+        let zoom_dir = cache_dir.join("tiles").join(survey).join(zoom.to_string());
+        fs::create_dir_all(&zoom_dir).unwrap();
+        fs::write(zoom_dir.join(tile_file_name(x, y)), bytes).unwrap();
+        data.tiles.insert(tile_key(survey, zoom, x, y), TileMetadata {
+            survey_id: survey.to_string(), zoom, x, y, size_bytes: bytes.len() as u64, cached_at: Utc::now(),
+        });
+    }
+
+    #[test]
+    fn test_survey_archive_round_trip() {
+        let root = archive_test_dir("round_trip");
+        let (source, dest) = (root.join("source"), root.join("dest"));
+        let mut data = CacheData::default();
+        seed_tile(&source, &mut data, "dss2", 3, 1, 2, b"tile-a");
+        seed_tile(&source, &mut data, "dss2", 4, 5, 6, b"tile-bb");
+        seed_tile(&source, &mut data, "other", 3, 1, 2, b"not exported");
+
+        let archive = root.join("dss2.tar");
+        assert_eq!(export_survey_archive(&source, &data, "dss2", &archive).unwrap(), 2);
+
+        let mut imported_data = CacheData::default();
+        let result = import_survey_archive(&dest, &mut imported_data, &archive).unwrap();
+        assert_eq!(result.survey_id, "dss2");
+        assert_eq!(result.imported, 2);
+        assert_eq!(result.skipped, 0);
+
+        assert_eq!(fs::read(dest.join("tiles/dss2/3/1_2.jpg")).unwrap(), b"tile-a");
+        assert_eq!(fs::read(dest.join("tiles/dss2/4/5_6.jpg")).unwrap(), b"tile-bb");
+        assert!(!dest.join("tiles/other").exists());
+        assert_eq!(imported_data.tiles.len(), 2);
+        for (key, meta) in &imported_data.tiles {
+            let original = &data.tiles[key];
+            assert_eq!(meta.size_bytes, original.size_bytes);
+            assert_eq!(meta.cached_at, original.cached_at);
+        }
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_survey_archive_import_keeps_newer_tiles() {
+        let root = archive_test_dir("newer");
+        let (source, dest) = (root.join("source"), root.join("dest"));
+        let mut data = CacheData::default();
+        seed_tile(&source, &mut data, "dss2", 3, 1, 2, b"old");
+        seed_tile(&source, &mut data, "dss2", 3, 2, 2, b"old");
+        let archive = root.join("dss2.tar");
+        export_survey_archive(&source, &data, "dss2", &archive).unwrap();
+
+        // The destination re-downloaded one tile after the export
+        let mut dest_data = CacheData::default();
+        seed_tile(&dest, &mut dest_data, "dss2", 3, 1, 2, b"fresh");
+        let result = import_survey_archive(&dest, &mut dest_data, &archive).unwrap();
+        assert_eq!((result.imported, result.skipped), (1, 1));
+        assert_eq!(fs::read(dest.join("tiles/dss2/3/1_2.jpg")).unwrap(), b"fresh");
+        assert_eq!(fs::read(dest.join("tiles/dss2/3/2_2.jpg")).unwrap(), b"old");
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_survey_archive_rejects_bad_manifest() {
+        let root = archive_test_dir("bad_manifest");
+        let write_archive = |name: &str, manifest: &SurveyArchiveManifest| {
+            let path = root.join(name);
+            let mut builder = tar::Builder::new(fs::File::create(&path).unwrap());
+            let bytes = serde_json::to_vec(manifest).unwrap();
+            let mut header = tar::Header::new_gnu();
+            header.set_size(bytes.len() as u64);
+            builder.append_data(&mut header, SURVEY_ARCHIVE_MANIFEST, bytes.as_slice()).unwrap();
+            builder.finish().unwrap();
+            path
+        };
+        let tile = TileMetadata {
+            survey_id: "dss2".to_string(), zoom: 3, x: 1, y: 2, size_bytes: 4, cached_at: Utc::now(),
+        };
+        let manifest = SurveyArchiveManifest {
+            version: SURVEY_ARCHIVE_VERSION,
+            survey_id: "dss2".to_string(),
+            exported_at: Utc::now(),
+            tiles: vec![tile],
+        };
+
+        let missing_tile = write_archive("missing.tar", &manifest);
+        let future = write_archive("future.tar", &SurveyArchiveManifest { version: 99, ..manifest.clone() });
+        let traversal = write_archive("traversal.tar", &SurveyArchiveManifest {
+            survey_id: "../escape".to_string(),
+            tiles: Vec::new(),
+            ..manifest.clone()
+        });
+
+        let dest = root.join("dest");
+        for archive in [missing_tile, future, traversal] {
+            let mut data = CacheData::default();
+            assert!(import_survey_archive(&dest, &mut data, &archive).is_err(), "{:?}", archive);
+            assert!(data.tiles.is_empty());
+        }
+        assert!(!dest.join("tiles").exists());
+
+        fs::write(root.join("garbage.tar"), b"not an archive").unwrap();
+        assert!(read_survey_archive_manifest(&root.join("garbage.tar")).is_err());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_export_survey_archive_requires_tiles() {
+        let root = archive_test_dir("empty");
+        let data = CacheData::default();
+        assert!(export_survey_archive(&root, &data, "dss2", &root.join("x.tar")).is_err());
+        assert!(export_survey_archive(&root, &data, "../dss2", &root.join("x.tar")).is_err());
+        let _ = fs::remove_dir_all(&root);
+    }
+
     // ------------------------------------------------------------------------
     // estimate_tile_count Tests
     // ------------------------------------------------------------------------
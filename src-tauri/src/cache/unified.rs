@@ -5,16 +5,19 @@
 //! to reduce I/O overhead for frequent cache operations.
 
 use chrono::{DateTime, Utc};
+use futures_util::future::{AbortHandle, Abortable, BoxFuture, FutureExt, Shared};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::future::Future;
 use std::fs;
 use std::path::PathBuf;
-use std::sync::{Mutex, OnceLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 use tauri::AppHandle;
 #[cfg(not(desktop))]
 use tauri::Manager;
 
-use crate::data::StorageError;
+use crate::data::{write_json_atomic, StorageError};
 use crate::network::{http_client, security};
 
 // ============================================================================
@@ -67,6 +70,9 @@ pub struct CacheEntryMeta {
     pub access_count: u64,
     #[serde(default)]
     pub last_access: i64,
+    /// Namespace the entry was stored under; `None` for the shared key space
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -100,8 +106,35 @@ pub struct UnifiedCacheStats {
 pub struct PrefetchResult {
     pub success: usize,
     pub failed: usize,
+    /// URLs that joined a fetch already in flight instead of requesting again
+    #[serde(default)]
+    pub coalesced: usize,
+}
+
+/// A prefetch in progress, shared by every caller asking for the same URL
+#[derive(Clone)]
+struct InFlightPrefetch {
+    fetch: Shared<BoxFuture<'static, bool>>,
+    abort: AbortHandle,
+}
+
+/// URLs currently being prefetched
+static PREFETCH_IN_FLIGHT: OnceLock<Mutex<HashMap<String, InFlightPrefetch>>> = OnceLock::new();
+
+/// Sequence for prefetch request IDs, which must stay unique while batches run concurrently
+static PREFETCH_REQUEST_SEQ: AtomicU64 = AtomicU64::new(0);
+
+fn next_prefetch_request_id() -> String {
+    format!(
+        "prefetch-{}-{}",
+        chrono::Utc::now().timestamp_millis(),
+        PREFETCH_REQUEST_SEQ.fetch_add(1, Ordering::Relaxed)
+    )
 }
 
+/// Concurrent fetches per `prefetch_urls` call
+const PREFETCH_CONCURRENCY: usize = 6;
+
 fn get_unified_cache_dir(app: &AppHandle) -> Result<PathBuf, StorageError> {
     #[cfg(desktop)]
     let base = crate::platform::path_config::resolve_cache_dir(app)?;
@@ -129,6 +162,61 @@ fn get_cache_data_dir(app: &AppHandle) -> Result<PathBuf, StorageError> {
     Ok(data_dir)
 }
 
+/// Build the index key for `key`, stored as `namespace:key` when namespaced
+/// Namespaces are restricted to lowercase letters, digits, `-` and `_` so the
+/// separator can never appear inside one, and un-namespaced keys may not
+/// contain it so they can't alias a namespaced entry
+fn namespaced_key(namespace: Option<&str>, key: &str) -> Result<String, StorageError> {
+    match namespace {
+        None if key.contains(':') => {
+            Err(StorageError::Other(format!("Cache key without a namespace may not contain ':': {:?}", key)))
+        }
+        None => Ok(key.to_string()),
+        Some(ns) => {
+            let valid = !ns.is_empty()
+                && ns.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+            if !valid {
+                return Err(StorageError::Other(format!("Invalid cache namespace: {:?}", ns)));
+            }
+            Ok(format!("{}:{}", ns, key))
+        }
+    }
+}
+
+/// Insert or replace an entry under `index_key`, keeping `total_size` in sync
+fn insert_index_entry(index: &mut CacheIndex, index_key: String, meta: CacheEntryMeta) {
+    if let Some(old_meta) = index.entries.get(&index_key) {
+        index.total_size = index.total_size.saturating_sub(old_meta.size_bytes);
+    }
+    index.total_size += meta.size_bytes;
+    index.entries.insert(index_key, meta);
+}
+
+/// Remove every entry in `namespace` from the index, returning their index keys
+fn remove_namespace_entries(index: &mut CacheIndex, namespace: &str) -> Vec<String> {
+    let keys: Vec<String> = index.entries.iter()
+        .filter(|(_, meta)| meta.namespace.as_deref() == Some(namespace))
+        .map(|(k, _)| k.clone())
+        .collect();
+    for key in &keys {
+        if let Some(meta) = index.entries.remove(key) {
+            index.total_size = index.total_size.saturating_sub(meta.size_bytes);
+        }
+    }
+    keys
+}
+
+/// Keys in the index, either all index keys or the bare keys of one namespace
+fn list_index_keys(index: &CacheIndex, namespace: Option<&str>) -> Vec<String> {
+    match namespace {
+        None => index.entries.keys().cloned().collect(),
+        Some(ns) => index.entries.values()
+            .filter(|meta| meta.namespace.as_deref() == Some(ns))
+            .map(|meta| meta.key.clone())
+            .collect(),
+    }
+}
+
 fn key_to_filename(key: &str) -> String {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
@@ -146,7 +234,7 @@ fn load_cache_index_from_disk(app: &AppHandle) -> Result<CacheIndex, StorageErro
 
 /// Save cache index to disk (internal use only)
 fn save_cache_index_to_disk(app: &AppHandle, index: &CacheIndex) -> Result<(), StorageError> {
-    fs::write(&get_cache_index_path(app)?, serde_json::to_string_pretty(index)?)?;
+    write_json_atomic(&get_cache_index_path(app)?, index)?;
     Ok(())
 }
 
@@ -233,9 +321,10 @@ fn invalidate_cache_index() {
 }
 
 #[tauri::command]
-pub async fn get_unified_cache_entry(app: AppHandle, key: String) -> Result<Option<UnifiedCacheResponse>, StorageError> {
+pub async fn get_unified_cache_entry(app: AppHandle, key: String, namespace: Option<String>) -> Result<Option<UnifiedCacheResponse>, StorageError> {
+    let index_key = namespaced_key(namespace.as_deref(), &key)?;
     let mut index = get_cache_index(&app)?;
-    let meta = match index.entries.get(&key) {
+    let meta = match index.entries.get(&index_key) {
         Some(m) => m.clone(),
         None => {
             record_cache_miss();
@@ -247,22 +336,22 @@ pub async fn get_unified_cache_entry(app: AppHandle, key: String) -> Result<Opti
         let now = Utc::now().timestamp_millis();
         if now > meta.timestamp + meta.ttl {
             record_cache_miss();
-            delete_unified_cache_entry(app, key).await?;
+            delete_unified_cache_entry(app, key, namespace).await?;
             return Ok(None);
         }
     }
 
-    let data_path = get_cache_data_dir(&app)?.join(key_to_filename(&key));
+    let data_path = get_cache_data_dir(&app)?.join(key_to_filename(&index_key));
     if !data_path.exists() {
         record_cache_miss();
         // Clean up orphaned index entry
-        delete_unified_cache_entry(app, key).await?;
+        delete_unified_cache_entry(app, key, namespace).await?;
         return Ok(None);
     }
 
     // Update access tracking
     let now = Utc::now().timestamp_millis();
-    if let Some(entry) = index.entries.get_mut(&key) {
+    if let Some(entry) = index.entries.get_mut(&index_key) {
         entry.access_count += 1;
         entry.last_access = now;
     }
@@ -352,7 +441,8 @@ fn evict_lru_by_size(app: &AppHandle, index: &mut CacheIndex, target_bytes: u64)
 }
 
 #[tauri::command]
-pub async fn put_unified_cache_entry(app: AppHandle, key: String, data: Vec<u8>, content_type: String, ttl: i64) -> Result<(), StorageError> {
+pub async fn put_unified_cache_entry(app: AppHandle, key: String, data: Vec<u8>, content_type: String, ttl: i64, namespace: Option<String>) -> Result<(), StorageError> {
+    let index_key = namespaced_key(namespace.as_deref(), &key)?;
     let mut index = get_cache_index(&app)?;
 
     if index.entries.len() >= security::limits::MAX_CACHE_ENTRIES {
@@ -371,30 +461,26 @@ pub async fn put_unified_cache_entry(app: AppHandle, key: String, data: Vec<u8>,
         }
     }
 
-    let data_path = get_cache_data_dir(&app)?.join(key_to_filename(&key));
+    let data_path = get_cache_data_dir(&app)?.join(key_to_filename(&index_key));
     let size_bytes = data.len() as u64;
     fs::write(&data_path, &data)?;
 
-    if let Some(old_meta) = index.entries.get(&key) {
-        index.total_size = index.total_size.saturating_sub(old_meta.size_bytes);
-    }
-
     let now = Utc::now().timestamp_millis();
-    index.entries.insert(key.clone(), CacheEntryMeta {
+    insert_index_entry(&mut index, index_key, CacheEntryMeta {
         key, content_type, size_bytes, timestamp: now, ttl, etag: None,
-        access_count: 0, last_access: now,
+        access_count: 0, last_access: now, namespace,
     });
-    index.total_size += size_bytes;
     update_cache_index(&app, index, false)?;
     Ok(())
 }
 
 #[tauri::command]
-pub async fn delete_unified_cache_entry(app: AppHandle, key: String) -> Result<bool, StorageError> {
+pub async fn delete_unified_cache_entry(app: AppHandle, key: String, namespace: Option<String>) -> Result<bool, StorageError> {
+    let index_key = namespaced_key(namespace.as_deref(), &key)?;
     let mut index = get_cache_index(&app)?;
-    if let Some(meta) = index.entries.remove(&key) {
+    if let Some(meta) = index.entries.remove(&index_key) {
         index.total_size = index.total_size.saturating_sub(meta.size_bytes);
-        let data_path = get_cache_data_dir(&app)?.join(key_to_filename(&key));
+        let data_path = get_cache_data_dir(&app)?.join(key_to_filename(&index_key));
         if data_path.exists() { fs::remove_file(&data_path)?; }
         update_cache_index(&app, index, false)?;
         return Ok(true);
@@ -402,8 +488,21 @@ pub async fn delete_unified_cache_entry(app: AppHandle, key: String) -> Result<b
     Ok(false)
 }
 
+/// Clear the whole cache, or only the entries of one namespace
 #[tauri::command]
-pub async fn clear_unified_cache(app: AppHandle) -> Result<u64, StorageError> {
+pub async fn clear_unified_cache(app: AppHandle, namespace: Option<String>) -> Result<u64, StorageError> {
+    if let Some(ns) = namespace {
+        namespaced_key(Some(&ns), "")?;
+        let mut index = get_cache_index(&app)?;
+        let removed = remove_namespace_entries(&mut index, &ns);
+        let data_dir = get_cache_data_dir(&app)?;
+        for key in &removed {
+            let _ = fs::remove_file(data_dir.join(key_to_filename(key)));
+        }
+        update_cache_index(&app, index, true)?;
+        return Ok(removed.len() as u64);
+    }
+
     let index = get_cache_index(&app)?;
     let deleted_count = index.entries.len() as u64;
     let data_dir = get_cache_data_dir(&app)?;
@@ -420,9 +519,10 @@ pub async fn get_unified_cache_size(app: AppHandle) -> Result<u64, StorageError>
     Ok(get_cache_index(&app)?.total_size)
 }
 
+/// List index keys, or the bare keys within `namespace` when one is given
 #[tauri::command]
-pub async fn list_unified_cache_keys(app: AppHandle) -> Result<Vec<String>, StorageError> {
-    Ok(get_cache_index(&app)?.entries.keys().cloned().collect())
+pub async fn list_unified_cache_keys(app: AppHandle, namespace: Option<String>) -> Result<Vec<String>, StorageError> {
+    Ok(list_index_keys(&get_cache_index(&app)?, namespace.as_deref()))
 }
 
 #[tauri::command]
@@ -486,8 +586,23 @@ pub async fn flush_unified_cache(app: AppHandle) -> Result<(), StorageError> {
 
 #[tauri::command]
 pub async fn prefetch_url(app: AppHandle, url: String, ttl: i64) -> Result<bool, StorageError> {
+    let (result, entry) = fetch_and_cache_url(&app, url, ttl).await;
+    crate::data::append_download_history(&app, vec![entry]);
+    result
+}
+
+/// Fetch `url` into the unified cache, returning the outcome with the
+/// download history entry describing it, named by its cache key
+async fn fetch_and_cache_url(
+    app: &AppHandle,
+    url: String,
+    ttl: i64,
+) -> (Result<bool, StorageError>, crate::data::DownloadEntry) {
     log::info!("Prefetching URL: {}", url);
-    let request_id = format!("prefetch-{}", chrono::Utc::now().timestamp_millis());
+    let request_id = next_prefetch_request_id();
+    let entry = |size: u64, success: bool| {
+        crate::data::DownloadEntry::now(url_to_cache_key(&url), size, url.as_str(), success)
+    };
 
     match http_client::http_request(app.clone(), http_client::RequestConfig {
         method: "GET".to_string(), url: url.clone(), request_id: Some(request_id),
@@ -496,31 +611,124 @@ pub async fn prefetch_url(app: AppHandle, url: String, ttl: i64) -> Result<bool,
         Ok(response) => {
             if response.status >= 200 && response.status < 300 {
                 let content_type = response.content_type.unwrap_or_else(|| "application/octet-stream".to_string());
-                security::validate_size(&response.body, security::limits::MAX_TILE_SIZE)
-                    .map_err(|e| StorageError::Other(e.to_string()))?;
+                let size = response.body.len() as u64;
+                if let Err(e) = security::validate_size(&response.body, security::limits::MAX_TILE_SIZE) {
+                    return (Err(StorageError::Other(e.to_string())), entry(size, false));
+                }
                 let key = url_to_cache_key(&url);
-                put_unified_cache_entry(app, key, response.body, content_type, ttl).await?;
-                Ok(true)
+                let stored = put_unified_cache_entry(app.clone(), key, response.body, content_type, ttl, None).await;
+                let recorded = entry(size, stored.is_ok());
+                (stored.map(|_| true), recorded)
             } else {
                 log::warn!("Prefetch failed with status: {}", response.status);
-                Ok(false)
+                (Ok(false), entry(0, false))
             }
         }
-        Err(e) => { log::warn!("Prefetch error: {}", e); Ok(false) }
+        Err(e) => {
+            log::warn!("Prefetch error: {}", e);
+            (Ok(false), entry(0, false))
+        }
     }
 }
 
+/// Prefetch `urls` concurrently, recording their download history in one write
 #[tauri::command]
 pub async fn prefetch_urls(app: AppHandle, urls: Vec<String>, ttl: i64) -> Result<PrefetchResult, StorageError> {
-    let mut success = 0;
-    let mut failed = 0;
-    for url in urls {
-        match prefetch_url(app.clone(), url, ttl).await {
-            Ok(true) => success += 1,
-            _ => failed += 1,
+    let in_flight = PREFETCH_IN_FLIGHT.get_or_init(|| Mutex::new(HashMap::new()));
+    let entries = Arc::new(Mutex::new(Vec::new()));
+    let result = prefetch_batch(in_flight, urls, |url| {
+        let app = app.clone();
+        let entries = Arc::clone(&entries);
+        async move {
+            let (result, entry) = fetch_and_cache_url(&app, url, ttl).await;
+            entries.lock().unwrap_or_else(|e| e.into_inner()).push(entry);
+            matches!(result, Ok(true))
+        }
+    })
+    .await;
+
+    let entries = std::mem::take(&mut *entries.lock().unwrap_or_else(|e| e.into_inner()));
+    crate::data::append_download_history(&app, entries);
+    Ok(result)
+}
+
+/// Fetch `url`, or wait for the fetch of it already in flight; returns the
+/// outcome and whether this call was coalesced into another
+async fn prefetch_coalesced<F, Fut>(
+    in_flight: &Mutex<HashMap<String, InFlightPrefetch>>,
+    url: String,
+    fetch: F,
+) -> (bool, bool)
+where
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = bool> + Send + 'static,
+{
+    let (shared, coalesced) = {
+        let mut guard = in_flight.lock().unwrap_or_else(|e| e.into_inner());
+        match guard.get(&url) {
+            Some(existing) => (existing.fetch.clone(), true),
+            None => {
+                let (abort, registration) = AbortHandle::new_pair();
+                let shared = Abortable::new(fetch(url.clone()), registration)
+                    .map(|outcome| outcome.unwrap_or(false))
+                    .boxed()
+                    .shared();
+                guard.insert(url.clone(), InFlightPrefetch { fetch: shared.clone(), abort });
+                (shared, false)
+            }
         }
+    };
+
+    let success = shared.await;
+    if !coalesced {
+        in_flight.lock().unwrap_or_else(|e| e.into_inner()).remove(&url);
     }
-    Ok(PrefetchResult { success, failed })
+    (success, coalesced)
+}
+
+/// Prefetch `urls` a few at a time, sharing fetches with any concurrent batch
+async fn prefetch_batch<F, Fut>(
+    in_flight: &Mutex<HashMap<String, InFlightPrefetch>>,
+    urls: Vec<String>,
+    fetch: F,
+) -> PrefetchResult
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = bool> + Send + 'static,
+{
+    use futures_util::stream::{self, StreamExt};
+
+    let fetch = &fetch;
+    let outcomes: Vec<(bool, bool)> = stream::iter(urls)
+        .map(|url| prefetch_coalesced(in_flight, url, fetch))
+        .buffer_unordered(PREFETCH_CONCURRENCY)
+        .collect()
+        .await;
+
+    let success = outcomes.iter().filter(|(ok, _)| *ok).count();
+    PrefetchResult {
+        success,
+        failed: outcomes.len() - success,
+        coalesced: outcomes.iter().filter(|(_, coalesced)| *coalesced).count(),
+    }
+}
+
+/// Abort every prefetch in flight; waiting callers see it as failed.
+/// Returns how many fetches were aborted
+pub(crate) fn abort_prefetches() -> usize {
+    PREFETCH_IN_FLIGHT.get().map_or(0, abort_in_flight)
+}
+
+fn abort_in_flight(in_flight: &Mutex<HashMap<String, InFlightPrefetch>>) -> usize {
+    let guard = in_flight.lock().unwrap_or_else(|e| e.into_inner());
+    let mut aborted = 0;
+    for prefetch in guard.values() {
+        if !prefetch.abort.is_aborted() {
+            prefetch.abort.abort();
+            aborted += 1;
+        }
+    }
+    aborted
 }
 
 fn url_to_cache_key(url: &str) -> String {
@@ -627,6 +835,7 @@ mod tests {
             etag: Some("abc123".to_string()),
             access_count: 0,
             last_access: 1704067200000,
+            namespace: None,
         };
 
         let json = serde_json::to_string(&meta).unwrap();
@@ -665,6 +874,7 @@ mod tests {
             etag: None,
             access_count: 0,
             last_access: 0,
+            namespace: None,
         };
 
         let cloned = meta.clone();
@@ -672,6 +882,90 @@ mod tests {
         assert_eq!(cloned.content_type, meta.content_type);
     }
 
+    // ------------------------------------------------------------------------
+    // Namespace Tests
+    // ------------------------------------------------------------------------
+
+    fn namespaced_meta(namespace: Option<&str>, key: &str, size_bytes: u64) -> CacheEntryMeta {
+        CacheEntryMeta {
+            key: key.to_string(),
+            content_type: "application/octet-stream".to_string(),
+            size_bytes,
+            timestamp: 0,
+            ttl: 0,
+            etag: None,
+            access_count: 0,
+            last_access: 0,
+            namespace: namespace.map(str::to_string),
+        }
+    }
+
+    fn insert_meta(index: &mut CacheIndex, namespace: Option<&str>, key: &str, size_bytes: u64) {
+        let index_key = namespaced_key(namespace, key).unwrap();
+        insert_index_entry(index, index_key, namespaced_meta(namespace, key, size_bytes));
+    }
+
+    #[test]
+    fn test_namespaced_key_format() {
+        assert_eq!(namespaced_key(None, "m31").unwrap(), "m31");
+        assert!(namespaced_key(None, "http:GET:https://example.com").is_err());
+        assert_eq!(namespaced_key(Some("http"), "GET:https://example.com").unwrap(), "http:GET:https://example.com");
+        assert_eq!(namespaced_key(Some("catalog"), "m31").unwrap(), "catalog:m31");
+        assert!(namespaced_key(Some(""), "m31").is_err());
+        assert!(namespaced_key(Some("tiles:2"), "m31").is_err());
+        assert!(namespaced_key(Some("Tiles"), "m31").is_err());
+    }
+
+    #[test]
+    fn test_same_key_in_different_namespaces_does_not_overwrite() {
+        let mut index = CacheIndex::default();
+        insert_meta(&mut index, Some("catalog"), "m31", 100);
+        insert_meta(&mut index, Some("tiles"), "m31", 250);
+        insert_meta(&mut index, None, "m31", 10);
+
+        assert_eq!(index.entries.len(), 3);
+        assert_eq!(index.total_size, 360);
+        assert_eq!(index.entries["catalog:m31"].size_bytes, 100);
+        assert_eq!(index.entries["tiles:m31"].size_bytes, 250);
+        assert_ne!(key_to_filename("catalog:m31"), key_to_filename("tiles:m31"));
+
+        // Replacing within a namespace updates the size in place
+        insert_meta(&mut index, Some("tiles"), "m31", 50);
+        assert_eq!(index.entries.len(), 3);
+        assert_eq!(index.total_size, 160);
+    }
+
+    #[test]
+    fn test_clear_namespace_leaves_others_intact() {
+        let mut index = CacheIndex::default();
+        insert_meta(&mut index, Some("catalog"), "m31", 100);
+        insert_meta(&mut index, Some("catalog"), "m42", 100);
+        insert_meta(&mut index, Some("tiles"), "m31", 250);
+        insert_meta(&mut index, None, "m31", 10);
+
+        let mut removed = remove_namespace_entries(&mut index, "catalog");
+        removed.sort();
+        assert_eq!(removed, vec!["catalog:m31", "catalog:m42"]);
+        assert_eq!(index.entries.len(), 2);
+        assert_eq!(index.total_size, 260);
+        assert!(index.entries.contains_key("tiles:m31"));
+        assert!(index.entries.contains_key("m31"));
+    }
+
+    #[test]
+    fn test_list_keys_filters_by_namespace() {
+        let mut index = CacheIndex::default();
+        insert_meta(&mut index, Some("catalog"), "m31", 1);
+        insert_meta(&mut index, Some("tiles"), "m42", 1);
+        insert_meta(&mut index, None, "plain", 1);
+
+        assert_eq!(list_index_keys(&index, Some("tiles")), vec!["m42"]);
+        assert!(list_index_keys(&index, Some("surveys")).is_empty());
+        let mut all = list_index_keys(&index, None);
+        all.sort();
+        assert_eq!(all, vec!["catalog:m31", "plain", "tiles:m42"]);
+    }
+
     // ------------------------------------------------------------------------
     // CacheIndex Tests
     // ------------------------------------------------------------------------
@@ -698,6 +992,7 @@ mod tests {
                 etag: None,
                 access_count: 0,
                 last_access: 0,
+                namespace: None,
             },
         );
         index.total_size = 100;
@@ -805,6 +1100,7 @@ mod tests {
         let result = PrefetchResult {
             success: 8,
             failed: 2,
+            coalesced: 0,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -818,11 +1114,83 @@ mod tests {
         let result: PrefetchResult = serde_json::from_str(json).unwrap();
         assert_eq!(result.success, 10);
         assert_eq!(result.failed, 0);
+        assert_eq!(result.coalesced, 0);
+    }
+
+    fn counting_fetch(
+        counts: &std::sync::Arc<Mutex<HashMap<String, usize>>>,
+    ) -> impl Fn(String) -> BoxFuture<'static, bool> {
+        let counts = std::sync::Arc::clone(counts);
+        move |url: String| {
+            *counts.lock().unwrap().entry(url.clone()).or_default() += 1;
+            async move {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                !url.contains("bad")
+            }
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_prefetch_batches_fetch_each_url_once() {
+        let in_flight = Mutex::new(HashMap::new());
+        let counts = std::sync::Arc::new(Mutex::new(HashMap::new()));
+        let urls = |names: &[&str]| names.iter().map(|n| format!("https://tiles.example/{}", n)).collect::<Vec<_>>();
+
+        let (first, second) = tokio::join!(
+            prefetch_batch(&in_flight, urls(&["a", "b", "c", "bad"]), counting_fetch(&counts)),
+            prefetch_batch(&in_flight, urls(&["c", "b", "d", "bad"]), counting_fetch(&counts)),
+        );
+
+        let counts = counts.lock().unwrap();
+        assert_eq!(counts.len(), 5);
+        assert!(counts.values().all(|&n| n == 1), "{:?}", counts);
+
+        assert_eq!(first.coalesced + second.coalesced, 3);
+        assert_eq!(first.success + second.success, 6);
+        assert_eq!((first.failed, second.failed), (1, 1));
+        assert!(in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_after_completion_fetches_again() {
+        let in_flight = Mutex::new(HashMap::new());
+        let counts = std::sync::Arc::new(Mutex::new(HashMap::new()));
+        let url = vec!["https://tiles.example/a".to_string()];
+
+        let first = prefetch_batch(&in_flight, url.clone(), counting_fetch(&counts)).await;
+        let second = prefetch_batch(&in_flight, url, counting_fetch(&counts)).await;
+        assert_eq!((first.coalesced, second.coalesced), (0, 0));
+        assert_eq!(counts.lock().unwrap()["https://tiles.example/a"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_abort_in_flight_fails_pending_prefetches() {
+        let in_flight = Mutex::new(HashMap::new());
+        let urls = vec!["https://tiles.example/a".to_string(), "https://tiles.example/b".to_string()];
+        let never_finishes = |_url: String| futures_util::future::pending::<bool>().boxed();
+
+        let batch = prefetch_batch(&in_flight, urls, never_finishes);
+        let abort = async {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            abort_in_flight(&in_flight)
+        };
+        let (result, aborted) = tokio::join!(batch, abort);
+
+        assert_eq!(aborted, 2);
+        assert_eq!((result.success, result.failed), (0, 2));
+        assert!(in_flight.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_prefetch_request_ids_are_unique_within_a_millisecond() {
+        let ids: std::collections::HashSet<String> = (0..100).map(|_| next_prefetch_request_id()).collect();
+        assert_eq!(ids.len(), 100);
     }
 
     #[test]
     fn test_prefetch_result_clone() {
-        let result = PrefetchResult { success: 5, failed: 3 };
+        let result = PrefetchResult { success: 5, failed: 3, coalesced: 1 };
         let cloned = result.clone();
         assert_eq!(cloned.success, result.success);
         assert_eq!(cloned.failed, result.failed);
@@ -856,6 +1224,7 @@ mod tests {
             etag: Some("W/\"abc123\"".to_string()),
             access_count: 0,
             last_access: 1000,
+            namespace: None,
         };
 
         let json = serde_json::to_string(&meta).unwrap();
@@ -879,6 +1248,7 @@ mod tests {
                     etag: None,
                     access_count: 0,
                     last_access: 0,
+                    namespace: None,
                 },
             );
         }
@@ -7,7 +7,7 @@ use std::fs;
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
 
-use super::storage::StorageError;
+use super::storage::{load_json_store, write_json_atomic, StorageError};
 use crate::utils::generate_id;
 
 // ============================================================================
@@ -100,6 +100,11 @@ pub struct Filter {
     pub name: String,
     pub filter_type: FilterType,
     pub bandwidth: Option<f64>, // nm for narrowband
+    // Transmission curve summary
+    #[serde(default)]
+    pub center_nm: Option<f64>,
+    #[serde(default)]
+    pub peak_transmission: Option<f64>, // 0-1
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -153,23 +158,14 @@ fn get_equipment_path(app: &AppHandle) -> Result<PathBuf, StorageError> {
 #[tauri::command]
 pub async fn load_equipment(app: AppHandle) -> Result<EquipmentData, StorageError> {
     let path = get_equipment_path(&app)?;
-
-    if !path.exists() {
-        return Ok(EquipmentData::default());
-    }
-
-    let data = fs::read_to_string(&path)?;
-    let equipment: EquipmentData = serde_json::from_str(&data)?;
-
-    Ok(equipment)
+    load_json_store(&app, &path, EquipmentData::default)
 }
 
 /// Save all equipment data
 #[tauri::command]
 pub async fn save_equipment(app: AppHandle, equipment: EquipmentData) -> Result<(), StorageError> {
     let path = get_equipment_path(&app)?;
-    let json = serde_json::to_string_pretty(&equipment)?;
-    fs::write(&path, json)?;
+    write_json_atomic(&path, &equipment)?;
 
     log::info!("Saved equipment data to {:?}", path);
     Ok(())
@@ -412,6 +408,8 @@ pub async fn update_filter(app: AppHandle, filter: Filter) -> Result<EquipmentDa
         existing.name = filter.name;
         existing.filter_type = filter.filter_type;
         existing.bandwidth = filter.bandwidth;
+        existing.center_nm = filter.center_nm;
+        existing.peak_transmission = filter.peak_transmission;
         existing.notes = filter.notes;
         existing.updated_at = Utc::now();
     }
@@ -420,6 +418,38 @@ pub async fn update_filter(app: AppHandle, filter: Filter) -> Result<EquipmentDa
     Ok(equipment)
 }
 
+/// Passband of a broadband luminance filter, the exposure planner's reference
+const BROADBAND_REFERENCE_NM: f64 = 300.0;
+
+/// Exposure-time multiplier for a filter relative to broadband
+#[tauri::command]
+pub async fn calculate_filter_effective_exposure_factor(
+    app: AppHandle,
+    filter_id: String,
+) -> Result<f64, StorageError> {
+    let equipment = load_equipment(app).await?;
+    let filter = equipment
+        .filters
+        .iter()
+        .find(|f| f.id == filter_id)
+        .ok_or_else(|| StorageError::Other(format!("Filter not found: {}", filter_id)))?;
+    Ok(filter_exposure_factor(filter))
+}
+
+/// Sky-limited exposure scales with the light a filter passes, so the factor is
+/// the broadband passband over the filter's effective width (bandwidth times peak
+/// transmission). Filters without a known bandwidth return a neutral 1.0.
+fn filter_exposure_factor(filter: &Filter) -> f64 {
+    let Some(bandwidth) = filter.bandwidth.filter(|b| *b > 0.0) else {
+        return 1.0;
+    };
+    let transmission = filter
+        .peak_transmission
+        .filter(|t| *t > 0.0)
+        .map_or(1.0, |t| t.min(1.0));
+    (BROADBAND_REFERENCE_NM / (bandwidth.min(BROADBAND_REFERENCE_NM) * transmission)).max(1.0)
+}
+
 /// Set default telescope
 #[tauri::command]
 pub async fn set_default_telescope(
@@ -737,6 +767,8 @@ mod tests {
             name: "Ha 7nm".to_string(),
             filter_type: FilterType::Ha,
             bandwidth: Some(7.0),
+            center_nm: None,
+            peak_transmission: None,
             notes: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -763,6 +795,61 @@ mod tests {
         let filter: Filter = serde_json::from_str(json).unwrap();
         assert_eq!(filter.bandwidth, Some(3.0));
         assert!(matches!(filter.filter_type, FilterType::Oiii));
+        assert!(filter.center_nm.is_none());
+        assert!(filter.peak_transmission.is_none());
+    }
+
+    #[test]
+    fn test_filter_ignores_stray_bandwidth_nm_key() {
+        let json = r#"{
+            "id": "f1",
+            "name": "Ha",
+            "filter_type": "ha",
+            "bandwidth": 3.0,
+            "bandwidth_nm": null,
+            "notes": null,
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }"#;
+
+        let filter: Filter = serde_json::from_str(json).unwrap();
+        assert_eq!(filter.bandwidth, Some(3.0));
+    }
+
+    fn spectral_filter(bandwidth: Option<f64>, peak_transmission: Option<f64>) -> Filter {
+        Filter {
+            id: "f".to_string(),
+            name: "Test".to_string(),
+            filter_type: FilterType::Ha,
+            bandwidth,
+            center_nm: Some(656.3),
+            peak_transmission,
+            notes: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_filter_exposure_factor_narrowband_much_higher() {
+        let ha_3nm = filter_exposure_factor(&spectral_filter(Some(3.0), Some(0.9)));
+        let wide_50nm = filter_exposure_factor(&spectral_filter(Some(50.0), Some(0.9)));
+        assert!(ha_3nm > wide_50nm * 10.0, "{} vs {}", ha_3nm, wide_50nm);
+        assert!((ha_3nm - 300.0 / (3.0 * 0.9)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_filter_exposure_factor_missing_bandwidth_is_neutral() {
+        assert_eq!(filter_exposure_factor(&spectral_filter(None, Some(0.9))), 1.0);
+        assert_eq!(filter_exposure_factor(&spectral_filter(Some(0.0), None)), 1.0);
+    }
+
+    #[test]
+    fn test_filter_exposure_factor_broadband_is_neutral() {
+        // Broadband filters never shorten exposure below the reference
+        let filter = spectral_filter(Some(400.0), None);
+        assert_eq!(filter_exposure_factor(&filter), 1.0);
+        assert!((filter_exposure_factor(&spectral_filter(Some(7.0), None)) - 300.0 / 7.0).abs() < 1e-9);
     }
 
     // ------------------------------------------------------------------------
@@ -810,6 +897,8 @@ mod tests {
             name: "Test Filter".to_string(),
             filter_type: FilterType::Luminance,
             bandwidth: None,
+            center_nm: None,
+            peak_transmission: None,
             notes: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -7,7 +7,8 @@ use std::fs;
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
 
-use super::storage::StorageError;
+use super::storage::{load_json_store, write_json_atomic, StorageError};
+use crate::astronomy::calculations::horizon::{normalize_horizon_profile, parse_horizon_file};
 use crate::utils::generate_id;
 
 /// Observation location/site
@@ -23,6 +24,9 @@ pub struct ObservationLocation {
     pub notes: Option<String>,
     pub is_default: bool,
     pub is_current: bool, // Currently active location
+    /// Local horizon as `(azimuth, altitude)` points in degrees
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub horizon_profile: Option<Vec<(f64, f64)>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -127,13 +131,7 @@ fn get_locations_path(app: &AppHandle) -> Result<PathBuf, StorageError> {
 #[tauri::command]
 pub async fn load_locations(app: AppHandle) -> Result<LocationsData, StorageError> {
     let path = get_locations_path(&app)?;
-
-    if !path.exists() {
-        return Ok(LocationsData::default());
-    }
-
-    let data = fs::read_to_string(&path)?;
-    let mut locations: LocationsData = serde_json::from_str(&data)?;
+    let mut locations = load_json_store(&app, &path, LocationsData::default)?;
 
     if normalize_locations(&mut locations, None, None) {
         save_locations(app, locations.clone()).await?;
@@ -146,8 +144,7 @@ pub async fn load_locations(app: AppHandle) -> Result<LocationsData, StorageErro
 #[tauri::command]
 pub async fn save_locations(app: AppHandle, locations: LocationsData) -> Result<(), StorageError> {
     let path = get_locations_path(&app)?;
-    let json = serde_json::to_string_pretty(&locations)?;
-    fs::write(&path, json)?;
+    write_json_atomic(&path, &locations)?;
 
     log::info!("Saved locations data to {:?}", path);
     Ok(())
@@ -297,6 +294,57 @@ pub async fn get_current_location(
     Ok(data.locations.into_iter().find(|l| l.is_default))
 }
 
+/// Set the horizon profile of a location (the current one when no id is
+/// given); an empty list clears it
+#[tauri::command]
+pub async fn set_horizon_profile(
+    app: AppHandle,
+    points: Vec<(f64, f64)>,
+    location_id: Option<String>,
+) -> Result<LocationsData, StorageError> {
+    let mut data = load_locations(app.clone()).await?;
+    apply_horizon_profile(&mut data, location_id.as_deref(), points)?;
+    save_locations(app, data.clone()).await?;
+
+    Ok(data)
+}
+
+/// Import a Horizon (.hrz) or CSV file as a location's horizon profile
+#[tauri::command]
+pub async fn import_horizon_profile(
+    app: AppHandle,
+    path: String,
+    location_id: Option<String>,
+) -> Result<LocationsData, StorageError> {
+    let content = fs::read_to_string(&path)?;
+    crate::network::security::validate_size(&content, crate::network::security::limits::MAX_CSV_SIZE)
+        .map_err(|e| StorageError::Other(e.to_string()))?;
+    let points = parse_horizon_file(&content).map_err(StorageError::Other)?;
+
+    set_horizon_profile(app, points, location_id).await
+}
+
+fn apply_horizon_profile(
+    data: &mut LocationsData,
+    location_id: Option<&str>,
+    points: Vec<(f64, f64)>,
+) -> Result<(), StorageError> {
+    let profile = normalize_horizon_profile(points).map_err(StorageError::Other)?;
+    let index = match location_id {
+        Some(id) => pick_index_by_id(&data.locations, Some(id))
+            .ok_or_else(|| StorageError::Other(format!("Location not found: {}", id)))?,
+        None if data.locations.is_empty() => {
+            return Err(StorageError::Other("No location to attach the horizon to".to_string()));
+        }
+        None => pick_current_index(data, None),
+    };
+
+    let location = &mut data.locations[index];
+    location.horizon_profile = (!profile.is_empty()).then_some(profile);
+    location.updated_at = Utc::now();
+    Ok(())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -317,6 +365,7 @@ mod tests {
             notes: None,
             is_default,
             is_current,
+            horizon_profile: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -339,6 +388,7 @@ mod tests {
             notes: Some("Great for deep sky".to_string()),
             is_default: true,
             is_current: true,
+            horizon_profile: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -388,6 +438,7 @@ mod tests {
             notes: None,
             is_default: false,
             is_current: false,
+            horizon_profile: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -445,6 +496,7 @@ mod tests {
             notes: None,
             is_default: true,
             is_current: true,
+            horizon_profile: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         });
@@ -510,6 +562,7 @@ mod tests {
             notes: None,
             is_default: false,
             is_current: false,
+            horizon_profile: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -532,6 +585,7 @@ mod tests {
             notes: None,
             is_default: false,
             is_current: false,
+            horizon_profile: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -560,6 +614,7 @@ mod tests {
                 notes: None,
                 is_default: false,
                 is_current: false,
+                horizon_profile: None,
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
             };
@@ -629,4 +684,49 @@ mod tests {
         assert!(data.locations[0].is_default);
         assert_eq!(data.current_location_id, Some("l2".to_string()));
     }
+
+    // ------------------------------------------------------------------------
+    // Horizon Profile Tests
+    // ------------------------------------------------------------------------
+
+    fn two_locations() -> LocationsData {
+        LocationsData {
+            locations: vec![
+                test_location("l1", "One", true, false),
+                test_location("l2", "Two", false, true),
+            ],
+            current_location_id: Some("l2".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_horizon_profile_defaults_to_current_location() {
+        let mut data = two_locations();
+        apply_horizon_profile(&mut data, None, vec![(90.0, 20.0), (-90.0, 5.0)]).unwrap();
+
+        assert!(data.locations[0].horizon_profile.is_none());
+        assert_eq!(data.locations[1].horizon_profile, Some(vec![(90.0, 20.0), (270.0, 5.0)]));
+    }
+
+    #[test]
+    fn test_horizon_profile_by_id_and_clearing() {
+        let mut data = two_locations();
+        apply_horizon_profile(&mut data, Some("l1"), vec![(0.0, 10.0)]).unwrap();
+        assert!(data.locations[0].horizon_profile.is_some());
+
+        apply_horizon_profile(&mut data, Some("l1"), Vec::new()).unwrap();
+        assert!(data.locations[0].horizon_profile.is_none());
+
+        assert!(apply_horizon_profile(&mut data, Some("missing"), vec![(0.0, 10.0)]).is_err());
+        assert!(apply_horizon_profile(&mut data, None, vec![(0.0, 120.0)]).is_err());
+    }
+
+    #[test]
+    fn test_location_without_horizon_profile_deserializes() {
+        let mut json = serde_json::to_value(test_location("l1", "One", true, true)).unwrap();
+        assert!(json.get("horizon_profile").is_none());
+        json["horizon_profile"] = serde_json::json!([[0.0, 10.0], [180.0, 20.0]]);
+        let location: ObservationLocation = serde_json::from_value(json).unwrap();
+        assert_eq!(location.horizon_profile, Some(vec![(0.0, 10.0), (180.0, 20.0)]));
+    }
 }
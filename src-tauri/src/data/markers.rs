@@ -7,9 +7,13 @@ use std::fs;
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
 
-use super::storage::StorageError;
+use super::storage::{load_json_store, write_json_atomic, StorageError};
+use crate::astronomy::{filter_catalog, format_dec_dms, format_ra_hms, CatalogFilter, CatalogObject};
 use crate::utils::generate_id;
 
+/// Color given to markers created from the catalog
+const CATALOG_MARKER_COLOR: &str = "#ef4444";
+
 /// Marker icon type
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -94,22 +98,18 @@ fn get_markers_path(app: &AppHandle) -> Result<PathBuf, StorageError> {
 #[tauri::command]
 pub async fn load_markers(app: AppHandle) -> Result<MarkersData, StorageError> {
     let path = get_markers_path(&app)?;
-    if !path.exists() {
-        return Ok(MarkersData {
-            markers: Vec::new(),
-            groups: vec!["Default".to_string()],
-            show_markers: true,
-            show_markers_updated_at: 0,
-        });
-    }
-    let data = fs::read_to_string(&path)?;
-    Ok(serde_json::from_str(&data)?)
+    load_json_store(&app, &path, || MarkersData {
+        markers: Vec::new(),
+        groups: vec!["Default".to_string()],
+        show_markers: true,
+        show_markers_updated_at: 0,
+    })
 }
 
 #[tauri::command]
 pub async fn save_markers(app: AppHandle, markers_data: MarkersData) -> Result<(), StorageError> {
     let path = get_markers_path(&app)?;
-    fs::write(&path, serde_json::to_string_pretty(&markers_data)?)?;
+    write_json_atomic(&path, &markers_data)?;
     log::info!("Saved markers to {:?}", path);
     Ok(())
 }
@@ -242,6 +242,53 @@ pub async fn rename_marker_group(app: AppHandle, old_name: String, new_name: Str
     Ok(data)
 }
 
+/// Add a marker per catalog object to `group`, skipping objects whose
+/// designation is already a marker name in that group; returns how many
+/// were added
+fn add_catalog_markers(data: &mut MarkersData, objects: &[CatalogObject], group: &str, now: i64) -> usize {
+    let mut added = 0;
+    for object in objects {
+        let exists = data.markers.iter().any(|m| {
+            m.group.as_deref() == Some(group) && m.name.eq_ignore_ascii_case(&object.designation)
+        });
+        if exists {
+            continue;
+        }
+
+        data.markers.push(SkyMarker {
+            id: generate_id("marker"),
+            name: object.designation.clone(),
+            description: (object.name != object.designation).then(|| object.name.clone()),
+            ra: object.ra,
+            dec: object.dec,
+            ra_string: format_ra_hms(object.ra),
+            dec_string: format_dec_dms(object.dec),
+            color: CATALOG_MARKER_COLOR.to_string(),
+            icon: MarkerIcon::Circle,
+            created_at: now,
+            updated_at: now,
+            group: Some(group.to_string()),
+            visible: true,
+        });
+        added += 1;
+    }
+    if !data.groups.iter().any(|g| g == group) {
+        data.groups.push(group.to_string());
+    }
+    added
+}
+
+/// Create markers for every bundled catalog object matching `filter`
+#[tauri::command]
+pub async fn add_markers_from_catalog(app: AppHandle, filter: CatalogFilter, group: String) -> Result<MarkersData, StorageError> {
+    let mut data = load_markers(app.clone()).await?;
+    let objects = filter_catalog(&filter);
+    let added = add_catalog_markers(&mut data, &objects, &group, Utc::now().timestamp_millis());
+    log::info!("Added {} of {} catalog markers to group {}", added, objects.len(), group);
+    save_markers(app, data.clone()).await?;
+    Ok(data)
+}
+
 #[tauri::command]
 pub async fn get_visible_markers(app: AppHandle) -> Result<Vec<SkyMarker>, StorageError> {
     let data = load_markers(app).await?;
@@ -560,4 +607,40 @@ mod tests {
         assert_eq!(data.markers.len(), 5);
         assert!(data.markers.iter().all(|m| m.group == Some("Messier".to_string())));
     }
+
+    // ------------------------------------------------------------------------
+    // Catalog Marker Tests
+    // ------------------------------------------------------------------------
+
+    fn messier_filter() -> CatalogFilter {
+        CatalogFilter { catalog: Some("Messier".to_string()), ..Default::default() }
+    }
+
+    #[test]
+    fn test_catalog_markers_for_messier() {
+        let mut data = MarkersData::default();
+        let added = add_catalog_markers(&mut data, &filter_catalog(&messier_filter()), "Messier", 1000);
+
+        assert_eq!(added, 110);
+        assert_eq!(data.markers.len(), 110);
+        assert!(data.groups.contains(&"Messier".to_string()));
+        let m42 = data.markers.iter().find(|m| m.name == "M42").unwrap();
+        assert_eq!(m42.description.as_deref(), Some("Orion Nebula"));
+        assert_eq!(m42.group.as_deref(), Some("Messier"));
+        assert!(m42.visible);
+    }
+
+    #[test]
+    fn test_catalog_markers_skip_duplicates_in_group() {
+        let mut data = MarkersData::default();
+        let objects = filter_catalog(&messier_filter());
+        add_catalog_markers(&mut data, &objects[..10], "Messier", 1000);
+
+        // Only the 100 missing ones are added to the group...
+        assert_eq!(add_catalog_markers(&mut data, &objects, "Messier", 2000), 100);
+        // ...while another group gets its own copies
+        assert_eq!(add_catalog_markers(&mut data, &objects[..10], "Tonight", 3000), 10);
+        assert_eq!(data.markers.len(), 120);
+        assert_eq!(data.groups.iter().filter(|g| *g == "Messier").count(), 1);
+    }
 }
@@ -6,7 +6,7 @@
 //! - `equipment`: Telescope, camera, eyepiece, and filter configurations
 //! - `locations`: Observation site management
 //! - `targets`: Target list management for observation planning
-//! - `target_io`: Target import/export (CSV, JSON, Stellarium formats)
+//! - `target_io`: Target import/export (CSV, JSON, Stellarium, KStars formats)
 //! - `session_io`: Session planner import/export and templates
 //! - `markers`: Sky marker annotations
 //! - `observation_log`: Observation session logging
@@ -20,13 +20,17 @@ pub mod session_io;
 pub mod markers;
 pub mod observation_log;
 
-// Re-export storage error type
-pub use storage::StorageError;
+// Re-export storage error type and atomic write helpers
+pub use storage::{
+    append_download_history, write_atomic, write_json_atomic, DownloadEntry, StorageError,
+    StoreRecovery, STORE_RECOVERED_EVENT,
+};
 
 // Re-export storage commands
 pub use storage::{
-    clear_all_data, delete_store_data, export_all_data, get_data_directory, get_storage_stats,
-    import_all_data, list_stores, load_store_data, save_store_data,
+    clear_all_data, clear_download_history, delete_store_data, export_all_data, get_data_directory,
+    get_download_history, get_storage_stats, import_all_data, list_stores, load_store_data,
+    record_download, save_store_data,
 };
 
 // Re-export equipment types and commands
@@ -35,7 +39,8 @@ pub use equipment::{
     BarlowReducer, Camera, CameraType, EquipmentData, Eyepiece, Filter, FilterType,
     Telescope, TelescopeType,
     // Commands
-    add_barlow_reducer, add_camera, add_eyepiece, add_filter, add_telescope, delete_equipment,
+    add_barlow_reducer, add_camera, add_eyepiece, add_filter, add_telescope,
+    calculate_filter_effective_exposure_factor, delete_equipment,
     get_default_camera, get_default_telescope, load_equipment, save_equipment, set_default_camera,
     set_default_telescope, update_barlow_reducer, update_camera, update_eyepiece, update_filter,
     update_telescope,
@@ -47,24 +52,31 @@ pub use locations::{
     LocationsData, ObservationLocation,
     // Commands
     add_location, delete_location, get_current_location, load_locations, save_locations,
-    set_current_location, set_default_location, update_location,
+    import_horizon_profile, set_current_location, set_default_location, set_horizon_profile, update_location,
 };
 
 // Re-export target list types and commands
 pub use targets::{
     // Types
-    BatchTargetInput, ExposurePlan, MosaicSettings, ObservableWindow, TargetInput, TargetItem,
-    TargetListData, TargetPriority, TargetStats, TargetStatus,
+    BatchTargetInput, ExposurePlan, ExposurePlanAdvanced, ExposurePlanInputs, MosaicSettings, ObservableWindow, ScheduledTarget, TargetInput, TargetItem,
+    TargetListData, TargetListWithVisibility, TargetPriority, TargetStats, TargetStatus,
+    WindowRecomputeProgress,
     // Commands
-    add_tag_to_targets, add_target, add_targets_batch, archive_completed_targets,
-    clear_all_targets, clear_completed_targets, get_target_stats, load_target_list,
-    remove_tag_from_targets, remove_target, remove_targets_batch, save_target_list, search_targets,
-    set_active_target, set_targets_priority_batch, set_targets_status_batch, toggle_target_archive,
+    add_tag_to_targets, add_target, add_target_from_catalog, add_targets_batch, archive_completed_targets,
+    calculate_exposure_plan_advanced, clear_all_targets, clear_completed_targets, delete_tag,
+    generate_observing_schedule, get_target_stats, load_target_list, load_target_list_with_visibility,
+    recompute_all_observable_windows, remove_tag_from_targets, remove_target, remove_targets_batch, rename_tag,
+    save_target_list, search_targets,
+    record_target_progress, set_active_target, set_targets_priority_batch, set_targets_status_batch, toggle_target_archive,
     toggle_target_favorite, update_target,
 };
 
 // Re-export target I/O
-pub use target_io::{export_targets, import_targets};
+pub use target_io::{
+    export_targets, export_targets_skysafari, import_targets, import_targets_kstars,
+    import_targets_stellarium,
+    KStarsImportResult,
+};
 
 // Re-export session planner I/O
 pub use session_io::{
@@ -76,7 +88,7 @@ pub use markers::{
     // Types
     MarkerIcon, MarkerInput, MarkerUpdateInput, MarkersData, SkyMarker,
     // Commands
-    add_marker, add_marker_group, clear_all_markers, get_visible_markers, load_markers,
+    add_marker, add_marker_group, add_markers_from_catalog, clear_all_markers, get_visible_markers, load_markers,
     remove_marker, remove_marker_group, remove_markers_by_group, rename_marker_group, save_markers,
     set_all_markers_visible, set_show_markers, toggle_marker_visibility, update_marker,
 };
@@ -84,10 +96,13 @@ pub use markers::{
 // Re-export observation log types and commands
 pub use observation_log::{
     // Types
-    CreatePlannedSessionPayload, ExecutionSummary, ExecutionTarget, Observation, ObservationLogData,
-    ObservationQueryFilters, ObservationSearchHit, ObservationSession, ObservationStats, WeatherConditions,
+    ActiveSessionInfo, CreatePlannedSessionPayload, ExecutionSummary, ExecutionTarget, FilterIntegration, FitsImportResult,
+    Observation, ObservationLogData, ObservationQueryFilters, ObservationSearchHit, ObservationSession,
+    ObservationStats, PeriodStats, SessionReport, SessionSummary, SkySnapshot, WeatherConditions,
     // Commands
     add_observation, create_planned_session, create_session, delete_observation, delete_session, end_session,
-    get_observation_stats, load_observation_log, save_observation_log, search_observations,
-    export_observation_log, update_observation, update_session,
+    generate_session_report, get_active_session, get_observation_stats, get_observation_stats_by_period, get_session_summary, load_observation_log, save_observation_log,
+    search_observations, export_observation_log, update_observation, update_session,
 };
+#[cfg(desktop)]
+pub use observation_log::import_observations_from_fits;
@@ -3,13 +3,29 @@
 
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
+#[cfg(desktop)]
+use std::io::Read;
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
 
-use super::storage::StorageError;
+use super::storage::{load_json_store, write_json_atomic, StorageError};
+use super::equipment::{load_equipment, EquipmentData};
+use super::locations::load_locations;
+use crate::astronomy::calculations::twilight::night_twilight;
+use crate::astronomy::{calculate_moon_phase, MoonPhase};
+#[cfg(desktop)]
+use crate::platform::plate_solver::fits::{
+    fits_hints_from_header, parse_f64_header_value, parse_fits_header_map_from_bytes,
+    parse_string_header_value,
+};
 use crate::utils::generate_id;
 
+/// Upper bound on header bytes read from each FITS file (100 blocks)
+#[cfg(desktop)]
+const MAX_FITS_HEADER_BYTES: u64 = 2880 * 100;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionTarget {
     pub id: String,
@@ -60,10 +76,26 @@ pub struct ObservationSession {
     pub execution_targets: Option<Vec<ExecutionTarget>>,
     pub weather_snapshot: Option<serde_json::Value>,
     pub execution_summary: Option<ExecutionSummary>,
+    /// Moon and darkness for the night, recorded when the session is created
+    #[serde(default)]
+    pub sky_snapshot: Option<SkySnapshot>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Moon phase and astronomical darkness window of a session's night
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkySnapshot {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Moon phase at local solar midnight
+    pub moon: MoonPhase,
+    /// Astronomical dusk (Unix seconds); `None` when it never gets fully dark
+    pub darkness_start: Option<i64>,
+    /// Astronomical dawn (Unix seconds)
+    pub darkness_end: Option<i64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherConditions {
     pub temperature: Option<f64>,
@@ -94,6 +126,12 @@ pub struct Observation {
     pub sketch_path: Option<String>,
     pub image_paths: Vec<String>,
     pub execution_target_id: Option<String>,
+    /// Length of a single sub-exposure in seconds
+    #[serde(default)]
+    pub exposure_seconds: Option<f64>,
+    /// Number of sub-exposures captured (defaults to 1 when an exposure is set)
+    #[serde(default)]
+    pub frame_count: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -164,6 +202,71 @@ pub struct ObservationLogData {
     pub sessions: Vec<ObservationSession>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterIntegration {
+    pub filter_id: Option<String>,
+    pub integration_seconds: f64,
+    pub frame_count: u32,
+    pub average_sub_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub observation_count: usize,
+    pub total_integration_seconds: f64,
+    pub total_frames: u32,
+    pub average_sub_seconds: f64,
+    pub filters: Vec<FilterIntegration>,
+}
+
+/// Activity in one month (`YYYY-MM`) or year (`YYYY`) of the log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodStats {
+    pub period: String,
+    pub session_count: usize,
+    pub integration_hours: f64,
+    pub distinct_targets: usize,
+}
+
+/// The session currently being observed, with its running time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveSessionInfo {
+    pub session: ObservationSession,
+    pub elapsed_seconds: i64,
+    pub observation_count: usize,
+}
+
+/// Result of importing observations from FITS headers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitsImportResult {
+    pub session: ObservationSession,
+    pub imported_frames: usize,
+    pub observations_added: usize,
+    /// Files that were not imported, with the reason
+    pub skipped: Vec<String>,
+}
+
+/// Night-session report with a Markdown rendering for sharing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionReport {
+    pub session_id: String,
+    pub date: NaiveDate,
+    pub location_name: Option<String>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub weather: Option<WeatherConditions>,
+    pub seeing: Option<u8>,
+    pub transparency: Option<u8>,
+    pub bortle_class: Option<u8>,
+    pub equipment_ids: Vec<String>,
+    pub targets: Vec<String>,
+    pub summary: SessionSummary,
+    pub moon_illumination: f64,
+    pub moon_phase_name: String,
+    pub markdown: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ObservationStats {
     pub total_sessions: usize,
@@ -412,6 +515,318 @@ fn build_filtered_log_data(
     ObservationLogData { sessions }
 }
 
+fn summarize_session(session: &ObservationSession) -> SessionSummary {
+    let mut by_filter: Vec<FilterIntegration> = Vec::new();
+
+    for obs in &session.observations {
+        let Some(exposure) = obs.exposure_seconds.filter(|value| *value > 0.0) else {
+            continue;
+        };
+        let frames = obs.frame_count.unwrap_or(1);
+        let entry = match by_filter.iter_mut().find(|f| f.filter_id == obs.filter_id) {
+            Some(entry) => entry,
+            None => {
+                by_filter.push(FilterIntegration {
+                    filter_id: obs.filter_id.clone(),
+                    integration_seconds: 0.0,
+                    frame_count: 0,
+                    average_sub_seconds: 0.0,
+                });
+                by_filter.last_mut().unwrap()
+            }
+        };
+        entry.integration_seconds += exposure * frames as f64;
+        entry.frame_count += frames;
+    }
+
+    for entry in &mut by_filter {
+        if entry.frame_count > 0 {
+            entry.average_sub_seconds = entry.integration_seconds / entry.frame_count as f64;
+        }
+    }
+    by_filter.sort_by(|a, b| b.integration_seconds.total_cmp(&a.integration_seconds));
+
+    let total_integration_seconds: f64 = by_filter.iter().map(|f| f.integration_seconds).sum();
+    let total_frames: u32 = by_filter.iter().map(|f| f.frame_count).sum();
+    let average_sub_seconds = if total_frames > 0 {
+        total_integration_seconds / total_frames as f64
+    } else {
+        0.0
+    };
+
+    SessionSummary {
+        session_id: session.id.clone(),
+        observation_count: session.observations.len(),
+        total_integration_seconds,
+        total_frames,
+        average_sub_seconds,
+        filters: by_filter,
+    }
+}
+
+/// Format a duration in seconds as e.g. "2h 05m 30s"
+fn format_integration(seconds: f64) -> String {
+    let total = seconds.max(0.0).round() as u64;
+    format!("{}h {:02}m {:02}s", total / 3600, (total % 3600) / 60, total % 60)
+}
+
+fn build_session_report(session: &ObservationSession, equipment: &EquipmentData) -> SessionReport {
+    let mut targets: Vec<String> = Vec::new();
+    for obs in &session.observations {
+        if !targets.contains(&obs.object_name) {
+            targets.push(obs.object_name.clone());
+        }
+    }
+
+    // Moon phase at session start, or at the midnight that follows the session date
+    let moon_timestamp = session
+        .start_time
+        .map(|t| t.timestamp())
+        .unwrap_or_else(|| {
+            let next_day = session.date.succ_opt().unwrap_or(session.date);
+            next_day.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp()
+        });
+    let moon = calculate_moon_phase(Some(moon_timestamp));
+
+    let mut report = SessionReport {
+        session_id: session.id.clone(),
+        date: session.date,
+        location_name: session.location_name.clone(),
+        start_time: session.start_time,
+        end_time: session.end_time,
+        weather: session.weather.clone(),
+        seeing: session.seeing,
+        transparency: session.transparency,
+        bortle_class: session.bortle_class,
+        equipment_ids: session.equipment_ids.clone(),
+        targets,
+        summary: summarize_session(session),
+        moon_illumination: moon.illumination,
+        moon_phase_name: moon.phase_name,
+        markdown: String::new(),
+    };
+    report.markdown = render_session_markdown(&report, equipment);
+    report
+}
+
+/// Name of the telescope, camera, eyepiece, barlow/reducer or filter with
+/// `id`, falling back to the ID itself for unknown equipment
+fn equipment_name<'a>(equipment: &'a EquipmentData, id: &'a str) -> &'a str {
+    equipment.telescopes.iter().find(|t| t.id == id).map(|t| t.name.as_str())
+        .or_else(|| equipment.cameras.iter().find(|c| c.id == id).map(|c| c.name.as_str()))
+        .or_else(|| equipment.eyepieces.iter().find(|e| e.id == id).map(|e| e.name.as_str()))
+        .or_else(|| equipment.barlow_reducers.iter().find(|b| b.id == id).map(|b| b.name.as_str()))
+        .or_else(|| equipment.filters.iter().find(|f| f.id == id).map(|f| f.name.as_str()))
+        .unwrap_or(id)
+}
+
+fn render_session_markdown(report: &SessionReport, equipment: &EquipmentData) -> String {
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    let mut md = format!("# Observation Session {}\n\n", report.date);
+
+    md.push_str(&format!("- **Location:** {}\n", or_dash(report.location_name.clone())));
+    if let (Some(start), Some(end)) = (report.start_time, report.end_time) {
+        md.push_str(&format!("- **Time:** {} - {} UTC\n", start.format("%H:%M"), end.format("%H:%M")));
+    }
+    md.push_str(&format!(
+        "- **Moon:** {} ({:.0}% illuminated)\n",
+        report.moon_phase_name, report.moon_illumination
+    ));
+    md.push_str(&format!("- **Seeing:** {}\n", or_dash(report.seeing.map(|v| format!("{}/5", v)))));
+    md.push_str(&format!("- **Transparency:** {}\n", or_dash(report.transparency.map(|v| format!("{}/5", v)))));
+    md.push_str(&format!("- **Bortle:** {}\n", or_dash(report.bortle_class.map(|v| v.to_string()))));
+
+    if let Some(weather) = &report.weather {
+        md.push_str("\n## Weather\n\n");
+        md.push_str(&format!("- Temperature: {}\n", or_dash(weather.temperature.map(|v| format!("{:.1} °C", v)))));
+        md.push_str(&format!("- Humidity: {}\n", or_dash(weather.humidity.map(|v| format!("{:.0}%", v)))));
+        md.push_str(&format!("- Wind: {}\n", or_dash(weather.wind_speed.map(|v| format!("{:.1} km/h", v)))));
+        md.push_str(&format!("- Cloud cover: {}\n", or_dash(weather.cloud_cover.map(|v| format!("{}%", v)))));
+    }
+
+    if !report.equipment_ids.is_empty() {
+        md.push_str("\n## Equipment\n\n");
+        for id in &report.equipment_ids {
+            md.push_str(&format!("- {}\n", equipment_name(equipment, id)));
+        }
+    }
+
+    md.push_str("\n## Targets\n\n");
+    if report.targets.is_empty() {
+        md.push_str("No targets observed.\n");
+    }
+    for target in &report.targets {
+        md.push_str(&format!("- {}\n", target));
+    }
+
+    let summary = &report.summary;
+    md.push_str("\n## Integration\n\n");
+    md.push_str(&format!(
+        "Total integration: {} ({} frames)\n",
+        format_integration(summary.total_integration_seconds),
+        summary.total_frames
+    ));
+    if !summary.filters.is_empty() {
+        md.push_str("\n| Filter | Frames | Integration |\n|--------|--------|-------------|\n");
+        for filter in &summary.filters {
+            md.push_str(&format!(
+                "| {} | {} | {} |\n",
+                filter.filter_id.as_deref().map_or("none", |id| equipment_name(equipment, id)),
+                filter.frame_count,
+                format_integration(filter.integration_seconds)
+            ));
+        }
+    }
+
+    md
+}
+
+/// Frames of one object through one filter, accumulated during a FITS import
+#[cfg(desktop)]
+struct FitsFrameGroup {
+    object: String,
+    filter: Option<String>,
+    first_frame: DateTime<Utc>,
+    total_exposure: f64,
+    frames: u32,
+    gains: Vec<String>,
+    temperatures: Vec<f64>,
+    ra: Option<f64>,
+    dec: Option<f64>,
+    paths: Vec<String>,
+}
+
+/// Parse a FITS `DATE-OBS` value (UTC, with or without time and fraction)
+#[cfg(desktop)]
+fn parse_fits_date_obs(value: &str) -> Option<DateTime<Utc>> {
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S"]
+        .iter()
+        .find_map(|fmt| chrono::NaiveDateTime::parse_from_str(value, fmt).ok())
+        .or_else(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+/// Group FITS headers into one observation per object/filter
+/// `DATE-OBS`, `EXPTIME` and `OBJECT` are required; `FILTER`, `GAIN` and
+/// `CCD-TEMP` are optional and summarized in the observation notes
+#[cfg(desktop)]
+fn observations_from_fits_headers(
+    headers: Vec<(String, HashMap<String, String>)>,
+) -> (Vec<Observation>, usize, Vec<String>) {
+    let mut groups: Vec<FitsFrameGroup> = Vec::new();
+    let mut skipped = Vec::new();
+    let mut imported = 0;
+
+    for (path, header) in headers {
+        let date = parse_string_header_value(&header, "DATE-OBS").and_then(|d| parse_fits_date_obs(&d));
+        let exposure = parse_f64_header_value(&header, "EXPTIME").filter(|e| *e >= 0.0);
+        let object = parse_string_header_value(&header, "OBJECT");
+        let object_missing = object.is_none();
+        let (Some(date), Some(exposure), Some(object)) = (date, exposure, object) else {
+            let missing: Vec<&str> = [
+                ("DATE-OBS", date.is_none()),
+                ("EXPTIME", exposure.is_none()),
+                ("OBJECT", object_missing),
+            ]
+            .iter()
+            .filter(|(_, missing)| *missing)
+            .map(|(card, _)| *card)
+            .collect();
+            skipped.push(format!("{}: missing or invalid {}", path, missing.join(", ")));
+            continue;
+        };
+        let filter = parse_string_header_value(&header, "FILTER");
+
+        let index = match groups.iter().position(|g| g.object == object && g.filter == filter) {
+            Some(index) => index,
+            None => {
+                let hints = fits_hints_from_header(&header);
+                groups.push(FitsFrameGroup {
+                    object,
+                    filter,
+                    first_frame: date,
+                    total_exposure: 0.0,
+                    frames: 0,
+                    gains: Vec::new(),
+                    temperatures: Vec::new(),
+                    ra: hints.ra,
+                    dec: hints.dec,
+                    paths: Vec::new(),
+                });
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[index];
+        group.first_frame = group.first_frame.min(date);
+        group.total_exposure += exposure;
+        group.frames += 1;
+        if let Some(gain) = parse_string_header_value(&header, "GAIN") {
+            if !group.gains.contains(&gain) {
+                group.gains.push(gain);
+            }
+        }
+        if let Some(temperature) = parse_f64_header_value(&header, "CCD-TEMP") {
+            group.temperatures.push(temperature);
+        }
+        group.paths.push(path);
+        imported += 1;
+    }
+
+    let observations = groups
+        .into_iter()
+        .map(|g| {
+            let mut notes = vec!["Imported from FITS headers".to_string()];
+            if let Some(filter) = &g.filter {
+                notes.push(format!("Filter: {}", filter));
+            }
+            if !g.gains.is_empty() {
+                notes.push(format!("Gain: {}", g.gains.join("/")));
+            }
+            if !g.temperatures.is_empty() {
+                let mean = g.temperatures.iter().sum::<f64>() / g.temperatures.len() as f64;
+                notes.push(format!("Sensor temp: {:.1} °C", mean));
+            }
+            Observation {
+                id: generate_id("obs"),
+                object_name: g.object,
+                object_type: None,
+                ra: g.ra,
+                dec: g.dec,
+                constellation: None,
+                observed_at: g.first_frame,
+                telescope_id: None,
+                eyepiece_id: None,
+                camera_id: None,
+                filter_id: None,
+                magnification: None,
+                rating: None,
+                difficulty: None,
+                notes: Some(notes.join("; ")),
+                sketch_path: None,
+                image_paths: g.paths,
+                execution_target_id: None,
+                exposure_seconds: Some(g.total_exposure / g.frames as f64),
+                frame_count: Some(g.frames),
+            }
+        })
+        .collect();
+
+    (observations, imported, skipped)
+}
+
+/// Read the primary header of a FITS file
+#[cfg(desktop)]
+fn read_fits_header(path: &str) -> Result<HashMap<String, String>, String> {
+    let mut data = Vec::new();
+    fs::File::open(path)
+        .and_then(|file| file.take(MAX_FITS_HEADER_BYTES).read_to_end(&mut data))
+        .map_err(|e| format!("{}: {}", path, e))?;
+    if !data.starts_with(b"SIMPLE") {
+        return Err(format!("{}: not a FITS file", path));
+    }
+    Ok(parse_fits_header_map_from_bytes(&data))
+}
+
 fn escape_csv_value(value: &str) -> String {
     if value.contains(',') || value.contains('"') || value.contains('\n') || value.contains('\r') {
         format!("\"{}\"", value.replace('"', "\"\""))
@@ -465,15 +880,13 @@ fn export_observation_log_csv(
 #[tauri::command]
 pub async fn load_observation_log(app: AppHandle) -> Result<ObservationLogData, StorageError> {
     let path = get_log_path(&app)?;
-    if !path.exists() { return Ok(ObservationLogData::default()); }
-    let data = fs::read_to_string(&path)?;
-    Ok(serde_json::from_str(&data)?)
+    load_json_store(&app, &path, ObservationLogData::default)
 }
 
 #[tauri::command]
 pub async fn save_observation_log(app: AppHandle, log: ObservationLogData) -> Result<(), StorageError> {
     let path = get_log_path(&app)?;
-    fs::write(&path, serde_json::to_string_pretty(&log)?)?;
+    write_json_atomic(&path, &log)?;
     log::info!("Saved observation log to {:?}", path);
     Ok(())
 }
@@ -482,20 +895,118 @@ pub async fn save_observation_log(app: AppHandle, log: ObservationLogData) -> Re
 pub async fn create_session(app: AppHandle, date: String, location_id: Option<String>, location_name: Option<String>) -> Result<ObservationSession, StorageError> {
     let mut log = load_observation_log(app.clone()).await?;
     let date = parse_session_date(&date)?;
+    let now = Utc::now();
+
+    // Best effort: a missing location only leaves the snapshot empty
+    let sky_snapshot = match &location_id {
+        Some(id) => match load_locations(app.clone()).await {
+            Ok(locations) => locations
+                .locations
+                .iter()
+                .find(|loc| &loc.id == id)
+                .map(|loc| sky_snapshot_for(date, loc.latitude, loc.longitude)),
+            Err(e) => {
+                log::warn!("Could not load locations for session sky snapshot: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
 
     let session = ObservationSession {
         id: generate_id("session"), date, location_id, location_name,
-        start_time: Some(Utc::now()), end_time: None, weather: None,
+        start_time: Some(now), end_time: None, weather: None,
         seeing: None, transparency: None, equipment_ids: Vec::new(),
         bortle_class: None, notes: None, observations: Vec::new(),
         source_plan_id: None, source_plan_name: None, execution_status: None, execution_targets: None,
-        weather_snapshot: None, execution_summary: None, created_at: Utc::now(), updated_at: Utc::now(),
+        weather_snapshot: None, execution_summary: None, sky_snapshot, created_at: now, updated_at: now,
     };
-    log.sessions.push(session.clone());
+    open_session(&mut log, session.clone(), now);
     save_observation_log(app, log).await?;
     Ok(session)
 }
 
+/// Moon and darkness for the night beginning on the evening of `date` at a
+/// site, with the Moon taken at local solar midnight
+fn sky_snapshot_for(date: NaiveDate, latitude: f64, longitude: f64) -> SkySnapshot {
+    let next_day = date.succ_opt().unwrap_or(date);
+    let midnight = next_day.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+    let local_midnight = midnight - (longitude * 240.0) as i64;
+    let (darkness_start, darkness_end) = night_twilight(latitude, longitude, local_midnight)
+        .map_or((None, None), |(evening, morning)| (evening.astronomical_dusk, morning.astronomical_dawn));
+
+    SkySnapshot {
+        latitude,
+        longitude,
+        moon: calculate_moon_phase(Some(local_midnight)),
+        darkness_start,
+        darkness_end,
+    }
+}
+
+/// A session is open while it has started and not yet ended
+fn is_session_open(session: &ObservationSession) -> bool {
+    session.start_time.is_some() && session.end_time.is_none()
+}
+
+fn close_session(session: &mut ObservationSession, now: DateTime<Utc>) {
+    session.end_time = Some(now);
+    session.updated_at = now;
+}
+
+/// When a session was last active: the end of its latest observation
+/// (including exposure time when recorded), or its start
+fn last_activity(session: &ObservationSession) -> Option<DateTime<Utc>> {
+    session
+        .observations
+        .iter()
+        .map(|o| {
+            let exposure = o.exposure_seconds.unwrap_or(0.0).max(0.0) * o.frame_count.unwrap_or(1) as f64;
+            o.observed_at + chrono::Duration::milliseconds((exposure * 1000.0) as i64)
+        })
+        .chain(session.start_time)
+        .max()
+}
+
+/// Add `session` to the log as the only open session, ending any session
+/// left running at its last activity so an abandoned session doesn't count
+/// the time until now; returns how many were closed
+fn open_session(log: &mut ObservationLogData, session: ObservationSession, now: DateTime<Utc>) -> usize {
+    let mut closed = 0;
+    for open in log.sessions.iter_mut().filter(|s| is_session_open(s)) {
+        log::info!("Closing session {} left open when a new session started", open.id);
+        let end = last_activity(open).map_or(now, |last| last.min(now));
+        open.end_time = Some(end);
+        open.updated_at = now;
+        closed += 1;
+    }
+    log.sessions.push(session);
+    closed
+}
+
+/// The most recently started open session and how long it has been running
+fn active_session_info(log: &ObservationLogData, now: DateTime<Utc>) -> Option<ActiveSessionInfo> {
+    let session = log
+        .sessions
+        .iter()
+        .filter(|s| is_session_open(s))
+        .max_by_key(|s| s.start_time)?;
+    let started = session.start_time?;
+
+    Some(ActiveSessionInfo {
+        elapsed_seconds: (now - started).num_seconds().max(0),
+        observation_count: session.observations.len(),
+        session: session.clone(),
+    })
+}
+
+/// The session currently in progress, if any
+#[tauri::command]
+pub async fn get_active_session(app: AppHandle) -> Result<Option<ActiveSessionInfo>, StorageError> {
+    let log = load_observation_log(app).await?;
+    Ok(active_session_info(&log, Utc::now()))
+}
+
 #[tauri::command]
 pub async fn create_planned_session(
     app: AppHandle,
@@ -530,11 +1041,12 @@ pub async fn create_planned_session(
         execution_targets: Some(execution_targets),
         weather_snapshot: payload.weather_snapshot,
         execution_summary: None,
+        sky_snapshot: None,
         created_at: now,
         updated_at: now,
     };
 
-    log.sessions.push(session.clone());
+    open_session(&mut log, session.clone(), now);
     save_observation_log(app, log).await?;
     Ok(session)
 }
@@ -589,8 +1101,7 @@ pub async fn end_session(app: AppHandle, session_id: String) -> Result<Observati
     let mut log = load_observation_log(app.clone()).await?;
     let session = log.sessions.iter_mut().find(|s| s.id == session_id)
         .ok_or_else(|| StorageError::StoreNotFound(session_id.clone()))?;
-    session.end_time = Some(Utc::now());
-    session.updated_at = Utc::now();
+    close_session(session, Utc::now());
     let result = session.clone();
     save_observation_log(app, log).await?;
     Ok(result)
@@ -627,6 +1138,8 @@ pub async fn update_observation(app: AppHandle, session_id: String, observation:
         existing.sketch_path = observation.sketch_path;
         existing.image_paths = observation.image_paths;
         existing.execution_target_id = observation.execution_target_id;
+        existing.exposure_seconds = observation.exposure_seconds;
+        existing.frame_count = observation.frame_count;
     } else {
         return Err(StorageError::StoreNotFound(observation.id));
     }
@@ -693,6 +1206,101 @@ pub async fn get_observation_stats(app: AppHandle) -> Result<ObservationStats, S
     Ok(ObservationStats { total_sessions, total_observations, unique_objects: unique_objects.len(), total_hours, objects_by_type, monthly_counts })
 }
 
+/// Session count, integration time and distinct targets per month or year,
+/// oldest first; `granularity` is "month" or "year"
+#[tauri::command]
+pub async fn get_observation_stats_by_period(
+    app: AppHandle,
+    granularity: String,
+) -> Result<Vec<PeriodStats>, StorageError> {
+    let log = load_observation_log(app).await?;
+    stats_by_period(&log, &granularity)
+}
+
+fn stats_by_period(log: &ObservationLogData, granularity: &str) -> Result<Vec<PeriodStats>, StorageError> {
+    let period_format = match granularity {
+        "month" => "%Y-%m",
+        "year" => "%Y",
+        other => return Err(StorageError::Other(format!("Unknown stats granularity: {}", other))),
+    };
+
+    let mut buckets: std::collections::BTreeMap<String, (usize, f64, std::collections::HashSet<String>)> =
+        std::collections::BTreeMap::new();
+    for session in &log.sessions {
+        let bucket = buckets.entry(session.date.format(period_format).to_string()).or_default();
+        bucket.0 += 1;
+        bucket.1 += summarize_session(session).total_integration_seconds;
+        bucket.2.extend(session.observations.iter().map(|obs| obs.object_name.to_lowercase()));
+    }
+
+    Ok(buckets
+        .into_iter()
+        .map(|(period, (session_count, integration_seconds, targets))| PeriodStats {
+            period,
+            session_count,
+            integration_hours: integration_seconds / 3600.0,
+            distinct_targets: targets.len(),
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_session_summary(app: AppHandle, session_id: String) -> Result<SessionSummary, StorageError> {
+    let log = load_observation_log(app).await?;
+    let session = log.sessions.iter().find(|s| s.id == session_id)
+        .ok_or_else(|| StorageError::StoreNotFound(session_id.clone()))?;
+    Ok(summarize_session(session))
+}
+
+#[tauri::command]
+pub async fn generate_session_report(app: AppHandle, session_id: String) -> Result<SessionReport, StorageError> {
+    let log = load_observation_log(app.clone()).await?;
+    let session = log.sessions.iter().find(|s| s.id == session_id)
+        .ok_or_else(|| StorageError::StoreNotFound(session_id.clone()))?;
+    // Best effort: without the equipment store the report shows raw IDs
+    let equipment = load_equipment(app).await.unwrap_or_else(|e| {
+        log::warn!("Could not load equipment for session report: {}", e);
+        EquipmentData::default()
+    });
+    Ok(build_session_report(session, &equipment))
+}
+
+/// Append observations to a session from the headers of captured FITS subs,
+/// one observation per object/filter
+#[cfg(desktop)]
+#[tauri::command]
+pub async fn import_observations_from_fits(
+    app: AppHandle,
+    session_id: String,
+    fits_paths: Vec<String>,
+) -> Result<FitsImportResult, StorageError> {
+    let mut headers = Vec::new();
+    let mut unreadable = Vec::new();
+    for path in fits_paths {
+        match read_fits_header(&path) {
+            Ok(header) => headers.push((path, header)),
+            Err(reason) => unreadable.push(reason),
+        }
+    }
+    let (observations, imported_frames, mut skipped) = observations_from_fits_headers(headers);
+    unreadable.append(&mut skipped);
+
+    let mut log = load_observation_log(app.clone()).await?;
+    let session = log.sessions.iter_mut().find(|s| s.id == session_id)
+        .ok_or_else(|| StorageError::StoreNotFound(session_id.clone()))?;
+    let observations_added = observations.len();
+    session.observations.extend(observations);
+    session.updated_at = Utc::now();
+
+    let session = session.clone();
+    save_observation_log(app, log).await?;
+    log::info!(
+        "Imported {} FITS frames as {} observations ({} skipped)",
+        imported_frames, observations_added, unreadable.len()
+    );
+    Ok(FitsImportResult { session, imported_frames, observations_added, skipped: unreadable })
+}
+
 #[tauri::command]
 pub async fn search_observations(
     app: AppHandle,
@@ -813,6 +1421,8 @@ mod tests {
             sketch_path: None,
             image_paths: vec!["img1.jpg".to_string()],
             execution_target_id: None,
+            exposure_seconds: None,
+            frame_count: None,
         };
 
         let json = serde_json::to_string(&obs).unwrap();
@@ -875,6 +1485,7 @@ mod tests {
             execution_status: None,
             execution_targets: None,
             weather_snapshot: None,
+            sky_snapshot: None,
             execution_summary: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -967,6 +1578,7 @@ mod tests {
             execution_status: None,
             execution_targets: None,
             weather_snapshot: None,
+            sky_snapshot: None,
             execution_summary: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -1062,12 +1674,15 @@ mod tests {
                         sketch_path: None,
                         image_paths: vec![],
                         execution_target_id: None,
+                        exposure_seconds: None,
+                        frame_count: None,
                     }],
                     source_plan_id: None,
                     source_plan_name: None,
                     execution_status: None,
                     execution_targets: None,
                     weather_snapshot: None,
+                    sky_snapshot: None,
                     execution_summary: None,
                     created_at: make_time("2025-01-10T19:00:00Z"),
                     updated_at: make_time("2025-01-10T21:00:00Z"),
@@ -1105,6 +1720,8 @@ mod tests {
                             sketch_path: None,
                             image_paths: vec![],
                             execution_target_id: None,
+                            exposure_seconds: None,
+                            frame_count: None,
                         },
                         Observation {
                             id: "obs-ngc7000".to_string(),
@@ -1125,6 +1742,8 @@ mod tests {
                             sketch_path: None,
                             image_paths: vec![],
                             execution_target_id: None,
+                            exposure_seconds: None,
+                            frame_count: None,
                         },
                     ],
                     source_plan_id: None,
@@ -1132,6 +1751,7 @@ mod tests {
                     execution_status: None,
                     execution_targets: None,
                     weather_snapshot: None,
+                    sky_snapshot: None,
                     execution_summary: None,
                     created_at: make_time("2025-01-12T19:00:00Z"),
                     updated_at: make_time("2025-01-12T22:30:00Z"),
@@ -1242,6 +1862,7 @@ mod tests {
             execution_status: None,
             execution_targets: None,
             weather_snapshot: None,
+            sky_snapshot: None,
             execution_summary: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -1278,6 +1899,8 @@ mod tests {
                 "img3.fits".to_string(),
             ],
             execution_target_id: None,
+            exposure_seconds: None,
+            frame_count: None,
         };
 
         let json = serde_json::to_string(&obs).unwrap();
@@ -1321,6 +1944,8 @@ mod tests {
                     sketch_path: None,
                     image_paths: vec![],
                     execution_target_id: None,
+                    exposure_seconds: None,
+                    frame_count: None,
                 },
                 Observation {
                     id: "o2".to_string(),
@@ -1341,6 +1966,8 @@ mod tests {
                     sketch_path: None,
                     image_paths: vec![],
                     execution_target_id: None,
+                    exposure_seconds: None,
+                    frame_count: None,
                 },
             ],
             source_plan_id: None,
@@ -1348,6 +1975,7 @@ mod tests {
             execution_status: None,
             execution_targets: None,
             weather_snapshot: None,
+            sky_snapshot: None,
             execution_summary: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -1357,4 +1985,397 @@ mod tests {
         let back: ObservationSession = serde_json::from_str(&json).unwrap();
         assert_eq!(back.observations.len(), 2);
     }
+
+    // ------------------------------------------------------------------------
+    // Sky Snapshot Tests
+    // ------------------------------------------------------------------------
+
+    fn utc(s: &str) -> i64 {
+        DateTime::parse_from_rfc3339(s).unwrap().timestamp()
+    }
+
+    #[test]
+    fn test_sky_snapshot_records_full_moon_and_darkness() {
+        // Full moon 2024-01-25 17:54 UTC, site at 40°N on the prime meridian
+        let date = NaiveDate::from_ymd_opt(2024, 1, 25).unwrap();
+        let snapshot = sky_snapshot_for(date, 40.0, 0.0);
+
+        assert!(snapshot.moon.illumination > 98.0, "got {}", snapshot.moon.illumination);
+        let dusk = snapshot.darkness_start.unwrap();
+        let dawn = snapshot.darkness_end.unwrap();
+        assert!((utc("2024-01-25T18:00:00Z")..utc("2024-01-25T19:30:00Z")).contains(&dusk));
+        assert!((utc("2024-01-26T05:00:00Z")..utc("2024-01-26T06:30:00Z")).contains(&dawn));
+    }
+
+    #[test]
+    fn test_sky_snapshot_new_moon_and_longitude() {
+        // New moon 2024-01-11 11:57 UTC; at 120°W local midnight is 08:00 UTC
+        let date = NaiveDate::from_ymd_opt(2024, 1, 11).unwrap();
+        let snapshot = sky_snapshot_for(date, 35.0, -120.0);
+
+        assert!(snapshot.moon.illumination < 2.0, "got {}", snapshot.moon.illumination);
+        let dusk = snapshot.darkness_start.unwrap();
+        assert!((utc("2024-01-12T02:00:00Z")..utc("2024-01-12T03:30:00Z")).contains(&dusk));
+        assert!(snapshot.darkness_end.unwrap() > dusk);
+    }
+
+    #[test]
+    fn test_sky_snapshot_without_astronomical_darkness() {
+        // Midsummer at 55°N never gets astronomically dark
+        let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+        let snapshot = sky_snapshot_for(date, 55.0, 0.0);
+        assert!(snapshot.darkness_start.is_none() || snapshot.darkness_end.is_none());
+    }
+
+    // ------------------------------------------------------------------------
+    // Active Session Tests
+    // ------------------------------------------------------------------------
+
+    fn started_session(id: &str, start: DateTime<Utc>) -> ObservationSession {
+        let mut session = build_test_log_data().sessions.remove(0);
+        session.id = id.to_string();
+        session.start_time = Some(start);
+        session.end_time = None;
+        session.observations.clear();
+        session
+    }
+
+    #[test]
+    fn test_created_session_becomes_active_until_ended() {
+        let start = Utc::now() - chrono::Duration::minutes(90);
+        let mut log = ObservationLogData { sessions: Vec::new() };
+        assert!(active_session_info(&log, Utc::now()).is_none());
+
+        let mut session = started_session("s1", start);
+        session.observations.push(imaging_observation("o1", "ha", 300.0, 10));
+        open_session(&mut log, session, start);
+
+        let now = start + chrono::Duration::minutes(90);
+        let active = active_session_info(&log, now).unwrap();
+        assert_eq!(active.session.id, "s1");
+        assert_eq!(active.elapsed_seconds, 90 * 60);
+        assert_eq!(active.observation_count, 1);
+
+        close_session(&mut log.sessions[0], now);
+        assert!(active_session_info(&log, now).is_none());
+    }
+
+    #[test]
+    fn test_second_session_closes_the_first() {
+        let first_start = Utc::now() - chrono::Duration::hours(3);
+        let second_start = first_start + chrono::Duration::hours(2);
+        let mut log = ObservationLogData { sessions: Vec::new() };
+
+        assert_eq!(open_session(&mut log, started_session("s1", first_start), first_start), 0);
+        assert_eq!(open_session(&mut log, started_session("s2", second_start), second_start), 1);
+
+        // Without observations the stale session ends where it started
+        assert_eq!(log.sessions[0].end_time, Some(first_start));
+        assert_eq!(log.sessions.iter().filter(|s| is_session_open(s)).count(), 1);
+        let active = active_session_info(&log, second_start).unwrap();
+        assert_eq!(active.session.id, "s2");
+        assert_eq!(active.elapsed_seconds, 0);
+    }
+
+    #[test]
+    fn test_stale_session_closes_at_last_observation() {
+        let start = Utc::now() - chrono::Duration::days(20);
+        let mut stale = started_session("s1", start);
+        let mut observation = imaging_observation("o1", "ha", 300.0, 10);
+        observation.observed_at = start + chrono::Duration::hours(1);
+        stale.observations.push(observation);
+
+        let mut log = ObservationLogData { sessions: Vec::new() };
+        open_session(&mut log, stale, start);
+        let now = Utc::now();
+        assert_eq!(open_session(&mut log, started_session("s2", now), now), 1);
+
+        // Ten 300 s subs starting an hour in end 1h50m after the start
+        let expected_end = start + chrono::Duration::minutes(110);
+        assert_eq!(log.sessions[0].end_time, Some(expected_end));
+        assert_eq!(log.sessions[0].updated_at, now);
+    }
+
+    #[test]
+    fn test_unstarted_sessions_are_never_active() {
+        // Imported sessions without a start time are not "running"
+        let log = build_test_log_data();
+        assert!(log.sessions.iter().all(|s| s.start_time.is_none() || s.end_time.is_some()));
+        assert!(active_session_info(&log, Utc::now()).is_none());
+    }
+
+    // ------------------------------------------------------------------------
+    // SessionSummary Tests
+    // ------------------------------------------------------------------------
+
+    fn imaging_observation(id: &str, filter_id: &str, exposure: f64, frames: u32) -> Observation {
+        Observation {
+            id: id.to_string(),
+            object_name: "M42".to_string(),
+            object_type: None,
+            ra: None,
+            dec: None,
+            constellation: None,
+            observed_at: Utc::now(),
+            telescope_id: None,
+            eyepiece_id: None,
+            camera_id: None,
+            filter_id: Some(filter_id.to_string()),
+            magnification: None,
+            rating: None,
+            difficulty: None,
+            notes: None,
+            sketch_path: None,
+            image_paths: vec![],
+            execution_target_id: None,
+            exposure_seconds: Some(exposure),
+            frame_count: Some(frames),
+        }
+    }
+
+    #[test]
+    fn test_summarize_session_per_filter_totals() {
+        let mut session = build_test_log_data().sessions.remove(0);
+        session.observations = vec![
+            imaging_observation("o1", "ha", 300.0, 10),
+            imaging_observation("o2", "oiii", 180.0, 20),
+            imaging_observation("o3", "ha", 300.0, 6),
+        ];
+
+        let summary = summarize_session(&session);
+        assert_eq!(summary.observation_count, 3);
+        assert_eq!(summary.total_frames, 36);
+        assert!((summary.total_integration_seconds - 8400.0).abs() < 1e-9);
+
+        let ha = summary.filters.iter().find(|f| f.filter_id.as_deref() == Some("ha")).unwrap();
+        assert!((ha.integration_seconds - 4800.0).abs() < 1e-9);
+        assert_eq!(ha.frame_count, 16);
+        assert!((ha.average_sub_seconds - 300.0).abs() < 1e-9);
+
+        let oiii = summary.filters.iter().find(|f| f.filter_id.as_deref() == Some("oiii")).unwrap();
+        assert!((oiii.integration_seconds - 3600.0).abs() < 1e-9);
+        assert_eq!(oiii.frame_count, 20);
+
+        assert_eq!(summary.filters[0].filter_id.as_deref(), Some("ha"));
+        assert!((summary.average_sub_seconds - 8400.0 / 36.0).abs() < 1e-9);
+    }
+
+    // ------------------------------------------------------------------------
+    // SessionReport Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_session_report_markdown_lists_targets_and_integration() {
+        let mut session = build_test_log_data().sessions.remove(0);
+        let mut m33 = imaging_observation("o3", "oiii", 120.0, 15);
+        m33.object_name = "M33".to_string();
+        session.observations = vec![
+            imaging_observation("o1", "ha", 300.0, 10),
+            imaging_observation("o2", "ha", 300.0, 2),
+            m33,
+        ];
+
+        let report = build_session_report(&session, &EquipmentData::default());
+        assert_eq!(report.targets, vec!["M42".to_string(), "M33".to_string()]);
+        assert!((report.summary.total_integration_seconds - 5400.0).abs() < 1e-9);
+        assert!(report.markdown.contains("- M42\n"));
+        assert!(report.markdown.contains("- M33\n"));
+        assert!(report.markdown.contains("Total integration: 1h 30m 00s (27 frames)"));
+        assert!(report.markdown.contains("Old Site"));
+        assert!(report.markdown.contains(&report.moon_phase_name));
+    }
+
+    #[test]
+    fn test_session_report_names_equipment() {
+        let mut session = build_test_log_data().sessions.remove(0);
+        session.equipment_ids = vec!["tel-1".to_string(), "cam-gone".to_string()];
+        session.observations = vec![
+            imaging_observation("o1", "ha", 300.0, 10),
+            imaging_observation("o2", "oiii", 300.0, 2),
+        ];
+        let equipment: EquipmentData = serde_json::from_value(serde_json::json!({
+            "telescopes": [{
+                "id": "tel-1", "name": "Esprit 100", "aperture": 100.0, "focal_length": 550.0,
+                "focal_ratio": 5.5, "telescope_type": "refractor", "mount_type": null, "notes": null,
+                "is_default": true, "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-01-01T00:00:00Z"
+            }],
+            "cameras": [],
+            "eyepieces": [],
+            "barlow_reducers": [],
+            "filters": [{
+                "id": "ha", "name": "Antlia 3nm Ha", "filter_type": "ha", "bandwidth": 3.0, "notes": null,
+                "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-01-01T00:00:00Z"
+            }]
+        }))
+        .unwrap();
+
+        let report = build_session_report(&session, &equipment);
+        assert!(report.markdown.contains("- Esprit 100\n"));
+        // Unknown equipment falls back to its ID
+        assert!(report.markdown.contains("- cam-gone\n"));
+        assert!(report.markdown.contains("| Antlia 3nm Ha |"));
+        assert!(report.markdown.contains("| oiii |"));
+    }
+
+    #[test]
+    fn test_session_report_without_observations() {
+        let mut session = build_test_log_data().sessions.remove(0);
+        session.observations.clear();
+
+        let report = build_session_report(&session, &EquipmentData::default());
+        assert!(report.targets.is_empty());
+        assert!(report.markdown.contains("No targets observed."));
+        assert!(report.markdown.contains("Total integration: 0h 00m 00s (0 frames)"));
+        assert!((0.0..=100.0).contains(&report.moon_illumination));
+    }
+
+    #[test]
+    fn test_format_integration() {
+        assert_eq!(format_integration(0.0), "0h 00m 00s");
+        assert_eq!(format_integration(3725.4), "1h 02m 05s");
+    }
+
+    // ------------------------------------------------------------------------
+    // Period Stats Tests
+    // ------------------------------------------------------------------------
+
+    fn dated_session(id: &str, date: (i32, u32, u32), observations: Vec<Observation>) -> ObservationSession {
+        let mut session = build_test_log_data().sessions.remove(0);
+        session.id = id.to_string();
+        session.date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+        session.observations = observations;
+        session
+    }
+
+    fn target_observation(name: &str, exposure: f64, frames: u32) -> Observation {
+        let mut obs = imaging_observation(name, "l", exposure, frames);
+        obs.object_name = name.to_string();
+        obs
+    }
+
+    fn two_month_log() -> ObservationLogData {
+        ObservationLogData {
+            sessions: vec![
+                dated_session("jan-1", (2025, 1, 10), vec![target_observation("M42", 300.0, 12)]),
+                dated_session(
+                    "jan-2",
+                    (2025, 1, 24),
+                    vec![target_observation("m42", 300.0, 6), target_observation("M45", 60.0, 30)],
+                ),
+                dated_session("feb-1", (2025, 2, 3), vec![target_observation("M81", 180.0, 40)]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_stats_by_month_buckets_sessions() {
+        let stats = stats_by_period(&two_month_log(), "month").unwrap();
+        assert_eq!(stats.len(), 2);
+
+        let jan = &stats[0];
+        assert_eq!(jan.period, "2025-01");
+        assert_eq!(jan.session_count, 2);
+        assert!((jan.integration_hours - 2.0).abs() < 1e-9);
+        assert_eq!(jan.distinct_targets, 2);
+
+        let feb = &stats[1];
+        assert_eq!(feb.period, "2025-02");
+        assert_eq!(feb.session_count, 1);
+        assert!((feb.integration_hours - 2.0).abs() < 1e-9);
+        assert_eq!(feb.distinct_targets, 1);
+    }
+
+    #[test]
+    fn test_stats_by_year_merges_months() {
+        let stats = stats_by_period(&two_month_log(), "year").unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].period, "2025");
+        assert_eq!(stats[0].session_count, 3);
+        assert!((stats[0].integration_hours - 4.0).abs() < 1e-9);
+        assert_eq!(stats[0].distinct_targets, 3);
+    }
+
+    #[test]
+    fn test_stats_by_period_rejects_unknown_granularity() {
+        assert!(stats_by_period(&two_month_log(), "week").is_err());
+        assert!(stats_by_period(&ObservationLogData::default(), "month").unwrap().is_empty());
+    }
+
+    // ------------------------------------------------------------------------
+    // FITS Import Tests
+    // ------------------------------------------------------------------------
+
+    #[cfg(desktop)]
+    fn fits_header(cards: &[(&str, &str)]) -> HashMap<String, String> {
+        let mut data = format!("{:<80}", "SIMPLE  =                    T");
+        for (key, value) in cards {
+            data.push_str(&format!("{:<8}= {:<70}", key, value));
+        }
+        data.push_str(&format!("{:<80}", "END"));
+        parse_fits_header_map_from_bytes(data.as_bytes())
+    }
+
+    #[cfg(desktop)]
+    #[test]
+    fn test_observations_from_fits_headers_groups_by_object_and_filter() {
+        let frame = |object: &str, filter: &str, time: &str, temp: &str| {
+            fits_header(&[
+                ("DATE-OBS", time),
+                ("EXPTIME", "300.0"),
+                ("OBJECT", object),
+                ("FILTER", filter),
+                ("GAIN", "100"),
+                ("CCD-TEMP", temp),
+            ])
+        };
+        let headers = vec![
+            ("m42_ha_2.fits".to_string(), frame("'M42'", "'Ha'", "'2024-01-15T22:05:00.000'", "-10.0")),
+            ("m42_ha_1.fits".to_string(), frame("'M42'", "'Ha'", "'2024-01-15T22:00:00'", "-9.0")),
+            ("m42_oiii_1.fits".to_string(), frame("'M42'", "'OIII'", "'2024-01-15T23:00:00'", "-10.0")),
+            ("ngc7000_ha.fits".to_string(), frame("'NGC 7000'", "'Ha'", "'2024-01-16T01:00:00'", "-10.0")),
+            ("flat.fits".to_string(), fits_header(&[("EXPTIME", "1.5"), ("FILTER", "'Ha'")])),
+        ];
+
+        let (observations, imported, skipped) = observations_from_fits_headers(headers);
+        assert_eq!(imported, 4);
+        assert_eq!(observations.len(), 3);
+
+        let m42_ha = &observations[0];
+        assert_eq!(m42_ha.object_name, "M42");
+        assert_eq!(m42_ha.frame_count, Some(2));
+        assert_eq!(m42_ha.exposure_seconds, Some(300.0));
+        assert_eq!(m42_ha.image_paths, vec!["m42_ha_2.fits", "m42_ha_1.fits"]);
+        assert_eq!(m42_ha.observed_at.to_rfc3339(), "2024-01-15T22:00:00+00:00");
+        let notes = m42_ha.notes.as_deref().unwrap();
+        assert!(notes.contains("Filter: Ha") && notes.contains("Gain: 100") && notes.contains("-9.5 °C"), "{}", notes);
+
+        assert_eq!(observations[1].object_name, "M42");
+        assert!(observations[1].notes.as_deref().unwrap().contains("Filter: OIII"));
+        assert_eq!(observations[2].object_name, "NGC 7000");
+
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].starts_with("flat.fits") && skipped[0].contains("DATE-OBS") && skipped[0].contains("OBJECT"));
+    }
+
+    #[cfg(desktop)]
+    #[test]
+    fn test_parse_fits_date_obs_formats() {
+        assert!(parse_fits_date_obs("2024-01-15T22:00:00.123").is_some());
+        assert!(parse_fits_date_obs("2024-01-15T22:00:00").is_some());
+        assert_eq!(parse_fits_date_obs("2024-01-15").unwrap().to_rfc3339(), "2024-01-15T00:00:00+00:00");
+        assert!(parse_fits_date_obs("15/01/2024").is_none());
+    }
+
+    #[test]
+    fn test_observation_deserializes_without_exposure_fields() {
+        let json = r#"{
+            "id": "o1", "object_name": "M13", "object_type": null, "ra": null, "dec": null,
+            "constellation": null, "observed_at": "2024-01-15T22:30:00Z", "telescope_id": null,
+            "eyepiece_id": null, "camera_id": null, "filter_id": null, "magnification": null,
+            "rating": null, "difficulty": null, "notes": null, "sketch_path": null, "image_paths": []
+        }"#;
+        let obs: Observation = serde_json::from_str(json).unwrap();
+        assert!(obs.exposure_seconds.is_none());
+        assert!(obs.frame_count.is_none());
+    }
 }
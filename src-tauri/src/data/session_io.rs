@@ -9,7 +9,7 @@ use tauri::AppHandle;
 use tauri::Manager;
 use tauri_plugin_dialog::DialogExt;
 
-use super::storage::StorageError;
+use super::storage::{write_json_atomic, StorageError};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionTemplateEntry {
@@ -64,8 +64,7 @@ fn load_templates_internal(app: &AppHandle) -> Result<SessionTemplateData, Stora
 
 fn save_templates_internal(app: &AppHandle, data: &SessionTemplateData) -> Result<(), StorageError> {
     let path = get_templates_path(app)?;
-    let serialized = serde_json::to_string_pretty(data)?;
-    fs::write(path, serialized)?;
+    write_json_atomic(&path, data)?;
     Ok(())
 }
 
@@ -2,11 +2,13 @@
 //! Provides file-based persistent storage for the desktop application
 
 use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use tauri::AppHandle;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use tauri::{AppHandle, Emitter};
 #[cfg(not(desktop))]
 use tauri::Manager;
 use thiserror::Error;
@@ -22,6 +24,10 @@ pub enum StorageError {
     Json(#[from] serde_json::Error),
     #[error("Store not found: {0}")]
     StoreNotFound(String),
+    #[error("Insufficient disk space: {required} bytes required, {available} bytes available")]
+    InsufficientSpace { required: u64, available: u64 },
+    #[error("Invalid coordinates: {0}")]
+    InvalidCoordinates(String),
     #[error("{0}")]
     Other(String),
 }
@@ -35,6 +41,15 @@ impl serde::Serialize for StorageError {
     }
 }
 
+impl From<crate::utils::PathError> for StorageError {
+    fn from(e: crate::utils::PathError) -> Self {
+        match e {
+            crate::utils::PathError::AppDataDirNotFound => StorageError::AppDataDirNotFound,
+            crate::utils::PathError::Io(e) => StorageError::Io(e),
+        }
+    }
+}
+
 /// Metadata for exported data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportMetadata {
@@ -66,6 +81,7 @@ const KNOWN_STORES: &[&str] = &[
     "skymap-locale",
     "skymap-solver-config",
     "skymap-app-settings",
+    "skymap-download-history",
 ];
 
 /// Get the base storage directory for the application
@@ -101,6 +117,95 @@ fn get_store_path(app: &AppHandle, store_name: &str) -> Result<PathBuf, StorageE
     Ok(storage_dir.join(format!("{}.json", store_name)))
 }
 
+/// Write bytes to a file atomically via [`crate::utils::atomic_write`]
+pub fn write_atomic(path: &Path, data: &[u8]) -> Result<(), StorageError> {
+    Ok(crate::utils::atomic_write(path, data)?)
+}
+
+/// Serialize a value as pretty JSON and write it atomically
+pub fn write_json_atomic<T: Serialize + ?Sized>(path: &Path, value: &T) -> Result<(), StorageError> {
+    let json = serde_json::to_string_pretty(value)?;
+    write_atomic(path, json.as_bytes())
+}
+
+// ============================================================================
+// Corrupt Store Recovery
+// ============================================================================
+
+/// Event emitted after a corrupt store file was moved aside and replaced by defaults
+pub const STORE_RECOVERED_EVENT: &str = "store-recovered";
+
+/// Details of a recovered store, sent with [`STORE_RECOVERED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoreRecovery {
+    pub path: String,
+    pub backup_path: String,
+    pub error: String,
+}
+
+/// Move a corrupt store file aside to `<name>.corrupt.<timestamp>`
+fn quarantine_corrupt_file(path: &Path) -> Result<PathBuf, StorageError> {
+    let mut file_name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push(format!(".corrupt.{}", Utc::now().timestamp_millis()));
+    let backup_path = path.with_file_name(file_name);
+    fs::rename(path, &backup_path)?;
+    Ok(backup_path)
+}
+
+fn recover_corrupt_file(path: &Path, error: String) -> Result<StoreRecovery, StorageError> {
+    let backup_path = quarantine_corrupt_file(path)?;
+    log::warn!("Store {:?} is corrupt ({}); moved to {:?}", path, error, backup_path);
+    Ok(StoreRecovery {
+        path: path.to_string_lossy().to_string(),
+        backup_path: backup_path.to_string_lossy().to_string(),
+        error,
+    })
+}
+
+fn notify_store_recovered(app: &AppHandle, recovery: &StoreRecovery) {
+    let _ = app.emit(STORE_RECOVERED_EVENT, recovery);
+}
+
+/// Whether a parse failure means the file itself is damaged; well-formed JSON
+/// that doesn't match the schema (e.g. written by a newer version) is not
+fn is_malformed_json(error: &serde_json::Error) -> bool {
+    matches!(error.classify(), serde_json::error::Category::Syntax | serde_json::error::Category::Eof)
+}
+
+/// Deserialize a JSON store file, falling back to `default()` when it is missing
+/// A malformed file is moved aside and reported instead of failing the load;
+/// schema errors are returned so the user's data is left untouched
+pub(crate) fn read_json_or_recover<T: DeserializeOwned>(
+    path: &Path,
+    default: impl FnOnce() -> T,
+) -> Result<(T, Option<StoreRecovery>), StorageError> {
+    if !path.exists() {
+        return Ok((default(), None));
+    }
+    let data = fs::read(path)?;
+    match serde_json::from_slice(&data) {
+        Ok(value) => Ok((value, None)),
+        Err(e) if is_malformed_json(&e) => Ok((default(), Some(recover_corrupt_file(path, e.to_string())?))),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// [`read_json_or_recover`] that emits [`STORE_RECOVERED_EVENT`] after a recovery
+pub(crate) fn load_json_store<T: DeserializeOwned>(
+    app: &AppHandle,
+    path: &Path,
+    default: impl FnOnce() -> T,
+) -> Result<T, StorageError> {
+    let (value, recovery) = read_json_or_recover(path, default)?;
+    if let Some(recovery) = recovery {
+        notify_store_recovered(app, &recovery);
+    }
+    Ok(value)
+}
+
 /// Save store data to file
 #[tauri::command]
 pub async fn save_store_data(
@@ -117,7 +222,7 @@ pub async fn save_store_data(
     // Validate JSON before saving
     let _: serde_json::Value = serde_json::from_str(&data)?;
 
-    fs::write(&path, &data)?;
+    write_atomic(&path, data.as_bytes())?;
     log::info!(
         "Saved store '{}' to {:?} ({} bytes)",
         store_name,
@@ -141,7 +246,17 @@ pub async fn load_store_data(
         return Ok(None);
     }
 
-    let data = fs::read_to_string(&path)?;
+    let data = fs::read(&path)?;
+    match serde_json::from_slice::<serde::de::IgnoredAny>(&data) {
+        Ok(_) => {}
+        Err(e) if is_malformed_json(&e) => {
+            let recovery = recover_corrupt_file(&path, e.to_string())?;
+            notify_store_recovered(&app, &recovery);
+            return Ok(None);
+        }
+        Err(e) => return Err(e.into()),
+    }
+    let data = String::from_utf8(data).map_err(|e| StorageError::Other(e.to_string()))?;
     log::info!("Loaded store '{}' from {:?}", store_name, path);
 
     Ok(Some(data))
@@ -240,8 +355,7 @@ pub async fn export_all_data(app: AppHandle, export_path: String) -> Result<(),
         stores,
     };
 
-    let json = serde_json::to_string_pretty(&export_data)?;
-    fs::write(&export_path, json)?;
+    write_json_atomic(Path::new(&export_path), &export_data)?;
 
     log::info!(
         "Exported {} stores to {}",
@@ -269,17 +383,11 @@ pub async fn import_all_data(
     for (store_name, value) in export_data.stores {
         let store_path = storage_dir.join(format!("{}.json", store_name));
 
-        match serde_json::to_string_pretty(&value) {
-            Ok(json) => match fs::write(&store_path, json) {
-                Ok(_) => {
-                    imported_count += 1;
-                    log::info!("Imported store '{}' to {:?}", store_name, store_path);
-                }
-                Err(e) => {
-                    errors.push(format!("{}: {}", store_name, e));
-                    skipped_count += 1;
-                }
-            },
+        match write_json_atomic(&store_path, &value) {
+            Ok(_) => {
+                imported_count += 1;
+                log::info!("Imported store '{}' to {:?}", store_name, store_path);
+            }
             Err(e) => {
                 errors.push(format!("{}: {}", store_name, e));
                 skipped_count += 1;
@@ -311,17 +419,55 @@ pub async fn get_data_directory(app: AppHandle) -> Result<String, StorageError>
     Ok(storage_dir.to_string_lossy().to_string())
 }
 
-/// Get storage statistics
+/// Get the base directory holding the offline and unified caches
+fn get_cache_base_dir(app: &AppHandle) -> Result<PathBuf, StorageError> {
+    #[cfg(desktop)]
+    let base_dir = crate::platform::path_config::resolve_cache_dir(app)?;
+
+    #[cfg(not(desktop))]
+    let base_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|_| StorageError::AppDataDirNotFound)?
+        .join("skymap");
+
+    Ok(base_dir)
+}
+
+/// Cache subdirectories counted towards the cache total
+const CACHE_SUBDIRS: &[&str] = &["cache", "unified_cache"];
+
+/// Get storage statistics; the cache total is only walked when `include_cache` is set
+/// Directory traversal runs on a blocking task so large caches don't stall the runtime
 #[tauri::command]
-pub async fn get_storage_stats(app: AppHandle) -> Result<StorageStats, StorageError> {
+pub async fn get_storage_stats(
+    app: AppHandle,
+    include_cache: Option<bool>,
+) -> Result<StorageStats, StorageError> {
     let storage_dir = get_storage_dir(&app)?;
+    let cache_dirs = if include_cache.unwrap_or(false) {
+        let base = get_cache_base_dir(&app)?;
+        Some(CACHE_SUBDIRS.iter().map(|d| base.join(d)).collect::<Vec<_>>())
+    } else {
+        None
+    };
 
+    tokio::task::spawn_blocking(move || collect_storage_stats(&storage_dir, cache_dirs.as_deref()))
+        .await
+        .map_err(|e| StorageError::Other(format!("Storage stats task failed: {}", e)))?
+}
+
+/// Collect per-store sizes and, optionally, the combined size of the cache directories
+fn collect_storage_stats(
+    storage_dir: &Path,
+    cache_dirs: Option<&[PathBuf]>,
+) -> Result<StorageStats, StorageError> {
     let mut total_size: u64 = 0;
     let mut store_count: usize = 0;
     let mut stores: Vec<StoreInfo> = Vec::new();
 
     if storage_dir.exists() {
-        for entry in fs::read_dir(&storage_dir)? {
+        for entry in fs::read_dir(storage_dir)? {
             let entry = entry?;
             let path = entry.path();
 
@@ -346,14 +492,37 @@ pub async fn get_storage_stats(app: AppHandle) -> Result<StorageStats, StorageEr
         }
     }
 
+    let cache_size = cache_dirs.map(|dirs| dirs.iter().map(|d| dir_size(d)).sum());
+
     Ok(StorageStats {
         total_size,
         store_count,
         stores,
         directory: storage_dir.to_string_lossy().to_string(),
+        cache_size,
     })
 }
 
+/// Total size of all regular files under a directory (symlinks are not followed)
+pub(crate) fn dir_size(root: &Path) -> u64 {
+    let mut total: u64 = 0;
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else { continue };
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                total += entry.metadata().map(|m| m.len()).unwrap_or(0);
+            }
+        }
+    }
+
+    total
+}
+
 /// Storage statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageStats {
@@ -361,6 +530,9 @@ pub struct StorageStats {
     pub store_count: usize,
     pub stores: Vec<StoreInfo>,
     pub directory: String,
+    /// Combined size of the tile and unified caches; `None` when not requested
+    #[serde(default)]
+    pub cache_size: Option<u64>,
 }
 
 /// Individual store information
@@ -397,6 +569,107 @@ pub async fn clear_all_data(app: AppHandle) -> Result<usize, StorageError> {
     Ok(deleted_count)
 }
 
+// ============================================================================
+// Download History
+// ============================================================================
+
+/// Store holding the download audit trail
+const DOWNLOAD_HISTORY_STORE: &str = "skymap-download-history";
+
+/// Oldest entries are dropped beyond this many records
+const MAX_DOWNLOAD_HISTORY: usize = 1000;
+
+/// Serializes read-modify-write cycles of the download history file
+static DOWNLOAD_HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+fn lock_download_history() -> MutexGuard<'static, ()> {
+    DOWNLOAD_HISTORY_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// A single completed or failed download (index files, survey tiles)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadEntry {
+    pub name: String,
+    pub size: u64,
+    pub timestamp: DateTime<Utc>,
+    pub source_url: String,
+    pub success: bool,
+}
+
+impl DownloadEntry {
+    /// Build an entry stamped with the current time
+    pub fn now(name: impl Into<String>, size: u64, source_url: impl Into<String>, success: bool) -> Self {
+        Self {
+            name: name.into(),
+            size,
+            timestamp: Utc::now(),
+            source_url: source_url.into(),
+            success,
+        }
+    }
+}
+
+fn read_download_history(path: &Path) -> Result<Vec<DownloadEntry>, StorageError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let data = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&data)?)
+}
+
+fn append_download_entries(path: &Path, entries: Vec<DownloadEntry>) -> Result<(), StorageError> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    let _guard = lock_download_history();
+    let mut history = read_download_history(path)?;
+    history.extend(entries);
+    if history.len() > MAX_DOWNLOAD_HISTORY {
+        history.drain(..history.len() - MAX_DOWNLOAD_HISTORY);
+    }
+    write_json_atomic(path, &history)
+}
+
+fn clear_download_history_at(path: &Path) -> Result<(), StorageError> {
+    let _guard = lock_download_history();
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Append downloads to the persisted history in one write
+/// Failures are logged rather than returned so a broken history file never
+/// fails the download that triggered it
+pub fn append_download_history(app: &AppHandle, entries: Vec<DownloadEntry>) {
+    let result = get_store_path(app, DOWNLOAD_HISTORY_STORE)
+        .and_then(|path| append_download_entries(&path, entries));
+    if let Err(e) = result {
+        log::warn!("Failed to record download history: {}", e);
+    }
+}
+
+/// Record a download performed by the frontend
+#[tauri::command]
+pub async fn record_download(app: AppHandle, entry: DownloadEntry) -> Result<(), StorageError> {
+    let path = get_store_path(&app, DOWNLOAD_HISTORY_STORE)?;
+    append_download_entries(&path, vec![entry])
+}
+
+/// Get all recorded downloads, oldest first
+#[tauri::command]
+pub async fn get_download_history(app: AppHandle) -> Result<Vec<DownloadEntry>, StorageError> {
+    let path = get_store_path(&app, DOWNLOAD_HISTORY_STORE)?;
+    read_download_history(&path)
+}
+
+/// Delete the download history
+#[tauri::command]
+pub async fn clear_download_history(app: AppHandle) -> Result<(), StorageError> {
+    let path = get_store_path(&app, DOWNLOAD_HISTORY_STORE)?;
+    clear_download_history_at(&path)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -590,6 +863,7 @@ mod tests {
                 },
             ],
             directory: "/path/to/stores".to_string(),
+            cache_size: None,
         };
 
         let json = serde_json::to_string(&stats).unwrap();
@@ -613,6 +887,39 @@ mod tests {
         assert_eq!(stats.directory, "/test/path");
     }
 
+    #[test]
+    fn test_collect_storage_stats_over_tree() {
+        let root = atomic_test_dir("stats");
+        let stores_dir = root.join("stores");
+        let tiles_dir = root.join("cache").join("tiles").join("dss").join("3");
+        let unified_dir = root.join("unified_cache").join("data");
+        fs::create_dir_all(&stores_dir).unwrap();
+        fs::create_dir_all(&tiles_dir).unwrap();
+        fs::create_dir_all(&unified_dir).unwrap();
+
+        fs::write(stores_dir.join("a.json"), vec![b' '; 100]).unwrap();
+        fs::write(stores_dir.join("b.json"), vec![b' '; 250]).unwrap();
+        fs::write(stores_dir.join("notes.txt"), vec![b' '; 999]).unwrap();
+        fs::write(tiles_dir.join("0_0.jpg"), vec![0_u8; 4000]).unwrap();
+        fs::write(tiles_dir.join("0_1.jpg"), vec![0_u8; 1000]).unwrap();
+        fs::write(unified_dir.join("entry"), vec![0_u8; 500]).unwrap();
+
+        let cache_dirs = vec![root.join("cache"), root.join("unified_cache"), root.join("missing")];
+        let stats = collect_storage_stats(&stores_dir, Some(&cache_dirs)).unwrap();
+
+        assert_eq!(stats.store_count, 2);
+        assert_eq!(stats.total_size, 350);
+        let b = stats.stores.iter().find(|s| s.name == "b").unwrap();
+        assert_eq!(b.size, 250);
+        assert_eq!(stats.cache_size, Some(5500));
+
+        let without_cache = collect_storage_stats(&stores_dir, None).unwrap();
+        assert_eq!(without_cache.total_size, 350);
+        assert!(without_cache.cache_size.is_none());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
     // ------------------------------------------------------------------------
     // StoreInfo Tests
     // ------------------------------------------------------------------------
@@ -656,6 +963,222 @@ mod tests {
         assert_eq!(cloned.size, info.size);
     }
 
+    // ------------------------------------------------------------------------
+    // Atomic Write Tests
+    // ------------------------------------------------------------------------
+
+    fn atomic_test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "skymap_storage_{}_{}",
+            name,
+            crate::utils::generate_id("t")
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn dir_entries(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_write_json_atomic_interrupted_write_keeps_original() {
+        let dir = atomic_test_dir("interrupted");
+        let path = dir.join("store.json");
+        write_json_atomic(&path, &serde_json::json!({"version": 1})).unwrap();
+
+        // JSON object keys must be strings, so serializing this map fails
+        let unserializable: HashMap<(u8, u8), u8> = HashMap::from([((1, 2), 3)]);
+        assert!(write_json_atomic(&path, &unserializable).is_err());
+
+        let original: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(original["version"], 1);
+        assert_eq!(dir_entries(&dir), vec!["store.json".to_string()]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_write_json_atomic_replaces_existing() {
+        let dir = atomic_test_dir("replace");
+        let path = dir.join("store.json");
+        fs::write(&path, b"{\"version\": 1}").unwrap();
+
+        write_json_atomic(&path, &serde_json::json!({"version": 2})).unwrap();
+
+        let value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(value["version"], 2);
+        assert_eq!(dir_entries(&dir), vec!["store.json".to_string()]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_write_atomic_failed_rename_keeps_target() {
+        let dir = atomic_test_dir("failed");
+        // A non-empty directory at the target path makes the rename fail
+        let path = dir.join("store.json");
+        fs::create_dir_all(path.join("child")).unwrap();
+
+        assert!(write_atomic(&path, b"{}").is_err());
+        assert!(path.join("child").is_dir());
+        assert_eq!(dir_entries(&dir), vec!["store.json".to_string()]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    // ------------------------------------------------------------------------
+    // Corrupt Store Recovery Tests
+    // ------------------------------------------------------------------------
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct SampleStore {
+        items: Vec<String>,
+    }
+
+    #[test]
+    fn test_read_json_or_recover_moves_malformed_file_aside() {
+        let dir = atomic_test_dir("corrupt");
+        let path = dir.join("targets.json");
+        fs::write(&path, b"{\"items\": [\"m31\", ").unwrap();
+
+        let (value, recovery) = read_json_or_recover(&path, SampleStore::default).unwrap();
+        assert_eq!(value, SampleStore::default());
+        let recovery = recovery.unwrap();
+        assert!(!path.exists());
+
+        let backup = PathBuf::from(&recovery.backup_path);
+        assert!(backup.file_name().unwrap().to_string_lossy().starts_with("targets.json.corrupt."));
+        assert_eq!(fs::read(&backup).unwrap(), b"{\"items\": [\"m31\", ");
+
+        // The next load starts clean
+        let (_, recovery) = read_json_or_recover(&path, SampleStore::default).unwrap();
+        assert!(recovery.is_none());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_json_or_recover_keeps_file_on_schema_error() {
+        let dir = atomic_test_dir("schema_error");
+        let path = dir.join("targets.json");
+        fs::write(&path, b"{\"items\": [42]}").unwrap();
+
+        assert!(read_json_or_recover(&path, SampleStore::default).is_err());
+        assert_eq!(fs::read(&path).unwrap(), b"{\"items\": [42]}");
+        assert_eq!(dir_entries(&dir), vec!["targets.json".to_string()]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_json_or_recover_valid_and_missing() {
+        let dir = atomic_test_dir("recover_valid");
+        let path = dir.join("store.json");
+
+        let (value, recovery) = read_json_or_recover(&path, || SampleStore { items: vec!["default".into()] }).unwrap();
+        assert_eq!(value.items, vec!["default"]);
+        assert!(recovery.is_none());
+
+        write_json_atomic(&path, &SampleStore { items: vec!["m42".into()] }).unwrap();
+        let (value, recovery) = read_json_or_recover(&path, SampleStore::default).unwrap();
+        assert_eq!(value.items, vec!["m42"]);
+        assert!(recovery.is_none());
+        assert!(path.exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    // ------------------------------------------------------------------------
+    // Download History Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_recorded_download_appears_in_history() {
+        let dir = atomic_test_dir("downloads");
+        let path = dir.join("history.json");
+        assert!(read_download_history(&path).unwrap().is_empty());
+
+        let url = "https://example.com/d50.zip";
+        append_download_entries(&path, vec![DownloadEntry::now("d50", 4096, url, true)]).unwrap();
+        append_download_entries(&path, vec![DownloadEntry::now("tile", 0, "https://example.com/t", false)])
+            .unwrap();
+
+        let history = read_download_history(&path).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].name, "d50");
+        assert_eq!(history[0].size, 4096);
+        assert_eq!(history[0].source_url, url);
+        assert!(history[0].success);
+        assert!(!history[1].success);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_clear_download_history_empties_it() {
+        let dir = atomic_test_dir("downloads_clear");
+        let path = dir.join("history.json");
+        append_download_entries(&path, vec![DownloadEntry::now("d50", 1, "https://example.com", true)]).unwrap();
+
+        clear_download_history_at(&path).unwrap();
+        assert!(read_download_history(&path).unwrap().is_empty());
+        // Clearing an already-empty history is not an error
+        clear_download_history_at(&path).unwrap();
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_download_history_is_capped() {
+        let dir = atomic_test_dir("downloads_cap");
+        let path = dir.join("history.json");
+        let history: Vec<DownloadEntry> = (0..MAX_DOWNLOAD_HISTORY)
+            .map(|i| DownloadEntry::now(format!("tile-{}", i), 1, "https://example.com", true))
+            .collect();
+        write_json_atomic(&path, &history).unwrap();
+
+        append_download_entries(&path, vec![DownloadEntry::now("latest", 1, "https://example.com", true)]).unwrap();
+
+        let history = read_download_history(&path).unwrap();
+        assert_eq!(history.len(), MAX_DOWNLOAD_HISTORY);
+        assert_eq!(history[0].name, "tile-1");
+        assert_eq!(history.last().unwrap().name, "latest");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_concurrent_download_appends_keep_every_entry() {
+        let dir = atomic_test_dir("downloads_concurrent");
+        let path = dir.join("history.json");
+
+        let handles: Vec<_> = (0..8)
+            .map(|thread| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for i in 0..10 {
+                        let entry = DownloadEntry::now(format!("tile-{}-{}", thread, i), 1, "https://example.com", true);
+                        append_download_entries(&path, vec![entry]).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(read_download_history(&path).unwrap().len(), 80);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     // ------------------------------------------------------------------------
     // KNOWN_STORES Tests
     // ------------------------------------------------------------------------
@@ -678,12 +1201,13 @@ mod tests {
         assert!(KNOWN_STORES.contains(&"skymap-locale"));
         assert!(KNOWN_STORES.contains(&"skymap-solver-config"));
         assert!(KNOWN_STORES.contains(&"skymap-app-settings"));
+        assert!(KNOWN_STORES.contains(&"skymap-download-history"));
     }
 
     #[test]
     fn test_known_stores_count() {
-        // Should have exactly 13 known stores after the update
-        assert_eq!(KNOWN_STORES.len(), 13, "Should have exactly 13 known stores, got {}", KNOWN_STORES.len());
+        // Should have exactly 14 known stores after the update
+        assert_eq!(KNOWN_STORES.len(), 14, "Should have exactly 14 known stores, got {}", KNOWN_STORES.len());
     }
 
     #[test]
@@ -9,6 +9,23 @@ use tauri::AppHandle;
 use tauri_plugin_dialog::DialogExt;
 
 use super::storage::StorageError;
+use super::targets::{
+    load_target_list, new_batch_target, save_target_list, BatchTargetInput, TargetItem,
+    TargetListData, TargetPriority,
+};
+use crate::astronomy::{
+    angular_separation, constellation_for_coords, format_dec_dms, format_ra_hms, identify_object,
+    parse_dec_dms, parse_ra_hms,
+};
+
+/// Tag applied to every target imported from a KStars observing list
+const KSTARS_TAG: &str = "kstars";
+
+/// Tag applied to every target imported from Stellarium bookmarks
+const STELLARIUM_TAG: &str = "stellarium";
+
+/// How far an imported target may be from a catalog object to take its type
+const ENRICH_MATCH_RADIUS_ARCMIN: f64 = 10.0;
 
 /// Static compiled regex for RA parsing (HMS format)
 static RA_REGEX: Lazy<regex_lite::Regex> = Lazy::new(|| {
@@ -46,6 +63,25 @@ pub struct ImportTargetsResult {
     pub skipped: usize,
     pub errors: Vec<String>,
     pub targets: Vec<TargetExportItem>,
+    #[serde(default)]
+    pub report: ImportReport,
+}
+
+/// Breakdown of targets accepted and rejected by the import guards
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub added: usize,
+    pub skipped_invalid: usize,
+    pub skipped_duplicate: usize,
+}
+
+/// KStars observing list import result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KStarsImportResult {
+    #[serde(flatten)]
+    pub target_list: TargetListData,
+    pub imported: usize,
+    pub skipped: Vec<String>,
 }
 
 /// Export formats
@@ -116,6 +152,7 @@ pub async fn export_targets(
 pub async fn import_targets(
     app: AppHandle,
     path: Option<String>,
+    dedup_radius_arcmin: Option<f64>,
 ) -> Result<ImportTargetsResult, StorageError> {
     let import_path = if let Some(p) = path {
         PathBuf::from(p)
@@ -150,17 +187,325 @@ pub async fn import_targets(
         .unwrap_or("")
         .to_lowercase();
 
-    let result = match extension.as_str() {
+    let parsed = match extension.as_str() {
         "csv" => import_csv(&content),
         "json" => import_json(&content)?,
         "txt" => import_stellarium(&content),
         _ => import_csv(&content),
     };
 
+    let existing: Vec<(f64, f64)> = match dedup_radius_arcmin {
+        Some(_) => load_target_list(app.clone())
+            .await?
+            .targets
+            .iter()
+            .map(|t| (t.ra, t.dec))
+            .collect(),
+        None => Vec::new(),
+    };
+    let mut result = apply_import_guards(parsed, &existing, dedup_radius_arcmin);
+    result.targets.iter_mut().for_each(enrich_export_item);
+
     log::info!("Imported {} targets from {:?}", result.imported, import_path);
     Ok(result)
 }
 
+/// Drop targets with out-of-range coordinates and, when a radius is given, targets
+/// lying within that many arcminutes of an existing or already-accepted target
+fn apply_import_guards(
+    mut result: ImportTargetsResult,
+    existing: &[(f64, f64)],
+    dedup_radius_arcmin: Option<f64>,
+) -> ImportTargetsResult {
+    let radius_deg = dedup_radius_arcmin.filter(|r| *r > 0.0).map(|r| r / 60.0);
+    let mut accepted_coords: Vec<(f64, f64)> = existing.to_vec();
+    let mut accepted = Vec::with_capacity(result.targets.len());
+    let mut report = ImportReport::default();
+
+    for target in std::mem::take(&mut result.targets) {
+        let valid = target.ra.is_finite()
+            && target.dec.is_finite()
+            && (0.0..360.0).contains(&target.ra)
+            && (-90.0..=90.0).contains(&target.dec);
+        if !valid {
+            result.errors.push(format!(
+                "{}: coords out of range (RA {}, Dec {})",
+                target.name, target.ra, target.dec
+            ));
+            report.skipped_invalid += 1;
+            continue;
+        }
+
+        if let Some(radius) = radius_deg {
+            let duplicate = accepted_coords
+                .iter()
+                .any(|&(ra, dec)| angular_separation(ra, dec, target.ra, target.dec) <= radius);
+            if duplicate {
+                report.skipped_duplicate += 1;
+                continue;
+            }
+        }
+
+        accepted_coords.push((target.ra, target.dec));
+        accepted.push(target);
+    }
+
+    report.added = accepted.len();
+    result.skipped += report.skipped_invalid + report.skipped_duplicate;
+    result.imported = accepted.len();
+    result.targets = accepted;
+    result.report = report;
+    result
+}
+
+/// Type of the nearest bundled catalog object, with the emission, reflection
+/// and dark nebula subtypes folded into "Nebula"
+fn nearest_object_type(ra: f64, dec: f64) -> Option<String> {
+    let nearest = identify_object(ra, dec, ENRICH_MATCH_RADIUS_ARCMIN).into_iter().next()?;
+    let object_type = match nearest.object_type.as_str() {
+        "EmissionNebula" | "ReflectionNebula" | "DarkNebula" => "Nebula".to_string(),
+        _ => nearest.object_type,
+    };
+    Some(object_type)
+}
+
+/// Fill in constellation and object type where the source file left them
+/// unset; a miss simply leaves the field empty
+fn enrich_export_item(item: &mut TargetExportItem) {
+    if item.constellation.is_none() {
+        item.constellation = Some(constellation_for_coords(item.ra, item.dec));
+    }
+    if item.object_type.is_none() {
+        item.object_type = nearest_object_type(item.ra, item.dec);
+    }
+}
+
+/// New medium-priority target for an imported entry, tagged with its source
+fn imported_target(input: BatchTargetInput, tag: &str) -> TargetItem {
+    let mut target = new_batch_target(input, TargetPriority::Medium, vec![tag.to_string()]);
+    target.object_type = nearest_object_type(target.ra, target.dec);
+    target
+}
+
+/// Import targets from a KStars observing list (.obslist/.txt) into the target list
+#[tauri::command]
+pub async fn import_targets_kstars(
+    app: AppHandle,
+    path: String,
+) -> Result<KStarsImportResult, StorageError> {
+    let content = fs::read_to_string(&path)?;
+
+    crate::network::security::validate_size(&content, crate::network::security::limits::MAX_CSV_SIZE)
+        .map_err(|e| StorageError::Other(e.to_string()))?;
+
+    let (parsed, skipped) = parse_kstars_list(&content);
+    let imported = parsed.len();
+
+    let mut target_list = load_target_list(app.clone()).await?;
+    for target in parsed {
+        target_list.targets.push(imported_target(target, KSTARS_TAG));
+    }
+    if imported > 0 && !target_list.available_tags.iter().any(|t| t == KSTARS_TAG) {
+        target_list.available_tags.push(KSTARS_TAG.to_string());
+    }
+    save_target_list(app, target_list.clone()).await?;
+
+    log::info!("Imported {} KStars targets from {} ({} skipped)", imported, path, skipped.len());
+    Ok(KStarsImportResult { target_list, imported, skipped })
+}
+
+/// Import targets from a Stellarium `bookmarks.json` into the target list
+#[tauri::command]
+pub async fn import_targets_stellarium(
+    app: AppHandle,
+    path: String,
+) -> Result<TargetListData, StorageError> {
+    let content = fs::read_to_string(&path)?;
+
+    crate::network::security::validate_size(&content, crate::network::security::limits::MAX_JSON_SIZE)
+        .map_err(|e| StorageError::Other(e.to_string()))?;
+
+    let (parsed, skipped) = parse_stellarium_bookmarks(&content)?;
+    let imported = parsed.len();
+
+    let mut target_list = load_target_list(app.clone()).await?;
+    for target in parsed {
+        target_list.targets.push(imported_target(target, STELLARIUM_TAG));
+    }
+    if imported > 0 && !target_list.available_tags.iter().any(|t| t == STELLARIUM_TAG) {
+        target_list.available_tags.push(STELLARIUM_TAG.to_string());
+    }
+    save_target_list(app, target_list.clone()).await?;
+
+    log::info!("Imported {} Stellarium bookmarks from {} ({} skipped)", imported, path, skipped.len());
+    Ok(target_list)
+}
+
+/// Parse Stellarium bookmarks
+/// Bookmarks live under a `bookmarks` object keyed by id; each has a `name` and
+/// J2000 `ra`/`dec` given either as hms/dms strings or as numbers in radians.
+/// Bookmarks without usable coordinates are returned as skipped names.
+fn parse_stellarium_bookmarks(content: &str) -> Result<(Vec<BatchTargetInput>, Vec<String>), StorageError> {
+    let root: serde_json::Value = serde_json::from_str(content.strip_prefix('\u{FEFF}').unwrap_or(content))?;
+    let bookmarks = root
+        .get("bookmarks")
+        .and_then(|b| b.as_object())
+        .ok_or_else(|| StorageError::Other("Not a Stellarium bookmarks file".to_string()))?;
+
+    let mut targets = Vec::new();
+    let mut skipped = Vec::new();
+    for (id, bookmark) in bookmarks {
+        let name = bookmark
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .unwrap_or(id);
+        let coords = bookmark
+            .get("ra")
+            .zip(bookmark.get("dec"))
+            .and_then(|(ra, dec)| parse_stellarium_coordinates(ra, dec));
+
+        match coords {
+            Some((ra, dec, ra_string, dec_string)) => targets.push(BatchTargetInput {
+                name: name.to_string(), ra, dec, ra_string, dec_string,
+            }),
+            None => skipped.push(name.to_string()),
+        }
+    }
+    Ok((targets, skipped))
+}
+
+/// Convert a bookmark's RA/Dec (radians or sexagesimal strings) to degrees
+fn parse_stellarium_coordinates(
+    ra: &serde_json::Value,
+    dec: &serde_json::Value,
+) -> Option<(f64, f64, String, String)> {
+    match (ra, dec) {
+        (serde_json::Value::Number(ra), serde_json::Value::Number(dec)) => {
+            let (ra, dec) = validate_coordinates(
+                ra.as_f64()?.to_degrees().rem_euclid(360.0),
+                dec.as_f64()?.to_degrees(),
+            )?;
+            Some((ra, dec, format_ra_hms(ra), format_dec_dms(dec)))
+        }
+        (serde_json::Value::String(ra_str), serde_json::Value::String(dec_str)) => {
+            let (ra, dec) = parse_coordinates(ra_str, dec_str)?;
+            Some((ra, dec, ra_str.trim().to_string(), dec_str.trim().to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Export the saved target list as a SkySafari observing list (.skylist)
+#[tauri::command]
+pub async fn export_targets_skysafari(app: AppHandle, path: String) -> Result<usize, StorageError> {
+    let target_list = load_target_list(app).await?;
+    fs::write(&path, export_skysafari(&target_list.targets))?;
+    log::info!("Exported {} targets to SkySafari list {}", target_list.targets.len(), path);
+    Ok(target_list.targets.len())
+}
+
+/// Render targets in SkySafari's observing list format
+/// SkySafari lists have no priority field, so priority travels in the comment
+/// alongside any notes; coordinates are J2000 (RA in hours, Dec in degrees)
+fn export_skysafari(targets: &[TargetItem]) -> String {
+    let mut lines = vec![
+        "SkySafariObservingListVersion=3.0".to_string(),
+        "SortedBy=Default Order".to_string(),
+    ];
+    for t in targets {
+        let name = skysafari_value(&t.name);
+        let priority = match t.priority {
+            TargetPriority::Low => "low",
+            TargetPriority::Medium => "medium",
+            TargetPriority::High => "high",
+        };
+        let mut comment = format!("Priority: {}", priority);
+        if let Some(notes) = t.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+            comment.push_str("; ");
+            comment.push_str(&skysafari_value(notes));
+        }
+
+        lines.push("SkyObject=BeginObject".to_string());
+        lines.push("\tObjectID=4,-1,-1".to_string());
+        lines.push(format!("\tCommonName={}", name));
+        lines.push(format!("\tCatalogNumber={}", name));
+        lines.push(format!("\tRA={:.6}", t.ra.rem_euclid(360.0) / 15.0));
+        lines.push(format!("\tDec={:.6}", t.dec));
+        lines.push(format!("\tComment={}", comment));
+        lines.push("EndObject=SkyObject".to_string());
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Values are single-line `key=value` text, so fold line breaks into spaces
+fn skysafari_value(s: &str) -> String {
+    s.split(['\r', '\n']).filter(|p| !p.is_empty()).collect::<Vec<_>>().join(" ")
+}
+
+/// Parse a KStars observing list
+/// Each line holds an object name followed by J2000 RA and Dec, either as separate
+/// tab/comma-separated fields or as whitespace-separated sexagesimal components
+fn parse_kstars_list(content: &str) -> (Vec<BatchTargetInput>, Vec<String>) {
+    let content = content.strip_prefix('\u{FEFF}').unwrap_or(content);
+    let mut targets = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (i, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+            continue;
+        }
+
+        match parse_kstars_line(line) {
+            Some(target) => targets.push(target),
+            None => skipped.push(format!("Line {}: {}", i + 1, line)),
+        }
+    }
+
+    (targets, skipped)
+}
+
+fn parse_kstars_line(line: &str) -> Option<BatchTargetInput> {
+    let (name, ra_str, dec_str) = split_kstars_fields(line)?;
+    if name.is_empty() {
+        return None;
+    }
+
+    let ra = parse_ra_hms(ra_str.clone()).ok()?;
+    let dec = parse_dec_dms(dec_str.clone()).ok()?;
+
+    Some(BatchTargetInput { name, ra, dec, ra_string: ra_str, dec_string: dec_str })
+}
+
+fn split_kstars_fields(line: &str) -> Option<(String, String, String)> {
+    for sep in ['\t', ','] {
+        let parts: Vec<&str> = line.split(sep).map(str::trim).filter(|p| !p.is_empty()).collect();
+        if parts.len() >= 3 {
+            return Some((parts[0].to_string(), parts[1].to_string(), parts[2].to_string()));
+        }
+    }
+
+    // Whitespace-separated: "<name> HH MM SS.s +DD MM SS" or "<name> <ra> <dec>"
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if tokens.len() >= 7 {
+        let n = tokens.len();
+        let ra = tokens[n - 6..n - 3].join(" ");
+        let dec = tokens[n - 3..].join(" ");
+        if parse_ra_hms(ra.clone()).is_ok() && parse_dec_dms(dec.clone()).is_ok() {
+            return Some((tokens[..n - 6].join(" "), ra, dec));
+        }
+    }
+    if tokens.len() >= 3 {
+        let n = tokens.len();
+        return Some((tokens[..n - 2].join(" "), tokens[n - 2].to_string(), tokens[n - 1].to_string()));
+    }
+
+    None
+}
+
 fn export_csv(targets: &[TargetExportItem]) -> String {
     let mut lines = vec![
         "Name,RA,Dec,RA_HMS,Dec_DMS,Type,Constellation,Magnitude,Size,Priority,Tags,Notes".to_string(),
@@ -197,6 +542,7 @@ fn import_csv(content: &str) -> ImportTargetsResult {
             imported: 0, skipped: 0,
             errors: vec![format!("CSV exceeds max rows: {}", lines.len())],
             targets: Vec::new(),
+            report: ImportReport::default(),
         };
     }
 
@@ -257,7 +603,9 @@ fn import_csv(content: &str) -> ImportTargetsResult {
             notes: fields.get(11).map(|s| s.to_string()).filter(|s| !s.is_empty()),
         });
     }
-    ImportTargetsResult { imported: targets.len(), skipped, errors, targets }
+    ImportTargetsResult {
+        imported: targets.len(), skipped, errors, targets, report: ImportReport::default(),
+    }
 }
 
 fn export_json(targets: &[TargetExportItem]) -> Result<String, StorageError> {
@@ -266,7 +614,9 @@ fn export_json(targets: &[TargetExportItem]) -> Result<String, StorageError> {
 
 fn import_json(content: &str) -> Result<ImportTargetsResult, StorageError> {
     let targets: Vec<TargetExportItem> = serde_json::from_str(content)?;
-    Ok(ImportTargetsResult { imported: targets.len(), skipped: 0, errors: Vec::new(), targets })
+    Ok(ImportTargetsResult {
+        imported: targets.len(), skipped: 0, errors: Vec::new(), targets, report: ImportReport::default(),
+    })
 }
 
 fn export_stellarium(targets: &[TargetExportItem]) -> String {
@@ -302,7 +652,9 @@ fn import_stellarium(content: &str) -> ImportTargetsResult {
             None => { errors.push(format!("Line {}: invalid coords", i + 1)); skipped += 1; }
         }
     }
-    ImportTargetsResult { imported: targets.len(), skipped, errors, targets }
+    ImportTargetsResult {
+        imported: targets.len(), skipped, errors, targets, report: ImportReport::default(),
+    }
 }
 
 fn export_mosaic(targets: &[TargetExportItem]) -> Result<String, StorageError> {
@@ -761,6 +1113,7 @@ mod tests {
             skipped: 2,
             errors: vec!["Error 1".to_string()],
             targets: vec![],
+            report: ImportReport::default(),
         };
         
         let json = serde_json::to_string(&result).unwrap();
@@ -783,6 +1136,274 @@ mod tests {
         assert_eq!(json, "\"stellarium\"");
     }
 
+    // ------------------------------------------------------------------------
+    // Import Guard Tests
+    // ------------------------------------------------------------------------
+
+    fn guard_item(name: &str, ra: f64, dec: f64) -> TargetExportItem {
+        TargetExportItem {
+            name: name.to_string(), ra, dec, ra_string: String::new(), dec_string: String::new(),
+            object_type: None, constellation: None, magnitude: None, size: None, notes: None,
+            priority: None, tags: None,
+        }
+    }
+
+    fn guard_input(targets: Vec<TargetExportItem>) -> ImportTargetsResult {
+        ImportTargetsResult {
+            imported: targets.len(), skipped: 0, errors: Vec::new(), targets,
+            report: ImportReport::default(),
+        }
+    }
+
+    #[test]
+    fn test_import_guards_reject_out_of_range_dec() {
+        let input = guard_input(vec![guard_item("ok", 10.0, 20.0), guard_item("bad", 10.0, 95.0)]);
+        let result = apply_import_guards(input, &[], None);
+
+        assert_eq!(result.imported, 1);
+        assert_eq!(result.targets[0].name, "ok");
+        assert_eq!(result.report.added, 1);
+        assert_eq!(result.report.skipped_invalid, 1);
+        assert_eq!(result.skipped, 1);
+        assert!(result.errors[0].contains("bad"));
+    }
+
+    #[test]
+    fn test_import_guards_reject_ra_at_360() {
+        let input = guard_input(vec![guard_item("wrap", 360.0, 0.0)]);
+        let result = apply_import_guards(input, &[], None);
+        assert_eq!(result.report.skipped_invalid, 1);
+    }
+
+    #[test]
+    fn test_import_guards_skip_duplicate_of_existing() {
+        // M31 already in the list; import a copy 0.5' away and an unrelated object
+        let existing = [(10.6847, 41.2690)];
+        let input = guard_input(vec![
+            guard_item("M31 copy", 10.6847, 41.2690 + 0.5 / 60.0),
+            guard_item("M42", 83.8221, -5.3911),
+        ]);
+        let result = apply_import_guards(input, &existing, Some(1.0));
+
+        assert_eq!(result.imported, 1);
+        assert_eq!(result.targets[0].name, "M42");
+        assert_eq!(result.report.skipped_duplicate, 1);
+        assert_eq!(result.report.skipped_invalid, 0);
+    }
+
+    #[test]
+    fn test_import_guards_keep_duplicates_when_dedup_off() {
+        let existing = [(10.6847, 41.2690)];
+        let input = guard_input(vec![guard_item("M31 copy", 10.6847, 41.2690)]);
+        let result = apply_import_guards(input, &existing, None);
+        assert_eq!(result.imported, 1);
+        assert_eq!(result.report.skipped_duplicate, 0);
+    }
+
+    #[test]
+    fn test_import_guards_skip_duplicates_within_batch() {
+        let input = guard_input(vec![
+            guard_item("first", 100.0, 20.0),
+            guard_item("second", 100.0, 20.0 + 0.2 / 60.0),
+        ]);
+        let result = apply_import_guards(input, &[], Some(1.0));
+        assert_eq!(result.imported, 1);
+        assert_eq!(result.targets[0].name, "first");
+    }
+
+    // ------------------------------------------------------------------------
+    // Enrichment Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_import_enriches_constellation_and_type() {
+        let csv = "Name,RA,Dec\nOrion,83.8221,-5.3911\nEmpty sky,120.0,-80.0";
+        let mut result = import_csv(csv);
+        result.targets.iter_mut().for_each(enrich_export_item);
+
+        assert_eq!(result.targets[0].constellation.as_deref(), Some("Orion"));
+        assert_eq!(result.targets[0].object_type.as_deref(), Some("Nebula"));
+        // No catalog object nearby: the constellation is still filled in
+        assert!(result.targets[1].constellation.is_some());
+        assert!(result.targets[1].object_type.is_none());
+    }
+
+    #[test]
+    fn test_enrichment_keeps_values_from_file() {
+        let csv = "Name,RA,Dec,RA_HMS,Dec_DMS,Type,Constellation\nM42,83.8221,-5.3911,,,HII,Ori";
+        let mut result = import_csv(csv);
+        enrich_export_item(&mut result.targets[0]);
+
+        assert_eq!(result.targets[0].object_type.as_deref(), Some("HII"));
+        assert_eq!(result.targets[0].constellation.as_deref(), Some("Ori"));
+    }
+
+    #[test]
+    fn test_imported_list_target_is_enriched() {
+        let input = BatchTargetInput {
+            name: "M 42".to_string(),
+            ra: 83.8221,
+            dec: -5.3911,
+            ra_string: String::new(),
+            dec_string: String::new(),
+        };
+        let target = imported_target(input, KSTARS_TAG);
+
+        assert_eq!(target.constellation.as_deref(), Some("Orion"));
+        assert_eq!(target.object_type.as_deref(), Some("Nebula"));
+        assert_eq!(target.tags, vec![KSTARS_TAG.to_string()]);
+    }
+
+    // ------------------------------------------------------------------------
+    // KStars Import Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_kstars_list() {
+        let content = "# KStars observing list\n\
+                       M 31\t00:42:44.3\t+41:16:09\n\
+                       NGC 7000  20 58 48.0  +44 20 00\n\
+                       M 42 05h35m17.3s -05:23:28\n\
+                       garbage line\n\
+                       \n\
+                       Bad RA\t27:00:00\t+10:00:00\n";
+        let (targets, skipped) = parse_kstars_list(content);
+
+        assert_eq!(targets.len(), 3);
+        assert_eq!(targets[0].name, "M 31");
+        assert!(approx_eq(targets[0].ra, (42.0 / 60.0 + 44.3 / 3600.0) * 15.0));
+        assert!(approx_eq(targets[0].dec, 41.0 + 16.0 / 60.0 + 9.0 / 3600.0));
+        assert_eq!(targets[1].name, "NGC 7000");
+        assert!(approx_eq(targets[1].ra, (20.0 + 58.0 / 60.0 + 48.0 / 3600.0) * 15.0));
+        assert_eq!(targets[2].name, "M 42");
+        assert!(targets[2].dec < 0.0);
+
+        assert_eq!(skipped.len(), 2);
+        assert!(skipped[0].contains("garbage"));
+        assert!(skipped[1].starts_with("Line 7"));
+    }
+
+    // ------------------------------------------------------------------------
+    // Stellarium Bookmarks Import Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_stellarium_bookmarks() {
+        let content = r#"{
+            "bookmarks": {
+                "{1b2c}": {
+                    "name": "M31",
+                    "nameI18n": "Andromeda Galaxy",
+                    "ra": "0h42m44.30s",
+                    "dec": "+41°16'09.0\"",
+                    "isVisibleMarker": false
+                },
+                "{3d4e}": {
+                    "name": "M42",
+                    "ra": 1.4633,
+                    "dec": -0.0941
+                },
+                "{5f6a}": {
+                    "name": "Jupiter"
+                }
+            }
+        }"#;
+        let (targets, skipped) = parse_stellarium_bookmarks(content).unwrap();
+
+        assert_eq!(targets.len(), 2);
+        let m31 = targets.iter().find(|t| t.name == "M31").unwrap();
+        assert!(approx_eq(m31.ra, (42.0 / 60.0 + 44.3 / 3600.0) * 15.0));
+        assert!(approx_eq(m31.dec, 41.0 + 16.0 / 60.0 + 9.0 / 3600.0));
+        assert_eq!(m31.ra_string, "0h42m44.30s");
+
+        let m42 = targets.iter().find(|t| t.name == "M42").unwrap();
+        assert!(approx_eq(m42.ra, 1.4633_f64.to_degrees()));
+        assert!(approx_eq(m42.dec, -0.0941_f64.to_degrees()));
+        assert!(!m42.ra_string.is_empty());
+
+        assert_eq!(skipped, vec!["Jupiter"]);
+    }
+
+    #[test]
+    fn test_parse_stellarium_bookmarks_rejects_other_json() {
+        assert!(parse_stellarium_bookmarks("[]").is_err());
+        assert!(parse_stellarium_bookmarks("not json").is_err());
+    }
+
+    // ------------------------------------------------------------------------
+    // SkySafari Export Tests
+    // ------------------------------------------------------------------------
+
+    /// Minimal SkySafari list reader: checks the header and object framing and
+    /// returns the key/value pairs of each object
+    fn parse_skysafari(content: &str) -> Result<Vec<Vec<(String, String)>>, String> {
+        let mut lines = content.lines().filter(|l| !l.trim().is_empty());
+        let header = lines.next().ok_or("empty list")?;
+        if !header.starts_with("SkySafariObservingListVersion=") {
+            return Err(format!("bad header: {}", header));
+        }
+        let mut objects = Vec::new();
+        let mut current: Option<Vec<(String, String)>> = None;
+        for line in lines {
+            let (key, value) = line.trim().split_once('=').ok_or(format!("not key=value: {}", line))?;
+            match (key, value, current.as_mut()) {
+                ("SkyObject", "BeginObject", None) => current = Some(Vec::new()),
+                ("EndObject", "SkyObject", Some(_)) => objects.push(current.take().unwrap()),
+                ("SkyObject", _, _) | ("EndObject", _, _) => return Err(format!("unbalanced: {}", line)),
+                (_, _, Some(fields)) => fields.push((key.to_string(), value.to_string())),
+                (_, _, None) => {}
+            }
+        }
+        if current.is_some() {
+            return Err("unterminated object".to_string());
+        }
+        Ok(objects)
+    }
+
+    fn skysafari_target(name: &str, ra: f64, dec: f64, priority: TargetPriority) -> TargetItem {
+        let input = BatchTargetInput {
+            name: name.to_string(),
+            ra,
+            dec,
+            ra_string: String::new(),
+            dec_string: String::new(),
+        };
+        new_batch_target(input, priority, Vec::new())
+    }
+
+    #[test]
+    fn test_export_skysafari_is_valid_list() {
+        let mut m42 = skysafari_target("M 42", 83.82, -5.39, TargetPriority::Medium);
+        m42.notes = Some("Trapezium\nshort subs".to_string());
+        let targets = vec![
+            skysafari_target("M 31", 10.6847, 41.2690, TargetPriority::High),
+            m42,
+            skysafari_target("NGC 7000", 314.75, 44.33, TargetPriority::Low),
+        ];
+
+        let content = export_skysafari(&targets);
+        let objects = parse_skysafari(&content).unwrap();
+        assert_eq!(objects.len(), 3);
+
+        let field = |obj: &Vec<(String, String)>, key: &str| {
+            obj.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone()).unwrap()
+        };
+        for (obj, target) in objects.iter().zip(&targets) {
+            assert_eq!(field(obj, "CommonName"), target.name);
+            assert_eq!(field(obj, "CatalogNumber"), target.name);
+        }
+        assert!(approx_eq(field(&objects[0], "RA").parse::<f64>().unwrap(), 10.6847 / 15.0));
+        assert!(approx_eq(field(&objects[1], "Dec").parse::<f64>().unwrap(), -5.39));
+        assert!(field(&objects[0], "Comment").contains("Priority: high"));
+        assert_eq!(field(&objects[1], "Comment"), "Priority: medium; Trapezium short subs");
+    }
+
+    #[test]
+    fn test_export_skysafari_empty_list() {
+        let objects = parse_skysafari(&export_skysafari(&[])).unwrap();
+        assert!(objects.is_empty());
+    }
+
     // ------------------------------------------------------------------------
     // Edge Cases
     // ------------------------------------------------------------------------
@@ -5,9 +5,20 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
-
-use super::storage::StorageError;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::equipment::{get_default_camera, get_default_telescope, Camera, Telescope};
+use super::locations::load_locations;
+use super::storage::{load_json_store, write_json_atomic, StorageError};
+use crate::astronomy::{
+    constellation_for_coords, find_catalog_object, CatalogObject,
+    format_dec_dms, format_ra_hms, parse_dec_dms, parse_ra_hms, VisibilityInfo,
+};
+use crate::astronomy::calculations::{
+    calculate_visibility_with_context, calculate_visibility_with_horizon, equatorial_to_horizontal_with_context, SkyContext,
+};
+use crate::astronomy::events::tonight_dark_window;
+use crate::mount::mount_estimate_slew_time;
 use crate::utils::generate_id;
 
 // ============================================================================
@@ -108,6 +119,12 @@ pub struct TargetItem {
     pub ra_string: String,
     #[serde(default)]
     pub dec_string: String,
+    // Constellation containing the target, computed on add
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constellation: Option<String>,
+    // Catalog object type of the nearest bundled object, filled in on import
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object_type: Option<String>,
     // Camera/FOV settings at time of adding
     pub sensor_width: Option<f64>,
     pub sensor_height: Option<f64>,
@@ -117,6 +134,9 @@ pub struct TargetItem {
     pub mosaic: Option<MosaicSettings>,
     // Exposure plan
     pub exposure_plan: Option<ExposurePlan>,
+    // Integration captured so far, minutes
+    #[serde(default)]
+    pub captured_exposure_minutes: f64,
     // Notes
     pub notes: Option<String>,
     // Timestamps
@@ -140,6 +160,14 @@ pub struct TargetListData {
     pub active_target_id: Option<String>,
 }
 
+/// Target list with each target's visibility, in the same order as `targets`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetListWithVisibility {
+    #[serde(flatten)]
+    pub target_list: TargetListData,
+    pub visibility: Vec<VisibilityInfo>,
+}
+
 /// Target input for adding new targets
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TargetInput {
@@ -173,6 +201,30 @@ pub struct BatchTargetInput {
     pub dec_string: String,
 }
 
+/// Progress of an observable window recomputation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowRecomputeProgress {
+    pub target_id: String,
+    pub completed: u32,
+    pub total: u32,
+}
+
+/// One slot of a generated observing schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledTarget {
+    pub target_id: String,
+    pub name: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Slew from the previous target (0 for the first)
+    pub slew_seconds: f64,
+    pub integration_minutes: f64,
+    pub max_altitude: f64,
+}
+
+/// Event emitted after each target's observable window is recomputed
+pub const WINDOW_RECOMPUTE_PROGRESS_EVENT: &str = "window-recompute-progress";
+
 // ============================================================================
 // Path Helpers
 // ============================================================================
@@ -192,6 +244,39 @@ fn get_target_list_path(app: &AppHandle) -> Result<PathBuf, StorageError> {
     Ok(dir.join("target_list.json"))
 }
 
+/// Build a new planned target from batch input
+pub(crate) fn new_batch_target(
+    target: BatchTargetInput,
+    priority: TargetPriority,
+    tags: Vec<String>,
+) -> TargetItem {
+    TargetItem {
+        id: generate_id("target"),
+        constellation: Some(constellation_for_coords(target.ra, target.dec)),
+        object_type: None,
+        name: target.name,
+        ra: target.ra,
+        dec: target.dec,
+        ra_string: target.ra_string,
+        dec_string: target.dec_string,
+        sensor_width: None,
+        sensor_height: None,
+        focal_length: None,
+        rotation_angle: None,
+        mosaic: None,
+        exposure_plan: None,
+        captured_exposure_minutes: 0.0,
+        notes: None,
+        added_at: Utc::now().timestamp_millis(),
+        priority,
+        status: TargetStatus::Planned,
+        tags,
+        observable_window: None,
+        is_favorite: false,
+        is_archived: false,
+    }
+}
+
 // ============================================================================
 // Tauri Commands
 // ============================================================================
@@ -200,26 +285,66 @@ fn get_target_list_path(app: &AppHandle) -> Result<PathBuf, StorageError> {
 #[tauri::command]
 pub async fn load_target_list(app: AppHandle) -> Result<TargetListData, StorageError> {
     let path = get_target_list_path(&app)?;
+    load_json_store(&app, &path, || TargetListData {
+        targets: Vec::new(),
+        available_tags: vec![
+            "galaxy".to_string(),
+            "nebula".to_string(),
+            "cluster".to_string(),
+            "planetary".to_string(),
+            "tonight".to_string(),
+            "priority".to_string(),
+        ],
+        active_target_id: None,
+    })
+}
 
-    if !path.exists() {
-        return Ok(TargetListData {
-            targets: Vec::new(),
-            available_tags: vec![
-                "galaxy".to_string(),
-                "nebula".to_string(),
-                "cluster".to_string(),
-                "planetary".to_string(),
-                "tonight".to_string(),
-                "priority".to_string(),
-            ],
-            active_target_id: None,
-        });
-    }
-
-    let data = fs::read_to_string(&path)?;
-    let target_list: TargetListData = serde_json::from_str(&data)?;
+/// Load target list together with every target's visibility at `timestamp`.
+/// With a `location_id`, rise/set follow that location's horizon profile.
+#[tauri::command]
+pub async fn load_target_list_with_visibility(
+    app: AppHandle,
+    latitude: f64,
+    longitude: f64,
+    timestamp: i64,
+    location_id: Option<String>,
+) -> Result<TargetListWithVisibility, StorageError> {
+    let target_list = load_target_list(app.clone()).await?;
+    let horizon_profile = match location_id {
+        Some(id) => load_locations(app)
+            .await?
+            .locations
+            .into_iter()
+            .find(|location| location.id == id)
+            .ok_or_else(|| StorageError::Other(format!("Location not found: {}", id)))?
+            .horizon_profile
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+    let visibility = target_visibilities(&target_list.targets, latitude, longitude, timestamp, &horizon_profile);
+    Ok(TargetListWithVisibility { target_list, visibility })
+}
 
-    Ok(target_list)
+/// Visibility for each target, sharing one sidereal-time context across the
+/// list; a non-empty (normalized) horizon profile replaces the flat horizon
+fn target_visibilities(
+    targets: &[TargetItem],
+    latitude: f64,
+    longitude: f64,
+    timestamp: i64,
+    horizon_profile: &[(f64, f64)],
+) -> Vec<VisibilityInfo> {
+    let context = SkyContext::new(timestamp, longitude);
+    targets
+        .iter()
+        .map(|t| {
+            if horizon_profile.is_empty() {
+                calculate_visibility_with_context(t.ra, t.dec, latitude, &context, None)
+            } else {
+                calculate_visibility_with_horizon(t.ra, t.dec, latitude, &context, None, horizon_profile)
+            }
+        })
+        .collect()
 }
 
 /// Save target list
@@ -229,8 +354,7 @@ pub async fn save_target_list(
     target_list: TargetListData,
 ) -> Result<(), StorageError> {
     let path = get_target_list_path(&app)?;
-    let json = serde_json::to_string_pretty(&target_list)?;
-    fs::write(&path, json)?;
+    write_json_atomic(&path, &target_list)?;
 
     log::info!("Saved target list to {:?}", path);
     Ok(())
@@ -242,10 +366,27 @@ pub async fn add_target(
     app: AppHandle,
     target: TargetInput,
 ) -> Result<TargetListData, StorageError> {
+    let mut target = normalize_target_coordinates(target)?;
+    let camera = if target.sensor_width.is_none() && target.sensor_height.is_none() {
+        best_effort_default(get_default_camera(app.clone()).await, "camera")
+    } else {
+        None
+    };
+    let telescope = if target.focal_length.is_none() {
+        best_effort_default(get_default_telescope(app.clone()).await, "telescope")
+    } else {
+        None
+    };
+    apply_default_equipment(&mut target, telescope.as_ref(), camera.as_ref());
+    if let Some(plan) = target.exposure_plan.as_mut() {
+        recompute_exposure_totals(plan);
+    }
     let mut data = load_target_list(app.clone()).await?;
 
     let new_target = TargetItem {
         id: generate_id("target"),
+        constellation: Some(constellation_for_coords(target.ra, target.dec)),
+        object_type: None,
         name: target.name,
         ra: target.ra,
         dec: target.dec,
@@ -257,6 +398,7 @@ pub async fn add_target(
         rotation_angle: target.rotation_angle,
         mosaic: target.mosaic,
         exposure_plan: target.exposure_plan,
+        captured_exposure_minutes: 0.0,
         notes: target.notes,
         added_at: Utc::now().timestamp_millis(),
         priority: target.priority.unwrap_or(TargetPriority::Medium),
@@ -273,6 +415,191 @@ pub async fn add_target(
     Ok(data)
 }
 
+/// Keep `total_exposure` (minutes) in step with its components whenever both
+/// the single exposure length and the sub-frame count are set
+fn recompute_exposure_totals(plan: &mut ExposurePlan) {
+    if plan.single_exposure > 0.0 && plan.sub_frames > 0 {
+        plan.total_exposure = plan.single_exposure * plan.sub_frames as f64 / 60.0;
+    }
+}
+
+/// Apply an `exposure_plan` update: `null` clears the plan, an object is
+/// merged field by field into the existing plan (or parsed as a new one).
+/// `advanced` is replaced as a whole; invalid values reject the update.
+fn apply_exposure_plan_update(target: &mut TargetItem, value: &serde_json::Value) -> Result<(), StorageError> {
+    if value.is_null() {
+        target.exposure_plan = None;
+        return Ok(());
+    }
+    let invalid = |e: String| StorageError::Other(format!("Invalid exposure plan update: {}", e));
+
+    match target.exposure_plan.as_mut() {
+        Some(plan) => {
+            if let Some(single) = value.get("single_exposure").and_then(|v| v.as_f64()) {
+                plan.single_exposure = single;
+            }
+            if let Some(subs) = value.get("sub_frames") {
+                plan.sub_frames = subs
+                    .as_u64()
+                    .and_then(|n| u32::try_from(n).ok())
+                    .ok_or_else(|| invalid(format!("sub_frames must be a whole number of frames, got {}", subs)))?;
+            }
+            if let Some(total) = value.get("total_exposure").and_then(|v| v.as_f64()) {
+                plan.total_exposure = total;
+            }
+            if let Some(filter) = value.get("filter") {
+                plan.filter = filter.as_str().map(|s| s.to_string());
+            }
+            if let Some(advanced) = value.get("advanced") {
+                plan.advanced = serde_json::from_value(advanced.clone()).map_err(|e| invalid(e.to_string()))?;
+            }
+        }
+        None => {
+            let plan = serde_json::from_value::<ExposurePlan>(value.clone()).map_err(|e| invalid(e.to_string()))?;
+            target.exposure_plan = Some(plan);
+        }
+    }
+
+    if let Some(plan) = target.exposure_plan.as_mut() {
+        recompute_exposure_totals(plan);
+    }
+    Ok(())
+}
+
+/// A default equipment lookup that failed leaves the fields empty rather
+/// than failing the add
+fn best_effort_default<T>(result: Result<Option<T>, StorageError>, kind: &str) -> Option<T> {
+    result.unwrap_or_else(|e| {
+        log::warn!("Could not load default {} for new target: {}", kind, e);
+        None
+    })
+}
+
+/// Fill sensor size and focal length the input left empty from the default
+/// camera and telescope; explicit values are kept. The sensor size is taken
+/// only as a pair, so an explicit width is never matched with another height
+fn apply_default_equipment(target: &mut TargetInput, telescope: Option<&Telescope>, camera: Option<&Camera>) {
+    if let Some(camera) = camera {
+        if target.sensor_width.is_none() && target.sensor_height.is_none() {
+            target.sensor_width = Some(camera.sensor_width);
+            target.sensor_height = Some(camera.sensor_height);
+        }
+    }
+    if let Some(telescope) = telescope {
+        target.focal_length = target.focal_length.or(Some(telescope.focal_length));
+    }
+}
+
+/// Derive missing degrees from the sexagesimal strings, wrap RA into [0, 360)
+/// and reject declinations outside [-90, 90]. A string is regenerated when it
+/// is empty or RA had to be wrapped, so it always agrees with the degrees
+fn normalize_coordinates(
+    ra: &mut f64,
+    dec: &mut f64,
+    ra_string: &mut String,
+    dec_string: &mut String,
+) -> Result<(), StorageError> {
+    if *ra == 0.0 && !ra_string.trim().is_empty() {
+        *ra = parse_ra_hms(ra_string.clone()).map_err(StorageError::InvalidCoordinates)?;
+    }
+    if *dec == 0.0 && !dec_string.trim().is_empty() {
+        *dec = parse_dec_dms(dec_string.clone()).map_err(StorageError::InvalidCoordinates)?;
+    }
+
+    if !ra.is_finite() || !dec.is_finite() {
+        return Err(StorageError::InvalidCoordinates(format!(
+            "RA/Dec must be finite, got {} / {}",
+            ra, dec
+        )));
+    }
+    if dec.abs() > 90.0 {
+        return Err(StorageError::InvalidCoordinates(format!(
+            "Dec {} is outside [-90, 90]",
+            dec
+        )));
+    }
+    let wrapped = ra.rem_euclid(360.0);
+    if wrapped != *ra || ra_string.trim().is_empty() {
+        *ra_string = format_ra_hms(wrapped);
+    }
+    *ra = wrapped;
+    if dec_string.trim().is_empty() {
+        *dec_string = format_dec_dms(*dec);
+    }
+
+    Ok(())
+}
+
+fn normalize_target_coordinates(mut target: TargetInput) -> Result<TargetInput, StorageError> {
+    normalize_coordinates(&mut target.ra, &mut target.dec, &mut target.ra_string, &mut target.dec_string)?;
+    Ok(target)
+}
+
+fn normalize_batch_coordinates(mut target: BatchTargetInput) -> Result<BatchTargetInput, StorageError> {
+    normalize_coordinates(&mut target.ra, &mut target.dec, &mut target.ra_string, &mut target.dec_string)?;
+    Ok(target)
+}
+
+/// Apply `ra`/`dec` updates (degrees), normalized like new targets; the
+/// sexagesimal strings and constellation are rebuilt from the new position
+fn apply_coordinate_update(target: &mut TargetItem, updates: &serde_json::Value) -> Result<(), StorageError> {
+    let new_ra = updates.get("ra").and_then(|v| v.as_f64());
+    let new_dec = updates.get("dec").and_then(|v| v.as_f64());
+    if new_ra.is_none() && new_dec.is_none() {
+        return Ok(());
+    }
+
+    let mut ra = new_ra.unwrap_or(target.ra);
+    let mut dec = new_dec.unwrap_or(target.dec);
+    let (mut ra_string, mut dec_string) = (String::new(), String::new());
+    normalize_coordinates(&mut ra, &mut dec, &mut ra_string, &mut dec_string)?;
+
+    target.ra = ra;
+    target.dec = dec;
+    target.ra_string = ra_string;
+    target.dec_string = dec_string;
+    target.constellation = Some(constellation_for_coords(ra, dec));
+    Ok(())
+}
+
+/// Add a target from the bundled deep-sky catalog by id (e.g. "M42")
+#[tauri::command]
+pub async fn add_target_from_catalog(
+    app: AppHandle,
+    object_id: String,
+) -> Result<TargetListData, StorageError> {
+    let object = find_catalog_object(&object_id)
+        .ok_or_else(|| StorageError::Other(format!("Unknown catalog object: {}", object_id)))?;
+
+    add_target(app, catalog_target_input(&object)).await
+}
+
+/// Build a target input from a catalog object
+fn catalog_target_input(object: &CatalogObject) -> TargetInput {
+    let name = if object.name == object.designation {
+        object.designation.clone()
+    } else {
+        format!("{} ({})", object.designation, object.name)
+    };
+
+    TargetInput {
+        name,
+        ra: object.ra,
+        dec: object.dec,
+        ra_string: format_ra_hms(object.ra),
+        dec_string: format_dec_dms(object.dec),
+        sensor_width: None,
+        sensor_height: None,
+        focal_length: None,
+        rotation_angle: None,
+        mosaic: None,
+        exposure_plan: None,
+        notes: None,
+        priority: None,
+        tags: Some(vec![object.object_type.clone()]),
+    }
+}
+
 /// Add multiple targets in batch
 #[tauri::command]
 pub async fn add_targets_batch(
@@ -281,32 +608,18 @@ pub async fn add_targets_batch(
     default_priority: Option<TargetPriority>,
     default_tags: Option<Vec<String>>,
 ) -> Result<TargetListData, StorageError> {
+    let targets = targets
+        .into_iter()
+        .map(normalize_batch_coordinates)
+        .collect::<Result<Vec<_>, _>>()?;
     let mut data = load_target_list(app.clone()).await?;
 
     for target in targets {
-        let new_target = TargetItem {
-            id: generate_id("target"),
-            name: target.name,
-            ra: target.ra,
-            dec: target.dec,
-            ra_string: target.ra_string,
-            dec_string: target.dec_string,
-            sensor_width: None,
-            sensor_height: None,
-            focal_length: None,
-            rotation_angle: None,
-            mosaic: None,
-            exposure_plan: None,
-            notes: None,
-            added_at: Utc::now().timestamp_millis(),
-            priority: default_priority.clone().unwrap_or(TargetPriority::Medium),
-            status: TargetStatus::Planned,
-            tags: default_tags.clone().unwrap_or_default(),
-            observable_window: None,
-            is_favorite: false,
-            is_archived: false,
-        };
-        data.targets.push(new_target);
+        data.targets.push(new_batch_target(
+            target,
+            default_priority.clone().unwrap_or(TargetPriority::Medium),
+            default_tags.clone().unwrap_or_default(),
+        ));
     }
 
     save_target_list(app, data.clone()).await?;
@@ -325,6 +638,7 @@ pub async fn update_target(
 
     if let Some(target) = data.targets.iter_mut().find(|t| t.id == target_id) {
         // Apply updates from JSON
+        apply_coordinate_update(target, &updates)?;
         if let Some(name) = updates.get("name").and_then(|v| v.as_str()) {
             target.name = name.to_string();
         }
@@ -357,6 +671,9 @@ pub async fn update_target(
                 .filter_map(|v| v.as_str().map(|s| s.to_string()))
                 .collect();
         }
+        if let Some(plan) = updates.get("exposure_plan") {
+            apply_exposure_plan_update(target, plan)?;
+        }
     }
 
     save_target_list(app, data.clone()).await?;
@@ -479,6 +796,42 @@ pub async fn set_targets_status_batch(
     Ok(data)
 }
 
+/// Add captured integration to a target, moving it to `InProgress` once work
+/// starts and to `Completed` once the exposure plan's total is reached
+#[tauri::command]
+pub async fn record_target_progress(
+    app: AppHandle,
+    target_id: String,
+    minutes: f64,
+) -> Result<TargetListData, StorageError> {
+    if !minutes.is_finite() || minutes <= 0.0 {
+        return Err(StorageError::Other("Captured minutes must be positive".to_string()));
+    }
+
+    let mut data = load_target_list(app.clone()).await?;
+    let target = data
+        .targets
+        .iter_mut()
+        .find(|t| t.id == target_id)
+        .ok_or_else(|| StorageError::Other(format!("Target not found: {}", target_id)))?;
+    apply_captured_minutes(target, minutes);
+
+    save_target_list(app, data.clone()).await?;
+
+    Ok(data)
+}
+
+fn apply_captured_minutes(target: &mut TargetItem, minutes: f64) {
+    target.captured_exposure_minutes += minutes;
+
+    let planned = target.exposure_plan.as_ref().map(|plan| plan.total_exposure);
+    if planned.is_some_and(|total| total > 0.0 && target.captured_exposure_minutes >= total) {
+        target.status = TargetStatus::Completed;
+    } else if matches!(target.status, TargetStatus::Planned) {
+        target.status = TargetStatus::InProgress;
+    }
+}
+
 /// Set priority for multiple targets
 #[tauri::command]
 pub async fn set_targets_priority_batch(
@@ -555,6 +908,72 @@ pub async fn remove_tag_from_targets(
     Ok(data)
 }
 
+/// Rename a tag on every target and in the available tags list
+#[tauri::command]
+pub async fn rename_tag(
+    app: AppHandle,
+    old: String,
+    new: String,
+) -> Result<TargetListData, StorageError> {
+    let new = new.trim().to_string();
+    if new.is_empty() {
+        return Err(StorageError::Other("Tag name cannot be empty".to_string()));
+    }
+
+    let mut data = load_target_list(app.clone()).await?;
+    apply_tag_rename(&mut data, &old, &new);
+    save_target_list(app, data.clone()).await?;
+
+    Ok(data)
+}
+
+/// Delete a tag from every target and from the available tags list
+#[tauri::command]
+pub async fn delete_tag(app: AppHandle, tag: String) -> Result<TargetListData, StorageError> {
+    let mut data = load_target_list(app.clone()).await?;
+    apply_tag_delete(&mut data, &tag);
+    save_target_list(app, data.clone()).await?;
+
+    Ok(data)
+}
+
+/// Replace `old` with `new` everywhere, merging into `new` if it already exists
+fn apply_tag_rename(data: &mut TargetListData, old: &str, new: &str) {
+    if old == new {
+        return;
+    }
+
+    for target in &mut data.targets {
+        if let Some(pos) = target.tags.iter().position(|t| t == old) {
+            if target.tags.iter().any(|t| t == new) {
+                target.tags.remove(pos);
+            } else {
+                target.tags[pos] = new.to_string();
+            }
+        }
+    }
+
+    match data.available_tags.iter().position(|t| t == old) {
+        Some(pos) if !data.available_tags.iter().any(|t| t == new) => {
+            data.available_tags[pos] = new.to_string();
+        }
+        Some(pos) => {
+            data.available_tags.remove(pos);
+        }
+        None if !data.available_tags.iter().any(|t| t == new) => {
+            data.available_tags.push(new.to_string());
+        }
+        None => {}
+    }
+}
+
+fn apply_tag_delete(data: &mut TargetListData, tag: &str) {
+    for target in &mut data.targets {
+        target.tags.retain(|t| t != tag);
+    }
+    data.available_tags.retain(|t| t != tag);
+}
+
 /// Archive all completed targets
 #[tauri::command]
 pub async fn archive_completed_targets(app: AppHandle) -> Result<TargetListData, StorageError> {
@@ -673,6 +1092,15 @@ pub async fn get_target_stats(app: AppHandle) -> Result<TargetStats, StorageErro
         }
     }
 
+    let captured_hours = data.targets.iter().map(|t| t.captured_exposure_minutes).sum::<f64>() / 60.0;
+    let planned_hours = data
+        .targets
+        .iter()
+        .filter_map(|t| t.exposure_plan.as_ref())
+        .map(|plan| plan.total_exposure)
+        .sum::<f64>()
+        / 60.0;
+
     Ok(TargetStats {
         total,
         planned,
@@ -684,9 +1112,225 @@ pub async fn get_target_stats(app: AppHandle) -> Result<TargetStats, StorageErro
         medium_priority,
         low_priority,
         by_tag: tag_counts.into_iter().collect(),
+        captured_hours,
+        planned_hours,
+    })
+}
+
+/// Recompute the observable window of every non-archived target for a site
+/// and date (YYYY-MM-DD), emitting progress as it goes. Returns the number of
+/// targets updated.
+#[tauri::command]
+pub async fn recompute_all_observable_windows(
+    app: AppHandle,
+    latitude: f64,
+    longitude: f64,
+    date: String,
+) -> Result<u32, StorageError> {
+    let timestamp = local_noon_timestamp(&date, longitude)?;
+    let mut data = load_target_list(app.clone()).await?;
+
+    let updated = recompute_observable_windows(&mut data, latitude, longitude, timestamp, |progress| {
+        let _ = app.emit(WINDOW_RECOMPUTE_PROGRESS_EVENT, progress);
+    });
+
+    save_target_list(app, data).await?;
+    Ok(updated)
+}
+
+/// Approximate local noon of a date (YYYY-MM-DD) at a longitude, as a Unix timestamp
+fn local_noon_timestamp(date: &str, longitude: f64) -> Result<i64, StorageError> {
+    let noon = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|e| StorageError::Other(format!("Invalid date format: {}", e)))?
+        .and_hms_opt(12, 0, 0)
+        .ok_or_else(|| StorageError::Other(format!("Invalid date: {}", date)))?
+        .and_utc()
+        .timestamp();
+    Ok(noon - (longitude / 15.0 * 3600.0) as i64)
+}
+
+/// Observable window of a target on the day containing `timestamp`;
+/// `None` if it never rises
+fn compute_observable_window(
+    target: &TargetItem,
+    latitude: f64,
+    longitude: f64,
+    timestamp: i64,
+) -> Option<ObservableWindow> {
+    let visibility = calculate_visibility_with_context(
+        target.ra,
+        target.dec,
+        latitude,
+        &SkyContext::new(timestamp, longitude),
+        None,
+    );
+    let transit = visibility.transit_time?;
+    let (start, end) = if visibility.is_circumpolar {
+        (transit - 12 * 3600, transit + 12 * 3600)
+    } else {
+        (visibility.rise_time?, visibility.set_time?)
+    };
+
+    Some(ObservableWindow {
+        start: DateTime::from_timestamp(start, 0)?,
+        end: DateTime::from_timestamp(end, 0)?,
+        max_altitude: visibility.transit_altitude,
+        transit_time: DateTime::from_timestamp(transit, 0)?,
+        is_circumpolar: visibility.is_circumpolar,
     })
 }
 
+/// Recompute observable windows for all non-archived targets in place,
+/// reporting progress after each one
+fn recompute_observable_windows(
+    data: &mut TargetListData,
+    latitude: f64,
+    longitude: f64,
+    timestamp: i64,
+    mut on_progress: impl FnMut(WindowRecomputeProgress),
+) -> u32 {
+    let total = data.targets.iter().filter(|t| !t.is_archived).count() as u32;
+    let mut completed = 0;
+
+    for target in data.targets.iter_mut().filter(|t| !t.is_archived) {
+        target.observable_window = compute_observable_window(target, latitude, longitude, timestamp);
+        completed += 1;
+        on_progress(WindowRecomputeProgress {
+            target_id: target.id.clone(),
+            completed,
+            total,
+        });
+    }
+
+    completed
+}
+
+/// Order tonight's observable targets into non-overlapping slots within the
+/// darkness window of `date` (YYYY-MM-DD)
+#[tauri::command]
+pub async fn generate_observing_schedule(
+    app: AppHandle,
+    latitude: f64,
+    longitude: f64,
+    date: String,
+    min_altitude: f64,
+    slew_rate_deg_per_sec: f64,
+) -> Result<Vec<ScheduledTarget>, StorageError> {
+    let noon = local_noon_timestamp(&date, longitude)?;
+    let noon = DateTime::from_timestamp(noon, 0)
+        .ok_or_else(|| StorageError::Other(format!("Invalid date: {}", date)))?;
+    let data = load_target_list(app).await?;
+
+    // No darkness (e.g. polar summer) means nothing to schedule
+    let Some(window) = tonight_dark_window(latitude, longitude, noon) else {
+        return Ok(Vec::new());
+    };
+    Ok(build_observing_schedule(&data.targets, latitude, longitude, window, min_altitude, slew_rate_deg_per_sec))
+}
+
+/// Altitude sampling step for scheduling
+const SCHEDULE_STEP_SECONDS: i64 = 300;
+/// Integration planned for targets without an exposure plan
+const DEFAULT_SCHEDULE_MINUTES: f64 = 60.0;
+/// Slots shorter than this are not worth a slew
+const MIN_SLOT_SECONDS: i64 = 15 * 60;
+
+/// Greedy scheduler: repeatedly picks the target that yields the most
+/// integration from the current time, less the time lost slewing and
+/// waiting for it to clear `min_altitude`
+fn build_observing_schedule(
+    targets: &[TargetItem],
+    latitude: f64,
+    longitude: f64,
+    (dark_start, dark_end): (i64, i64),
+    min_altitude: f64,
+    slew_rate_deg_per_sec: f64,
+) -> Vec<ScheduledTarget> {
+    let contexts: Vec<SkyContext> = (dark_start..=dark_end)
+        .step_by(SCHEDULE_STEP_SECONDS as usize)
+        .map(|ts| SkyContext::new(ts, longitude))
+        .collect();
+
+    // Altitude track per target; never-rises targets drop out here
+    let mut remaining: Vec<(&TargetItem, Vec<f64>)> = targets
+        .iter()
+        .filter(|t| !t.is_archived)
+        .map(|t| {
+            let track = contexts
+                .iter()
+                .map(|ctx| equatorial_to_horizontal_with_context(t.ra, t.dec, latitude, ctx, true).alt)
+                .collect::<Vec<f64>>();
+            (t, track)
+        })
+        .filter(|(_, track)| track.iter().any(|alt| *alt >= min_altitude))
+        .collect();
+
+    let sample_index = |ts: i64| ((ts - dark_start) / SCHEDULE_STEP_SECONDS) as usize;
+    let mut schedule = Vec::new();
+    let mut now = dark_start;
+    let mut position: Option<(f64, f64)> = None;
+
+    while now < dark_end && !remaining.is_empty() {
+        let mut best: Option<(usize, i64, i64, f64, i64)> = None;
+
+        for (i, (target, track)) in remaining.iter().enumerate() {
+            let slew = position
+                .map(|(ra, dec)| mount_estimate_slew_time(ra, dec, target.ra, target.dec, slew_rate_deg_per_sec))
+                .unwrap_or(0.0);
+            let ready = now + slew.ceil() as i64;
+
+            // First sample at or after `ready` with the target high enough
+            let Some(first) = (sample_index(ready + SCHEDULE_STEP_SECONDS - 1)..track.len())
+                .find(|&k| track[k] >= min_altitude)
+            else {
+                continue;
+            };
+            let start = (dark_start + first as i64 * SCHEDULE_STEP_SECONDS).max(ready);
+            let last = (first..track.len()).take_while(|&k| track[k] >= min_altitude).last().unwrap_or(first);
+            let sets_at = (dark_start + (last as i64 + 1) * SCHEDULE_STEP_SECONDS).min(dark_end);
+
+            let wanted = target
+                .exposure_plan
+                .as_ref()
+                .map(|plan| plan.total_exposure)
+                .filter(|minutes| *minutes > 0.0)
+                .unwrap_or(DEFAULT_SCHEDULE_MINUTES);
+            let end = (start + (wanted * 60.0) as i64).min(sets_at);
+            if end - start < MIN_SLOT_SECONDS {
+                continue;
+            }
+
+            let score = (end - start) - (start - now);
+            if best.map_or(true, |(_, _, _, _, best_score)| score > best_score) {
+                best = Some((i, start, end, slew, score));
+            }
+        }
+
+        let Some((i, start, end, slew, _)) = best else {
+            break;
+        };
+        let (target, track) = remaining.remove(i);
+        let max_altitude = track[sample_index(start)..=sample_index(end).min(track.len() - 1)]
+            .iter()
+            .cloned()
+            .fold(f64::MIN, f64::max);
+
+        schedule.push(ScheduledTarget {
+            target_id: target.id.clone(),
+            name: target.name.clone(),
+            start: DateTime::from_timestamp(start, 0).unwrap_or_default(),
+            end: DateTime::from_timestamp(end, 0).unwrap_or_default(),
+            slew_seconds: slew,
+            integration_minutes: (end - start) as f64 / 60.0,
+            max_altitude,
+        });
+        position = Some((target.ra, target.dec));
+        now = end;
+    }
+
+    schedule
+}
+
 /// Target statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TargetStats {
@@ -700,6 +1344,124 @@ pub struct TargetStats {
     pub medium_priority: usize,
     pub low_priority: usize,
     pub by_tag: Vec<(String, usize)>,
+    /// Integration captured across all targets
+    #[serde(default)]
+    pub captured_hours: f64,
+    /// Integration planned across all targets' exposure plans
+    #[serde(default)]
+    pub planned_hours: f64,
+}
+
+// ============================================================================
+// Exposure Planning
+// ============================================================================
+
+/// V-band zero-magnitude photon flux, photons/s/cm²/nm
+const ZERO_MAG_PHOTON_FLUX: f64 = 1.0e4;
+const MIN_SUB_EXPOSURE_SEC: f64 = 5.0;
+const MAX_SUB_EXPOSURE_SEC: f64 = 600.0;
+/// Stacked exposure-time to noise ratio the stack estimate aims for
+const TARGET_TIME_NOISE_RATIO: f64 = 80.0;
+
+fn default_aperture_mm() -> f64 { 80.0 }
+fn default_quantum_efficiency() -> f64 { 0.8 }
+fn default_full_well() -> f64 { 50000.0 }
+fn default_read_noise_limit_percent() -> f64 { 5.0 }
+
+/// Camera, optics and sky parameters for an advanced exposure plan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposurePlanInputs {
+    /// Read noise, e-
+    pub read_noise: f64,
+    /// Dark current, e-/pixel/s
+    pub dark_current: f64,
+    pub gain: f64,
+    /// Sky brightness, mag/arcsec²
+    pub sqm: f64,
+    /// Image scale, arcsec/pixel
+    pub pixel_scale: f64,
+    pub filter_bandwidth_nm: f64,
+    #[serde(default = "default_aperture_mm")]
+    pub aperture_mm: f64,
+    #[serde(default = "default_quantum_efficiency")]
+    pub quantum_efficiency: f64,
+    /// Full well at zero gain, e-
+    #[serde(default = "default_full_well")]
+    pub full_well: f64,
+    /// Share of total noise read noise may add over sky noise
+    #[serde(default = "default_read_noise_limit_percent")]
+    pub read_noise_limit_percent: f64,
+}
+
+/// Sky-limited exposure plan: the sub length at which read noise adds only
+/// `read_noise_limit_percent` to the sky noise, with the noise budget,
+/// dynamic range and frames needed at that length
+#[tauri::command]
+pub fn calculate_exposure_plan_advanced(inputs: ExposurePlanInputs) -> Result<ExposurePlanAdvanced, StorageError> {
+    let positive = [inputs.pixel_scale, inputs.filter_bandwidth_nm, inputs.aperture_mm, inputs.quantum_efficiency, inputs.full_well];
+    if !inputs.sqm.is_finite() || positive.iter().any(|v| !v.is_finite() || *v <= 0.0) {
+        return Err(StorageError::Other("Exposure inputs need a finite SQM and positive optics".to_string()));
+    }
+    let non_negative = [inputs.read_noise, inputs.dark_current, inputs.gain];
+    if non_negative.iter().any(|v| !v.is_finite() || *v < 0.0) {
+        return Err(StorageError::Other("Read noise, dark current and gain must be finite and not negative".to_string()));
+    }
+    if !inputs.read_noise_limit_percent.is_finite() {
+        return Err(StorageError::Other("Read noise limit must be a finite percentage".to_string()));
+    }
+
+    let aperture_cm2 = std::f64::consts::PI * (inputs.aperture_mm / 20.0).powi(2);
+    let sky_flux = ZERO_MAG_PHOTON_FLUX
+        * 10f64.powf(-0.4 * inputs.sqm)
+        * inputs.pixel_scale.powi(2)
+        * aperture_cm2
+        * inputs.filter_bandwidth_nm
+        * inputs.quantum_efficiency.min(1.0);
+
+    let limit = inputs.read_noise_limit_percent.clamp(1.0, 50.0) / 100.0;
+    let read_variance = inputs.read_noise.powi(2);
+    let exposure = (read_variance / (sky_flux * ((1.0 + limit).powi(2) - 1.0)))
+        .clamp(MIN_SUB_EXPOSURE_SEC, MAX_SUB_EXPOSURE_SEC);
+
+    let sky_variance = sky_flux * exposure;
+    let dark_variance = inputs.dark_current * exposure;
+    let total_variance = (read_variance + sky_variance + dark_variance).max(f64::MIN_POSITIVE);
+
+    // Higher gain trades full well for lower read noise
+    let effective_full_well = inputs.full_well * (1.0 - inputs.gain / 500.0).max(0.25);
+    let noise_floor = (read_variance + dark_variance).sqrt().max(1e-6);
+    let dynamic_range = effective_full_well / noise_floor;
+
+    let time_noise_ratio = exposure / total_variance.sqrt();
+    let frames = (TARGET_TIME_NOISE_RATIO / time_noise_ratio).powi(2).ceil().max(1.0) as u32;
+
+    Ok(ExposurePlanAdvanced {
+        sqm: Some(inputs.sqm),
+        filter_bandwidth_nm: Some(inputs.filter_bandwidth_nm),
+        read_noise_limit_percent: Some(limit * 100.0),
+        gain_strategy: Some("manual".to_string()),
+        recommended_gain: Some(inputs.gain),
+        recommended_exposure_sec: Some(exposure),
+        sky_flux_per_pixel: Some(sky_flux),
+        target_signal_per_pixel_per_sec: None,
+        dynamic_range_score: Some(dynamic_range),
+        dynamic_range_stops: Some(dynamic_range.max(1.0).log2()),
+        read_noise_used: Some(inputs.read_noise),
+        dark_current_used: Some(inputs.dark_current),
+        noise_fractions: Some(ExposurePlanAdvancedNoiseFractions {
+            read: Some(read_variance / total_variance),
+            sky: Some(sky_variance / total_variance),
+            dark: Some(dark_variance / total_variance),
+        }),
+        stack_estimate: Some(ExposurePlanAdvancedStackEstimate {
+            recommended_frame_count: Some(frames),
+            estimated_total_minutes: Some(frames as f64 * exposure / 60.0),
+            frames_for_target_snr: None,
+            frames_for_time_noise: Some(frames),
+            target_snr: None,
+            target_time_noise_ratio: Some(TARGET_TIME_NOISE_RATIO),
+        }),
+    })
 }
 
 // ============================================================================
@@ -825,6 +1587,74 @@ mod tests {
         assert!(plan.advanced.is_none());
     }
 
+    #[test]
+    fn test_recompute_exposure_totals() {
+        let mut plan = ExposurePlan {
+            single_exposure: 300.0,
+            total_exposure: 0.0,
+            sub_frames: 60,
+            filter: None,
+            advanced: None,
+        };
+        recompute_exposure_totals(&mut plan);
+        assert_eq!(plan.total_exposure, 300.0);
+
+        // Without a sub count the stored total is left alone
+        plan.sub_frames = 0;
+        plan.total_exposure = 42.0;
+        recompute_exposure_totals(&mut plan);
+        assert_eq!(plan.total_exposure, 42.0);
+    }
+
+    #[test]
+    fn test_exposure_plan_update_keeps_total_consistent() {
+        let mut target = batch_target("M42", 83.82, -5.39);
+        apply_exposure_plan_update(
+            &mut target,
+            &serde_json::json!({"single_exposure": 300.0, "total_exposure": 1.0, "sub_frames": 60, "filter": "Ha"}),
+        )
+        .unwrap();
+        let plan = target.exposure_plan.as_ref().unwrap();
+        assert_eq!(plan.total_exposure, 300.0);
+        assert_eq!(plan.filter.as_deref(), Some("Ha"));
+
+        // Changing only the sub count updates the total and keeps the rest
+        apply_exposure_plan_update(&mut target, &serde_json::json!({"sub_frames": 24})).unwrap();
+        let plan = target.exposure_plan.as_ref().unwrap();
+        assert_eq!(plan.sub_frames, 24);
+        assert_eq!(plan.total_exposure, 120.0);
+        assert_eq!(plan.filter.as_deref(), Some("Ha"));
+
+        apply_exposure_plan_update(&mut target, &serde_json::Value::Null).unwrap();
+        assert!(target.exposure_plan.is_none());
+    }
+
+    #[test]
+    fn test_exposure_plan_update_rejects_bad_sub_counts() {
+        let mut target = batch_target("M42", 83.82, -5.39);
+        apply_exposure_plan_update(&mut target, &serde_json::json!({"single_exposure": 300.0, "total_exposure": 0.0, "sub_frames": 10, "filter": null})).unwrap();
+
+        for subs in [serde_json::json!(2.5), serde_json::json!(-3), serde_json::json!(5_000_000_000u64), serde_json::json!("12")] {
+            assert!(apply_exposure_plan_update(&mut target, &serde_json::json!({ "sub_frames": subs })).is_err());
+        }
+        assert_eq!(target.exposure_plan.as_ref().unwrap().sub_frames, 10);
+    }
+
+    #[test]
+    fn test_exposure_plan_update_replaces_advanced() {
+        let mut target = batch_target("M42", 83.82, -5.39);
+        apply_exposure_plan_update(&mut target, &serde_json::json!({"single_exposure": 180.0, "total_exposure": 0.0, "sub_frames": 20, "filter": "L"})).unwrap();
+        assert!(target.exposure_plan.as_ref().unwrap().advanced.is_none());
+
+        apply_exposure_plan_update(&mut target, &serde_json::json!({"advanced": {"sqm": 21.3, "gain_strategy": "unity"}})).unwrap();
+        let advanced = target.exposure_plan.as_ref().unwrap().advanced.as_ref().unwrap();
+        assert_eq!(advanced.sqm, Some(21.3));
+        assert_eq!(advanced.gain_strategy.as_deref(), Some("unity"));
+
+        apply_exposure_plan_update(&mut target, &serde_json::json!({"advanced": null})).unwrap();
+        assert!(target.exposure_plan.as_ref().unwrap().advanced.is_none());
+    }
+
     #[test]
     fn test_exposure_plan_with_advanced_fields() {
         let json = r#"{
@@ -913,12 +1743,15 @@ mod tests {
             dec: 41.27,
             ra_string: "00h 42m 44s".to_string(),
             dec_string: "+41 16".to_string(),
+            constellation: None,
+            object_type: None,
             sensor_width: Some(23.2),
             sensor_height: Some(15.5),
             focal_length: Some(400.0),
             rotation_angle: Some(0.0),
             mosaic: None,
             exposure_plan: None,
+            captured_exposure_minutes: 0.0,
             notes: Some("Andromeda Galaxy".to_string()),
             added_at: 1704067200000,
             priority: TargetPriority::High,
@@ -1082,6 +1915,8 @@ mod tests {
             medium_priority: 4,
             low_priority: 3,
             by_tag: vec![("galaxy".to_string(), 5), ("nebula".to_string(), 3)],
+            captured_hours: 1.5,
+            planned_hours: 4.0,
         };
 
         let json = serde_json::to_string(&stats).unwrap();
@@ -1124,6 +1959,8 @@ mod tests {
             dec: 0.0,
             ra_string: String::new(),
             dec_string: String::new(),
+            constellation: None,
+            object_type: None,
             sensor_width: Some(36.0),
             sensor_height: Some(24.0),
             focal_length: Some(1000.0),
@@ -1141,6 +1978,7 @@ mod tests {
                 filter: Some("L".to_string()),
                 advanced: None,
             }),
+            captured_exposure_minutes: 0.0,
             notes: Some("Complete target".to_string()),
             added_at: 0,
             priority: TargetPriority::High,
@@ -1172,12 +2010,15 @@ mod tests {
             dec: 89.99,
             ra_string: "23h 59m 58s".to_string(),
             dec_string: "+89 59".to_string(),
+            constellation: None,
+            object_type: None,
             sensor_width: None,
             sensor_height: None,
             focal_length: None,
             rotation_angle: None,
             mosaic: None,
             exposure_plan: None,
+            captured_exposure_minutes: 0.0,
             notes: None,
             added_at: 0,
             priority: TargetPriority::Low,
@@ -1203,12 +2044,15 @@ mod tests {
             dec: 0.0,
             ra_string: String::new(),
             dec_string: String::new(),
+            constellation: None,
+            object_type: None,
             sensor_width: None,
             sensor_height: None,
             focal_length: None,
             rotation_angle: None,
             mosaic: None,
             exposure_plan: None,
+            captured_exposure_minutes: 0.0,
             notes: None,
             added_at: 0,
             priority: TargetPriority::Medium,
@@ -1229,4 +2073,529 @@ mod tests {
         assert_eq!(back.tags.len(), 4);
         assert!(back.tags.contains(&"tonight".to_string()));
     }
+
+    // ------------------------------------------------------------------------
+    // Catalog Target Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_catalog_target_input() {
+        let m57 = find_catalog_object("M57").unwrap();
+        let input = catalog_target_input(&m57);
+        assert_eq!(input.name, "M57 (Ring Nebula)");
+        assert_eq!(input.ra, m57.ra);
+        assert_eq!(input.dec, m57.dec);
+        assert!(input.ra_string.starts_with("18h"));
+        assert_eq!(input.tags, Some(vec![m57.object_type.clone()]));
+    }
+
+    // ------------------------------------------------------------------------
+    // Target Progress Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_captured_minutes_advance_status_at_thresholds() {
+        let mut target = planned_target("M31", 10.68, 41.27, 120.0);
+
+        apply_captured_minutes(&mut target, 30.0);
+        assert!(matches!(target.status, TargetStatus::InProgress));
+
+        apply_captured_minutes(&mut target, 89.5);
+        assert!(matches!(target.status, TargetStatus::InProgress));
+
+        apply_captured_minutes(&mut target, 0.5);
+        assert_eq!(target.captured_exposure_minutes, 120.0);
+        assert!(matches!(target.status, TargetStatus::Completed));
+
+        apply_captured_minutes(&mut target, 10.0);
+        assert!(matches!(target.status, TargetStatus::Completed));
+    }
+
+    #[test]
+    fn test_captured_minutes_without_plan_stay_in_progress() {
+        let mut target = batch_target("M33", 23.46, 30.66);
+        apply_captured_minutes(&mut target, 600.0);
+        assert_eq!(target.captured_exposure_minutes, 600.0);
+        assert!(matches!(target.status, TargetStatus::InProgress));
+    }
+
+    #[test]
+    fn test_target_item_without_captured_minutes_defaults_to_zero() {
+        let mut json = serde_json::to_value(batch_target("M33", 23.46, 30.66)).unwrap();
+        json.as_object_mut().unwrap().remove("captured_exposure_minutes");
+        let target: TargetItem = serde_json::from_value(json).unwrap();
+        assert_eq!(target.captured_exposure_minutes, 0.0);
+    }
+
+    // ------------------------------------------------------------------------
+    // Observing Schedule Tests
+    // ------------------------------------------------------------------------
+
+    fn planned_target(name: &str, ra: f64, dec: f64, minutes: f64) -> TargetItem {
+        let mut target = batch_target(name, ra, dec);
+        target.exposure_plan = Some(ExposurePlan {
+            single_exposure: 300.0,
+            total_exposure: minutes,
+            sub_frames: (minutes / 5.0) as u32,
+            filter: None,
+            advanced: None,
+        });
+        target
+    }
+
+    fn test_dark_window() -> (i64, i64) {
+        let noon = DateTime::from_timestamp(local_noon_timestamp("2024-01-15", 0.0).unwrap(), 0).unwrap();
+        tonight_dark_window(40.0, 0.0, noon).unwrap()
+    }
+
+    #[test]
+    fn test_schedule_two_targets_without_overlap() {
+        let targets = vec![
+            planned_target("M42", 83.82, -5.39, 120.0),
+            planned_target("M31", 10.68, 41.27, 120.0),
+        ];
+        let window = test_dark_window();
+
+        let schedule = build_observing_schedule(&targets, 40.0, 0.0, window, 30.0, 2.0);
+        assert_eq!(schedule.len(), 2);
+
+        // M31 is already high at dusk while M42 is still rising
+        assert_eq!(schedule[0].name, "M31");
+        assert_eq!(schedule[1].name, "M42");
+        assert_eq!(schedule[0].slew_seconds, 0.0);
+        assert!(schedule[1].slew_seconds > 0.0);
+
+        let (first, second) = (&schedule[0], &schedule[1]);
+        assert!(first.start.timestamp() >= window.0);
+        assert!(second.end.timestamp() <= window.1);
+        assert!(first.end <= second.start, "slots overlap");
+        assert!(second.start.timestamp() as f64 >= first.end.timestamp() as f64 + second.slew_seconds);
+        assert!((first.integration_minutes - 120.0).abs() < 1e-9);
+        assert!(schedule.iter().all(|s| s.max_altitude >= 30.0));
+    }
+
+    #[test]
+    fn test_schedule_excludes_archived_and_never_rising() {
+        let mut archived = planned_target("Archived", 83.82, -5.39, 60.0);
+        archived.is_archived = true;
+        let targets = vec![archived, planned_target("South", 0.0, -80.0, 60.0)];
+
+        let schedule = build_observing_schedule(&targets, 40.0, 0.0, test_dark_window(), 20.0, 2.0);
+        assert!(schedule.is_empty());
+    }
+
+    // ------------------------------------------------------------------------
+    // Tag Rename/Delete Tests
+    // ------------------------------------------------------------------------
+
+    fn tagged_list(tags: &[&[&str]], available: &[&str]) -> TargetListData {
+        let targets = tags
+            .iter()
+            .enumerate()
+            .map(|(i, tags)| {
+                let mut target = batch_target(&format!("T{}", i), 10.0, 20.0);
+                target.tags = tags.iter().map(|t| t.to_string()).collect();
+                target
+            })
+            .collect();
+        TargetListData {
+            targets,
+            available_tags: available.iter().map(|t| t.to_string()).collect(),
+            active_target_id: None,
+        }
+    }
+
+    #[test]
+    fn test_rename_tag_updates_all_targets() {
+        let mut data = tagged_list(&[&["galaxy", "winter"], &["winter"], &["summer"]], &["galaxy", "winter", "summer"]);
+        apply_tag_rename(&mut data, "winter", "cold");
+
+        assert_eq!(data.targets[0].tags, vec!["galaxy", "cold"]);
+        assert_eq!(data.targets[1].tags, vec!["cold"]);
+        assert_eq!(data.targets[2].tags, vec!["summer"]);
+        assert_eq!(data.available_tags, vec!["galaxy", "cold", "summer"]);
+    }
+
+    #[test]
+    fn test_rename_tag_merges_into_existing() {
+        let mut data = tagged_list(&[&["winter", "cold"], &["winter"]], &["winter", "cold"]);
+        apply_tag_rename(&mut data, "winter", "cold");
+
+        assert_eq!(data.targets[0].tags, vec!["cold"]);
+        assert_eq!(data.targets[1].tags, vec!["cold"]);
+        assert_eq!(data.available_tags, vec!["cold"]);
+    }
+
+    #[test]
+    fn test_delete_tag_removes_everywhere() {
+        let mut data = tagged_list(&[&["galaxy", "winter"], &["winter"]], &["galaxy", "winter"]);
+        apply_tag_delete(&mut data, "winter");
+
+        assert_eq!(data.targets[0].tags, vec!["galaxy"]);
+        assert!(data.targets[1].tags.is_empty());
+        assert_eq!(data.available_tags, vec!["galaxy"]);
+    }
+
+    // ------------------------------------------------------------------------
+    // Coordinate Normalization Tests
+    // ------------------------------------------------------------------------
+
+    fn coordinate_input(ra: f64, dec: f64, ra_string: &str, dec_string: &str) -> TargetInput {
+        let mut input = catalog_target_input(&find_catalog_object("M42").unwrap());
+        input.ra = ra;
+        input.dec = dec;
+        input.ra_string = ra_string.to_string();
+        input.dec_string = dec_string.to_string();
+        input
+    }
+
+    #[test]
+    fn test_normalize_wraps_ra() {
+        let input = normalize_target_coordinates(coordinate_input(400.0, 10.0, "", "")).unwrap();
+        assert!((input.ra - 40.0).abs() < 1e-9);
+        assert_eq!(input.ra_string, format_ra_hms(40.0));
+        assert_eq!(input.dec_string, format_dec_dms(10.0));
+        // A string describing the unwrapped RA is replaced
+        let input = normalize_target_coordinates(coordinate_input(400.0, 10.0, "26h 40m 00s", "+10° 00' 00\"")).unwrap();
+        assert_eq!(input.ra_string, format_ra_hms(40.0));
+        assert_eq!(input.dec_string, "+10° 00' 00\"");
+        let input = normalize_target_coordinates(coordinate_input(-30.0, 10.0, "", "")).unwrap();
+        assert!((input.ra - 330.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_normalize_rejects_dec_out_of_range() {
+        let err = normalize_target_coordinates(coordinate_input(10.0, 95.0, "", "")).unwrap_err();
+        assert!(matches!(err, StorageError::InvalidCoordinates(_)));
+        assert!(normalize_target_coordinates(coordinate_input(10.0, -90.5, "", "")).is_err());
+        assert!(normalize_target_coordinates(coordinate_input(f64::NAN, 0.0, "", "")).is_err());
+    }
+
+    #[test]
+    fn test_normalize_derives_degrees_from_strings() {
+        let input =
+            normalize_target_coordinates(coordinate_input(0.0, 0.0, "05h 35m 17.3s", "-05° 23' 28\"")).unwrap();
+        assert!((input.ra - 83.822).abs() < 0.01, "ra {}", input.ra);
+        assert!((input.dec + 5.391).abs() < 0.01, "dec {}", input.dec);
+
+        // Explicit degrees win over strings
+        let input = normalize_target_coordinates(coordinate_input(10.0, 20.0, "05h 35m 17.3s", "-05° 23' 28\"")).unwrap();
+        assert_eq!((input.ra, input.dec), (10.0, 20.0));
+
+        let err = normalize_target_coordinates(coordinate_input(0.0, 0.0, "garbage", "")).unwrap_err();
+        assert!(matches!(err, StorageError::InvalidCoordinates(_)));
+    }
+
+    #[test]
+    fn test_batch_targets_are_normalized() {
+        let input = BatchTargetInput {
+            name: "Wrapped".to_string(),
+            ra: -30.0,
+            dec: 10.0,
+            ra_string: "-02h 00m 00s".to_string(),
+            dec_string: String::new(),
+        };
+        let input = normalize_batch_coordinates(input).unwrap();
+        assert!((input.ra - 330.0).abs() < 1e-9);
+        assert_eq!(input.ra_string, format_ra_hms(330.0));
+        assert_eq!(input.dec_string, format_dec_dms(10.0));
+
+        let bad = BatchTargetInput { name: "Bad".to_string(), ra: 10.0, dec: 95.0, ra_string: String::new(), dec_string: String::new() };
+        assert!(normalize_batch_coordinates(bad).is_err());
+    }
+
+    #[test]
+    fn test_coordinate_update_is_normalized() {
+        let mut target = new_batch_target(
+            normalize_batch_coordinates(BatchTargetInput {
+                name: "M42".to_string(),
+                ra: 83.82,
+                dec: -5.39,
+                ra_string: String::new(),
+                dec_string: String::new(),
+            })
+            .unwrap(),
+            TargetPriority::Medium,
+            Vec::new(),
+        );
+
+        apply_coordinate_update(&mut target, &serde_json::json!({ "ra": 370.0 })).unwrap();
+        assert!((target.ra - 10.0).abs() < 1e-9);
+        assert_eq!(target.dec, -5.39);
+        assert_eq!(target.ra_string, format_ra_hms(10.0));
+        assert_eq!(target.constellation, Some(constellation_for_coords(10.0, -5.39)));
+
+        assert!(apply_coordinate_update(&mut target, &serde_json::json!({ "dec": -91.0 })).is_err());
+        assert_eq!(target.dec, -5.39);
+        apply_coordinate_update(&mut target, &serde_json::json!({ "name": "x" })).unwrap();
+        assert!((target.ra - 10.0).abs() < 1e-9);
+    }
+
+    // ------------------------------------------------------------------------
+    // Default Equipment Tests
+    // ------------------------------------------------------------------------
+
+    fn default_telescope() -> Telescope {
+        Telescope {
+            id: "t1".to_string(),
+            name: "Refractor".to_string(),
+            aperture: 80.0,
+            focal_length: 480.0,
+            focal_ratio: 6.0,
+            telescope_type: crate::data::TelescopeType::Refractor,
+            mount_type: None,
+            notes: None,
+            is_default: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn default_camera() -> Camera {
+        Camera {
+            id: "c1".to_string(),
+            name: "APS-C".to_string(),
+            sensor_width: 23.5,
+            sensor_height: 15.6,
+            pixel_size: 3.76,
+            resolution_x: 6248,
+            resolution_y: 4176,
+            camera_type: crate::data::CameraType::Cmos,
+            has_cooler: true,
+            notes: None,
+            is_default: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_apply_default_equipment_fills_missing() {
+        let mut input = coordinate_input(83.82, -5.39, "", "");
+        input.sensor_width = None;
+        input.sensor_height = None;
+        input.focal_length = None;
+
+        apply_default_equipment(&mut input, Some(&default_telescope()), Some(&default_camera()));
+        assert_eq!(input.sensor_width, Some(23.5));
+        assert_eq!(input.sensor_height, Some(15.6));
+        assert_eq!(input.focal_length, Some(480.0));
+    }
+
+    #[test]
+    fn test_best_effort_default_ignores_load_errors() {
+        let failed: Result<Option<Camera>, StorageError> = Err(StorageError::Other("unreadable".to_string()));
+        assert!(best_effort_default(failed, "camera").is_none());
+        assert_eq!(best_effort_default(Ok(Some(default_camera())), "camera").map(|c| c.id), Some("c1".to_string()));
+    }
+
+    #[test]
+    fn test_apply_default_equipment_keeps_explicit_values() {
+        let mut input = coordinate_input(83.82, -5.39, "", "");
+        input.sensor_width = Some(36.0);
+        input.sensor_height = None;
+        input.focal_length = Some(1000.0);
+
+        apply_default_equipment(&mut input, Some(&default_telescope()), Some(&default_camera()));
+        assert_eq!(input.sensor_width, Some(36.0));
+        assert_eq!(input.sensor_height, None);
+        assert_eq!(input.focal_length, Some(1000.0));
+
+        // Without defaults configured the fields stay empty
+        let mut input = coordinate_input(83.82, -5.39, "", "");
+        input.focal_length = None;
+        apply_default_equipment(&mut input, None, None);
+        assert!(input.focal_length.is_none());
+    }
+
+    // ------------------------------------------------------------------------
+    // Observable Window Recompute Tests
+    // ------------------------------------------------------------------------
+
+    fn batch_target(name: &str, ra: f64, dec: f64) -> TargetItem {
+        new_batch_target(
+            BatchTargetInput {
+                name: name.to_string(),
+                ra,
+                dec,
+                ra_string: String::new(),
+                dec_string: String::new(),
+            },
+            TargetPriority::Medium,
+            Vec::new(),
+        )
+    }
+
+    // Ottawa-like site, 2024-01-16 00:00 UTC
+    const VISIBILITY_SITE: (f64, f64, i64) = (45.0, -75.0, 1705363200);
+
+    #[test]
+    fn test_target_visibilities_match_reference_rise_and_set() {
+        let targets = vec![
+            batch_target("M42", 83.82, -5.39),
+            batch_target("M31", 10.68, 41.27),
+            batch_target("Polaris", 37.95, 89.26),
+            batch_target("Omega Centauri", 201.70, -47.48),
+        ];
+        let (latitude, longitude, timestamp) = VISIBILITY_SITE;
+
+        let enriched = target_visibilities(&targets, latitude, longitude, timestamp, &[]);
+        assert_eq!(enriched.len(), targets.len());
+
+        // Reference transit/rise/set from the IAU 1982 GMST formula with
+        // -0.5667° refraction; M42 rises 21:14, transits 02:55, sets 08:36 UTC
+        let near = |actual: Option<i64>, expected: i64| {
+            actual.is_some_and(|t| (t - expected).abs() <= 180)
+        };
+        let m42 = &enriched[0];
+        assert!(near(m42.rise_time, 1705353264), "M42 rise {:?}", m42.rise_time);
+        assert!(near(m42.transit_time, 1705373703), "M42 transit {:?}", m42.transit_time);
+        assert!(near(m42.set_time, 1705394141), "M42 set {:?}", m42.set_time);
+
+        let m31 = &enriched[1];
+        assert!(near(m31.rise_time, 1705319418), "M31 rise {:?}", m31.rise_time);
+        assert!(near(m31.transit_time, 1705356197), "M31 transit {:?}", m31.transit_time);
+        assert!(near(m31.set_time, 1705392975), "M31 set {:?}", m31.set_time);
+
+        assert!(enriched[2].is_circumpolar);
+        assert!(enriched[2].rise_time.is_none() && enriched[2].transit_time.is_some());
+        assert!(enriched[3].never_rises);
+    }
+
+    #[test]
+    fn test_target_visibilities_follow_horizon_profile() {
+        let targets = vec![batch_target("M42", 83.82, -5.39)];
+        let (latitude, longitude, timestamp) = VISIBILITY_SITE;
+        let flat = &target_visibilities(&targets, latitude, longitude, timestamp, &[])[0];
+        let eastern_wall = [(0.0, 0.0), (60.0, 25.0), (150.0, 25.0), (180.0, 0.0)];
+        let obstructed = &target_visibilities(&targets, latitude, longitude, timestamp, &eastern_wall)[0];
+
+        assert!(obstructed.rise_time.unwrap() > flat.rise_time.unwrap() + 3600);
+        assert!((obstructed.set_time.unwrap() - flat.set_time.unwrap()).abs() < 300);
+    }
+
+    #[test]
+    fn test_recompute_observable_windows_populates_all() {
+        let mut data = TargetListData {
+            targets: vec![
+                batch_target("M42", 83.82, -5.39),
+                batch_target("M31", 10.68, 41.27),
+                batch_target("M13", 250.42, 36.46),
+                batch_target("Polaris", 37.95, 89.26),
+            ],
+            available_tags: Vec::new(),
+            active_target_id: None,
+        };
+        let timestamp = local_noon_timestamp("2024-01-15", 0.0).unwrap();
+
+        let mut events = Vec::new();
+        let updated = recompute_observable_windows(&mut data, 45.0, 0.0, timestamp, |p| events.push(p));
+
+        assert_eq!(updated, 4);
+        assert_eq!(events.len(), 4);
+        assert_eq!(events.last().unwrap().completed, 4);
+        assert!(events.iter().all(|p| p.total == 4));
+
+        for target in &data.targets {
+            let window = target.observable_window.as_ref().unwrap();
+            assert!(window.start < window.end, "{}", target.name);
+            assert!(window.start <= window.transit_time && window.transit_time <= window.end);
+        }
+        assert!(data.targets[3].observable_window.as_ref().unwrap().is_circumpolar);
+    }
+
+    #[test]
+    fn test_recompute_observable_windows_skips_archived() {
+        let mut archived = batch_target("Archived", 83.82, -5.39);
+        archived.is_archived = true;
+        let mut data = TargetListData {
+            targets: vec![archived, batch_target("South", 0.0, -80.0)],
+            available_tags: Vec::new(),
+            active_target_id: None,
+        };
+
+        let updated = recompute_observable_windows(&mut data, 45.0, 0.0, 1705320000, |_| {});
+
+        assert_eq!(updated, 1);
+        assert!(data.targets[0].observable_window.is_none());
+        // Never rises from +45
+        assert!(data.targets[1].observable_window.is_none());
+        assert!(local_noon_timestamp("not-a-date", 0.0).is_err());
+    }
+
+    // ------------------------------------------------------------------------
+    // Advanced Exposure Plan Tests
+    // ------------------------------------------------------------------------
+
+    fn exposure_inputs(sqm: f64) -> ExposurePlanInputs {
+        ExposurePlanInputs {
+            read_noise: 3.0,
+            dark_current: 0.01,
+            gain: 100.0,
+            sqm,
+            pixel_scale: 2.0,
+            filter_bandwidth_nm: 300.0,
+            aperture_mm: default_aperture_mm(),
+            quantum_efficiency: default_quantum_efficiency(),
+            full_well: default_full_well(),
+            read_noise_limit_percent: default_read_noise_limit_percent(),
+        }
+    }
+
+    #[test]
+    fn test_darker_sky_lengthens_recommended_exposure() {
+        let suburban = calculate_exposure_plan_advanced(exposure_inputs(19.0)).unwrap();
+        let dark = calculate_exposure_plan_advanced(exposure_inputs(21.0)).unwrap();
+
+        assert!(dark.sky_flux_per_pixel.unwrap() < suburban.sky_flux_per_pixel.unwrap());
+        let (short, long) = (suburban.recommended_exposure_sec.unwrap(), dark.recommended_exposure_sec.unwrap());
+        assert!(long > short, "{} should exceed {}", long, short);
+        assert!(long < MAX_SUB_EXPOSURE_SEC && short > MIN_SUB_EXPOSURE_SEC);
+    }
+
+    #[test]
+    fn test_noise_fractions_sum_to_one() {
+        for sqm in [18.0, 20.0, 22.0] {
+            let plan = calculate_exposure_plan_advanced(exposure_inputs(sqm)).unwrap();
+            let fractions = plan.noise_fractions.unwrap();
+            let sum = fractions.read.unwrap() + fractions.sky.unwrap() + fractions.dark.unwrap();
+            assert!((sum - 1.0).abs() < 1e-9, "fractions sum to {}", sum);
+            // Sky-limited unless the clamp cut the exposure short
+            if plan.recommended_exposure_sec.unwrap() < MAX_SUB_EXPOSURE_SEC {
+                assert!(fractions.sky.unwrap() > fractions.read.unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_exposure_plan_stack_and_dynamic_range() {
+        let plan = calculate_exposure_plan_advanced(exposure_inputs(20.0)).unwrap();
+        let stack = plan.stack_estimate.unwrap();
+        let frames = stack.recommended_frame_count.unwrap();
+        assert!(frames >= 1);
+        let expected_minutes = frames as f64 * plan.recommended_exposure_sec.unwrap() / 60.0;
+        assert!((stack.estimated_total_minutes.unwrap() - expected_minutes).abs() < 1e-9);
+
+        let stops = plan.dynamic_range_stops.unwrap();
+        assert!((stops - plan.dynamic_range_score.unwrap().log2()).abs() < 1e-9);
+        assert!(stops > 10.0 && stops < 16.0, "got {} stops", stops);
+    }
+
+    #[test]
+    fn test_exposure_plan_rejects_bad_inputs() {
+        let mut inputs = exposure_inputs(20.0);
+        inputs.pixel_scale = 0.0;
+        assert!(calculate_exposure_plan_advanced(inputs).is_err());
+        assert!(calculate_exposure_plan_advanced(exposure_inputs(f64::NAN)).is_err());
+
+        let mut inputs = exposure_inputs(20.0);
+        inputs.read_noise = f64::NAN;
+        assert!(calculate_exposure_plan_advanced(inputs).is_err());
+        let mut inputs = exposure_inputs(20.0);
+        inputs.dark_current = f64::INFINITY;
+        assert!(calculate_exposure_plan_advanced(inputs).is_err());
+        let mut inputs = exposure_inputs(20.0);
+        inputs.gain = f64::NAN;
+        assert!(calculate_exposure_plan_advanced(inputs).is_err());
+    }
 }
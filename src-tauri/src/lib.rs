@@ -21,45 +21,60 @@ pub mod platform;
 // Re-export for backward compatibility and ease of use
 use data::{
     // Storage
-    clear_all_data, delete_store_data, export_all_data, get_data_directory, get_storage_stats,
-    import_all_data, list_stores, load_store_data, save_store_data,
+    clear_all_data, clear_download_history, delete_store_data, export_all_data, get_data_directory,
+    get_download_history, get_storage_stats, import_all_data, list_stores, load_store_data,
+    record_download, save_store_data,
     // Equipment
-    add_barlow_reducer, add_camera, add_eyepiece, add_filter, add_telescope, delete_equipment,
+    add_barlow_reducer, add_camera, add_eyepiece, add_filter, add_telescope,
+    calculate_filter_effective_exposure_factor, delete_equipment,
     get_default_camera, get_default_telescope, load_equipment, save_equipment, set_default_camera,
     set_default_telescope, update_barlow_reducer, update_camera, update_eyepiece, update_filter,
     update_telescope,
     // Locations
     add_location, delete_location, get_current_location, load_locations, save_locations,
-    set_current_location, set_default_location, update_location,
+    import_horizon_profile, set_current_location, set_default_location, set_horizon_profile, update_location,
     // Observation log
     add_observation, create_planned_session, create_session, delete_observation, delete_session, end_session,
-    get_observation_stats, load_observation_log, save_observation_log, search_observations,
-    export_observation_log, update_observation, update_session,
+    generate_session_report, get_active_session, get_observation_stats, get_observation_stats_by_period, get_session_summary, load_observation_log, save_observation_log,
+    search_observations, export_observation_log, update_observation, update_session,
     // Target I/O
-    export_targets, import_targets,
+    export_targets, export_targets_skysafari, import_targets, import_targets_kstars,
+    import_targets_stellarium,
     // Session I/O
     export_session_plan, import_session_plan, load_session_templates, save_session_template,
     // Target list
-    add_tag_to_targets, add_target, add_targets_batch, archive_completed_targets,
-    clear_all_targets, clear_completed_targets, get_target_stats, load_target_list,
-    remove_tag_from_targets, remove_target, remove_targets_batch, save_target_list, search_targets,
-    set_active_target, set_targets_priority_batch, set_targets_status_batch, toggle_target_archive,
+    add_tag_to_targets, add_target, add_target_from_catalog, add_targets_batch, archive_completed_targets,
+    calculate_exposure_plan_advanced, clear_all_targets, clear_completed_targets, delete_tag, generate_observing_schedule, get_target_stats,
+    load_target_list, load_target_list_with_visibility, recompute_all_observable_windows, remove_tag_from_targets, remove_target, remove_targets_batch,
+    rename_tag, save_target_list, search_targets,
+    record_target_progress, set_active_target, set_targets_priority_batch, set_targets_status_batch, toggle_target_archive,
     toggle_target_favorite, update_target,
     // Markers
-    add_marker, add_marker_group, clear_all_markers, get_visible_markers, load_markers,
+    add_marker, add_marker_group, add_markers_from_catalog, clear_all_markers, get_visible_markers, load_markers,
     remove_marker, remove_marker_group, remove_markers_by_group, rename_marker_group, save_markers,
     set_all_markers_visible, set_show_markers, toggle_marker_visibility, update_marker,
 };
+#[cfg(desktop)]
+use data::import_observations_from_fits;
 
 use astronomy::{
     // Calculations
-    angular_separation, calculate_fov, calculate_moon_phase, calculate_moon_position,
-    calculate_mosaic_coverage, calculate_sun_position, calculate_twilight, calculate_visibility,
+    angular_separation, angular_separation_epoch, batch_equatorial_to_horizontal, calculate_drift_rate, calculate_fov, calculate_fov_footprint, calculate_frame_fill,
+    calculate_moon_phase, calculate_moon_altitude_curve, calculate_sun_altitude_curve,
+    calculate_moon_position, calculate_mosaic_coverage, calculate_mosaic_panels, mosaic_slew_order,
+    calculate_best_observation_time, calculate_sun_position, calculate_photography_times, calculate_twilight,
+    calculate_effective_darkness, get_imaging_conditions,
+    calculate_visibility, calculate_visibility_calendar,
     ecliptic_to_equatorial, equatorial_to_ecliptic, equatorial_to_galactic,
-    equatorial_to_horizontal, format_dec_dms, format_ra_hms, galactic_to_equatorial,
-    horizontal_to_equatorial, parse_dec_dms, parse_ra_hms,
+    equatorial_to_horizontal, format_dec_dms, format_dec_dms_precision, format_ra_hms,
+    format_ra_hms_precision, galactic_to_equatorial, generate_coordinate_grid,
+    horizontal_to_equatorial, parse_dec_dms, parse_ra_hms, utc_to_bjd, utc_to_hjd,
+    calculate_nutation, calculate_obliquity_command, get_target_hour_angle,
+    // Catalog
+    constellation_for_coords, identify_object, search_catalog,
     // Events
     get_astro_events, get_daily_astro_events, get_meteor_showers, get_moon_phases_for_month,
+    get_moon_quarter_times,
     get_seasonal_events, get_tonight_highlights,
 };
 
@@ -67,7 +82,7 @@ use cache::{
     // Offline cache
     clear_all_cache, clear_survey_cache, create_cache_region, delete_cache_region,
     get_cache_directory, get_cache_stats, is_tile_cached, list_cache_regions, load_cached_tile,
-    save_cached_tile, update_cache_region,
+    save_cached_tile, update_cache_region, export_survey_cache, import_survey_cache,
     // Unified cache
     cleanup_unified_cache, clear_unified_cache, delete_unified_cache_entry,
     flush_unified_cache, get_unified_cache_entry, get_unified_cache_size,
@@ -76,25 +91,34 @@ use cache::{
 };
 
 use network::{
-    cancel_request, get_active_requests, get_http_config, http_batch_download,
+    cancel_request, get_active_requests, get_http_config, get_request_log, http_batch_download,
+    http_batch_download_streamed,
     http_cancel_all_requests, http_cancel_request, http_check_url, http_download,
     http_get, http_head, http_post, http_request, set_http_config,
+    // Rate limiter persistence and domain overrides
+    get_domain_rate_limits, load_rate_limit_state, save_rate_limit_state, set_domain_rate_limit,
 };
 
 use mount::{
     mount_connect, mount_disconnect, mount_get_state, mount_get_capabilities,
-    mount_slew_to, mount_sync_to, mount_abort_slew,
+    mount_set_heartbeat_interval,
+    mount_slew_to, mount_estimate_slew_time, mount_sync_to, mount_abort_slew, mount_spiral_search,
+    mount_abort_spiral,
+    mount_add_sync_point, mount_clear_alignment, mount_get_alignment_stats,
     mount_park, mount_unpark,
     mount_set_tracking, mount_set_tracking_rate,
     mount_move_axis, mount_stop_axis, mount_set_slew_rate,
     mount_discover, mount_get_observing_conditions, mount_get_safety_state,
+    mount_get_config, mount_set_config, mount_get_park_position, mount_set_park_position,
 };
 
 #[cfg(desktop)]
 use platform::{
     // App settings
-    add_recent_file, clear_recent_files, get_system_info, load_app_settings, open_path,
+    add_recent_file, clear_recent_files, create_settings_profile, delete_settings_profile,
+    get_shortcuts, get_system_info, list_settings_profiles, load_app_settings, open_path,
     restore_window_state, reveal_in_file_manager, save_app_settings, save_window_state,
+    set_shortcut, switch_settings_profile,
     // App control
     handle_tray_icon_event, initialize_tray, is_dev_mode, is_tray_positioning_ready,
     quit_app, reload_webview, restart_app, TrayRuntimeState,
@@ -102,21 +126,26 @@ use platform::{
     handle_forwarded_cli_invocation, parse_cli_matches_from_args,
     // Updater
     check_for_update, clear_pending_update, download_and_install_update, download_update,
-    get_current_version, has_pending_update, install_update,
+    create_update_rollback_point, get_current_version, has_pending_update, install_update,
+    rollback_update,
     // Path config
     get_path_config, set_custom_data_dir, set_custom_cache_dir,
     migrate_data_dir, migrate_cache_dir, reset_paths_to_default, validate_directory,
+    available_disk_space,
     // Secure map API keys
     save_map_api_key, list_map_api_keys_meta, get_map_api_key, delete_map_api_key, set_active_map_api_key,
+    record_map_api_key_use, reset_map_api_key_usage, validate_map_api_key_request,
     // Secret vault bootstrap
     get_or_create_secret_vault_bootstrap,
+    // Operations
+    cancel_all_operations,
     // Plate solver
-    analyse_image, delete_index, detect_plate_solvers, download_index,
+    analyse_image, calculate_focus_metrics, delete_index, detect_plate_solvers, download_index,
     extract_stars, get_astap_databases, get_available_indexes,
     get_default_index_path, get_downloadable_indexes, get_installed_indexes,
     get_recommended_indexes, get_solver_indexes, get_solver_info, load_solver_config,
-    cancel_online_solve, cancel_plate_solve, plate_solve, recommend_astap_database, save_solver_config,
-    solve_image_local, solve_online, validate_solver_path,
+    cancel_online_solve, cancel_plate_solve, clear_astrometry_session, plate_solve, recommend_astap_database, save_solver_config,
+    solve_image_local, solve_online, validate_solver_path, write_wcs_file,
 };
 
 #[cfg(desktop)]
@@ -206,6 +235,10 @@ pub fn run() {
                 )?;
             }
 
+            if let Err(error) = load_rate_limit_state(app.handle()) {
+                log::warn!("Failed to restore rate limit state: {error}");
+            }
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -219,6 +252,9 @@ pub fn run() {
             get_data_directory,
             get_storage_stats,
             clear_all_data,
+            record_download,
+            get_download_history,
+            clear_download_history,
             // Equipment
             load_equipment,
             save_equipment,
@@ -233,6 +269,7 @@ pub fn run() {
             update_eyepiece,
             update_barlow_reducer,
             update_filter,
+            calculate_filter_effective_exposure_factor,
             set_default_telescope,
             set_default_camera,
             get_default_telescope,
@@ -246,6 +283,8 @@ pub fn run() {
             set_current_location,
             set_default_location,
             get_current_location,
+            set_horizon_profile,
+            import_horizon_profile,
             // Observation log
             load_observation_log,
             save_observation_log,
@@ -256,13 +295,22 @@ pub fn run() {
             delete_observation,
             update_session,
             end_session,
+            get_active_session,
             delete_session,
             get_observation_stats,
+            get_observation_stats_by_period,
+            get_session_summary,
+            generate_session_report,
+            #[cfg(desktop)]
+            import_observations_from_fits,
             search_observations,
             export_observation_log,
             // Target import/export
             export_targets,
             import_targets,
+            import_targets_kstars,
+            import_targets_stellarium,
+            export_targets_skysafari,
             // Session plan import/export/templates
             export_session_plan,
             import_session_plan,
@@ -270,23 +318,48 @@ pub fn run() {
             load_session_templates,
             // Astronomy calculations
             equatorial_to_horizontal,
+            batch_equatorial_to_horizontal,
             horizontal_to_equatorial,
             equatorial_to_galactic,
             galactic_to_equatorial,
             equatorial_to_ecliptic,
             ecliptic_to_equatorial,
             calculate_visibility,
+            calculate_visibility_calendar,
+            calculate_best_observation_time,
             calculate_twilight,
+            calculate_photography_times,
+            calculate_effective_darkness,
+            get_imaging_conditions,
             calculate_moon_phase,
             calculate_moon_position,
             calculate_sun_position,
+            calculate_sun_altitude_curve,
+            calculate_moon_altitude_curve,
             calculate_fov,
+            calculate_fov_footprint,
+            calculate_frame_fill,
+            calculate_drift_rate,
             calculate_mosaic_coverage,
+            calculate_mosaic_panels,
+            mosaic_slew_order,
             angular_separation,
+            angular_separation_epoch,
+            generate_coordinate_grid,
             format_ra_hms,
+            format_ra_hms_precision,
+            format_dec_dms_precision,
             format_dec_dms,
             parse_ra_hms,
             parse_dec_dms,
+            utc_to_hjd,
+            utc_to_bjd,
+            calculate_obliquity_command,
+            calculate_nutation,
+            get_target_hour_angle,
+            constellation_for_coords,
+            search_catalog,
+            identify_object,
             // Offline cache
             get_cache_stats,
             list_cache_regions,
@@ -297,6 +370,8 @@ pub fn run() {
             load_cached_tile,
             is_tile_cached,
             clear_survey_cache,
+            export_survey_cache,
+            import_survey_cache,
             clear_all_cache,
             get_cache_directory,
             // Unified cache
@@ -313,6 +388,7 @@ pub fn run() {
             prefetch_urls,
             // Astro events
             get_moon_phases_for_month,
+            get_moon_quarter_times,
             get_meteor_showers,
             get_seasonal_events,
             get_astro_events,
@@ -320,8 +396,10 @@ pub fn run() {
             get_tonight_highlights,
             // Target list
             load_target_list,
+            load_target_list_with_visibility,
             save_target_list,
             add_target,
+            add_target_from_catalog,
             add_targets_batch,
             update_target,
             remove_target,
@@ -331,8 +409,14 @@ pub fn run() {
             toggle_target_archive,
             set_targets_status_batch,
             set_targets_priority_batch,
+            record_target_progress,
             add_tag_to_targets,
             remove_tag_from_targets,
+            rename_tag,
+            delete_tag,
+            generate_observing_schedule,
+            calculate_exposure_plan_advanced,
+            recompute_all_observable_windows,
             archive_completed_targets,
             clear_completed_targets,
             clear_all_targets,
@@ -345,6 +429,7 @@ pub fn run() {
             update_marker,
             remove_marker,
             remove_markers_by_group,
+            add_markers_from_catalog,
             clear_all_markers,
             toggle_marker_visibility,
             set_all_markers_visible,
@@ -360,6 +445,9 @@ pub fn run() {
             get_active_requests,
             get_http_config,
             set_http_config,
+            get_request_log,
+            get_domain_rate_limits,
+            set_domain_rate_limit,
             http_get,
             http_post,
             http_head,
@@ -367,14 +455,22 @@ pub fn run() {
             http_cancel_request,
             http_cancel_all_requests,
             http_batch_download,
+            http_batch_download_streamed,
             // Mount control
             mount_connect,
             mount_disconnect,
+            mount_set_heartbeat_interval,
             mount_get_state,
             mount_get_capabilities,
             mount_slew_to,
+            mount_estimate_slew_time,
             mount_sync_to,
+            mount_add_sync_point,
+            mount_clear_alignment,
+            mount_get_alignment_stats,
             mount_abort_slew,
+            mount_spiral_search,
+            mount_abort_spiral,
             mount_park,
             mount_unpark,
             mount_set_tracking,
@@ -385,6 +481,10 @@ pub fn run() {
             mount_discover,
             mount_get_observing_conditions,
             mount_get_safety_state,
+            mount_get_config,
+            mount_set_config,
+            mount_get_park_position,
+            mount_set_park_position,
             // Desktop-only commands
             #[cfg(desktop)]
             load_app_settings,
@@ -404,6 +504,18 @@ pub fn run() {
             open_path,
             #[cfg(desktop)]
             reveal_in_file_manager,
+            #[cfg(desktop)]
+            get_shortcuts,
+            #[cfg(desktop)]
+            set_shortcut,
+            #[cfg(desktop)]
+            list_settings_profiles,
+            #[cfg(desktop)]
+            create_settings_profile,
+            #[cfg(desktop)]
+            switch_settings_profile,
+            #[cfg(desktop)]
+            delete_settings_profile,
             // Updater (desktop only)
             #[cfg(desktop)]
             check_for_update,
@@ -419,6 +531,10 @@ pub fn run() {
             clear_pending_update,
             #[cfg(desktop)]
             has_pending_update,
+            #[cfg(desktop)]
+            create_update_rollback_point,
+            #[cfg(desktop)]
+            rollback_update,
             // App control (desktop only)
             #[cfg(desktop)]
             restart_app,
@@ -444,6 +560,12 @@ pub fn run() {
             #[cfg(desktop)]
             set_active_map_api_key,
             #[cfg(desktop)]
+            record_map_api_key_use,
+            #[cfg(desktop)]
+            reset_map_api_key_usage,
+            #[cfg(desktop)]
+            validate_map_api_key_request,
+            #[cfg(desktop)]
             get_or_create_secret_vault_bootstrap,
             // Plate Solver (desktop only)
             #[cfg(desktop)]
@@ -488,9 +610,18 @@ pub fn run() {
             #[cfg(desktop)]
             extract_stars,
             #[cfg(desktop)]
+            calculate_focus_metrics,
+            #[cfg(desktop)]
             solve_online,
             #[cfg(desktop)]
             cancel_online_solve,
+            #[cfg(desktop)]
+            clear_astrometry_session,
+            #[cfg(desktop)]
+            write_wcs_file,
+            // Operations (desktop only)
+            #[cfg(desktop)]
+            cancel_all_operations,
             // Path config (desktop only)
             #[cfg(desktop)]
             get_path_config,
@@ -506,9 +637,18 @@ pub fn run() {
             reset_paths_to_default,
             #[cfg(desktop)]
             validate_directory,
+            #[cfg(desktop)]
+            available_disk_space,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                if let Err(error) = save_rate_limit_state(app) {
+                    log::warn!("Failed to save rate limit state: {error}");
+                }
+            }
+        });
 }
 
 #[cfg(test)]
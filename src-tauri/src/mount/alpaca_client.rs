@@ -6,6 +6,7 @@
 use std::sync::atomic::{AtomicU32, Ordering};
 
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
 use crate::mount::types::*;
@@ -17,6 +18,45 @@ fn next_transaction_id() -> u32 {
     TRANSACTION_ID.fetch_add(1, Ordering::Relaxed)
 }
 
+/// Alpaca error numbers with a dedicated [`MountError`] variant
+const ALPACA_NOT_IMPLEMENTED: i32 = 0x400;
+const ALPACA_NOT_CONNECTED: i32 = 0x407;
+const ALPACA_INVALID_WHILE_PARKED: i32 = 0x408;
+const ALPACA_INVALID_OPERATION: i32 = 0x40B;
+const ALPACA_ACTION_NOT_IMPLEMENTED: i32 = 0x40C;
+
+/// Map an Alpaca `ErrorNumber`/`ErrorMessage` pair to a typed error. Drivers
+/// report slewing and parked conflicts as a generic invalid operation, so the
+/// message decides those; anything else, including driver-specific codes
+/// (0x500-0xFFF), stays an `AlpacaError`.
+fn map_alpaca_error(code: i32, message: String) -> MountError {
+    let lower = message.to_lowercase();
+    match code {
+        ALPACA_NOT_CONNECTED => MountError::NotConnected,
+        ALPACA_INVALID_WHILE_PARKED => MountError::Parked,
+        ALPACA_NOT_IMPLEMENTED | ALPACA_ACTION_NOT_IMPLEMENTED => MountError::NotSupported(message),
+        ALPACA_INVALID_OPERATION if lower.contains("parked") => MountError::Parked,
+        ALPACA_INVALID_OPERATION if lower.contains("slewing") => MountError::AlreadySlewing,
+        _ => MountError::AlpacaError { code, message },
+    }
+}
+
+/// Decode an Alpaca JSON reply. Non-2xx statuses carry a plain-text body
+/// (e.g. 400 for an invalid value), reported as an `AlpacaError` with the
+/// HTTP status as its code.
+async fn read_alpaca_json<R: DeserializeOwned>(response: reqwest::Response) -> Result<R, MountError> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        let message = match body.trim() {
+            "" => status.canonical_reason().unwrap_or("HTTP error").to_string(),
+            text => text.to_string(),
+        };
+        return Err(MountError::AlpacaError { code: i32::from(status.as_u16()), message });
+    }
+    Ok(response.json().await?)
+}
+
 /// Standard Alpaca JSON response wrapper
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -33,10 +73,7 @@ struct AlpacaResponse<T> {
 impl<T> AlpacaResponse<T> {
     fn into_result(self) -> Result<T, MountError> {
         if self.error_number != 0 {
-            return Err(MountError::AlpacaError {
-                code: self.error_number,
-                message: self.error_message,
-            });
+            return Err(map_alpaca_error(self.error_number, self.error_message));
         }
         self.value.ok_or_else(|| MountError::AlpacaError {
             code: -1,
@@ -56,10 +93,7 @@ struct AlpacaVoidResponse {
 impl AlpacaVoidResponse {
     fn into_result(self) -> Result<(), MountError> {
         if self.error_number != 0 {
-            return Err(MountError::AlpacaError {
-                code: self.error_number,
-                message: self.error_message,
-            });
+            return Err(map_alpaca_error(self.error_number, self.error_message));
         }
         Ok(())
     }
@@ -97,21 +131,21 @@ impl AlpacaClient {
     async fn get_bool(&self, property: &str) -> Result<bool, MountError> {
         let url = format!("{}/{}?ClientID={}&ClientTransactionID={}",
             self.base_url, property, CLIENT_ID, next_transaction_id());
-        let resp: AlpacaResponse<bool> = self.client.get(&url).send().await?.json().await?;
+        let resp: AlpacaResponse<bool> = read_alpaca_json(self.client.get(&url).send().await?).await?;
         resp.into_result()
     }
 
     async fn get_f64(&self, property: &str) -> Result<f64, MountError> {
         let url = format!("{}/{}?ClientID={}&ClientTransactionID={}",
             self.base_url, property, CLIENT_ID, next_transaction_id());
-        let resp: AlpacaResponse<f64> = self.client.get(&url).send().await?.json().await?;
+        let resp: AlpacaResponse<f64> = read_alpaca_json(self.client.get(&url).send().await?).await?;
         resp.into_result()
     }
 
     async fn get_i32(&self, property: &str) -> Result<i32, MountError> {
         let url = format!("{}/{}?ClientID={}&ClientTransactionID={}",
             self.base_url, property, CLIENT_ID, next_transaction_id());
-        let resp: AlpacaResponse<i32> = self.client.get(&url).send().await?.json().await?;
+        let resp: AlpacaResponse<i32> = read_alpaca_json(self.client.get(&url).send().await?).await?;
         resp.into_result()
     }
 
@@ -126,7 +160,7 @@ impl AlpacaClient {
             CLIENT_ID,
             next_transaction_id()
         );
-        let resp: AlpacaResponse<f64> = self.client.get(&url).send().await?.json().await?;
+        let resp: AlpacaResponse<f64> = read_alpaca_json(self.client.get(&url).send().await?).await?;
         resp.into_result()
     }
 
@@ -141,7 +175,7 @@ impl AlpacaClient {
             CLIENT_ID,
             next_transaction_id()
         );
-        let resp: AlpacaResponse<bool> = self.client.get(&url).send().await?.json().await?;
+        let resp: AlpacaResponse<bool> = read_alpaca_json(self.client.get(&url).send().await?).await?;
         resp.into_result()
     }
 
@@ -149,7 +183,7 @@ impl AlpacaClient {
     async fn get_string(&self, property: &str) -> Result<String, MountError> {
         let url = format!("{}/{}?ClientID={}&ClientTransactionID={}",
             self.base_url, property, CLIENT_ID, next_transaction_id());
-        let resp: AlpacaResponse<String> = self.client.get(&url).send().await?.json().await?;
+        let resp: AlpacaResponse<String> = read_alpaca_json(self.client.get(&url).send().await?).await?;
         resp.into_result()
     }
 
@@ -164,7 +198,7 @@ impl AlpacaClient {
             ("ClientTransactionID", next_transaction_id().to_string()),
         ];
         form.extend(params.iter().map(|(k, v)| (*k, v.clone())));
-        let resp: AlpacaVoidResponse = self.client.put(&url).form(&form).send().await?.json().await?;
+        let resp: AlpacaVoidResponse = read_alpaca_json(self.client.put(&url).form(&form).send().await?).await?;
         resp.into_result()
     }
 
@@ -202,6 +236,16 @@ impl AlpacaClient {
         self.get_f64("declination").await
     }
 
+    /// Altitude in degrees
+    pub async fn get_altitude(&self) -> Result<f64, MountError> {
+        self.get_f64("altitude").await
+    }
+
+    /// Azimuth in degrees (N=0, E=90)
+    pub async fn get_azimuth(&self) -> Result<f64, MountError> {
+        self.get_f64("azimuth").await
+    }
+
     // ========================================================================
     // Status
     // ========================================================================
@@ -250,6 +294,7 @@ impl AlpacaClient {
         let can_sync = self.get_bool("cansync").await.unwrap_or(false);
         let can_park = self.get_bool("canpark").await.unwrap_or(false);
         let can_unpark = self.get_bool("canunpark").await.unwrap_or(false);
+        let can_set_park = self.get_bool("cansetpark").await.unwrap_or(false);
         let can_set_tracking = self.get_bool("cansettracking").await.unwrap_or(false);
         let can_move_axis = self.get_bool("canmoveaxis").await.unwrap_or(false);
         let can_pulse_guide = self.get_bool("canpulseguide").await.unwrap_or(false);
@@ -262,6 +307,7 @@ impl AlpacaClient {
             can_sync,
             can_park,
             can_unpark,
+            can_set_park,
             can_set_tracking,
             can_move_axis,
             can_pulse_guide,
@@ -314,6 +360,11 @@ impl AlpacaClient {
         self.put_void("unpark", &[]).await
     }
 
+    /// Store the mount's current position as its park position
+    pub async fn set_park(&self) -> Result<(), MountError> {
+        self.put_void("setpark", &[]).await
+    }
+
     pub async fn set_tracking(&self, enabled: bool) -> Result<(), MountError> {
         self.put_void("tracking", &[("Tracking", enabled.to_string())]).await
     }
@@ -407,49 +458,27 @@ impl AlpacaClient {
     // Discovery
     // ========================================================================
 
-    /// Discover Alpaca devices on the network via UDP broadcast
+    /// Discover Alpaca telescopes on the network: UDP broadcast, then query each
+    /// responding server's configured devices. Network failures yield an empty list.
     pub async fn discover(timeout_ms: u64) -> Result<Vec<DiscoveredDevice>, MountError> {
-        use tokio::net::UdpSocket;
-        use tokio::time::{timeout, Duration};
-
-        let socket = UdpSocket::bind("0.0.0.0:0")
-            .await
-            .map_err(|e| MountError::Other(format!("UDP bind failed: {}", e)))?;
-
-        socket
-            .set_broadcast(true)
-            .map_err(|e| MountError::Other(format!("Set broadcast failed: {}", e)))?;
+        let servers = match broadcast_discovery(timeout_ms).await {
+            Ok(servers) => servers,
+            Err(e) => {
+                log::warn!("Alpaca discovery broadcast failed: {}", e);
+                return Ok(Vec::new());
+            }
+        };
 
-        // Alpaca discovery message
-        let discovery_msg = b"alpacadiscovery1";
-        socket
-            .send_to(discovery_msg, "255.255.255.255:32227")
-            .await
-            .map_err(|e| MountError::Other(format!("UDP send failed: {}", e)))?;
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_millis(timeout_ms.max(1000)))
+            .build()
+            .unwrap_or_default();
 
         let mut devices = Vec::new();
-        let mut buf = [0u8; 1024];
-
-        let deadline = Duration::from_millis(timeout_ms);
-        loop {
-            match timeout(deadline, socket.recv_from(&mut buf)).await {
-                Ok(Ok((len, addr))) => {
-                    if let Ok(text) = std::str::from_utf8(&buf[..len]) {
-                        // Parse Alpaca discovery response: {"AlpacaPort": 11111}
-                        if let Ok(val) = serde_json::from_str::<serde_json::Value>(text) {
-                            if let Some(port) = val.get("AlpacaPort").and_then(|v| v.as_u64()) {
-                                devices.push(DiscoveredDevice {
-                                    host: addr.ip().to_string(),
-                                    port: port as u16,
-                                    device_id: 0,
-                                    device_name: format!("Alpaca Server @ {}", addr.ip()),
-                                    device_type: "Telescope".to_string(),
-                                });
-                            }
-                        }
-                    }
-                }
-                Ok(Err(_)) | Err(_) => break,
+        for (host, port) in servers {
+            match query_configured_telescopes(&client, &host, port).await {
+                Ok(found) => devices.extend(found),
+                Err(e) => log::warn!("Alpaca server {}:{} did not list devices: {}", host, port, e),
             }
         }
 
@@ -457,3 +486,300 @@ impl AlpacaClient {
         Ok(devices)
     }
 }
+
+/// Alpaca discovery port (ASCOM Alpaca API, "Discovery Protocol")
+const DISCOVERY_PORT: u16 = 32227;
+
+/// Send the discovery broadcast and collect unique `(host, AlpacaPort)` responders
+async fn broadcast_discovery(timeout_ms: u64) -> Result<Vec<(String, u16)>, MountError> {
+    use tokio::net::UdpSocket;
+    use tokio::time::{timeout_at, Duration, Instant};
+
+    let socket = UdpSocket::bind("0.0.0.0:0")
+        .await
+        .map_err(|e| MountError::Other(format!("UDP bind failed: {}", e)))?;
+    socket
+        .set_broadcast(true)
+        .map_err(|e| MountError::Other(format!("Set broadcast failed: {}", e)))?;
+    socket
+        .send_to(b"alpacadiscovery1", ("255.255.255.255", DISCOVERY_PORT))
+        .await
+        .map_err(|e| MountError::Other(format!("UDP send failed: {}", e)))?;
+
+    let mut servers: Vec<(String, u16)> = Vec::new();
+    let mut buf = [0u8; 1024];
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+    while let Ok(Ok((len, addr))) = timeout_at(deadline, socket.recv_from(&mut buf)).await {
+        if let Some(port) = parse_discovery_response(&buf[..len]) {
+            let server = (addr.ip().to_string(), port);
+            if !servers.contains(&server) {
+                servers.push(server);
+            }
+        }
+    }
+    Ok(servers)
+}
+
+/// Parse a discovery reply such as `{"AlpacaPort": 11111}`
+fn parse_discovery_response(data: &[u8]) -> Option<u16> {
+    let value: serde_json::Value = serde_json::from_slice(data).ok()?;
+    value
+        .get("AlpacaPort")?
+        .as_u64()
+        .and_then(|port| u16::try_from(port).ok())
+}
+
+/// Entry of `/management/v1/configureddevices`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ConfiguredDevice {
+    device_name: String,
+    device_type: String,
+    device_number: u32,
+    #[serde(rename = "UniqueID")]
+    unique_id: String,
+}
+
+/// Management API response wrapper; `ErrorNumber` and `ErrorMessage` are
+/// optional there, unlike device responses
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ManagementResponse<T> {
+    #[serde(default)]
+    error_number: i32,
+    #[serde(default)]
+    error_message: String,
+    value: Option<T>,
+}
+
+impl<T> ManagementResponse<T> {
+    fn into_result(self) -> Result<T, MountError> {
+        AlpacaResponse {
+            client_transaction_id: None,
+            server_transaction_id: None,
+            error_number: self.error_number,
+            error_message: self.error_message,
+            value: self.value,
+        }
+        .into_result()
+    }
+}
+
+/// Telescopes listed in a `configureddevices` response body
+fn parse_configured_telescopes(
+    host: &str,
+    port: u16,
+    json: &str,
+) -> Result<Vec<DiscoveredDevice>, MountError> {
+    let resp: ManagementResponse<Vec<ConfiguredDevice>> = serde_json::from_str(json)
+        .map_err(|e| MountError::Other(format!("Invalid configureddevices response: {}", e)))?;
+    Ok(resp
+        .into_result()?
+        .into_iter()
+        .filter(|device| device.device_type.eq_ignore_ascii_case("telescope"))
+        .map(|device| DiscoveredDevice {
+            address: format!("{}:{}", host, port),
+            host: host.to_string(),
+            port,
+            device_id: device.device_number,
+            device_name: device.device_name,
+            device_type: device.device_type,
+            unique_id: device.unique_id,
+            interface_version: None,
+        })
+        .collect())
+}
+
+async fn query_configured_telescopes(
+    client: &Client,
+    host: &str,
+    port: u16,
+) -> Result<Vec<DiscoveredDevice>, MountError> {
+    let url = format!(
+        "http://{}:{}/management/v1/configureddevices?ClientID={}&ClientTransactionID={}",
+        host, port, CLIENT_ID, next_transaction_id()
+    );
+    let response = client.get(&url).send().await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(MountError::AlpacaError { code: i32::from(status.as_u16()), message: body.trim().to_string() });
+    }
+    let mut devices = parse_configured_telescopes(host, port, &body)?;
+
+    for device in &mut devices {
+        let url = format!(
+            "http://{}:{}/api/v1/telescope/{}/interfaceversion?ClientID={}&ClientTransactionID={}",
+            host, port, device.device_id, CLIENT_ID, next_transaction_id()
+        );
+        let version: Result<AlpacaResponse<i32>, _> = async {
+            client.get(&url).send().await?.json().await
+        }
+        .await;
+        device.interface_version = version.ok().and_then(|resp| resp.into_result().ok());
+    }
+    Ok(devices)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ------------------------------------------------------------------------
+    // Discovery Parsing Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_discovery_response() {
+        assert_eq!(parse_discovery_response(br#"{"AlpacaPort":11111}"#), Some(11111));
+        assert_eq!(parse_discovery_response(br#"{"AlpacaPort": 32323}"#), Some(32323));
+        assert_eq!(parse_discovery_response(br#"{"AlpacaPort": 70000}"#), None);
+        assert_eq!(parse_discovery_response(b"alpacadiscovery1"), None);
+    }
+
+    #[test]
+    fn test_parse_configured_telescopes() {
+        let json = r#"{
+            "Value": [
+                {"DeviceName": "EQ6-R Pro", "DeviceType": "Telescope", "DeviceNumber": 0,
+                 "UniqueID": "7f0ad6b1-3c1e-4f8e-9d0a-2b8d5e4c1a10"},
+                {"DeviceName": "ZWO ASI294MC", "DeviceType": "Camera", "DeviceNumber": 0,
+                 "UniqueID": "0d6a9c53-1b7e-4b3f-a3a8-5a4b1f9c2e77"},
+                {"DeviceName": "Simulator", "DeviceType": "telescope", "DeviceNumber": 1,
+                 "UniqueID": "c1f3e0aa-91b2-4d55-8e0e-5f1d2a3b4c5d"}
+            ],
+            "ClientTransactionID": 1,
+            "ServerTransactionID": 17,
+            "ErrorNumber": 0,
+            "ErrorMessage": ""
+        }"#;
+
+        let devices = parse_configured_telescopes("192.168.1.20", 11111, json).unwrap();
+        assert_eq!(devices.len(), 2);
+        assert_eq!(
+            devices[0],
+            DiscoveredDevice {
+                address: "192.168.1.20:11111".to_string(),
+                host: "192.168.1.20".to_string(),
+                port: 11111,
+                device_id: 0,
+                device_name: "EQ6-R Pro".to_string(),
+                device_type: "Telescope".to_string(),
+                unique_id: "7f0ad6b1-3c1e-4f8e-9d0a-2b8d5e4c1a10".to_string(),
+                interface_version: None,
+            }
+        );
+        assert_eq!(devices[1].device_id, 1);
+
+        // Management responses may omit the error fields entirely
+        let json = r#"{"Value": [{"DeviceName": "Mount", "DeviceType": "Telescope", "DeviceNumber": 2,
+            "UniqueID": "u-2"}], "ServerTransactionID": 4}"#;
+        let devices = parse_configured_telescopes("192.168.1.20", 11111, json).unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].device_id, 2);
+    }
+
+    #[test]
+    fn test_parse_configured_telescopes_error_response() {
+        let json = r#"{"Value": null, "ErrorNumber": 1280, "ErrorMessage": "Not ready"}"#;
+        let result = parse_configured_telescopes("10.0.0.2", 11111, json);
+        assert!(matches!(result, Err(MountError::AlpacaError { code: 1280, .. })));
+        assert!(parse_configured_telescopes("10.0.0.2", 11111, "<html>").is_err());
+    }
+
+    // ------------------------------------------------------------------------
+    // Error Mapping Tests
+    // ------------------------------------------------------------------------
+
+    fn void_error(json: &str) -> MountError {
+        serde_json::from_str::<AlpacaVoidResponse>(json).unwrap().into_result().unwrap_err()
+    }
+
+    #[test]
+    fn test_slew_while_parked_maps_to_parked() {
+        let err = void_error(r#"{"ClientTransactionID": 3, "ServerTransactionID": 9,
+            "ErrorNumber": 1032, "ErrorMessage": "SlewToCoordinatesAsync is not allowed while parked"}"#);
+        assert!(matches!(err, MountError::Parked));
+
+        let err = void_error(r#"{"ErrorNumber": 1035, "ErrorMessage": "The telescope is Parked"}"#);
+        assert!(matches!(err, MountError::Parked));
+    }
+
+    #[test]
+    fn test_not_connected_and_slewing_errors() {
+        let err = void_error(r#"{"ErrorNumber": 1031, "ErrorMessage": "Telescope is not connected"}"#);
+        assert!(matches!(err, MountError::NotConnected));
+
+        let err = void_error(r#"{"ErrorNumber": 1035, "ErrorMessage": "Cannot sync while slewing"}"#);
+        assert!(matches!(err, MountError::AlreadySlewing));
+    }
+
+    #[test]
+    fn test_not_implemented_maps_to_not_supported() {
+        let resp: AlpacaResponse<bool> = serde_json::from_str(
+            r#"{"Value": false, "ErrorNumber": 1024, "ErrorMessage": "Property CanPulseGuide is not implemented"}"#,
+        )
+        .unwrap();
+        match resp.into_result() {
+            Err(MountError::NotSupported(message)) => assert!(message.contains("CanPulseGuide")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_other_errors_keep_code_and_message() {
+        let err = void_error(r#"{"ErrorNumber": 1025, "ErrorMessage": "Declination out of range"}"#);
+        assert!(matches!(err, MountError::AlpacaError { code: 1025, ref message } if message == "Declination out of range"));
+
+        let err = void_error(r#"{"ErrorNumber": 1291, "ErrorMessage": "Motor stalled"}"#);
+        assert!(matches!(err, MountError::AlpacaError { code: 1291, .. }));
+        assert_eq!(err.to_string(), "Alpaca error (1291): Motor stalled");
+    }
+
+    #[tokio::test]
+    async fn test_http_error_with_text_body_maps_to_alpaca_error() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let _server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = socket.read(&mut request);
+            let body = "Value 'abc' for RightAscension is not a number";
+            let reply = format!(
+                "HTTP/1.1 400 Bad Request\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(reply.as_bytes()).unwrap();
+        });
+
+        let client = AlpacaClient::new("127.0.0.1", port, 0);
+        match client.get_bool("tracking").await {
+            Err(MountError::AlpacaError { code: 400, message }) => assert!(message.contains("RightAscension")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unresponsive_device_maps_to_timeout() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let _hold = tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+        });
+
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_millis(100))
+            .build()
+            .unwrap();
+        let err = client.get(format!("http://{}/api/v1/telescope/0/connected", addr)).send().await.unwrap_err();
+        assert!(matches!(MountError::from(err), MountError::Timeout(_)));
+    }
+}
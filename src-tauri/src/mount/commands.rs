@@ -2,10 +2,16 @@
 //!
 //! All commands are async and use a global `Mutex` to hold the active mount instance.
 
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
 use once_cell::sync::Lazy;
+use tauri::{AppHandle, Emitter};
 use tokio::sync::Mutex;
 
 use crate::mount::alpaca_client::AlpacaClient;
+use crate::mount::limits::check_slew_target;
 use crate::mount::simulator::MountSimulator;
 use crate::mount::types::*;
 
@@ -20,6 +26,9 @@ enum MountDriver {
 
 static MOUNT: Lazy<Mutex<Option<MountDriver>>> = Lazy::new(|| Mutex::new(None));
 
+/// Site and slew limits, applied to whichever driver is connected
+static MOUNT_CONFIG: Lazy<Mutex<MountConfig>> = Lazy::new(|| Mutex::new(MountConfig::default()));
+
 /// Tracks the user-selected slew rate index (shared across drivers)
 static SLEW_RATE_INDEX: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(3);
 
@@ -29,6 +38,7 @@ static SLEW_RATE_INDEX: std::sync::atomic::AtomicUsize = std::sync::atomic::Atom
 
 #[tauri::command]
 pub async fn mount_connect(
+    app: AppHandle,
     protocol: MountProtocol,
     host: String,
     port: u16,
@@ -47,6 +57,7 @@ pub async fn mount_connect(
     match protocol {
         MountProtocol::Simulator => {
             let mut sim = MountSimulator::new();
+            sim.set_config(MOUNT_CONFIG.lock().await.clone());
             sim.connect()?;
             let caps = sim.get_capabilities();
             *guard = Some(MountDriver::Simulator(sim));
@@ -60,6 +71,7 @@ pub async fn mount_connect(
             })?;
             let caps = client.get_capabilities().await.unwrap_or_default();
             *guard = Some(MountDriver::Alpaca(client));
+            spawn_heartbeat(app);
             log::info!("Mount connected via Alpaca to {}:{}", host, port);
             Ok(caps)
         }
@@ -68,6 +80,8 @@ pub async fn mount_connect(
 
 #[tauri::command]
 pub async fn mount_disconnect() -> Result<(), MountError> {
+    // Retire any running heartbeat before the driver goes away
+    HEARTBEAT_GENERATION.fetch_add(1, Ordering::SeqCst);
     let mut guard = MOUNT.lock().await;
     if let Some(ref mut driver) = *guard {
         match driver {
@@ -79,6 +93,130 @@ pub async fn mount_disconnect() -> Result<(), MountError> {
     Ok(())
 }
 
+// ============================================================================
+// Heartbeat / auto-reconnect
+// ============================================================================
+
+/// Heartbeat poll interval in milliseconds (0 disables polling)
+static HEARTBEAT_INTERVAL_MS: AtomicU64 = AtomicU64::new(5000);
+/// Bumped on every connect/disconnect so stale heartbeat tasks exit
+static HEARTBEAT_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+const MIN_HEARTBEAT_INTERVAL_MS: u64 = 500;
+const MAX_RECONNECT_ATTEMPTS: u32 = 3;
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+/// Result of a single heartbeat check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HeartbeatOutcome {
+    Healthy,
+    Reconnected { attempts: u32 },
+    GaveUp { attempts: u32 },
+}
+
+/// Poll the connection once; on failure emit `mount-connection-lost` and retry
+/// `reconnect` up to `max_attempts` times, emitting `mount-reconnected` on success
+async fn heartbeat_check<P, PF, R, RF, E>(
+    mut poll: P,
+    mut reconnect: R,
+    max_attempts: u32,
+    retry_delay: Duration,
+    mut emit: E,
+) -> HeartbeatOutcome
+where
+    P: FnMut() -> PF,
+    PF: Future<Output = Result<bool, MountError>>,
+    R: FnMut() -> RF,
+    RF: Future<Output = Result<(), MountError>>,
+    E: FnMut(&'static str, MountHeartbeatEvent),
+{
+    let reason = match poll().await {
+        Ok(true) => return HeartbeatOutcome::Healthy,
+        Ok(false) => "Mount reports disconnected".to_string(),
+        Err(e) => e.to_string(),
+    };
+    log::warn!("Mount heartbeat failed: {}", reason);
+    emit(
+        "mount-connection-lost",
+        MountHeartbeatEvent {
+            reason: Some(reason),
+            reconnect_attempts: 0,
+        },
+    );
+
+    for attempt in 1..=max_attempts {
+        tokio::time::sleep(retry_delay).await;
+        if reconnect().await.is_ok() && matches!(poll().await, Ok(true)) {
+            log::info!("Mount reconnected after {} attempt(s)", attempt);
+            emit(
+                "mount-reconnected",
+                MountHeartbeatEvent {
+                    reason: None,
+                    reconnect_attempts: attempt,
+                },
+            );
+            return HeartbeatOutcome::Reconnected { attempts: attempt };
+        }
+    }
+    HeartbeatOutcome::GaveUp {
+        attempts: max_attempts,
+    }
+}
+
+async fn reconnect_alpaca() -> Result<(), MountError> {
+    let guard = MOUNT.lock().await;
+    match guard.as_ref() {
+        Some(MountDriver::Alpaca(client)) => client.connect().await,
+        _ => Err(MountError::NotConnected),
+    }
+}
+
+/// Run the heartbeat for the current Alpaca connection until it is replaced,
+/// disconnected, or reconnecting gives up
+fn spawn_heartbeat(app: AppHandle) {
+    let generation = HEARTBEAT_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let interval = HEARTBEAT_INTERVAL_MS.load(Ordering::Relaxed);
+            tokio::time::sleep(Duration::from_millis(interval.max(MIN_HEARTBEAT_INTERVAL_MS))).await;
+            if HEARTBEAT_GENERATION.load(Ordering::SeqCst) != generation {
+                break;
+            }
+            if interval == 0 {
+                continue;
+            }
+
+            let outcome = heartbeat_check(
+                || async { mount_get_state().await.map(|state| state.connected) },
+                reconnect_alpaca,
+                MAX_RECONNECT_ATTEMPTS,
+                RECONNECT_DELAY,
+                |event, payload| {
+                    let _ = app.emit(event, payload);
+                },
+            )
+            .await;
+            if let HeartbeatOutcome::GaveUp { attempts } = outcome {
+                log::error!("Mount heartbeat gave up after {} reconnect attempts", attempts);
+                break;
+            }
+        }
+    });
+}
+
+/// Set the heartbeat poll interval in milliseconds (0 disables it)
+#[tauri::command]
+pub async fn mount_set_heartbeat_interval(ms: u64) -> Result<(), MountError> {
+    if ms != 0 && ms < MIN_HEARTBEAT_INTERVAL_MS {
+        return Err(MountError::Other(format!(
+            "Heartbeat interval must be at least {} ms",
+            MIN_HEARTBEAT_INTERVAL_MS
+        )));
+    }
+    HEARTBEAT_INTERVAL_MS.store(ms, Ordering::Relaxed);
+    Ok(())
+}
+
 // ============================================================================
 // State query
 // ============================================================================
@@ -107,6 +245,26 @@ pub async fn mount_get_capabilities() -> Result<MountCapabilities, MountError> {
     }
 }
 
+// ============================================================================
+// Configuration
+// ============================================================================
+
+#[tauri::command]
+pub async fn mount_get_config() -> Result<MountConfig, MountError> {
+    Ok(MOUNT_CONFIG.lock().await.clone())
+}
+
+/// Replace the site and slew limits (also pushed to a connected simulator)
+#[tauri::command]
+pub async fn mount_set_config(config: MountConfig) -> Result<(), MountError> {
+    let mut guard = MOUNT.lock().await;
+    if let Some(MountDriver::Simulator(sim)) = guard.as_mut() {
+        sim.set_config(config.clone());
+    }
+    *MOUNT_CONFIG.lock().await = config;
+    Ok(())
+}
+
 // ============================================================================
 // Slew / Sync / Abort
 // ============================================================================
@@ -118,6 +276,7 @@ pub async fn mount_slew_to(ra: f64, dec: f64) -> Result<(), MountError> {
     match guard.as_mut() {
         Some(MountDriver::Simulator(sim)) => sim.slew_to(ra, dec),
         Some(MountDriver::Alpaca(client)) => {
+            check_slew_target(&*MOUNT_CONFIG.lock().await, ra, dec, None)?;
             // Alpaca expects RA in hours
             let ra_hours = ra / 15.0;
             client.slew_to_coordinates_async(ra_hours, dec).await
@@ -126,6 +285,25 @@ pub async fn mount_slew_to(ra: f64, dec: f64) -> Result<(), MountError> {
     }
 }
 
+/// Estimated goto duration in seconds between two positions (degrees).
+/// RA and Dec axes slew independently, so the longer axis move dominates;
+/// the RA move takes the short way around.
+#[tauri::command]
+pub fn mount_estimate_slew_time(
+    from_ra: f64,
+    from_dec: f64,
+    to_ra: f64,
+    to_dec: f64,
+    slew_rate_deg_per_sec: f64,
+) -> f64 {
+    if slew_rate_deg_per_sec <= 0.0 {
+        return 0.0;
+    }
+    let ra_distance = ((to_ra - from_ra + 180.0).rem_euclid(360.0) - 180.0).abs();
+    let dec_distance = (to_dec - from_dec).abs();
+    ra_distance.max(dec_distance) / slew_rate_deg_per_sec
+}
+
 /// Sync mount to coordinates (RA in degrees, Dec in degrees)
 #[tauri::command]
 pub async fn mount_sync_to(ra: f64, dec: f64) -> Result<(), MountError> {
@@ -140,6 +318,46 @@ pub async fn mount_sync_to(ra: f64, dec: f64) -> Result<(), MountError> {
     }
 }
 
+/// Add a multi-point alignment sync point: the mount currently points at RA/Dec (degrees)
+#[tauri::command]
+pub async fn mount_add_sync_point(ra: f64, dec: f64) -> Result<AlignmentStats, MountError> {
+    let mut guard = MOUNT.lock().await;
+    match guard.as_mut() {
+        Some(MountDriver::Simulator(sim)) => sim.add_sync_point(ra, dec),
+        Some(MountDriver::Alpaca(_)) => Err(MountError::NotSupported(
+            "Alpaca mounts keep their own pointing model; use sync instead".to_string(),
+        )),
+        None => Err(MountError::NotConnected),
+    }
+}
+
+#[tauri::command]
+pub async fn mount_clear_alignment() -> Result<(), MountError> {
+    let mut guard = MOUNT.lock().await;
+    match guard.as_mut() {
+        Some(MountDriver::Simulator(sim)) => {
+            sim.clear_alignment();
+            Ok(())
+        }
+        Some(MountDriver::Alpaca(_)) => Err(MountError::NotSupported(
+            "Alpaca mounts keep their own pointing model".to_string(),
+        )),
+        None => Err(MountError::NotConnected),
+    }
+}
+
+#[tauri::command]
+pub async fn mount_get_alignment_stats() -> Result<AlignmentStats, MountError> {
+    let guard = MOUNT.lock().await;
+    match guard.as_ref() {
+        Some(MountDriver::Simulator(sim)) => Ok(sim.get_alignment_stats()),
+        Some(MountDriver::Alpaca(_)) => Err(MountError::NotSupported(
+            "Alpaca mounts keep their own pointing model".to_string(),
+        )),
+        None => Err(MountError::NotConnected),
+    }
+}
+
 #[tauri::command]
 pub async fn mount_abort_slew() -> Result<(), MountError> {
     let mut guard = MOUNT.lock().await;
@@ -150,6 +368,149 @@ pub async fn mount_abort_slew() -> Result<(), MountError> {
     }
 }
 
+// ============================================================================
+// Spiral search
+// ============================================================================
+
+static SPIRAL_RUNNING: AtomicBool = AtomicBool::new(false);
+static SPIRAL_CANCEL: AtomicBool = AtomicBool::new(false);
+
+/// Poll interval while waiting for a spiral slew to finish
+const SPIRAL_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// Give up on a single spiral slew after this long
+const SPIRAL_SLEW_TIMEOUT: Duration = Duration::from_secs(120);
+/// Pause at each point so a camera/solver can check for the target
+const SPIRAL_SETTLE: Duration = Duration::from_millis(500);
+
+/// Most points a spiral search visits: a 21x21 square around the start
+const MAX_SPIRAL_STEPS: u32 = 440;
+
+/// Offsets (in steps) of an expanding square spiral around the origin:
+/// legs of 1, 1, 2, 2, 3, 3, ... heading east, north, west, south
+/// `max_steps` is capped at [`MAX_SPIRAL_STEPS`]
+pub fn square_spiral_offsets(max_steps: u32) -> Vec<(i32, i32)> {
+    const DIRECTIONS: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+    let max_steps = max_steps.min(MAX_SPIRAL_STEPS);
+    let mut offsets = Vec::with_capacity(max_steps as usize);
+    let (mut x, mut y) = (0, 0);
+    let mut leg = 0usize;
+
+    while offsets.len() < max_steps as usize {
+        let (dx, dy) = DIRECTIONS[leg % 4];
+        let length = leg / 2 + 1;
+        for _ in 0..length {
+            if offsets.len() == max_steps as usize {
+                break;
+            }
+            x += dx;
+            y += dy;
+            offsets.push((x, y));
+        }
+        leg += 1;
+    }
+    offsets
+}
+
+/// RA/Dec (degrees) of a spiral offset; RA steps are widened by 1/cos(dec)
+/// so each step covers the same angle on the sky
+pub fn spiral_step_coordinates(
+    center_ra: f64,
+    center_dec: f64,
+    offset: (i32, i32),
+    step_deg: f64,
+) -> (f64, f64) {
+    let cos_dec = center_dec.to_radians().cos().max(0.01);
+    let ra = center_ra + offset.0 as f64 * step_deg / cos_dec;
+    let dec = center_dec + offset.1 as f64 * step_deg;
+    (ra.rem_euclid(360.0), dec.clamp(-90.0, 90.0))
+}
+
+async fn wait_for_slew_end() -> Result<MountState, MountError> {
+    let started = std::time::Instant::now();
+    loop {
+        let state = mount_get_state().await?;
+        if !state.slewing {
+            return Ok(state);
+        }
+        if SPIRAL_CANCEL.load(Ordering::SeqCst) {
+            return Ok(state);
+        }
+        if started.elapsed() > SPIRAL_SLEW_TIMEOUT {
+            return Err(MountError::Timeout("Spiral search slew did not finish".to_string()));
+        }
+        tokio::time::sleep(SPIRAL_POLL_INTERVAL).await;
+    }
+}
+
+/// Clears the running flag however the spiral search ends
+struct SpiralGuard;
+
+impl Drop for SpiralGuard {
+    fn drop(&mut self) {
+        SPIRAL_RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Move in an expanding square spiral around the current position, emitting
+/// `mount-spiral-step` at each point. Returns the visited points.
+#[tauri::command]
+pub async fn mount_spiral_search(
+    app: AppHandle,
+    step_arcmin: f64,
+    max_steps: u32,
+) -> Result<Vec<SpiralStep>, MountError> {
+    if !step_arcmin.is_finite() || step_arcmin <= 0.0 {
+        return Err(MountError::Other("Spiral step must be a positive number".to_string()));
+    }
+    if SPIRAL_RUNNING.swap(true, Ordering::SeqCst) {
+        return Err(MountError::Other("Spiral search already running".to_string()));
+    }
+    let _guard = SpiralGuard;
+    SPIRAL_CANCEL.store(false, Ordering::SeqCst);
+
+    let center = mount_get_state().await?;
+    if !center.connected {
+        return Err(MountError::NotConnected);
+    }
+
+    let mut visited = Vec::new();
+    for (index, offset) in square_spiral_offsets(max_steps).into_iter().enumerate() {
+        if SPIRAL_CANCEL.load(Ordering::SeqCst) {
+            break;
+        }
+        let (ra, dec) = spiral_step_coordinates(center.ra, center.dec, offset, step_arcmin / 60.0);
+        mount_slew_to(ra, dec).await?;
+        let state = wait_for_slew_end().await?;
+        if SPIRAL_CANCEL.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let step = SpiralStep {
+            index: index as u32,
+            offset_x: offset.0,
+            offset_y: offset.1,
+            ra: state.ra,
+            dec: state.dec,
+        };
+        let _ = app.emit("mount-spiral-step", &step);
+        visited.push(step);
+        tokio::time::sleep(SPIRAL_SETTLE).await;
+    }
+
+    log::info!("Spiral search visited {} points", visited.len());
+    Ok(visited)
+}
+
+/// Stop a running spiral search, leaving the mount at its current point
+#[tauri::command]
+pub async fn mount_abort_spiral() -> Result<(), MountError> {
+    if SPIRAL_RUNNING.load(Ordering::SeqCst) {
+        SPIRAL_CANCEL.store(true, Ordering::SeqCst);
+        mount_abort_slew().await?;
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Park / Unpark
 // ============================================================================
@@ -174,6 +535,51 @@ pub async fn mount_unpark() -> Result<(), MountError> {
     }
 }
 
+/// Alpaca `SetPark` only records the current position, so it is used when the
+/// mount already points within this many degrees of the requested park position
+const PARK_MATCH_TOLERANCE_DEG: f64 = 1.0;
+
+#[tauri::command]
+pub async fn mount_get_park_position() -> Result<Option<ParkPosition>, MountError> {
+    Ok(MOUNT_CONFIG.lock().await.park_position)
+}
+
+/// Set the park position (altitude/azimuth in degrees)
+#[tauri::command]
+pub async fn mount_set_park_position(alt: f64, az: f64) -> Result<ParkPosition, MountError> {
+    if !(-90.0..=90.0).contains(&alt) {
+        return Err(MountError::Other(format!("Park altitude out of range: {}", alt)));
+    }
+    let position = ParkPosition { alt, az: az.rem_euclid(360.0) };
+
+    let mut guard = MOUNT.lock().await;
+    let mut config = MOUNT_CONFIG.lock().await;
+    config.park_position = Some(position);
+
+    match guard.as_mut() {
+        Some(MountDriver::Simulator(sim)) => sim.set_config(config.clone()),
+        Some(MountDriver::Alpaca(client)) => {
+            let can_set_park = client.get_capabilities().await.map(|c| c.can_set_park).unwrap_or(false);
+            if can_set_park {
+                let current_alt = client.get_altitude().await?;
+                let current_az = client.get_azimuth().await?;
+                let daz = ((current_az - position.az + 540.0) % 360.0 - 180.0).abs();
+                if (current_alt - position.alt).abs() <= PARK_MATCH_TOLERANCE_DEG
+                    && daz <= PARK_MATCH_TOLERANCE_DEG
+                {
+                    client.set_park().await?;
+                } else {
+                    log::warn!("Alpaca mount is not at the requested park position; driver park unchanged");
+                }
+            }
+        }
+        None => {}
+    }
+
+    log::info!("Mount park position set to Alt={:.2}° Az={:.2}°", position.alt, position.az);
+    Ok(position)
+}
+
 // ============================================================================
 // Tracking
 // ============================================================================
@@ -281,3 +687,194 @@ pub async fn mount_get_safety_state() -> Result<SafetyState, MountError> {
         None => Err(MountError::NotConnected),
     }
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ------------------------------------------------------------------------
+    // Heartbeat Tests
+    // ------------------------------------------------------------------------
+
+    use std::sync::atomic::AtomicU32;
+    use std::sync::Arc;
+
+    /// Alpaca stand-in: polls fail until a reconnect has succeeded
+    #[derive(Default)]
+    struct MockAlpaca {
+        online: AtomicBool,
+        failing_reconnects: AtomicU32,
+        reconnect_calls: AtomicU32,
+    }
+
+    impl MockAlpaca {
+        async fn poll(&self) -> Result<bool, MountError> {
+            if self.online.load(Ordering::SeqCst) {
+                Ok(true)
+            } else {
+                Err(MountError::HttpError("connection reset".to_string()))
+            }
+        }
+
+        async fn reconnect(&self) -> Result<(), MountError> {
+            self.reconnect_calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing_reconnects.load(Ordering::SeqCst) > 0 {
+                self.failing_reconnects.fetch_sub(1, Ordering::SeqCst);
+                return Err(MountError::Timeout("no route to host".to_string()));
+            }
+            self.online.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    type RecordedEvents = Vec<(&'static str, MountHeartbeatEvent)>;
+
+    async fn run_check(mock: &Arc<MockAlpaca>) -> (HeartbeatOutcome, RecordedEvents) {
+        let mut events = Vec::new();
+        let outcome = heartbeat_check(
+            || {
+                let mock = mock.clone();
+                async move { mock.poll().await }
+            },
+            || {
+                let mock = mock.clone();
+                async move { mock.reconnect().await }
+            },
+            MAX_RECONNECT_ATTEMPTS,
+            Duration::ZERO,
+            |event, payload| events.push((event, payload)),
+        )
+        .await;
+        (outcome, events)
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_healthy_emits_nothing() {
+        let mock = Arc::new(MockAlpaca::default());
+        mock.online.store(true, Ordering::SeqCst);
+
+        let (outcome, events) = run_check(&mock).await;
+        assert_eq!(outcome, HeartbeatOutcome::Healthy);
+        assert!(events.is_empty());
+        assert_eq!(mock.reconnect_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_reconnects_after_failures() {
+        let mock = Arc::new(MockAlpaca::default());
+        mock.failing_reconnects.store(1, Ordering::SeqCst);
+
+        let (outcome, events) = run_check(&mock).await;
+        assert_eq!(outcome, HeartbeatOutcome::Reconnected { attempts: 2 });
+        assert_eq!(mock.reconnect_calls.load(Ordering::SeqCst), 2);
+
+        let names: Vec<&str> = events.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["mount-connection-lost", "mount-reconnected"]);
+        assert!(events[0].1.reason.as_deref().unwrap().contains("connection reset"));
+        assert_eq!(events[1].1.reconnect_attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_gives_up_after_bounded_attempts() {
+        let mock = Arc::new(MockAlpaca::default());
+        mock.failing_reconnects.store(10, Ordering::SeqCst);
+
+        let (outcome, events) = run_check(&mock).await;
+        assert_eq!(
+            outcome,
+            HeartbeatOutcome::GaveUp {
+                attempts: MAX_RECONNECT_ATTEMPTS
+            }
+        );
+        assert_eq!(mock.reconnect_calls.load(Ordering::SeqCst), MAX_RECONNECT_ATTEMPTS);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, "mount-connection-lost");
+    }
+
+    #[tokio::test]
+    async fn test_set_heartbeat_interval_validates_minimum() {
+        assert!(mount_set_heartbeat_interval(100).await.is_err());
+        assert!(mount_set_heartbeat_interval(0).await.is_ok());
+        assert!(mount_set_heartbeat_interval(5000).await.is_ok());
+    }
+
+    // ------------------------------------------------------------------------
+    // Slew Time Estimate Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_estimate_slew_time_pure_ra() {
+        assert!((mount_estimate_slew_time(10.0, 20.0, 70.0, 20.0, 3.0) - 20.0).abs() < 1e-9);
+        // 350 -> 10 wraps through 0: 20 degrees, not 340
+        assert!((mount_estimate_slew_time(350.0, 0.0, 10.0, 0.0, 2.0) - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimate_slew_time_pure_dec() {
+        assert!((mount_estimate_slew_time(100.0, -30.0, 100.0, 60.0, 5.0) - 18.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimate_slew_time_diagonal_uses_longer_axis() {
+        // RA 40 degrees and Dec 30 degrees move together; RA dominates
+        assert!((mount_estimate_slew_time(0.0, 0.0, 40.0, 30.0, 4.0) - 10.0).abs() < 1e-9);
+        assert!((mount_estimate_slew_time(0.0, 0.0, 10.0, -50.0, 5.0) - 10.0).abs() < 1e-9);
+        assert_eq!(mount_estimate_slew_time(0.0, 0.0, 10.0, 10.0, 0.0), 0.0);
+    }
+
+    // ------------------------------------------------------------------------
+    // Spiral Search Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_square_spiral_offsets_order() {
+        assert_eq!(
+            square_spiral_offsets(12),
+            vec![
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (2, -1),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+            ]
+        );
+        assert!(square_spiral_offsets(0).is_empty());
+    }
+
+    #[test]
+    fn test_square_spiral_covers_ring_without_repeats() {
+        // 24 steps fill the 5x5 square around the centre exactly once
+        let offsets = square_spiral_offsets(24);
+        let unique: std::collections::HashSet<_> = offsets.iter().collect();
+        assert_eq!(unique.len(), 24);
+        assert!(offsets.iter().all(|(x, y)| x.abs() <= 2 && y.abs() <= 2));
+        assert!(!offsets.contains(&(0, 0)));
+    }
+
+    #[test]
+    fn test_square_spiral_offsets_capped() {
+        assert_eq!(square_spiral_offsets(u32::MAX).len(), MAX_SPIRAL_STEPS as usize);
+        assert!(square_spiral_offsets(u32::MAX).iter().all(|(x, y)| x.abs() <= 10 && y.abs() <= 10));
+    }
+
+    #[test]
+    fn test_spiral_step_coordinates_scale_ra_by_declination() {
+        let (ra, dec) = spiral_step_coordinates(100.0, 60.0, (1, -2), 0.5);
+        assert!((ra - 101.0).abs() < 1e-9);
+        assert!((dec - 59.0).abs() < 1e-9);
+
+        let (ra, _) = spiral_step_coordinates(359.8, 0.0, (1, 0), 0.5);
+        assert!((ra - 0.3).abs() < 1e-9);
+    }
+}
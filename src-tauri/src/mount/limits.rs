@@ -0,0 +1,111 @@
+//! Slew safety limits
+//!
+//! Rejects goto targets below a fixed altitude limit or a point-based horizon profile.
+
+use crate::astronomy::calculations::horizon;
+use crate::astronomy::equatorial_to_horizontal;
+use crate::mount::types::{MountConfig, MountError};
+
+/// Horizon altitude at `az`, linearly interpolated between profile points
+/// (wrapping through north). A repeated azimuth uses its highest altitude.
+/// Returns `None` for an empty profile.
+pub fn horizon_altitude_at(profile: &[(f64, f64)], az: f64) -> Option<f64> {
+    if profile.is_empty() {
+        return None;
+    }
+    let points = horizon::merge_horizon_points(profile.iter().copied());
+    Some(horizon::horizon_altitude_at(&points, az))
+}
+
+/// Lowest allowed altitude at `az` under the configured limit and horizon profile
+pub fn minimum_altitude_at(config: &MountConfig, az: f64) -> Option<f64> {
+    let fixed = config.altitude_limit.map(|limit| limit.min_altitude_deg);
+    match (fixed, horizon_altitude_at(&config.horizon_profile, az)) {
+        (Some(fixed), Some(horizon)) => Some(fixed.max(horizon)),
+        (fixed, horizon) => fixed.or(horizon),
+    }
+}
+
+/// Reject a slew whose target (RA/Dec in degrees) sits below the configured limits
+pub fn check_slew_target(
+    config: &MountConfig,
+    ra: f64,
+    dec: f64,
+    timestamp: Option<i64>,
+) -> Result<(), MountError> {
+    if config.altitude_limit.is_none() && config.horizon_profile.is_empty() {
+        return Ok(());
+    }
+
+    let target = equatorial_to_horizontal(
+        ra,
+        dec,
+        config.latitude,
+        config.longitude,
+        timestamp,
+        Some(false),
+    );
+    match minimum_altitude_at(config, target.az) {
+        Some(limit) if target.alt < limit => Err(MountError::BelowHorizon {
+            altitude: target.alt,
+            limit,
+        }),
+        _ => Ok(()),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mount::types::AltitudeLimit;
+
+    #[test]
+    fn test_horizon_altitude_interpolates_and_wraps() {
+        let profile = [(90.0, 20.0), (0.0, 10.0), (270.0, 30.0)];
+        assert_eq!(horizon_altitude_at(&profile, 45.0), Some(15.0));
+        assert_eq!(horizon_altitude_at(&profile, 90.0), Some(20.0));
+        assert_eq!(horizon_altitude_at(&profile, 180.0), Some(25.0));
+        // Wraps from 270° (30°) through north to 0° (10°)
+        assert_eq!(horizon_altitude_at(&profile, 315.0), Some(20.0));
+        assert!(horizon_altitude_at(&[], 10.0).is_none());
+    }
+
+    #[test]
+    fn test_duplicate_azimuth_uses_highest_altitude() {
+        let profile = [(90.0, 10.0), (90.0, 40.0), (450.0, 25.0), (270.0, 0.0)];
+        assert_eq!(horizon_altitude_at(&profile, 90.0), Some(40.0));
+        assert_eq!(horizon_altitude_at(&profile, 180.0), Some(20.0));
+    }
+
+    #[test]
+    fn test_minimum_altitude_takes_stricter_limit() {
+        let config = MountConfig {
+            altitude_limit: Some(AltitudeLimit { min_altitude_deg: 15.0 }),
+            horizon_profile: vec![(0.0, 10.0), (180.0, 30.0)],
+            ..MountConfig::default()
+        };
+        assert_eq!(minimum_altitude_at(&config, 0.0), Some(15.0));
+        assert_eq!(minimum_altitude_at(&config, 180.0), Some(30.0));
+        assert!(minimum_altitude_at(&MountConfig::default(), 0.0).is_none());
+    }
+
+    #[test]
+    fn test_check_slew_target_against_profile() {
+        // At latitude 40°N, Dec -80° never rises and Dec +80° never drops below 30°
+        let config = MountConfig {
+            latitude: 40.0,
+            longitude: 0.0,
+            altitude_limit: None,
+            horizon_profile: vec![(0.0, 20.0), (180.0, 20.0)],
+            park_position: None,
+        };
+        let refused = check_slew_target(&config, 100.0, -80.0, Some(1_767_225_600));
+        assert!(matches!(refused, Err(MountError::BelowHorizon { limit, .. }) if limit == 20.0));
+        assert!(check_slew_target(&config, 100.0, 80.0, Some(1_767_225_600)).is_ok());
+        assert!(check_slew_target(&MountConfig::default(), 100.0, -80.0, None).is_ok());
+    }
+}
@@ -7,22 +7,31 @@
 //! Submodules:
 //! - `types`: Shared type definitions
 //! - `simulator`: Built-in mount simulator
+//! - `limits`: Altitude limit and horizon profile checks
 //! - `alpaca_client`: ASCOM Alpaca REST client
 //! - `commands`: Tauri commands
 
 pub mod types;
 pub mod simulator;
+pub mod limits;
 pub mod alpaca_client;
 pub mod commands;
 
 pub use commands::{
     mount_connect,
     mount_disconnect,
+    mount_set_heartbeat_interval,
     mount_get_state,
     mount_get_capabilities,
     mount_slew_to,
+    mount_estimate_slew_time,
     mount_sync_to,
+    mount_add_sync_point,
+    mount_clear_alignment,
+    mount_get_alignment_stats,
     mount_abort_slew,
+    mount_spiral_search,
+    mount_abort_spiral,
     mount_park,
     mount_unpark,
     mount_set_tracking,
@@ -33,4 +42,8 @@ pub use commands::{
     mount_discover,
     mount_get_observing_conditions,
     mount_get_safety_state,
+    mount_get_config,
+    mount_set_config,
+    mount_get_park_position,
+    mount_set_park_position,
 };
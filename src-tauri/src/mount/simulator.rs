@@ -5,15 +5,146 @@
 
 use std::time::Instant;
 
+use crate::astronomy::horizontal_to_equatorial;
+use crate::mount::limits::check_slew_target;
 use crate::mount::types::*;
 
-/// Simulated slew speed in degrees per second
-const SLEW_SPEED_DEG_PER_SEC: f64 = 5.0;
+/// Simulated slew speed of each axis in degrees per second
+pub const SLEW_SPEED_DEG_PER_SEC: f64 = 5.0;
 
-/// Park position (Celestial pole)
+/// Default park position (Celestial pole)
 const PARK_RA: f64 = 0.0;
 const PARK_DEC: f64 = 90.0;
 
+/// Wrap an angle difference to [-180, 180)
+fn wrap_delta(delta: f64) -> f64 {
+    (delta + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Sync point pairing the mount's own coordinates with the true sky position
+#[derive(Debug, Clone, Copy)]
+struct SyncPoint {
+    mount_ra: f64,
+    mount_dec: f64,
+    sky_ra: f64,
+    sky_dec: f64,
+}
+
+/// Multi-point alignment: a least-squares rotation + offset mapping mount
+/// coordinates onto the sky in the (RA, Dec) plane
+#[derive(Debug, Clone, Default)]
+struct AlignmentModel {
+    points: Vec<SyncPoint>,
+    /// RA around which points are unwrapped, so fits work across 0h
+    reference_ra: f64,
+    rotation_rad: f64,
+    offset_ra: f64,
+    offset_dec: f64,
+}
+
+impl AlignmentModel {
+    fn add_point(&mut self, point: SyncPoint) {
+        if self.points.is_empty() {
+            self.reference_ra = point.mount_ra;
+        }
+        self.points.push(point);
+        self.fit();
+    }
+
+    fn is_active(&self) -> bool {
+        !self.points.is_empty()
+    }
+
+    /// Points relative to the reference RA as ((mount x, y), (sky x, y))
+    fn planar_points(&self) -> Vec<((f64, f64), (f64, f64))> {
+        self.points
+            .iter()
+            .map(|p| {
+                (
+                    (wrap_delta(p.mount_ra - self.reference_ra), p.mount_dec),
+                    (wrap_delta(p.sky_ra - self.reference_ra), p.sky_dec),
+                )
+            })
+            .collect()
+    }
+
+    fn fit(&mut self) {
+        let planar = self.planar_points();
+        let n = planar.len() as f64;
+        if planar.is_empty() {
+            *self = Self::default();
+            return;
+        }
+
+        let (mut mx, mut my, mut sx, mut sy) = (0.0, 0.0, 0.0, 0.0);
+        for ((ax, ay), (bx, by)) in &planar {
+            mx += ax;
+            my += ay;
+            sx += bx;
+            sy += by;
+        }
+        let (mx, my, sx, sy) = (mx / n, my / n, sx / n, sy / n);
+
+        // Closed-form 2D Procrustes rotation on the centred point sets
+        let (mut cross, mut dot) = (0.0, 0.0);
+        for ((ax, ay), (bx, by)) in &planar {
+            let (ax, ay, bx, by) = (ax - mx, ay - my, bx - sx, by - sy);
+            cross += ax * by - ay * bx;
+            dot += ax * bx + ay * by;
+        }
+        self.rotation_rad = if planar.len() > 1 && (cross != 0.0 || dot != 0.0) {
+            cross.atan2(dot)
+        } else {
+            0.0
+        };
+
+        let (sin, cos) = self.rotation_rad.sin_cos();
+        self.offset_ra = sx - (cos * mx - sin * my);
+        self.offset_dec = sy - (sin * mx + cos * my);
+    }
+
+    /// Mount coordinates to corrected sky coordinates
+    fn mount_to_sky(&self, ra: f64, dec: f64) -> (f64, f64) {
+        let x = wrap_delta(ra - self.reference_ra);
+        let (sin, cos) = self.rotation_rad.sin_cos();
+        let sky_x = cos * x - sin * dec + self.offset_ra;
+        let sky_y = sin * x + cos * dec + self.offset_dec;
+        ((sky_x + self.reference_ra).rem_euclid(360.0), sky_y.clamp(-90.0, 90.0))
+    }
+
+    /// Sky coordinates to the mount coordinates that point at them
+    fn sky_to_mount(&self, ra: f64, dec: f64) -> (f64, f64) {
+        let x = wrap_delta(ra - self.reference_ra) - self.offset_ra;
+        let y = dec - self.offset_dec;
+        let (sin, cos) = self.rotation_rad.sin_cos();
+        let mount_x = cos * x + sin * y;
+        let mount_y = -sin * x + cos * y;
+        ((mount_x + self.reference_ra).rem_euclid(360.0), mount_y.clamp(-90.0, 90.0))
+    }
+
+    fn stats(&self) -> AlignmentStats {
+        let residual_sq: f64 = self
+            .points
+            .iter()
+            .map(|p| {
+                let (ra, dec) = self.mount_to_sky(p.mount_ra, p.mount_dec);
+                wrap_delta(ra - p.sky_ra).powi(2) + (dec - p.sky_dec).powi(2)
+            })
+            .sum();
+        AlignmentStats {
+            point_count: self.points.len(),
+            ra_offset: self.offset_ra,
+            dec_offset: self.offset_dec,
+            rotation_deg: self.rotation_rad.to_degrees(),
+            rms_error_arcsec: if self.points.is_empty() {
+                0.0
+            } else {
+                (residual_sq / self.points.len() as f64).sqrt() * 3600.0
+            },
+        }
+    }
+}
+
 /// Internal simulator state
 pub struct MountSimulator {
     connected: bool,
@@ -35,6 +166,12 @@ pub struct MountSimulator {
     primary_axis_rate: f64,
     secondary_axis_rate: f64,
 
+    // Site and slew limits
+    config: MountConfig,
+
+    // Pointing model built from sync points
+    alignment: AlignmentModel,
+
     // Timing
     last_tick: Instant,
 }
@@ -56,6 +193,8 @@ impl MountSimulator {
             slew_target_dec: 0.0,
             primary_axis_rate: 0.0,
             secondary_axis_rate: 0.0,
+            config: MountConfig::default(),
+            alignment: AlignmentModel::default(),
             last_tick: Instant::now(),
         }
     }
@@ -74,32 +213,26 @@ impl MountSimulator {
             return;
         }
 
+        self.advance(dt);
+    }
+
+    /// Advance motion by `dt` seconds
+    fn advance(&mut self, dt: f64) {
         // Handle goto slewing
         if self.slewing {
-            let dra = self.slew_target_ra - self.ra;
+            // Axes move independently at full speed, matching mount_estimate_slew_time
+            let dra = wrap_delta(self.slew_target_ra - self.ra);
             let ddec = self.slew_target_dec - self.dec;
+            let step = SLEW_SPEED_DEG_PER_SEC * dt;
 
-            // Wrap RA difference to [-180, 180]
-            let dra = ((dra + 180.0) % 360.0 + 360.0) % 360.0 - 180.0;
-
-            let dist = (dra * dra + ddec * ddec).sqrt();
-
-            if dist < 0.01 {
+            if dra.abs().max(ddec.abs()) <= step.max(0.01) {
                 // Arrived
                 self.ra = self.slew_target_ra;
                 self.dec = self.slew_target_dec;
                 self.slewing = false;
             } else {
-                let step = SLEW_SPEED_DEG_PER_SEC * dt;
-                if step >= dist {
-                    self.ra = self.slew_target_ra;
-                    self.dec = self.slew_target_dec;
-                    self.slewing = false;
-                } else {
-                    let factor = step / dist;
-                    self.ra += dra * factor;
-                    self.dec += ddec * factor;
-                }
+                self.ra += dra.clamp(-step, step);
+                self.dec += ddec.clamp(-step, step);
             }
 
             self.normalize_coordinates();
@@ -169,10 +302,11 @@ impl MountSimulator {
 
     pub fn get_state(&mut self) -> MountState {
         self.tick();
+        let (ra, dec) = self.sky_position();
         MountState {
             connected: self.connected,
-            ra: self.ra,
-            dec: self.dec,
+            ra,
+            dec,
             tracking: self.tracking,
             tracking_rate: self.tracking_rate,
             slewing: self.slewing,
@@ -194,7 +328,13 @@ impl MountSimulator {
         if self.parked {
             return Err(MountError::Parked);
         }
+        check_slew_target(&self.config, ra, dec, None)?;
 
+        let (ra, dec) = if self.alignment.is_active() {
+            self.alignment.sky_to_mount(ra, dec)
+        } else {
+            (ra, dec)
+        };
         self.slew_target_ra = ((ra % 360.0) + 360.0) % 360.0;
         self.slew_target_dec = dec.clamp(-90.0, 90.0);
         self.slewing = true;
@@ -212,6 +352,13 @@ impl MountSimulator {
         if !self.connected {
             return Err(MountError::NotConnected);
         }
+        // The sync coordinates are sky positions; store the mount position that
+        // the alignment model reports as them
+        let (ra, dec) = if self.alignment.is_active() {
+            self.alignment.sky_to_mount(ra, dec)
+        } else {
+            (ra, dec)
+        };
         self.ra = ((ra % 360.0) + 360.0) % 360.0;
         self.dec = dec.clamp(-90.0, 90.0);
         self.update_pier_side();
@@ -231,8 +378,9 @@ impl MountSimulator {
         if !self.connected {
             return Err(MountError::NotConnected);
         }
-        self.slew_target_ra = PARK_RA;
-        self.slew_target_dec = PARK_DEC;
+        let (park_ra, park_dec) = self.park_coordinates(None);
+        self.slew_target_ra = park_ra;
+        self.slew_target_dec = park_dec;
         self.slewing = true;
         self.tracking = false;
         // Will set parked=true when slew completes
@@ -301,4 +449,253 @@ impl MountSimulator {
             self.slew_rate_index = index;
         }
     }
+
+    pub fn set_config(&mut self, config: MountConfig) {
+        self.config = config;
+    }
+
+    /// Current position corrected by the alignment model
+    fn sky_position(&self) -> (f64, f64) {
+        if self.alignment.is_active() {
+            self.alignment.mount_to_sky(self.ra, self.dec)
+        } else {
+            (self.ra, self.dec)
+        }
+    }
+
+    /// Record that the mount's current position is really at `ra`/`dec`
+    /// (degrees) and refit the alignment model
+    pub fn add_sync_point(&mut self, ra: f64, dec: f64) -> Result<AlignmentStats, MountError> {
+        if !self.connected {
+            return Err(MountError::NotConnected);
+        }
+        self.tick();
+        self.alignment.add_point(SyncPoint {
+            mount_ra: self.ra,
+            mount_dec: self.dec,
+            sky_ra: ra.rem_euclid(360.0),
+            sky_dec: dec.clamp(-90.0, 90.0),
+        });
+        log::info!(
+            "Simulator alignment point {} added at RA={:.4}° Dec={:.4}°",
+            self.alignment.points.len(),
+            ra,
+            dec
+        );
+        Ok(self.alignment.stats())
+    }
+
+    pub fn clear_alignment(&mut self) {
+        self.alignment = AlignmentModel::default();
+        log::info!("Simulator alignment cleared");
+    }
+
+    pub fn get_alignment_stats(&self) -> AlignmentStats {
+        self.alignment.stats()
+    }
+
+    /// RA/Dec of the park position at `timestamp` (now when `None`)
+    fn park_coordinates(&self, timestamp: Option<i64>) -> (f64, f64) {
+        match self.config.park_position {
+            Some(park) => {
+                let coords = horizontal_to_equatorial(
+                    park.alt,
+                    park.az,
+                    self.config.latitude,
+                    self.config.longitude,
+                    timestamp,
+                );
+                (coords.ra, coords.dec)
+            }
+            None => (PARK_RA, PARK_DEC),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connected_unparked() -> MountSimulator {
+        let mut sim = MountSimulator::new();
+        sim.connect().unwrap();
+        sim.unpark().unwrap();
+        sim
+    }
+
+    // ------------------------------------------------------------------------
+    // Slew Timing Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_simulated_slew_takes_estimated_time() {
+        let mut sim = connected_unparked();
+        sim.ra = 350.0;
+        sim.dec = 10.0;
+        sim.slew_to(30.0, -5.0).unwrap();
+
+        let expected =
+            crate::mount::commands::mount_estimate_slew_time(350.0, 10.0, 30.0, -5.0, SLEW_SPEED_DEG_PER_SEC);
+        let dt = 0.05;
+        let mut elapsed = 0.0;
+        while sim.slewing && elapsed < 60.0 {
+            sim.advance(dt);
+            elapsed += dt;
+        }
+
+        assert!(!sim.slewing);
+        assert!((elapsed - expected).abs() <= dt + 1e-9, "elapsed {} expected {}", elapsed, expected);
+        assert!((sim.ra - 30.0).abs() < 1e-9);
+        assert!((sim.dec + 5.0).abs() < 1e-9);
+    }
+
+    // ------------------------------------------------------------------------
+    // Slew Limit Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_slew_below_altitude_limit_is_refused() {
+        let mut sim = connected_unparked();
+        sim.set_config(MountConfig {
+            latitude: 40.0,
+            altitude_limit: Some(AltitudeLimit { min_altitude_deg: 10.0 }),
+            ..MountConfig::default()
+        });
+
+        let result = sim.slew_to(100.0, -80.0);
+        assert!(matches!(result, Err(MountError::BelowHorizon { .. })));
+        assert!(!sim.get_state().slewing);
+    }
+
+    #[test]
+    fn test_slew_above_horizon_profile_passes() {
+        let mut sim = connected_unparked();
+        sim.set_config(MountConfig {
+            latitude: 40.0,
+            horizon_profile: vec![(0.0, 15.0), (90.0, 25.0), (180.0, 15.0), (270.0, 25.0)],
+            ..MountConfig::default()
+        });
+
+        sim.slew_to(100.0, 80.0).unwrap();
+        assert!(sim.get_state().slewing);
+    }
+
+    // ------------------------------------------------------------------------
+    // Alignment Model Tests
+    // ------------------------------------------------------------------------
+
+    /// Sync at a mount position whose true sky position is offset by a constant error
+    fn sync_with_error(sim: &mut MountSimulator, ra: f64, dec: f64, dra: f64, ddec: f64) {
+        sim.ra = ra;
+        sim.dec = dec;
+        sim.add_sync_point(ra + dra, dec + ddec).unwrap();
+    }
+
+    #[test]
+    fn test_alignment_corrects_constant_offset() {
+        let mut sim = connected_unparked();
+        sim.set_tracking(false).unwrap();
+        for (ra, dec) in [(350.0, 10.0), (20.0, 40.0), (60.0, -15.0)] {
+            sync_with_error(&mut sim, ra, dec, 0.5, -0.3);
+        }
+
+        let stats = sim.get_alignment_stats();
+        assert_eq!(stats.point_count, 3);
+        assert!((stats.ra_offset - 0.5).abs() < 1e-6);
+        assert!((stats.dec_offset + 0.3).abs() < 1e-6);
+        assert!(stats.rotation_deg.abs() < 1e-6);
+        assert!(stats.rms_error_arcsec < 0.01);
+
+        // Slewing to the true position drives the mount to the uncorrected coordinates
+        sim.slew_to(100.0, 30.0).unwrap();
+        assert!((sim.slew_target_ra - 99.5).abs() < 1e-6);
+        assert!((sim.slew_target_dec - 30.3).abs() < 1e-6);
+
+        sim.advance(120.0);
+        let state = sim.get_state();
+        assert!((state.ra - 100.0).abs() < 1e-6);
+        assert!((state.dec - 30.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sync_after_alignment_reports_synced_position() {
+        let mut sim = connected_unparked();
+        sim.set_tracking(false).unwrap();
+        for (ra, dec) in [(350.0, 10.0), (20.0, 40.0), (60.0, -15.0)] {
+            sync_with_error(&mut sim, ra, dec, 0.5, -0.3);
+        }
+
+        sim.sync_to(100.0, 30.0).unwrap();
+        let state = sim.get_state();
+        assert!((state.ra - 100.0).abs() < 1e-6);
+        assert!((state.dec - 30.0).abs() < 1e-6);
+        assert!((sim.ra - 99.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_clear_alignment_restores_raw_pointing() {
+        let mut sim = connected_unparked();
+        sim.set_tracking(false).unwrap();
+        sync_with_error(&mut sim, 10.0, 20.0, 1.0, 1.0);
+        assert_eq!(sim.get_alignment_stats().point_count, 1);
+
+        sim.clear_alignment();
+        assert_eq!(sim.get_alignment_stats(), AlignmentStats::default());
+        sim.slew_to(100.0, 30.0).unwrap();
+        assert_eq!(sim.slew_target_ra, 100.0);
+        assert_eq!(sim.slew_target_dec, 30.0);
+    }
+
+    // ------------------------------------------------------------------------
+    // Park Position Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_park_slews_to_configured_position() {
+        let mut sim = connected_unparked();
+        sim.set_config(MountConfig {
+            latitude: 40.0,
+            longitude: -75.0,
+            park_position: Some(ParkPosition { alt: 30.0, az: 180.0 }),
+            ..MountConfig::default()
+        });
+
+        sim.park().unwrap();
+        sim.advance(120.0);
+        let state = sim.get_state();
+        assert!(state.parked);
+        assert!(!state.slewing);
+        assert!(!state.tracking);
+
+        let parked = crate::astronomy::equatorial_to_horizontal(
+            state.ra,
+            state.dec,
+            40.0,
+            -75.0,
+            None,
+            Some(false),
+        );
+        assert!((parked.alt - 30.0).abs() < 0.1, "alt {}", parked.alt);
+        assert!((parked.az - 180.0).abs() < 0.1, "az {}", parked.az);
+    }
+
+    #[test]
+    fn test_park_defaults_to_pole_and_unpark_clears_flag() {
+        let mut sim = connected_unparked();
+        sim.sync_to(120.0, 10.0).unwrap();
+
+        sim.park().unwrap();
+        sim.advance(120.0);
+        let state = sim.get_state();
+        assert!(state.parked);
+        assert_eq!(state.dec, PARK_DEC);
+
+        sim.unpark().unwrap();
+        assert!(!sim.get_state().parked);
+        assert!(sim.slew_to(200.0, 20.0).is_ok());
+    }
 }
@@ -122,6 +122,77 @@ impl Default for MountState {
     }
 }
 
+// ============================================================================
+// Mount Configuration & Limits
+// ============================================================================
+
+/// Fixed minimum altitude a slew target must clear
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AltitudeLimit {
+    pub min_altitude_deg: f64,
+}
+
+/// Park position in horizontal coordinates (degrees)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParkPosition {
+    pub alt: f64,
+    pub az: f64,
+}
+
+/// Observer site and slew safety limits applied before every goto
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MountConfig {
+    pub latitude: f64,
+    pub longitude: f64,
+    #[serde(default)]
+    pub altitude_limit: Option<AltitudeLimit>,
+    /// Local horizon as `(azimuth, altitude)` points in degrees
+    #[serde(default)]
+    pub horizon_profile: Vec<(f64, f64)>,
+    /// Custom park position; `None` keeps the driver's default
+    #[serde(default)]
+    pub park_position: Option<ParkPosition>,
+}
+
+/// Summary of the fitted multi-point alignment model
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlignmentStats {
+    pub point_count: usize,
+    /// Fitted RA offset in degrees (sky minus mount)
+    pub ra_offset: f64,
+    /// Fitted Dec offset in degrees (sky minus mount)
+    pub dec_offset: f64,
+    /// Fitted field rotation in degrees
+    pub rotation_deg: f64,
+    /// RMS residual of the sync points after correction, in arcseconds
+    pub rms_error_arcsec: f64,
+}
+
+/// One visited point of a spiral search, emitted as `mount-spiral-step`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpiralStep {
+    pub index: u32,
+    /// Offset from the centre in steps (+x = east/RA, +y = north/Dec)
+    pub offset_x: i32,
+    pub offset_y: i32,
+    /// Position reported by the mount after arriving, in degrees
+    pub ra: f64,
+    pub dec: f64,
+}
+
+/// Payload of the `mount-connection-lost` and `mount-reconnected` events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MountHeartbeatEvent {
+    pub reason: Option<String>,
+    pub reconnect_attempts: u32,
+}
+
 // ============================================================================
 // Mount Capabilities
 // ============================================================================
@@ -135,6 +206,8 @@ pub struct MountCapabilities {
     pub can_sync: bool,
     pub can_park: bool,
     pub can_unpark: bool,
+    #[serde(default)]
+    pub can_set_park: bool,
     pub can_set_tracking: bool,
     pub can_move_axis: bool,
     pub can_pulse_guide: bool,
@@ -150,6 +223,7 @@ impl Default for MountCapabilities {
             can_sync: true,
             can_park: true,
             can_unpark: true,
+            can_set_park: true,
             can_set_tracking: true,
             can_move_axis: true,
             can_pulse_guide: true,
@@ -164,14 +238,20 @@ impl Default for MountCapabilities {
 // ============================================================================
 
 /// A device discovered via Alpaca UDP broadcast
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DiscoveredDevice {
+    /// `host:port` of the Alpaca server
+    pub address: String,
     pub host: String,
     pub port: u16,
     pub device_id: u32,
     pub device_name: String,
     pub device_type: String,
+    #[serde(default)]
+    pub unique_id: String,
+    #[serde(default)]
+    pub interface_version: Option<i32>,
 }
 
 // ============================================================================
@@ -219,8 +299,8 @@ pub enum MountError {
     #[error("Mount is already slewing")]
     AlreadySlewing,
 
-    #[error("Target below horizon")]
-    BelowHorizon,
+    #[error("Target below horizon (altitude {altitude:.1}°, limit {limit:.1}°)")]
+    BelowHorizon { altitude: f64, limit: f64 },
 
     #[error("Operation not supported: {0}")]
     NotSupported(String),
@@ -234,7 +314,13 @@ pub enum MountError {
 
 impl From<reqwest::Error> for MountError {
     fn from(e: reqwest::Error) -> Self {
-        MountError::HttpError(e.to_string())
+        if e.is_timeout() {
+            MountError::Timeout(e.to_string())
+        } else if e.is_connect() {
+            MountError::ConnectionFailed(e.to_string())
+        } else {
+            MountError::HttpError(e.to_string())
+        }
     }
 }
 
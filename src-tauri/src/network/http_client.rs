@@ -1,15 +1,19 @@
 //! Enhanced HTTP client module
-//! Provides HTTP requests with retries, progress reporting, and cancellation
+//! Provides HTTP requests with retries, progress reporting, cancellation,
+//! and opt-in response caching through the unified cache
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use chrono::Utc;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter};
 
 use super::security::{self, SecurityError};
+use crate::cache::{get_unified_cache_entry, put_unified_cache_entry, UnifiedCacheResponse};
 
 #[derive(Debug, thiserror::Error)]
 pub enum HttpClientError {
@@ -54,6 +58,18 @@ pub struct RequestConfig {
     pub allow_http: bool,
     #[serde(default)]
     pub report_progress: bool,
+    /// Serve and store GET responses through the unified cache when set
+    #[serde(default)]
+    pub cache: Option<CachePolicy>,
+}
+
+/// Opt-in caching for a request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CachePolicy {
+    /// TTL used when the server sends no `Cache-Control: max-age`;
+    /// without either the response is not stored
+    #[serde(default)]
+    pub default_ttl_seconds: Option<u64>,
 }
 
 fn default_timeout() -> u64 { 30 }
@@ -63,7 +79,7 @@ impl Default for RequestConfig {
         Self {
             method: "GET".to_string(), url: String::new(), headers: HashMap::new(),
             body: None, timeout_seconds: 30, max_retries: 3, retry_delay_ms: 1000,
-            request_id: None, allow_http: false, report_progress: false,
+            request_id: None, allow_http: false, report_progress: false, cache: None,
         }
     }
 }
@@ -115,44 +131,165 @@ fn unregister_request(request_id: &Option<String>) {
     }
 }
 
-#[tauri::command]
-pub async fn http_request(app: AppHandle, config: RequestConfig) -> Result<HttpResponse, HttpClientError> {
-    security::validate_url(&config.url, config.allow_http, None)?;
-    register_request(&config.request_id);
+// ============================================================================
+// Concurrency Limit
+// ============================================================================
 
-    // Get global HTTP configuration
-    let global_config = HTTP_CONFIG.lock().map(|c| c.clone()).unwrap_or_default();
+#[derive(Debug, Default)]
+struct SlotCounts {
+    in_flight: usize,
+    queued: usize,
+}
 
-    // Build client with global and request-specific configuration
-    let mut client_builder = reqwest::Client::builder()
-        .timeout(Duration::from_secs(config.timeout_seconds))
-        .connect_timeout(Duration::from_millis(global_config.connect_timeout_ms))
-        .user_agent(&global_config.user_agent);
+/// Bounds the number of requests on the wire at once; excess requests wait
+/// in a queue until a slot is released
+#[derive(Debug, Default)]
+struct RequestSlots {
+    counts: Mutex<SlotCounts>,
+    released: tokio::sync::Notify,
+}
 
-    // Apply compression setting
-    if global_config.enable_compression {
-        client_builder = client_builder.gzip(true).deflate(true);
-    } else {
-        client_builder = client_builder.no_gzip().no_deflate();
+/// An occupied request slot, released on drop
+struct RequestSlot<'a> {
+    slots: &'a RequestSlots,
+}
+
+/// A place in the queue, given up on drop if the wait is abandoned
+struct QueueTicket<'a> {
+    slots: &'a RequestSlots,
+    waiting: bool,
+}
+
+impl RequestSlots {
+    /// Wait for a free slot; `max` is read per call so config changes apply
+    async fn acquire(&self, max: usize) -> RequestSlot<'_> {
+        let max = max.max(1);
+        let mut ticket = QueueTicket { slots: self, waiting: false };
+
+        loop {
+            let notified = self.released.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            {
+                let mut counts = self.counts.lock().unwrap();
+                if counts.in_flight < max {
+                    counts.in_flight += 1;
+                    if ticket.waiting {
+                        counts.queued -= 1;
+                        ticket.waiting = false;
+                    }
+                    return RequestSlot { slots: self };
+                }
+                if !ticket.waiting {
+                    counts.queued += 1;
+                    ticket.waiting = true;
+                }
+            }
+
+            notified.await;
+        }
     }
 
-    // Apply redirect settings
-    if global_config.follow_redirects {
-        client_builder = client_builder.redirect(reqwest::redirect::Policy::limited(global_config.max_redirects as usize));
-    } else {
-        client_builder = client_builder.redirect(reqwest::redirect::Policy::none());
+    fn counts(&self) -> (usize, usize) {
+        let counts = self.counts.lock().unwrap();
+        (counts.in_flight, counts.queued)
+    }
+}
+
+impl Drop for RequestSlot<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut counts) = self.slots.counts.lock() {
+            counts.in_flight = counts.in_flight.saturating_sub(1);
+        }
+        self.slots.released.notify_waiters();
     }
+}
 
-    // Apply proxy if configured
-    if let Some(ref proxy_url) = global_config.proxy_url {
-        if let Ok(proxy) = reqwest::Proxy::all(proxy_url) {
-            client_builder = client_builder.proxy(proxy);
+impl Drop for QueueTicket<'_> {
+    fn drop(&mut self) {
+        if self.waiting {
+            if let Ok(mut counts) = self.slots.counts.lock() {
+                counts.queued = counts.queued.saturating_sub(1);
+            }
         }
     }
+}
 
-    let client = client_builder
-        .build()
-        .map_err(|e| HttpClientError::Request(e.to_string()))?;
+static REQUEST_SLOTS: Lazy<RequestSlots> = Lazy::new(RequestSlots::default);
+
+/// Snapshot of request activity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveRequests {
+    /// Ids of tracked requests, whether in flight or queued
+    pub request_ids: Vec<String>,
+    pub in_flight: usize,
+    pub queued: usize,
+    pub max_concurrent: usize,
+}
+
+#[tauri::command]
+pub async fn http_request(app: AppHandle, config: RequestConfig) -> Result<HttpResponse, HttpClientError> {
+    let cacheable = config.method.eq_ignore_ascii_case("GET");
+    let Some(policy) = config.cache.clone().filter(|_| cacheable) else {
+        return send_request(app, config).await;
+    };
+
+    security::validate_url(&config.url, config.allow_http, None)?;
+    let key = response_cache_key(&config.method, &config.url);
+    let lookup_app = app.clone();
+    let store_app = app.clone();
+
+    request_with_cache(
+        key,
+        &policy,
+        Utc::now().timestamp_millis(),
+        |key| async move {
+            get_unified_cache_entry(lookup_app, key, Some(RESPONSE_CACHE_NAMESPACE.to_string())).await.unwrap_or_else(|e| {
+                log::warn!("Response cache lookup failed: {}", e);
+                None
+            })
+        },
+        |key, response, ttl_ms| async move {
+            let content_type = response.content_type.clone()
+                .unwrap_or_else(|| "application/octet-stream".to_string());
+            if let Err(e) = put_unified_cache_entry(
+                store_app, key, response.body, content_type, ttl_ms, Some(RESPONSE_CACHE_NAMESPACE.to_string()),
+            ).await {
+                log::warn!("Response cache store failed: {}", e);
+            }
+        },
+        || send_request(app, config),
+    ).await
+}
+
+/// Send a request over the network, bypassing the response cache, and log
+/// its outcome when request logging is on
+async fn send_request(app: AppHandle, config: RequestConfig) -> Result<HttpResponse, HttpClientError> {
+    let log_requests = HTTP_CONFIG.lock().map(|c| c.log_requests).unwrap_or(false);
+    if !log_requests {
+        return send_request_unlogged(app, config).await;
+    }
+
+    let method = config.method.to_uppercase();
+    let url = config.url.clone();
+    let started = Instant::now();
+    let result = send_request_unlogged(app, config).await;
+    record_request(&method, &url, &result, started.elapsed());
+    result
+}
+
+async fn send_request_unlogged(app: AppHandle, config: RequestConfig) -> Result<HttpResponse, HttpClientError> {
+    security::validate_url(&config.url, config.allow_http, None)?;
+    register_request(&config.request_id);
+
+    // Get global HTTP configuration
+    let global_config = HTTP_CONFIG.lock().map(|c| c.clone()).unwrap_or_default();
+
+    // Hold a slot for the rest of the request; queued requests wait here
+    let _slot = REQUEST_SLOTS.acquire(global_config.max_concurrent_requests).await;
+
+    let client = build_client(&global_config, Duration::from_secs(config.timeout_seconds))?;
 
     let mut last_error = None;
     for attempt in 0..=config.max_retries {
@@ -229,6 +366,10 @@ pub async fn http_request(app: AppHandle, config: RequestConfig) -> Result<HttpR
                                     });
                                 }
                             }
+                            Err(e) if e.is_timeout() => {
+                                unregister_request(&config.request_id);
+                                return Err(read_timeout_error(global_config.read_timeout_ms));
+                            }
                             Err(e) => {
                                 stream_error = Some(HttpClientError::Request(e.to_string()));
                                 break;
@@ -243,8 +384,17 @@ pub async fn http_request(app: AppHandle, config: RequestConfig) -> Result<HttpR
                     }
                     body_bytes
                 } else {
-                    response.bytes().await
-                        .map_err(|e| HttpClientError::Request(e.to_string()))?.to_vec()
+                    match response.bytes().await {
+                        Ok(bytes) => bytes.to_vec(),
+                        Err(e) => {
+                            unregister_request(&config.request_id);
+                            return Err(if e.is_timeout() {
+                                read_timeout_error(global_config.read_timeout_ms)
+                            } else {
+                                HttpClientError::Request(e.to_string())
+                            });
+                        }
+                    }
                 };
 
                 unregister_request(&config.request_id);
@@ -284,8 +434,113 @@ pub fn cancel_request(request_id: String) -> bool {
 }
 
 #[tauri::command]
-pub fn get_active_requests() -> Vec<String> {
-    ACTIVE_REQUESTS.lock().map(|r| r.keys().cloned().collect()).unwrap_or_default()
+pub fn get_active_requests() -> ActiveRequests {
+    let (in_flight, queued) = REQUEST_SLOTS.counts();
+    ActiveRequests {
+        request_ids: ACTIVE_REQUESTS.lock().map(|r| r.keys().cloned().collect()).unwrap_or_default(),
+        in_flight,
+        queued,
+        max_concurrent: get_http_config().max_concurrent_requests,
+    }
+}
+
+// ============================================================================
+// Response Cache
+// ============================================================================
+
+/// Caching directives from a `Cache-Control` header
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct CacheDirectives {
+    no_store: bool,
+    max_age: Option<u64>,
+}
+
+fn parse_cache_control(headers: &HashMap<String, String>) -> CacheDirectives {
+    let mut directives = CacheDirectives::default();
+    let Some(value) = headers.iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("cache-control"))
+        .map(|(_, v)| v)
+    else {
+        return directives;
+    };
+
+    for part in value.split(',').map(str::trim) {
+        let lower = part.to_ascii_lowercase();
+        if lower == "no-store" {
+            directives.no_store = true;
+        } else if let Some(age) = lower.strip_prefix("max-age=") {
+            directives.max_age = age.trim_matches('"').parse().ok();
+        }
+    }
+    directives
+}
+
+/// Unified cache namespace holding stored responses
+const RESPONSE_CACHE_NAMESPACE: &str = "http";
+
+/// Cache key for a request within [`RESPONSE_CACHE_NAMESPACE`]: method and URL
+fn response_cache_key(method: &str, url: &str) -> String {
+    format!("{}:{}", method.to_ascii_uppercase(), url)
+}
+
+/// TTL in milliseconds to store a response for, or `None` if it must not be cached
+fn response_cache_ttl_ms(policy: &CachePolicy, response: &HttpResponse) -> Option<i64> {
+    if !(200..300).contains(&response.status) {
+        return None;
+    }
+    let directives = parse_cache_control(&response.headers);
+    if directives.no_store {
+        return None;
+    }
+    directives.max_age
+        .or(policy.default_ttl_seconds)
+        .filter(|&secs| secs > 0)
+        .map(|secs| secs as i64 * 1000)
+}
+
+fn is_cache_entry_fresh(entry: &UnifiedCacheResponse, now_ms: i64) -> bool {
+    entry.ttl <= 0 || now_ms <= entry.timestamp + entry.ttl
+}
+
+fn cached_http_response(entry: UnifiedCacheResponse) -> HttpResponse {
+    let headers = HashMap::from([("content-type".to_string(), entry.content_type.clone())]);
+    HttpResponse {
+        status: 200,
+        headers,
+        content_length: Some(entry.data.len() as u64),
+        content_type: Some(entry.content_type),
+        body: entry.data,
+    }
+}
+
+/// Serve a fresh cached entry, or fetch and store the response when cacheable
+async fn request_with_cache<L, LF, S, SF, F, FF>(
+    key: String,
+    policy: &CachePolicy,
+    now_ms: i64,
+    lookup: L,
+    store: S,
+    fetch: F,
+) -> Result<HttpResponse, HttpClientError>
+where
+    L: FnOnce(String) -> LF,
+    LF: Future<Output = Option<UnifiedCacheResponse>>,
+    S: FnOnce(String, HttpResponse, i64) -> SF,
+    SF: Future<Output = ()>,
+    F: FnOnce() -> FF,
+    FF: Future<Output = Result<HttpResponse, HttpClientError>>,
+{
+    if let Some(entry) = lookup(key.clone()).await {
+        if is_cache_entry_fresh(&entry, now_ms) {
+            return Ok(cached_http_response(entry));
+        }
+    }
+
+    let response = fetch().await?;
+    if let Some(ttl_ms) = response_cache_ttl_ms(policy, &response) {
+        store(key, response.clone(), ttl_ms).await;
+    }
+    Ok(response)
 }
 
 // ============================================================================
@@ -306,8 +561,16 @@ pub struct HttpClientConfig {
     pub enable_compression: bool,
     pub follow_redirects: bool,
     pub max_redirects: u32,
+    /// Requests allowed on the wire at once; the rest queue
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+    /// Log each request at debug level and keep recent summaries for `get_request_log`
+    #[serde(default)]
+    pub log_requests: bool,
 }
 
+fn default_max_concurrent_requests() -> usize { 8 }
+
 impl Default for HttpClientConfig {
     fn default() -> Self {
         Self {
@@ -323,6 +586,8 @@ impl Default for HttpClientConfig {
             enable_compression: true,
             follow_redirects: true,
             max_redirects: 10,
+            max_concurrent_requests: default_max_concurrent_requests(),
+            log_requests: false,
         }
     }
 }
@@ -330,28 +595,32 @@ impl Default for HttpClientConfig {
 static HTTP_CONFIG: Lazy<Arc<Mutex<HttpClientConfig>>> =
     Lazy::new(|| Arc::new(Mutex::new(HttpClientConfig::default())));
 
-/// Build a reqwest client with global configuration applied
-fn build_configured_client(timeout_secs: u64) -> Result<reqwest::Client, HttpClientError> {
-    let global_config = HTTP_CONFIG.lock().map(|c| c.clone()).unwrap_or_default();
-    
+/// Build a reqwest client from an explicit configuration.
+/// `read_timeout_ms` bounds the gap between body chunks, so a transfer that
+/// stalls mid-body fails long before the total `timeout` elapses.
+fn build_client(config: &HttpClientConfig, timeout: Duration) -> Result<reqwest::Client, HttpClientError> {
     let mut builder = reqwest::Client::builder()
-        .timeout(Duration::from_secs(timeout_secs))
-        .connect_timeout(Duration::from_millis(global_config.connect_timeout_ms))
-        .user_agent(&global_config.user_agent);
+        .timeout(timeout)
+        .connect_timeout(Duration::from_millis(config.connect_timeout_ms))
+        .user_agent(&config.user_agent);
+
+    if config.read_timeout_ms > 0 {
+        builder = builder.read_timeout(Duration::from_millis(config.read_timeout_ms));
+    }
 
-    if global_config.enable_compression {
+    if config.enable_compression {
         builder = builder.gzip(true).deflate(true);
     } else {
         builder = builder.no_gzip().no_deflate();
     }
 
-    if global_config.follow_redirects {
-        builder = builder.redirect(reqwest::redirect::Policy::limited(global_config.max_redirects as usize));
+    if config.follow_redirects {
+        builder = builder.redirect(reqwest::redirect::Policy::limited(config.max_redirects as usize));
     } else {
         builder = builder.redirect(reqwest::redirect::Policy::none());
     }
 
-    if let Some(ref proxy_url) = global_config.proxy_url {
+    if let Some(ref proxy_url) = config.proxy_url {
         if let Ok(proxy) = reqwest::Proxy::all(proxy_url) {
             builder = builder.proxy(proxy);
         }
@@ -360,6 +629,17 @@ fn build_configured_client(timeout_secs: u64) -> Result<reqwest::Client, HttpCli
     builder.build().map_err(|e| HttpClientError::Request(e.to_string()))
 }
 
+/// Build a reqwest client with global configuration applied
+fn build_configured_client(timeout_secs: u64) -> Result<reqwest::Client, HttpClientError> {
+    let global_config = HTTP_CONFIG.lock().map(|c| c.clone()).unwrap_or_default();
+    build_client(&global_config, Duration::from_secs(timeout_secs))
+}
+
+/// Timeout error for a body that stopped arriving, rounded up to whole seconds
+fn read_timeout_error(read_timeout_ms: u64) -> HttpClientError {
+    HttpClientError::Timeout(read_timeout_ms.div_ceil(1000))
+}
+
 #[tauri::command]
 pub fn get_http_config() -> HttpClientConfig {
     HTTP_CONFIG.lock().map(|c| c.clone()).unwrap_or_default()
@@ -372,6 +652,83 @@ pub fn set_http_config(config: HttpClientConfig) {
     }
 }
 
+// ============================================================================
+// Request Log
+// ============================================================================
+
+/// Request summaries kept for `get_request_log`
+const REQUEST_LOG_CAPACITY: usize = 100;
+
+/// Query parameters whose values never reach the log
+const REDACTED_QUERY_KEYS: &[&str] = &["apikey", "api_key"];
+
+/// One logged request; `status` is set when a response arrived, `error` otherwise
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestLogEntry {
+    pub method: String,
+    pub url: String,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+    pub timestamp: i64,
+}
+
+static REQUEST_LOG: Lazy<Mutex<VecDeque<RequestLogEntry>>> = Lazy::new(Default::default);
+
+/// `text` with the values of API key query parameters replaced; applied to
+/// URLs and to error messages that quote them
+fn redact_api_keys(text: &str) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(['?', '&']) {
+        redacted.push_str(&rest[..=pos]);
+        rest = &rest[pos + 1..];
+        let end = rest
+            .find(|c: char| matches!(c, '&' | '#' | ')' | '"' | '\'') || c.is_whitespace())
+            .unwrap_or(rest.len());
+        if let Some((key, _)) = rest[..end].split_once('=') {
+            if REDACTED_QUERY_KEYS.iter().any(|k| key.eq_ignore_ascii_case(k)) {
+                redacted.push_str(key);
+                redacted.push_str("=REDACTED");
+                rest = &rest[end..];
+            }
+        }
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
+fn record_request(method: &str, url: &str, result: &Result<HttpResponse, HttpClientError>, elapsed: Duration) {
+    let entry = RequestLogEntry {
+        method: method.to_string(),
+        url: redact_api_keys(url),
+        status: result.as_ref().ok().map(|r| r.status),
+        error: result.as_ref().err().map(|e| redact_api_keys(&e.to_string())),
+        duration_ms: elapsed.as_millis() as u64,
+        timestamp: Utc::now().timestamp_millis(),
+    };
+    match (&entry.status, &entry.error) {
+        (Some(status), _) => log::debug!("{} {} -> {} in {} ms", entry.method, entry.url, status, entry.duration_ms),
+        (None, error) => log::debug!(
+            "{} {} failed in {} ms: {}",
+            entry.method, entry.url, entry.duration_ms, error.as_deref().unwrap_or_default()
+        ),
+    }
+
+    if let Ok(mut log) = REQUEST_LOG.lock() {
+        if log.len() == REQUEST_LOG_CAPACITY {
+            log.pop_front();
+        }
+        log.push_back(entry);
+    }
+}
+
+/// Recent request summaries, oldest first; empty unless `log_requests` is on
+#[tauri::command]
+pub fn get_request_log() -> Vec<RequestLogEntry> {
+    REQUEST_LOG.lock().map(|log| log.iter().cloned().collect()).unwrap_or_default()
+}
+
 // ============================================================================
 // Convenience HTTP Methods
 // ============================================================================
@@ -455,11 +812,22 @@ pub fn http_cancel_request(request_id: String) -> bool {
 
 #[tauri::command]
 pub fn http_cancel_all_requests() {
-    if let Ok(mut requests) = ACTIVE_REQUESTS.lock() {
-        for (_, cancelled) in requests.iter_mut() {
+    cancel_all_requests();
+}
+
+/// Flag every active request as cancelled; returns how many were flagged
+pub(crate) fn cancel_all_requests() -> usize {
+    let Ok(mut requests) = ACTIVE_REQUESTS.lock() else {
+        return 0;
+    };
+    let mut flagged = 0;
+    for (_, cancelled) in requests.iter_mut() {
+        if !*cancelled {
             *cancelled = true;
+            flagged += 1;
         }
     }
+    flagged
 }
 
 // ============================================================================
@@ -484,60 +852,162 @@ pub struct BatchItemResult {
     pub error: Option<String>,
 }
 
-#[tauri::command]
-pub async fn http_batch_download(
-    app: AppHandle,
+/// Aggregate progress emitted as `batch-progress` after each item completes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchProgress {
+    pub batch_id: String,
+    pub url: String,
+    pub completed: usize,
+    pub total: usize,
+    pub success: usize,
+    pub failed: usize,
+    pub total_bytes: u64,
+}
+
+fn batch_item_result(url: String, outcome: Result<HttpResponse, HttpClientError>) -> BatchItemResult {
+    match outcome {
+        Ok(response) => BatchItemResult {
+            url,
+            success: response.status >= 200 && response.status < 300,
+            status: Some(response.status),
+            size: Some(response.body.len()),
+            error: None,
+        },
+        Err(e) => BatchItemResult {
+            url,
+            success: false,
+            status: None,
+            size: None,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Download `urls` with bounded concurrency, reporting after each item.
+/// Once `batch_id` is cancelled, items that have not started are skipped
+/// and reported as cancelled.
+async fn run_batch<F, Fut, P>(
     urls: Vec<String>,
-    concurrency: Option<usize>,
-    allow_http: Option<bool>,
-) -> Result<BatchDownloadResult, HttpClientError> {
+    concurrency: usize,
+    batch_id: Option<String>,
+    fetch: F,
+    mut on_progress: P,
+) -> Vec<BatchItemResult>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<HttpResponse, HttpClientError>>,
+    P: FnMut(BatchProgress),
+{
     use futures_util::stream::{self, StreamExt};
-    
-    let start = std::time::Instant::now();
-    let concurrency = concurrency.unwrap_or(4).min(10);
-    let allow_http = allow_http.unwrap_or(false);
-    
-    let results: Vec<BatchItemResult> = stream::iter(urls.clone())
-        .map(|url| {
-            let app_clone = app.clone();
-            async move {
-                match http_request(app_clone, RequestConfig {
-                    method: "GET".to_string(),
-                    url: url.clone(),
-                    allow_http,
-                    ..Default::default()
-                }).await {
-                    Ok(response) => BatchItemResult {
-                        url,
-                        success: response.status >= 200 && response.status < 300,
-                        status: Some(response.status),
-                        size: Some(response.body.len()),
-                        error: None,
-                    },
-                    Err(e) => BatchItemResult {
-                        url,
-                        success: false,
-                        status: None,
-                        size: None,
-                        error: Some(e.to_string()),
-                    },
-                }
+
+    let total = urls.len();
+    let fetch = &fetch;
+    let cancel_id = &batch_id;
+    let mut items = stream::iter(urls)
+        .map(|url| async move {
+            if is_cancelled(cancel_id) {
+                return batch_item_result(url, Err(HttpClientError::Cancelled));
             }
+            let outcome = fetch(url.clone()).await;
+            batch_item_result(url, outcome)
         })
-        .buffer_unordered(concurrency)
-        .collect()
-        .await;
+        .buffer_unordered(concurrency.max(1));
 
+    let mut results = Vec::with_capacity(total);
+    let (mut success, mut total_bytes) = (0, 0u64);
+    while let Some(item) = items.next().await {
+        if item.success {
+            success += 1;
+        }
+        total_bytes += item.size.unwrap_or(0) as u64;
+        results.push(item);
+
+        on_progress(BatchProgress {
+            batch_id: batch_id.clone().unwrap_or_default(),
+            url: results.last().map(|r| r.url.clone()).unwrap_or_default(),
+            completed: results.len(),
+            total,
+            success,
+            failed: results.len() - success,
+            total_bytes,
+        });
+    }
+    results
+}
+
+fn batch_download_result(results: Vec<BatchItemResult>, start: std::time::Instant) -> BatchDownloadResult {
     let success = results.iter().filter(|r| r.success).count();
     let failed = results.len() - success;
 
-    Ok(BatchDownloadResult {
+    BatchDownloadResult {
         total: results.len(),
         success,
         failed,
         results,
         total_time_ms: start.elapsed().as_millis() as u64,
-    })
+    }
+}
+
+#[tauri::command]
+pub async fn http_batch_download(
+    app: AppHandle,
+    urls: Vec<String>,
+    concurrency: Option<usize>,
+    allow_http: Option<bool>,
+) -> Result<BatchDownloadResult, HttpClientError> {
+    let start = std::time::Instant::now();
+    let concurrency = concurrency.unwrap_or(4).min(10);
+    let allow_http = allow_http.unwrap_or(false);
+
+    let results = run_batch(
+        urls,
+        concurrency,
+        None,
+        |url| http_request(app.clone(), RequestConfig {
+            method: "GET".to_string(),
+            url,
+            allow_http,
+            ..Default::default()
+        }),
+        |_| {},
+    ).await;
+
+    Ok(batch_download_result(results, start))
+}
+
+/// Batch download that emits `batch-progress` after every item. Cancelling
+/// `batch_id` via `cancel_request` skips the items that have not started.
+#[tauri::command]
+pub async fn http_batch_download_streamed(
+    app: AppHandle,
+    urls: Vec<String>,
+    batch_id: String,
+    concurrency: Option<usize>,
+    allow_http: Option<bool>,
+) -> Result<BatchDownloadResult, HttpClientError> {
+    let start = std::time::Instant::now();
+    let concurrency = concurrency.unwrap_or(4).min(10);
+    let allow_http = allow_http.unwrap_or(false);
+    let batch = Some(batch_id);
+    register_request(&batch);
+
+    let results = run_batch(
+        urls,
+        concurrency,
+        batch.clone(),
+        |url| http_request(app.clone(), RequestConfig {
+            method: "GET".to_string(),
+            url,
+            allow_http,
+            ..Default::default()
+        }),
+        |progress| {
+            let _ = app.emit("batch-progress", progress);
+        },
+    ).await;
+
+    unregister_request(&batch);
+    Ok(batch_download_result(results, start))
 }
 
 // ============================================================================
@@ -579,6 +1049,7 @@ mod tests {
             request_id: Some("test-123".to_string()),
             allow_http: true,
             report_progress: true,
+            cache: Some(CachePolicy { default_ttl_seconds: Some(60) }),
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -693,6 +1164,78 @@ mod tests {
         assert!(json.contains("user_agent"));
     }
 
+    // ------------------------------------------------------------------------
+    // Request Log Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_redact_api_keys_in_urls() {
+        assert_eq!(
+            redact_api_keys("https://nova.astrometry.net/api?apikey=secret123&job=42"),
+            "https://nova.astrometry.net/api?apikey=REDACTED&job=42"
+        );
+        assert_eq!(redact_api_keys("https://x.test/a?q=1&API_KEY=abc#frag"), "https://x.test/a?q=1&API_KEY=REDACTED#frag");
+        assert_eq!(redact_api_keys("https://x.test/tiles/1/2/3.jpg"), "https://x.test/tiles/1/2/3.jpg");
+        assert_eq!(redact_api_keys("https://x.test/?monkey=1"), "https://x.test/?monkey=1");
+        assert_eq!(
+            redact_api_keys("error sending request for url (https://x.test/api?apikey=abc)"),
+            "error sending request for url (https://x.test/api?apikey=REDACTED)"
+        );
+    }
+
+    #[test]
+    fn test_record_request_adds_redacted_summary() {
+        let url = format!("https://log-test-{}.invalid/api?apikey=topsecret", std::process::id());
+        let response = HttpResponse {
+            status: 204,
+            headers: HashMap::new(),
+            body: Vec::new(),
+            content_type: None,
+            content_length: None,
+        };
+        record_request("GET", &url, &Ok(response), Duration::from_millis(42));
+        record_request("POST", &url, &Err(HttpClientError::Timeout(5)), Duration::from_millis(7));
+
+        let entries: Vec<_> = get_request_log()
+            .into_iter()
+            .filter(|e| e.url.contains(&format!("log-test-{}", std::process::id())))
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].method, "GET");
+        assert_eq!(entries[0].status, Some(204));
+        assert_eq!(entries[0].duration_ms, 42);
+        assert!(!entries[0].url.contains("topsecret"));
+        assert!(entries[1].status.is_none());
+        assert_eq!(entries[1].error.as_deref(), Some("Timeout after 5 seconds"));
+    }
+
+    #[tokio::test]
+    async fn test_record_request_redacts_api_key_in_error() {
+        let url = "http://127.0.0.1:9/log-error-test?apikey=errsecret&job=1";
+        let error = reqwest::Client::new().get(url).send().await.unwrap_err();
+        let error = HttpClientError::Request(error.to_string());
+        assert!(error.to_string().contains("errsecret"));
+        record_request("GET", url, &Err(error), Duration::from_millis(3));
+
+        let entry = get_request_log()
+            .into_iter()
+            .find(|e| e.url.contains("log-error-test"))
+            .unwrap();
+        let message = entry.error.unwrap();
+        assert!(!message.contains("errsecret"));
+        assert!(message.contains("apikey=REDACTED&job=1"));
+    }
+
+    #[test]
+    fn test_request_logging_is_off_by_default() {
+        assert!(!HttpClientConfig::default().log_requests);
+        let config: HttpClientConfig = serde_json::from_str(
+            &serde_json::to_string(&HttpClientConfig::default()).unwrap().replace(",\"log_requests\":false", ""),
+        )
+        .unwrap();
+        assert!(!config.log_requests);
+    }
+
     // ------------------------------------------------------------------------
     // BatchDownloadResult Tests
     // ------------------------------------------------------------------------
@@ -822,7 +1365,8 @@ mod tests {
         // When no requests are active, should return empty vec
         // Note: This might not be empty if other tests are running
         // Just verify it doesn't panic and returns a Vec
-        let _requests = get_active_requests();
+        let requests = get_active_requests();
+        assert!(requests.max_concurrent > 0);
     }
 
     #[test]
@@ -926,4 +1470,285 @@ mod tests {
         assert!(progress.total.is_none());
         assert_eq!(progress.percent, 0.0);
     }
+
+    // ------------------------------------------------------------------------
+    // Response Cache Tests
+    // ------------------------------------------------------------------------
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type MemoryCache = Arc<Mutex<HashMap<String, UnifiedCacheResponse>>>;
+
+    fn ok_response(cache_control: Option<&str>) -> HttpResponse {
+        let mut headers = HashMap::new();
+        if let Some(value) = cache_control {
+            headers.insert("cache-control".to_string(), value.to_string());
+        }
+        HttpResponse {
+            status: 200,
+            headers,
+            body: b"catalog".to_vec(),
+            content_type: Some("application/json".to_string()),
+            content_length: Some(7),
+        }
+    }
+
+    async fn cached_get(
+        cache: &MemoryCache,
+        fetches: &AtomicUsize,
+        now_ms: i64,
+        cache_control: Option<&str>,
+    ) -> HttpResponse {
+        let key = response_cache_key("get", "https://example.com/catalog");
+        request_with_cache(
+            key,
+            &CachePolicy::default(),
+            now_ms,
+            |key| async move { cache.lock().unwrap().get(&key).cloned() },
+            |key, response, ttl| async move {
+                cache.lock().unwrap().insert(key, UnifiedCacheResponse {
+                    data: response.body,
+                    content_type: response.content_type.unwrap_or_default(),
+                    timestamp: now_ms,
+                    ttl,
+                });
+            },
+            || async move {
+                fetches.fetch_add(1, Ordering::SeqCst);
+                Ok(ok_response(cache_control))
+            },
+        ).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_second_cached_get_skips_network() {
+        let cache = MemoryCache::default();
+        let fetches = AtomicUsize::new(0);
+
+        let first = cached_get(&cache, &fetches, 1_000, Some("public, max-age=60")).await;
+        let second = cached_get(&cache, &fetches, 30_000, Some("public, max-age=60")).await;
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(second.body, first.body);
+        assert_eq!(second.content_type.as_deref(), Some("application/json"));
+        assert!(cache.lock().unwrap().contains_key("GET:https://example.com/catalog"));
+    }
+
+    #[tokio::test]
+    async fn test_expired_cache_entry_refetches() {
+        let cache = MemoryCache::default();
+        let fetches = AtomicUsize::new(0);
+
+        cached_get(&cache, &fetches, 1_000, Some("max-age=60")).await;
+        cached_get(&cache, &fetches, 1_000 + 61_000, Some("max-age=60")).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_store_response_is_not_cached() {
+        let cache = MemoryCache::default();
+        let fetches = AtomicUsize::new(0);
+
+        cached_get(&cache, &fetches, 1_000, Some("no-store, max-age=60")).await;
+        cached_get(&cache, &fetches, 2_000, Some("no-store, max-age=60")).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        assert!(cache.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_response_cache_ttl() {
+        let policy = CachePolicy { default_ttl_seconds: Some(300) };
+        assert_eq!(response_cache_ttl_ms(&policy, &ok_response(Some("max-age=120"))), Some(120_000));
+        assert_eq!(response_cache_ttl_ms(&policy, &ok_response(None)), Some(300_000));
+        assert_eq!(response_cache_ttl_ms(&CachePolicy::default(), &ok_response(None)), None);
+        assert_eq!(response_cache_ttl_ms(&policy, &ok_response(Some("max-age=0"))), None);
+
+        let mut not_found = ok_response(Some("max-age=120"));
+        not_found.status = 404;
+        assert_eq!(response_cache_ttl_ms(&policy, &not_found), None);
+    }
+
+    // ------------------------------------------------------------------------
+    // Concurrency Limit Tests
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_requests_beyond_cap_queue_until_slots_free() {
+        let slots = Arc::new(RequestSlots::default());
+        let first = slots.acquire(2).await;
+        let second = slots.acquire(2).await;
+
+        let completed = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let (slots, completed, peak) = (slots.clone(), completed.clone(), peak.clone());
+                tokio::spawn(async move {
+                    let _slot = slots.acquire(2).await;
+                    peak.fetch_max(slots.counts().0, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    completed.fetch_add(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(slots.counts(), (2, 3));
+        assert_eq!(completed.load(Ordering::SeqCst), 0);
+
+        drop(first);
+        drop(second);
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(completed.load(Ordering::SeqCst), 3);
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+        assert_eq!(slots.counts(), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_abandoned_wait_leaves_queue() {
+        let slots = RequestSlots::default();
+        let held = slots.acquire(1).await;
+
+        let waited = tokio::time::timeout(Duration::from_millis(10), slots.acquire(1)).await;
+        assert!(waited.is_err());
+        assert_eq!(slots.counts(), (1, 0));
+
+        drop(held);
+        let _slot = slots.acquire(1).await;
+        assert_eq!(slots.counts(), (1, 0));
+    }
+
+    #[test]
+    fn test_http_client_config_concurrency_default() {
+        let config: HttpClientConfig = serde_json::from_value(serde_json::json!({
+            "connect_timeout_ms": 1, "read_timeout_ms": 1, "request_timeout_ms": 1,
+            "max_retries": 0, "retry_base_delay_ms": 0, "retry_max_delay_ms": 0,
+            "user_agent": "x", "proxy_url": null, "max_response_size": 1,
+            "enable_compression": false, "follow_redirects": false, "max_redirects": 0
+        })).unwrap();
+        assert_eq!(config.max_concurrent_requests, 8);
+    }
+
+    // ------------------------------------------------------------------------
+    // Streamed Batch Tests
+    // ------------------------------------------------------------------------
+
+    fn sized_response(size: usize) -> HttpResponse {
+        HttpResponse {
+            status: 200,
+            headers: HashMap::new(),
+            body: vec![0; size],
+            content_type: None,
+            content_length: Some(size as u64),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_emits_progress_per_item() {
+        let urls: Vec<String> = (0..5).map(|i| format!("https://example.com/{}", i)).collect();
+        let mut events = Vec::new();
+
+        let results = run_batch(
+            urls.clone(),
+            2,
+            Some(crate::utils::generate_id("batch")),
+            |url| async move {
+                if url.ends_with("/3") {
+                    Err(HttpClientError::Request("boom".to_string()))
+                } else {
+                    Ok(sized_response(10))
+                }
+            },
+            |progress| events.push(progress),
+        ).await;
+
+        assert_eq!(results.len(), urls.len());
+        assert_eq!(events.len(), urls.len());
+        let last = events.last().unwrap();
+        assert_eq!((last.completed, last.total), (5, 5));
+        assert_eq!((last.success, last.failed), (4, 1));
+        assert_eq!(last.total_bytes, 40);
+        assert!(events.windows(2).all(|w| w[0].completed + 1 == w[1].completed));
+    }
+
+    #[tokio::test]
+    async fn test_batch_cancellation_stops_remaining_downloads() {
+        let batch_id = crate::utils::generate_id("batch");
+        let batch = Some(batch_id.clone());
+        register_request(&batch);
+
+        let urls: Vec<String> = (0..6).map(|i| format!("https://example.com/{}", i)).collect();
+        let fetches = AtomicUsize::new(0);
+        let fetches_ref = &fetches;
+        let cancel_id = batch_id.clone();
+
+        let results = run_batch(
+            urls,
+            1,
+            batch.clone(),
+            |_url| {
+                let cancel_id = cancel_id.clone();
+                async move {
+                    // Cancel the batch from inside the second download
+                    if fetches_ref.fetch_add(1, Ordering::SeqCst) == 1 {
+                        cancel_request(cancel_id);
+                    }
+                    Ok(sized_response(1))
+                }
+            },
+            |_| {},
+        ).await;
+        unregister_request(&batch);
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        assert_eq!(results.len(), 6);
+        let cancelled = results.iter().filter(|r| r.error.as_deref() == Some("Request cancelled")).count();
+        assert_eq!(cancelled, 4);
+    }
+
+    // ------------------------------------------------------------------------
+    // Read Timeout Tests
+    // ------------------------------------------------------------------------
+
+    /// Serve one response that sends headers and part of the body, then stalls
+    fn spawn_stalling_server(stall: Duration) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut request = [0u8; 1024];
+                let _ = stream.read(&mut request);
+                let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\npartial");
+                let _ = stream.flush();
+                std::thread::sleep(stall);
+            }
+        });
+        format!("http://{}/stall", addr)
+    }
+
+    #[tokio::test]
+    async fn test_read_timeout_fires_before_total_timeout() {
+        let url = spawn_stalling_server(Duration::from_secs(5));
+        let config = HttpClientConfig { read_timeout_ms: 200, ..HttpClientConfig::default() };
+        let client = build_client(&config, Duration::from_secs(10)).unwrap();
+
+        let start = std::time::Instant::now();
+        let response = client.get(&url).send().await.unwrap();
+        assert_eq!(response.status().as_u16(), 200);
+
+        let err = response.bytes().await.unwrap_err();
+        assert!(err.is_timeout(), "expected a timeout, got {}", err);
+        assert!(start.elapsed() < Duration::from_secs(3), "read timeout did not fire early");
+    }
+
+    #[test]
+    fn test_read_timeout_error_rounds_up() {
+        assert!(matches!(read_timeout_error(200), HttpClientError::Timeout(1)));
+        assert!(matches!(read_timeout_error(30000), HttpClientError::Timeout(30)));
+    }
 }
@@ -13,10 +13,11 @@ pub mod rate_limiter;
 // Re-export HTTP client types and commands
 pub use http_client::{
     // Types
-    BatchDownloadResult, BatchItemResult, DownloadProgress, HttpClientConfig,
-    HttpClientError, HttpResponse, RequestConfig,
+    ActiveRequests, BatchDownloadResult, BatchItemResult, BatchProgress, CachePolicy, DownloadProgress, HttpClientConfig,
+    HttpClientError, HttpResponse, RequestConfig, RequestLogEntry,
     // Commands
-    cancel_request, get_active_requests, get_http_config, http_batch_download,
+    cancel_request, get_active_requests, get_http_config, get_request_log, http_batch_download,
+    http_batch_download_streamed,
     http_cancel_all_requests, http_cancel_request, http_check_url, http_download,
     http_get, http_head, http_post, http_request, set_http_config,
 };
@@ -28,6 +29,8 @@ pub use security::{
 
 // Re-export rate limiter types
 pub use rate_limiter::{
-    GlobalRateLimiter, RateLimitConfig, RateLimitResult, RateLimitState, SlidingWindowLimiter,
-    get_command_rate_limit,
+    GlobalRateLimiter, PersistedRateLimit, PersistedRateLimits, RateLimitConfig, RateLimitResult,
+    RateLimitState, SlidingWindowLimiter, GLOBAL_RATE_LIMITER,
+    get_command_rate_limit, get_domain_rate_limits, get_rate_limit_for_domain, load_rate_limit_state,
+    save_rate_limit_state, set_domain_rate_limit,
 };
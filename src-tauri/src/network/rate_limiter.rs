@@ -1,12 +1,21 @@
 //! Rate limiting utilities
-//! Provides token bucket and sliding window rate limiters
+//! Provides token bucket and sliding window rate limiters, with limiter state
+//! persisted across restarts
 
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+#[cfg(not(desktop))]
+use tauri::Manager;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::data::{write_json_atomic, StorageError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RateLimitConfig {
     pub max_requests: usize,
     pub window_seconds: u64,
@@ -97,10 +106,15 @@ impl GlobalRateLimiter {
         Self { limiters: Arc::new(Mutex::new(HashMap::new())) }
     }
 
+    /// Count a request against `command`'s window. A changed config takes
+    /// effect immediately while keeping the requests already counted.
     pub fn check(&self, command: &str, config: RateLimitConfig) -> RateLimitResult {
         let mut limiters = self.limiters.lock().unwrap();
         let entry = limiters.entry(command.to_string())
             .or_insert_with(|| (SlidingWindowLimiter::new(config.clone()), RateLimitState::default()));
+        if entry.0.config != config {
+            entry.0 = SlidingWindowLimiter::new(config);
+        }
         entry.0.check(&mut entry.1)
     }
 
@@ -114,6 +128,256 @@ impl Default for GlobalRateLimiter {
     fn default() -> Self { Self::new() }
 }
 
+/// Process-wide rate limiter whose state is saved on shutdown
+pub static GLOBAL_RATE_LIMITER: Lazy<GlobalRateLimiter> = Lazy::new(GlobalRateLimiter::new);
+
+// ============================================================================
+// Persistence
+// ============================================================================
+
+/// On-disk form of one limiter; instants are stored as Unix milliseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedRateLimit {
+    pub config: RateLimitConfig,
+    pub requests: Vec<i64>,
+    pub banned_until: Option<i64>,
+}
+
+/// On-disk form of every limiter, keyed by command
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PersistedRateLimits {
+    pub saved_at: i64,
+    pub limiters: HashMap<String, PersistedRateLimit>,
+}
+
+fn unix_millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or(0)
+}
+
+/// Map a monotonic instant onto wall-clock milliseconds
+fn instant_to_millis(instant: Instant, now: Instant, now_millis: i64) -> i64 {
+    if instant <= now {
+        now_millis - now.duration_since(instant).as_millis() as i64
+    } else {
+        now_millis + instant.duration_since(now).as_millis() as i64
+    }
+}
+
+/// Map wall-clock milliseconds back onto a monotonic instant
+fn millis_to_instant(millis: i64, now: Instant, now_millis: i64) -> Option<Instant> {
+    let delta = Duration::from_millis((millis - now_millis).unsigned_abs());
+    if millis <= now_millis { now.checked_sub(delta) } else { now.checked_add(delta) }
+}
+
+impl GlobalRateLimiter {
+    /// Capture every limiter's request window and ban timer
+    pub fn snapshot(&self, now: SystemTime) -> PersistedRateLimits {
+        let now_instant = Instant::now();
+        let now_millis = unix_millis(now);
+        let limiters = self.limiters.lock().unwrap();
+
+        PersistedRateLimits {
+            saved_at: now_millis,
+            limiters: limiters
+                .iter()
+                .map(|(key, (limiter, state))| {
+                    let persisted = PersistedRateLimit {
+                        config: limiter.config.clone(),
+                        requests: state.requests.iter()
+                            .map(|&t| instant_to_millis(t, now_instant, now_millis))
+                            .collect(),
+                        banned_until: state.banned_until
+                            .map(|t| instant_to_millis(t, now_instant, now_millis)),
+                    };
+                    (key.clone(), persisted)
+                })
+                .collect(),
+        }
+    }
+
+    /// Replace limiter state from a snapshot, dropping requests that have left
+    /// their window and bans that have already expired
+    pub fn restore(&self, persisted: PersistedRateLimits, now: SystemTime) {
+        let now_instant = Instant::now();
+        let now_millis = unix_millis(now);
+        let mut limiters = self.limiters.lock().unwrap();
+        limiters.clear();
+
+        for (key, entry) in persisted.limiters {
+            let window_millis = entry.config.window_seconds as i64 * 1000;
+            let requests: Vec<Instant> = entry.requests.iter()
+                .filter(|&&t| t <= now_millis && now_millis - t < window_millis)
+                .filter_map(|&t| millis_to_instant(t, now_instant, now_millis))
+                .collect();
+            let banned_until = entry.banned_until
+                .filter(|&t| t > now_millis)
+                .and_then(|t| millis_to_instant(t, now_instant, now_millis));
+
+            if requests.is_empty() && banned_until.is_none() {
+                continue;
+            }
+            limiters.insert(
+                key,
+                (SlidingWindowLimiter::new(entry.config), RateLimitState { requests, banned_until }),
+            );
+        }
+    }
+}
+
+/// Rate limit files live in the data directory, which honours a custom
+/// data directory on desktop
+fn rate_limit_file_path(app: &AppHandle, file_name: &str) -> Result<PathBuf, StorageError> {
+    #[cfg(desktop)]
+    let dir = crate::platform::path_config::resolve_data_dir(app)?;
+
+    #[cfg(not(desktop))]
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|_| StorageError::AppDataDirNotFound)?
+        .join("skymap");
+
+    if !dir.exists() {
+        fs::create_dir_all(&dir)?;
+    }
+
+    Ok(dir.join(file_name))
+}
+
+fn save_rate_limit_state_to(limiter: &GlobalRateLimiter, path: &Path) -> Result<(), StorageError> {
+    write_json_atomic(path, &limiter.snapshot(SystemTime::now()))
+}
+
+fn load_rate_limit_state_from(limiter: &GlobalRateLimiter, path: &Path) -> Result<(), StorageError> {
+    if !path.exists() {
+        return Ok(());
+    }
+    let persisted: PersistedRateLimits = serde_json::from_str(&fs::read_to_string(path)?)?;
+    limiter.restore(persisted, SystemTime::now());
+    Ok(())
+}
+
+/// Save the global limiter state; called on shutdown
+pub fn save_rate_limit_state(app: &AppHandle) -> Result<(), StorageError> {
+    let path = rate_limit_file_path(app, RATE_LIMIT_STATE_FILE)?;
+    save_rate_limit_state_to(&GLOBAL_RATE_LIMITER, &path)
+}
+
+/// Restore the global limiter state and domain overrides; called on startup
+pub fn load_rate_limit_state(app: &AppHandle) -> Result<(), StorageError> {
+    let overrides = load_domain_rate_limits_from(&rate_limit_file_path(app, DOMAIN_RATE_LIMITS_FILE)?)?;
+    *DOMAIN_RATE_LIMITS.lock().unwrap() = overrides;
+
+    let path = rate_limit_file_path(app, RATE_LIMIT_STATE_FILE)?;
+    load_rate_limit_state_from(&GLOBAL_RATE_LIMITER, &path)
+}
+
+// ============================================================================
+// Per-Domain Limits
+// ============================================================================
+
+const RATE_LIMIT_STATE_FILE: &str = "rate_limits.json";
+const DOMAIN_RATE_LIMITS_FILE: &str = "domain_rate_limits.json";
+
+/// User overrides keyed by host, consulted before the built-in defaults
+static DOMAIN_RATE_LIMITS: Lazy<Mutex<HashMap<String, RateLimitConfig>>> = Lazy::new(Default::default);
+
+const fn per_window(max_requests: usize, window_seconds: u64) -> RateLimitConfig {
+    RateLimitConfig { max_requests, window_seconds, ban_on_exceed: false, ban_duration_seconds: None }
+}
+
+/// Built-in limits for providers with published usage policies, keyed by host
+/// (covering subdomains); hosts not listed fall back to `moderate`
+static PROVIDER_RATE_LIMITS: Lazy<HashMap<String, RateLimitConfig>> = Lazy::new(|| {
+    [
+        // OSM tile usage policy discourages bulk fetching
+        ("tile.openstreetmap.org", per_window(60, 60)),
+        // Nominatim allows at most one request per second
+        ("nominatim.openstreetmap.org", per_window(1, 1)),
+        // SIMBAD and VizieR ask for no more than a few queries per second;
+        // HiPS tiles on the other CDS hosts (alasky) are left at the default
+        ("simbad.cds.unistra.fr", per_window(5, 1)),
+        ("simbad.u-strasbg.fr", per_window(5, 1)),
+        ("vizier.cds.unistra.fr", per_window(5, 1)),
+        ("vizier.u-strasbg.fr", per_window(5, 1)),
+        ("nova.astrometry.net", per_window(30, 60)),
+        // api.nasa.gov keys allow 1000 requests per hour
+        ("api.nasa.gov", per_window(1000, 3600)),
+        ("ssd-api.jpl.nasa.gov", per_window(30, 60)),
+        ("celestrak.org", per_window(10, 60)),
+        ("minorplanetcenter.net", per_window(30, 60)),
+        ("api.n2yo.com", per_window(1000, 3600)),
+        // Unauthenticated GitHub API calls are capped at 60 per hour
+        ("api.github.com", per_window(60, 3600)),
+    ]
+    .into_iter()
+    .map(|(host, config)| (host.to_string(), config))
+    .collect()
+});
+
+fn normalize_domain(domain: &str) -> String {
+    domain.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// Override for `domain` itself or, failing that, its nearest parent domain,
+/// so an entry for `example.org` also covers `tiles.example.org`
+fn find_domain_override(overrides: &HashMap<String, RateLimitConfig>, domain: &str) -> Option<RateLimitConfig> {
+    let domain = normalize_domain(domain);
+    let mut candidate = domain.as_str();
+    loop {
+        if let Some(config) = overrides.get(candidate) {
+            return Some(config.clone());
+        }
+        candidate = candidate.split_once('.')?.1;
+    }
+}
+
+/// User override for `domain`, else the provider default, else `moderate`
+fn resolve_domain_limit(overrides: &HashMap<String, RateLimitConfig>, domain: &str) -> RateLimitConfig {
+    find_domain_override(overrides, domain)
+        .or_else(|| find_domain_override(&PROVIDER_RATE_LIMITS, domain))
+        .unwrap_or_else(RateLimitConfig::moderate)
+}
+
+/// Limit applied to requests against `domain`
+pub fn get_rate_limit_for_domain(domain: &str) -> RateLimitConfig {
+    let overrides = DOMAIN_RATE_LIMITS.lock().unwrap();
+    resolve_domain_limit(&overrides, domain)
+}
+
+fn save_domain_rate_limits_to(overrides: &HashMap<String, RateLimitConfig>, path: &Path) -> Result<(), StorageError> {
+    write_json_atomic(path, overrides)
+}
+
+fn load_domain_rate_limits_from(path: &Path) -> Result<HashMap<String, RateLimitConfig>, StorageError> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Set and persist the limit for a domain (and its subdomains)
+#[tauri::command]
+pub fn set_domain_rate_limit(app: AppHandle, domain: String, config: RateLimitConfig) -> Result<(), StorageError> {
+    let domain = normalize_domain(&domain);
+    if domain.is_empty() {
+        return Err(StorageError::Other("Domain must not be empty".to_string()));
+    }
+    if config.max_requests == 0 || config.window_seconds == 0 {
+        return Err(StorageError::Other("Rate limit needs a non-zero request count and window".to_string()));
+    }
+
+    let mut overrides = DOMAIN_RATE_LIMITS.lock().unwrap();
+    overrides.insert(domain, config);
+    save_domain_rate_limits_to(&overrides, &rate_limit_file_path(&app, DOMAIN_RATE_LIMITS_FILE)?)
+}
+
+/// All user-configured domain limits
+#[tauri::command]
+pub fn get_domain_rate_limits() -> HashMap<String, RateLimitConfig> {
+    DOMAIN_RATE_LIMITS.lock().unwrap().clone()
+}
+
 pub fn get_command_rate_limit(command: &str) -> RateLimitConfig {
     match command {
         "open_path" | "reveal_in_file_manager" | "import_all_data" | "export_all_data" 
@@ -155,4 +419,135 @@ mod tests {
         assert!(!limiter.check("test", config.clone()).is_allowed());
         assert!(limiter.check("other", config).is_allowed());
     }
+
+    // ------------------------------------------------------------------------
+    // Persistence Tests
+    // ------------------------------------------------------------------------
+
+    fn banning_config() -> RateLimitConfig {
+        RateLimitConfig { max_requests: 2, window_seconds: 60, ban_on_exceed: true, ban_duration_seconds: Some(300) }
+    }
+
+    #[test]
+    fn test_rate_limit_state_round_trip() {
+        let limiter = GlobalRateLimiter::new();
+        let window = RateLimitConfig { max_requests: 3, window_seconds: 60, ban_on_exceed: false, ban_duration_seconds: None };
+        assert!(limiter.check("window", window.clone()).is_allowed());
+        assert!(limiter.check("window", window.clone()).is_allowed());
+        for _ in 0..3 { limiter.check("banned", banning_config()); }
+
+        let dir = std::env::temp_dir().join(crate::utils::generate_id("rate-limits"));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("rate_limits.json");
+        save_rate_limit_state_to(&limiter, &path).unwrap();
+
+        let restored = GlobalRateLimiter::new();
+        load_rate_limit_state_from(&restored, &path).unwrap();
+
+        // One request left in the restored window, then limited
+        assert!(restored.check("window", window.clone()).is_allowed());
+        assert!(!restored.check("window", window).is_allowed());
+        // The ban survives the restart
+        assert!(matches!(restored.check("banned", banning_config()), RateLimitResult::Banned { .. }));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_rate_limit_state_expires_on_load() {
+        let limiter = GlobalRateLimiter::new();
+        limiter.check("window", banning_config());
+        for _ in 0..3 { limiter.check("banned", banning_config()); }
+        let snapshot = limiter.snapshot(SystemTime::now());
+
+        // Ten minutes later the 60 s window and the 300 s ban have both lapsed
+        let later = SystemTime::now() + Duration::from_secs(600);
+        let restored = GlobalRateLimiter::new();
+        restored.restore(snapshot.clone(), later);
+        assert!(restored.limiters.lock().unwrap().is_empty());
+
+        // Two minutes later only the ban remains
+        restored.restore(snapshot, SystemTime::now() + Duration::from_secs(120));
+        let limiters = restored.limiters.lock().unwrap();
+        assert!(!limiters.contains_key("window"));
+        let (_, state) = &limiters["banned"];
+        assert!(state.requests.is_empty());
+        assert!(state.banned_until.is_some());
+    }
+
+    // ------------------------------------------------------------------------
+    // Domain Override Tests
+    // ------------------------------------------------------------------------
+
+    fn mirror_config() -> RateLimitConfig {
+        RateLimitConfig { max_requests: 5, window_seconds: 10, ban_on_exceed: false, ban_duration_seconds: None }
+    }
+
+    #[test]
+    fn test_domain_override_takes_precedence() {
+        let mut overrides = HashMap::new();
+        assert!(find_domain_override(&overrides, "mirror.example.org").is_none());
+
+        overrides.insert("example.org".to_string(), mirror_config());
+        let exact = find_domain_override(&overrides, "Example.ORG.").unwrap();
+        assert_eq!(exact.max_requests, 5);
+        let sub = find_domain_override(&overrides, "tiles.mirror.example.org").unwrap();
+        assert_eq!(sub.window_seconds, 10);
+        assert!(find_domain_override(&overrides, "otherexample.org").is_none());
+
+        // Without an override the built-in default applies
+        let default = get_rate_limit_for_domain("unconfigured.invalid");
+        assert_eq!(default.max_requests, RateLimitConfig::moderate().max_requests);
+    }
+
+    #[test]
+    fn test_provider_defaults_sit_beneath_overrides() {
+        let mut overrides = HashMap::new();
+        assert_eq!(resolve_domain_limit(&overrides, "simbad.cds.unistra.fr"), per_window(5, 1));
+        assert_eq!(resolve_domain_limit(&overrides, "nominatim.openstreetmap.org"), per_window(1, 1));
+        assert_eq!(resolve_domain_limit(&overrides, "www.openstreetmap.org"), RateLimitConfig::moderate());
+        // HiPS tile hosts share the CDS domain but not the query limit
+        assert_eq!(resolve_domain_limit(&overrides, "alasky.cds.unistra.fr"), RateLimitConfig::moderate());
+        assert_eq!(resolve_domain_limit(&overrides, "alasky.u-strasbg.fr"), RateLimitConfig::moderate());
+
+        overrides.insert("cds.unistra.fr".to_string(), mirror_config());
+        assert_eq!(resolve_domain_limit(&overrides, "simbad.cds.unistra.fr"), mirror_config());
+    }
+
+    #[test]
+    fn test_changed_config_applies_to_existing_limiter() {
+        let limiter = GlobalRateLimiter::new();
+        for _ in 0..3 {
+            assert!(limiter.check("http:host", per_window(5, 60)).is_allowed());
+        }
+        assert!(!limiter.check("http:host", per_window(3, 60)).is_allowed());
+        assert!(limiter.check("http:host", per_window(10, 60)).is_allowed());
+    }
+
+    #[test]
+    fn test_domain_overrides_round_trip() {
+        let dir = std::env::temp_dir().join(crate::utils::generate_id("domain-limits"));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(DOMAIN_RATE_LIMITS_FILE);
+        assert!(load_domain_rate_limits_from(&path).unwrap().is_empty());
+
+        let mut overrides = HashMap::new();
+        overrides.insert("proxy.local".to_string(), mirror_config());
+        save_domain_rate_limits_to(&overrides, &path).unwrap();
+
+        let loaded = load_domain_rate_limits_from(&path).unwrap();
+        let config = find_domain_override(&loaded, "proxy.local").unwrap();
+        assert_eq!(config.max_requests, 5);
+        assert_eq!(config.window_seconds, 10);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_load_missing_rate_limit_state_is_noop() {
+        let limiter = GlobalRateLimiter::new();
+        let path = std::env::temp_dir().join(crate::utils::generate_id("missing")).join("rate_limits.json");
+        assert!(load_rate_limit_state_from(&limiter, &path).is_ok());
+        assert!(limiter.limiters.lock().unwrap().is_empty());
+    }
 }
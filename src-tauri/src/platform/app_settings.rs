@@ -2,11 +2,13 @@
 //! Manages window state, recent files, and app preferences
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize};
 
-use crate::data::StorageError;
+use crate::data::{write_json_atomic, StorageError};
+use super::updater::UpdateChannel;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WindowState {
@@ -24,6 +26,56 @@ impl Default for WindowState {
     }
 }
 
+/// Screen-space rectangle in physical pixels (monitor bounds or work area)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ScreenRect {
+    fn intersects(&self, other: &ScreenRect) -> bool {
+        let (ax2, ay2) = (self.x as i64 + self.width as i64, self.y as i64 + self.height as i64);
+        let (bx2, by2) = (other.x as i64 + other.width as i64, other.y as i64 + other.height as i64);
+        (self.x as i64) < bx2 && (other.x as i64) < ax2 && (self.y as i64) < by2 && (other.y as i64) < ay2
+    }
+}
+
+impl WindowState {
+    fn rect(&self) -> ScreenRect {
+        ScreenRect { x: self.x, y: self.y, width: self.width, height: self.height }
+    }
+}
+
+/// Validate a saved window state against the connected monitors
+/// Returns a corrected state clamped into `work_area` when the saved rectangle
+/// doesn't intersect any monitor, or `None` if the saved state is usable as-is
+pub fn sanitize_window_state(
+    state: &WindowState,
+    monitors: &[ScreenRect],
+    work_area: ScreenRect,
+) -> Option<WindowState> {
+    let rect = state.rect();
+    if monitors.iter().any(|m| rect.intersects(m)) {
+        return None;
+    }
+
+    let width = state.width.clamp(1, work_area.width.max(1));
+    let height = state.height.clamp(1, work_area.height.max(1));
+    let max_x = work_area.x + work_area.width.saturating_sub(width) as i32;
+    let max_y = work_area.y + work_area.height.saturating_sub(height) as i32;
+
+    Some(WindowState {
+        width,
+        height,
+        x: state.x.clamp(work_area.x, max_x),
+        y: state.y.clamp(work_area.y, max_y),
+        ..state.clone()
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecentFile {
     pub path: String,
@@ -45,6 +97,67 @@ pub struct AppSettings {
     pub sidebar_collapsed: bool,
     pub show_welcome: bool,
     pub language: String,
+    #[serde(default = "default_shortcuts")]
+    pub shortcuts: Shortcuts,
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+}
+
+/// Keyboard shortcut bindings keyed by action name
+pub type Shortcuts = BTreeMap<String, String>;
+
+/// Remappable actions and their default accelerators
+const DEFAULT_SHORTCUTS: &[(&str, &str)] = &[
+    ("open_solver", "CmdOrCtrl+Shift+P"),
+    ("slew_to_active", "CmdOrCtrl+G"),
+    ("search", "CmdOrCtrl+F"),
+    ("toggle_sidebar", "CmdOrCtrl+B"),
+    ("open_settings", "CmdOrCtrl+Comma"),
+];
+
+fn default_shortcuts() -> Shortcuts {
+    DEFAULT_SHORTCUTS
+        .iter()
+        .map(|(action, accelerator)| (action.to_string(), accelerator.to_string()))
+        .collect()
+}
+
+fn parse_accelerator(accelerator: &str) -> Result<tauri_plugin_global_shortcut::Shortcut, StorageError> {
+    accelerator
+        .parse()
+        .map_err(|e| StorageError::Other(format!("Invalid accelerator '{}': {}", accelerator, e)))
+}
+
+/// Check that every bound accelerator parses and no two actions share one
+/// Empty accelerators mean the action is unbound
+fn validate_shortcuts(shortcuts: &Shortcuts) -> Result<(), StorageError> {
+    let mut seen: Vec<(tauri_plugin_global_shortcut::Shortcut, &str)> = Vec::new();
+    for (action, accelerator) in shortcuts {
+        if accelerator.trim().is_empty() {
+            continue;
+        }
+        let parsed = parse_accelerator(accelerator)?;
+        if let Some((_, other)) = seen.iter().find(|(existing, _)| *existing == parsed) {
+            return Err(StorageError::Other(format!(
+                "Accelerator '{}' is already bound to '{}'",
+                accelerator, other
+            )));
+        }
+        seen.push((parsed, action));
+    }
+    Ok(())
+}
+
+/// Rebind a single action, rejecting unknown actions and conflicting accelerators
+fn apply_shortcut(shortcuts: &mut Shortcuts, action: &str, accelerator: &str) -> Result<(), StorageError> {
+    if !DEFAULT_SHORTCUTS.iter().any(|(known, _)| *known == action) {
+        return Err(StorageError::Other(format!("Unknown shortcut action: {}", action)));
+    }
+    let mut updated = shortcuts.clone();
+    updated.insert(action.to_string(), accelerator.trim().to_string());
+    validate_shortcuts(&updated)?;
+    *shortcuts = updated;
+    Ok(())
 }
 
 impl Default for AppSettings {
@@ -54,6 +167,7 @@ impl Default for AppSettings {
             last_export_dir: None, last_import_dir: None, auto_save_interval: 300,
             check_updates: true, telemetry_enabled: false, theme: "system".to_string(),
             sidebar_collapsed: false, show_welcome: true, language: "en".to_string(),
+            shortcuts: default_shortcuts(), update_channel: UpdateChannel::default(),
         }
     }
 }
@@ -80,10 +194,145 @@ pub struct SystemInfo {
     pub host_id: Option<String>,
 }
 
-fn get_settings_path(app: &AppHandle) -> Result<PathBuf, StorageError> {
+/// Profile backed by the legacy `app_settings.json` file
+const DEFAULT_PROFILE: &str = "default";
+
+/// Settings profiles and which one is active
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsProfileList {
+    pub active: String,
+    pub profiles: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ActiveProfileFile {
+    active: Option<String>,
+}
+
+fn get_settings_base_dir(app: &AppHandle) -> Result<PathBuf, StorageError> {
     let dir = super::path_config::resolve_data_dir(app)?;
     if !dir.exists() { fs::create_dir_all(&dir)?; }
-    Ok(dir.join("app_settings.json"))
+    Ok(dir)
+}
+
+fn get_settings_path(app: &AppHandle) -> Result<PathBuf, StorageError> {
+    let base = get_settings_base_dir(app)?;
+    Ok(profile_settings_path(&base, &read_active_profile(&base)))
+}
+
+fn profiles_dir(base: &Path) -> PathBuf {
+    base.join("settings_profiles")
+}
+
+fn active_profile_path(base: &Path) -> PathBuf {
+    base.join("active_settings_profile.json")
+}
+
+fn profile_settings_path(base: &Path, name: &str) -> PathBuf {
+    if name == DEFAULT_PROFILE {
+        base.join("app_settings.json")
+    } else {
+        profiles_dir(base).join(format!("{}.json", name))
+    }
+}
+
+fn validate_profile_name(name: &str) -> Result<(), StorageError> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(StorageError::Other(format!(
+            "Invalid profile name '{}': use 1-64 letters, digits, '-' or '_'",
+            name
+        )))
+    }
+}
+
+fn read_active_profile(base: &Path) -> String {
+    fs::read_to_string(active_profile_path(base))
+        .ok()
+        .and_then(|content| serde_json::from_str::<ActiveProfileFile>(&content).ok())
+        .and_then(|file| file.active)
+        .filter(|name| profile_settings_path(base, name).exists() || name == DEFAULT_PROFILE)
+        .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+}
+
+fn read_settings_file(path: &Path) -> Result<AppSettings, StorageError> {
+    if !path.exists() { return Ok(AppSettings::default()); }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Update channel of the active profile, falling back to the default channel
+/// when its settings can't be read
+fn active_update_channel_in(base: &Path) -> UpdateChannel {
+    match read_settings_file(&profile_settings_path(base, &read_active_profile(base))) {
+        Ok(settings) => settings.update_channel,
+        Err(e) => {
+            log::warn!("Failed to read update channel from settings: {}", e);
+            UpdateChannel::default()
+        }
+    }
+}
+
+fn list_profiles_in(base: &Path) -> Result<SettingsProfileList, StorageError> {
+    let mut profiles = vec![DEFAULT_PROFILE.to_string()];
+    let dir = profiles_dir(base);
+    if dir.exists() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) == Some("json") {
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    if stem != DEFAULT_PROFILE {
+                        profiles.push(stem.to_string());
+                    }
+                }
+            }
+        }
+    }
+    profiles[1..].sort();
+    Ok(SettingsProfileList { active: read_active_profile(base), profiles })
+}
+
+/// Create a profile seeded with a copy of the active profile's settings
+fn create_profile_in(base: &Path, name: &str) -> Result<SettingsProfileList, StorageError> {
+    validate_profile_name(name)?;
+    let path = profile_settings_path(base, name);
+    if name == DEFAULT_PROFILE || path.exists() {
+        return Err(StorageError::Other(format!("Profile '{}' already exists", name)));
+    }
+    let current = read_settings_file(&profile_settings_path(base, &read_active_profile(base)))?;
+    write_json_atomic(&path, &current)?;
+    list_profiles_in(base)
+}
+
+fn switch_profile_in(base: &Path, name: &str) -> Result<AppSettings, StorageError> {
+    let path = profile_settings_path(base, name);
+    if name != DEFAULT_PROFILE && (validate_profile_name(name).is_err() || !path.exists()) {
+        return Err(StorageError::StoreNotFound(format!("settings profile '{}'", name)));
+    }
+    write_json_atomic(&active_profile_path(base), &ActiveProfileFile { active: Some(name.to_string()) })?;
+    read_settings_file(&path)
+}
+
+fn delete_profile_in(base: &Path, name: &str) -> Result<SettingsProfileList, StorageError> {
+    if name == DEFAULT_PROFILE {
+        return Err(StorageError::Other("The default profile cannot be deleted".to_string()));
+    }
+    if read_active_profile(base) == name {
+        return Err(StorageError::Other(format!(
+            "Profile '{}' is active; switch to another profile before deleting it",
+            name
+        )));
+    }
+    validate_profile_name(name)?;
+    let path = profile_settings_path(base, name);
+    if !path.exists() {
+        return Err(StorageError::StoreNotFound(format!("settings profile '{}'", name)));
+    }
+    fs::remove_file(&path)?;
+    list_profiles_in(base)
 }
 
 fn to_optional_non_empty(value: String) -> Option<String> {
@@ -110,19 +359,51 @@ fn derive_host_id(hostname: &str) -> Option<String> {
     Some(format!("host-{hash:016x}"))
 }
 
+/// Update channel persisted in the active settings profile
+pub(crate) fn persisted_update_channel(app: &AppHandle) -> UpdateChannel {
+    match get_settings_base_dir(app) {
+        Ok(base) => active_update_channel_in(&base),
+        Err(e) => {
+            log::warn!("Failed to resolve settings directory: {}", e);
+            UpdateChannel::default()
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn load_app_settings(app: AppHandle) -> Result<AppSettings, StorageError> {
-    let path = get_settings_path(&app)?;
-    if !path.exists() { return Ok(AppSettings::default()); }
-    Ok(serde_json::from_str(&fs::read_to_string(&path)?)?)
+    read_settings_file(&get_settings_path(&app)?)
 }
 
 #[tauri::command]
 pub async fn save_app_settings(app: AppHandle, settings: AppSettings) -> Result<(), StorageError> {
-    fs::write(&get_settings_path(&app)?, serde_json::to_string_pretty(&settings)?)?;
+    validate_shortcuts(&settings.shortcuts)?;
+    write_json_atomic(&get_settings_path(&app)?, &settings)?;
     Ok(())
 }
 
+#[tauri::command]
+pub async fn list_settings_profiles(app: AppHandle) -> Result<SettingsProfileList, StorageError> {
+    list_profiles_in(&get_settings_base_dir(&app)?)
+}
+
+#[tauri::command]
+pub async fn create_settings_profile(app: AppHandle, name: String) -> Result<SettingsProfileList, StorageError> {
+    create_profile_in(&get_settings_base_dir(&app)?, name.trim())
+}
+
+#[tauri::command]
+pub async fn switch_settings_profile(app: AppHandle, name: String) -> Result<AppSettings, StorageError> {
+    let settings = switch_profile_in(&get_settings_base_dir(&app)?, name.trim())?;
+    log::info!("Switched to settings profile '{}'", name.trim());
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn delete_settings_profile(app: AppHandle, name: String) -> Result<SettingsProfileList, StorageError> {
+    delete_profile_in(&get_settings_base_dir(&app)?, name.trim())
+}
+
 #[tauri::command]
 pub async fn save_window_state(app: AppHandle) -> Result<(), StorageError> {
     let window = app.get_webview_window("main")
@@ -144,12 +425,31 @@ pub async fn save_window_state(app: AppHandle) -> Result<(), StorageError> {
 
 #[tauri::command]
 pub async fn restore_window_state(app: AppHandle) -> Result<(), StorageError> {
-    let settings = load_app_settings(app.clone()).await?;
-    let state = settings.window_state;
+    let mut settings = load_app_settings(app.clone()).await?;
 
     let window = app.get_webview_window("main")
         .ok_or_else(|| StorageError::Io(std::io::Error::new(std::io::ErrorKind::NotFound, "Main window not found")))?;
 
+    let monitors: Vec<ScreenRect> = window.available_monitors().unwrap_or_default().iter()
+        .map(|m| ScreenRect { x: m.position().x, y: m.position().y, width: m.size().width, height: m.size().height })
+        .collect();
+    let primary = window.primary_monitor().ok().flatten().filter(|_| !monitors.is_empty());
+    if let Some(primary) = primary {
+        let area = primary.work_area();
+        let work_area = ScreenRect {
+            x: area.position.x, y: area.position.y, width: area.size.width, height: area.size.height,
+        };
+        if let Some(corrected) = sanitize_window_state(&settings.window_state, &monitors, work_area) {
+            log::warn!(
+                "Saved window position ({}, {}) is off-screen; moving to ({}, {})",
+                settings.window_state.x, settings.window_state.y, corrected.x, corrected.y
+            );
+            settings.window_state = corrected;
+            save_app_settings(app.clone(), settings.clone()).await?;
+        }
+    }
+    let state = settings.window_state;
+
     let _ = window.set_size(PhysicalSize::new(state.width, state.height));
     let _ = window.set_position(PhysicalPosition::new(state.x, state.y));
     if state.fullscreen { let _ = window.set_fullscreen(true); }
@@ -157,6 +457,20 @@ pub async fn restore_window_state(app: AppHandle) -> Result<(), StorageError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn get_shortcuts(app: AppHandle) -> Result<Shortcuts, StorageError> {
+    Ok(load_app_settings(app).await?.shortcuts)
+}
+
+#[tauri::command]
+pub async fn set_shortcut(app: AppHandle, action: String, accelerator: String) -> Result<Shortcuts, StorageError> {
+    let mut settings = load_app_settings(app.clone()).await?;
+    apply_shortcut(&mut settings.shortcuts, &action, &accelerator)?;
+    let shortcuts = settings.shortcuts.clone();
+    save_app_settings(app, settings).await?;
+    Ok(shortcuts)
+}
+
 #[tauri::command]
 pub async fn add_recent_file(app: AppHandle, path: String, file_type: String) -> Result<(), StorageError> {
     let mut settings = load_app_settings(app.clone()).await?;
@@ -288,6 +602,57 @@ mod tests {
         assert_eq!(cloned.height, state.height);
     }
 
+    // ------------------------------------------------------------------------
+    // Window Sanitization Tests
+    // ------------------------------------------------------------------------
+
+    const PRIMARY: ScreenRect = ScreenRect { x: 0, y: 0, width: 1920, height: 1080 };
+    const PRIMARY_WORK_AREA: ScreenRect = ScreenRect { x: 0, y: 0, width: 1920, height: 1040 };
+
+    #[test]
+    fn test_sanitize_window_state_outside_all_monitors() {
+        // Window was on a second monitor to the right that has been unplugged
+        let state = WindowState { width: 1600, height: 900, x: 2200, y: 150, ..WindowState::default() };
+        let fixed = sanitize_window_state(&state, &[PRIMARY], PRIMARY_WORK_AREA).unwrap();
+
+        assert_eq!((fixed.width, fixed.height), (1600, 900));
+        assert_eq!((fixed.x, fixed.y), (320, 140));
+        assert!(fixed.rect().intersects(&PRIMARY));
+    }
+
+    #[test]
+    fn test_sanitize_window_state_shrinks_oversized_window() {
+        let state = WindowState { width: 3840, height: 2160, x: -5000, y: -5000, ..WindowState::default() };
+        let fixed = sanitize_window_state(&state, &[PRIMARY], PRIMARY_WORK_AREA).unwrap();
+
+        assert_eq!((fixed.width, fixed.height), (1920, 1040));
+        assert_eq!((fixed.x, fixed.y), (0, 0));
+    }
+
+    #[test]
+    fn test_sanitize_window_state_zero_size_work_area() {
+        let work_area = ScreenRect { x: 10, y: 20, width: 0, height: 0 };
+        let state = WindowState { width: 1280, height: 800, x: 5000, y: 5000, ..WindowState::default() };
+        let fixed = sanitize_window_state(&state, &[PRIMARY], work_area).unwrap();
+
+        assert_eq!((fixed.width, fixed.height), (1, 1));
+        assert_eq!((fixed.x, fixed.y), (10, 20));
+    }
+
+    #[test]
+    fn test_sanitize_window_state_partial_overlap_is_kept() {
+        // Hanging off the right edge but still partly visible
+        let state = WindowState { width: 1280, height: 800, x: 1500, y: 600, ..WindowState::default() };
+        assert!(sanitize_window_state(&state, &[PRIMARY], PRIMARY_WORK_AREA).is_none());
+    }
+
+    #[test]
+    fn test_sanitize_window_state_on_secondary_monitor() {
+        let secondary = ScreenRect { x: -2560, y: 0, width: 2560, height: 1440 };
+        let state = WindowState { width: 1280, height: 800, x: -2000, y: 100, ..WindowState::default() };
+        assert!(sanitize_window_state(&state, &[PRIMARY, secondary], PRIMARY_WORK_AREA).is_none());
+    }
+
     // ------------------------------------------------------------------------
     // RecentFile Tests
     // ------------------------------------------------------------------------
@@ -359,6 +724,8 @@ mod tests {
             sidebar_collapsed: true,
             show_welcome: false,
             language: "zh".to_string(),
+            shortcuts: default_shortcuts(),
+            update_channel: UpdateChannel::Beta,
         };
 
         let json = serde_json::to_string(&settings).unwrap();
@@ -366,6 +733,7 @@ mod tests {
         assert!(json.contains("recent_files"));
         assert!(json.contains("dark"));
         assert!(json.contains("zh"));
+        assert!(json.contains("\"update_channel\":\"beta\""));
     }
 
     #[test]
@@ -391,6 +759,165 @@ mod tests {
         assert_eq!(settings.theme, "light");
     }
 
+    #[test]
+    fn test_app_settings_deserialization_defaults_shortcuts() {
+        let json = r#"{
+            "window_state": {"width": 1280, "height": 800, "x": 0, "y": 0, "maximized": false, "fullscreen": false},
+            "recent_files": [], "last_export_dir": null, "last_import_dir": null,
+            "auto_save_interval": 300, "check_updates": true, "telemetry_enabled": false,
+            "theme": "system", "sidebar_collapsed": false, "show_welcome": true, "language": "en"
+        }"#;
+
+        let settings: AppSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(settings.shortcuts, default_shortcuts());
+    }
+
+    // ------------------------------------------------------------------------
+    // Shortcut Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_default_shortcuts_are_valid() {
+        assert!(validate_shortcuts(&default_shortcuts()).is_ok());
+    }
+
+    #[test]
+    fn test_set_shortcut_rejects_duplicate_accelerator() {
+        let mut shortcuts = default_shortcuts();
+        assert!(apply_shortcut(&mut shortcuts, "slew_to_active", "Alt+Shift+S").is_ok());
+
+        // Same key combination under a different spelling is still a duplicate
+        assert!(apply_shortcut(&mut shortcuts, "search", "shift+alt+s").is_err());
+        assert!(apply_shortcut(&mut shortcuts, "search", "CmdOrCtrl+Shift+P").is_err());
+        assert_eq!(shortcuts["search"], "CmdOrCtrl+F");
+    }
+
+    #[test]
+    fn test_set_shortcut_rejects_invalid_accelerator_and_action() {
+        let mut shortcuts = default_shortcuts();
+        assert!(apply_shortcut(&mut shortcuts, "open_solver", "Ctrl+Shift+NotAKey").is_err());
+        assert!(apply_shortcut(&mut shortcuts, "open_solver", "Ctrl++").is_err());
+        assert!(apply_shortcut(&mut shortcuts, "make_coffee", "Ctrl+K").is_err());
+        assert_eq!(shortcuts, default_shortcuts());
+    }
+
+    #[test]
+    fn test_set_shortcut_remap_persists_through_save_load() {
+        let mut settings = AppSettings::default();
+        apply_shortcut(&mut settings.shortcuts, "slew_to_active", "Alt+Shift+S").unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "skymap_settings_{}.json",
+            crate::utils::generate_id("shortcuts")
+        ));
+        write_json_atomic(&path, &settings).unwrap();
+        let loaded: AppSettings = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded.shortcuts["slew_to_active"], "Alt+Shift+S");
+        assert_eq!(loaded.shortcuts["open_solver"], "CmdOrCtrl+Shift+P");
+        assert!(validate_shortcuts(&loaded.shortcuts).is_ok());
+    }
+
+    // ------------------------------------------------------------------------
+    // Settings Profile Tests
+    // ------------------------------------------------------------------------
+
+    fn profile_test_base() -> PathBuf {
+        let base = std::env::temp_dir().join(format!(
+            "skymap_profiles_{}",
+            crate::utils::generate_id("t")
+        ));
+        fs::create_dir_all(&base).unwrap();
+        base
+    }
+
+    fn save_active(base: &Path, settings: &AppSettings) {
+        write_json_atomic(&profile_settings_path(base, &read_active_profile(base)), settings).unwrap();
+    }
+
+    fn load_active(base: &Path) -> AppSettings {
+        read_settings_file(&profile_settings_path(base, &read_active_profile(base))).unwrap()
+    }
+
+    #[test]
+    fn test_active_update_channel_follows_profile() {
+        let base = profile_test_base();
+        assert_eq!(active_update_channel_in(&base), UpdateChannel::default());
+
+        let settings = AppSettings { update_channel: UpdateChannel::Beta, ..AppSettings::default() };
+        save_active(&base, &settings);
+        assert_eq!(active_update_channel_in(&base), UpdateChannel::Beta);
+
+        create_profile_in(&base, "stable_site").unwrap();
+        switch_profile_in(&base, "stable_site").unwrap();
+        save_active(&base, &AppSettings::default());
+        assert_eq!(active_update_channel_in(&base), UpdateChannel::Stable);
+
+        switch_profile_in(&base, DEFAULT_PROFILE).unwrap();
+        assert_eq!(active_update_channel_in(&base), UpdateChannel::Beta);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_settings_profiles_are_isolated() {
+        let base = profile_test_base();
+        assert_eq!(list_profiles_in(&base).unwrap().active, DEFAULT_PROFILE);
+
+        create_profile_in(&base, "home").unwrap();
+        let list = create_profile_in(&base, "remote").unwrap();
+        assert_eq!(list.profiles, vec!["default", "home", "remote"]);
+
+        switch_profile_in(&base, "home").unwrap();
+        let mut home = load_active(&base);
+        home.theme = "dark".to_string();
+        save_active(&base, &home);
+
+        let remote = switch_profile_in(&base, "remote").unwrap();
+        assert_eq!(remote.theme, "system");
+        let mut remote = load_active(&base);
+        remote.language = "zh".to_string();
+        save_active(&base, &remote);
+
+        let home = switch_profile_in(&base, "home").unwrap();
+        assert_eq!(home.theme, "dark");
+        assert_eq!(home.language, "en");
+        assert_eq!(list_profiles_in(&base).unwrap().active, "home");
+
+        let default = switch_profile_in(&base, DEFAULT_PROFILE).unwrap();
+        assert_eq!(default.theme, "system");
+
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_settings_profile_delete_rules() {
+        let base = profile_test_base();
+        create_profile_in(&base, "field").unwrap();
+        switch_profile_in(&base, "field").unwrap();
+
+        assert!(delete_profile_in(&base, "field").is_err());
+        assert!(delete_profile_in(&base, DEFAULT_PROFILE).is_err());
+
+        switch_profile_in(&base, DEFAULT_PROFILE).unwrap();
+        let list = delete_profile_in(&base, "field").unwrap();
+        assert_eq!(list.profiles, vec!["default"]);
+        assert!(switch_profile_in(&base, "field").is_err());
+
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_settings_profile_name_validation() {
+        let base = profile_test_base();
+        assert!(create_profile_in(&base, "").is_err());
+        assert!(create_profile_in(&base, "../escape").is_err());
+        assert!(create_profile_in(&base, DEFAULT_PROFILE).is_err());
+        create_profile_in(&base, "site_2").unwrap();
+        assert!(create_profile_in(&base, "site_2").is_err());
+        let _ = fs::remove_dir_all(&base);
+    }
+
     #[test]
     fn test_app_settings_clone() {
         let settings = AppSettings::default();
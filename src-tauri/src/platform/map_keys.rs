@@ -6,12 +6,31 @@ use keyring::Entry;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
 
-use crate::data::StorageError;
+use crate::data::{write_json_atomic, StorageError};
 
 const KEYRING_SERVICE: &str = "com.skymap.desktop.mapkeys";
 
+/// Usage fraction at which an approaching-quota warning is raised
+const QUOTA_WARNING_RATIO: f64 = 0.8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MapApiKeyQuotaWarning {
+    Approaching,
+    Exceeded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MapApiKeyQuotaPeriod {
+    Daily,
+    Monthly,
+}
+
+/// Usage limits of a key. `used` counts uses since the last reset against
+/// `monthly`; `used_today` counts uses on `usage_day` (UTC) against `daily`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MapApiKeyQuota {
@@ -19,6 +38,26 @@ pub struct MapApiKeyQuota {
     pub monthly: Option<u64>,
     pub used: Option<u64>,
     pub reset_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<MapApiKeyQuotaWarning>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub used_today: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_day: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_warning: Option<MapApiKeyQuotaWarning>,
+}
+
+/// Payload of the `map-key-quota-warning` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapApiKeyQuotaEvent {
+    pub key_id: String,
+    pub provider: String,
+    pub period: MapApiKeyQuotaPeriod,
+    pub warning: MapApiKeyQuotaWarning,
+    pub used: u64,
+    pub limit: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,11 +109,123 @@ fn load_meta(app: &AppHandle) -> Result<Vec<MapApiKeyMeta>, StorageError> {
 
 fn save_meta(app: &AppHandle, metas: &[MapApiKeyMeta]) -> Result<(), StorageError> {
     let path = get_meta_path(app)?;
-    let content = serde_json::to_string_pretty(metas)?;
-    fs::write(path, content)?;
+    write_json_atomic(&path, metas)?;
     Ok(())
 }
 
+fn quota_warning_for(used: u64, limit: u64) -> Option<MapApiKeyQuotaWarning> {
+    if limit == 0 || used >= limit {
+        Some(MapApiKeyQuotaWarning::Exceeded)
+    } else if used as f64 >= limit as f64 * QUOTA_WARNING_RATIO {
+        Some(MapApiKeyQuotaWarning::Approaching)
+    } else {
+        None
+    }
+}
+
+/// Warning level `used` has newly reached against `limit`, recorded in
+/// `previous` so each threshold is reported once
+fn crossed_quota_threshold(
+    used: u64,
+    limit: Option<u64>,
+    previous: &mut Option<MapApiKeyQuotaWarning>,
+) -> Option<MapApiKeyQuotaWarning> {
+    let warning = quota_warning_for(used, limit?)?;
+    if previous.is_some_and(|previous| previous >= warning) {
+        return None;
+    }
+    *previous = Some(warning);
+    Some(warning)
+}
+
+/// Count one use of a key against its daily and monthly limits. Returns an
+/// event for each limit whose threshold this use newly crosses, so each
+/// threshold is reported once per quota period.
+fn record_key_use(meta: &mut MapApiKeyMeta) -> Vec<MapApiKeyQuotaEvent> {
+    let now = Utc::now();
+    meta.last_used = Some(now.to_rfc3339());
+    let Some(quota) = meta.quota.as_mut() else {
+        return Vec::new();
+    };
+
+    let today = now.format("%Y-%m-%d").to_string();
+    if quota.usage_day.as_deref() != Some(today.as_str()) {
+        quota.usage_day = Some(today);
+        quota.used_today = Some(0);
+        quota.daily_warning = None;
+    }
+    let used = quota.used.unwrap_or(0).saturating_add(1);
+    let used_today = quota.used_today.unwrap_or(0).saturating_add(1);
+    quota.used = Some(used);
+    quota.used_today = Some(used_today);
+
+    let counters = [
+        (MapApiKeyQuotaPeriod::Daily, used_today, quota.daily, &mut quota.daily_warning),
+        (MapApiKeyQuotaPeriod::Monthly, used, quota.monthly, &mut quota.warning),
+    ];
+    counters
+        .into_iter()
+        .filter_map(|(period, used, limit, previous)| {
+            let warning = crossed_quota_threshold(used, limit, previous)?;
+            Some(MapApiKeyQuotaEvent {
+                key_id: meta.id.clone(),
+                provider: meta.provider.clone(),
+                period,
+                warning,
+                used,
+                limit: limit?,
+            })
+        })
+        .collect()
+}
+
+fn reset_key_usage(meta: &mut MapApiKeyMeta) {
+    if let Some(quota) = meta.quota.as_mut() {
+        quota.used = Some(0);
+        quota.warning = None;
+        quota.used_today = Some(0);
+        quota.daily_warning = None;
+        quota.reset_date = Some(Utc::now().to_rfc3339());
+    }
+}
+
+/// Match a host against an allowed domain; `*.example.com` covers subdomains
+/// only and a bare `*` allows any host
+fn domain_matches(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.trim().trim_end_matches('.').to_lowercase();
+    if pattern == "*" {
+        return true;
+    }
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => host.len() > suffix.len() + 1 && host.ends_with(&format!(".{suffix}")),
+        None => host == pattern,
+    }
+}
+
+/// Whether a key with these restrictions may be sent to `url`.
+/// Keys without a domain list are unrestricted.
+fn is_request_allowed(restrictions: Option<&MapApiKeyRestrictions>, url: &str) -> bool {
+    let Some(host) = url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.trim_end_matches('.').to_lowercase()))
+    else {
+        return false;
+    };
+    match restrictions.and_then(|r| r.domains.as_ref()) {
+        Some(domains) if !domains.is_empty() => {
+            domains.iter().any(|pattern| domain_matches(pattern, &host))
+        }
+        _ => true,
+    }
+}
+
+/// Whether a key is limited to a non-empty domain list
+fn has_domain_restrictions(restrictions: Option<&MapApiKeyRestrictions>) -> bool {
+    restrictions
+        .and_then(|r| r.domains.as_ref())
+        .is_some_and(|domains| !domains.is_empty())
+}
+
 fn entry_for_key(key_id: &str) -> Result<Entry, StorageError> {
     Entry::new(KEYRING_SERVICE, key_id)
         .map_err(|e| StorageError::Other(format!("Failed to create keyring entry: {e}")))
@@ -119,7 +270,33 @@ pub async fn list_map_api_keys_meta(app: AppHandle) -> Result<Vec<MapApiKeyMeta>
 }
 
 #[tauri::command]
-pub async fn get_map_api_key(key_id: String) -> Result<Option<String>, StorageError> {
+pub async fn get_map_api_key(
+    app: AppHandle,
+    key_id: String,
+    request_url: Option<String>,
+) -> Result<Option<String>, StorageError> {
+    match request_url {
+        Some(url) => {
+            if !validate_map_api_key_request(app, key_id.clone(), url.clone()).await? {
+                return Err(StorageError::Other(format!(
+                    "Key {key_id} is not allowed for {url}"
+                )));
+            }
+        }
+        None => {
+            let metas = load_meta(&app)?;
+            let restricted = metas
+                .iter()
+                .find(|m| m.id == key_id)
+                .is_some_and(|m| has_domain_restrictions(m.restrictions.as_ref()));
+            if restricted {
+                return Err(StorageError::Other(format!(
+                    "Key {key_id} is restricted to specific domains; a request URL is required"
+                )));
+            }
+        }
+    }
+
     let entry = entry_for_key(&key_id)?;
     match entry.get_password() {
         Ok(password) => Ok(Some(password)),
@@ -201,3 +378,241 @@ pub async fn set_active_map_api_key(
     save_meta(&app, &metas)
 }
 
+/// Check a request URL against the key's domain restrictions
+#[tauri::command]
+pub async fn validate_map_api_key_request(
+    app: AppHandle,
+    key_id: String,
+    url: String,
+) -> Result<bool, StorageError> {
+    let metas = load_meta(&app)?;
+    let Some(meta) = metas.iter().find(|m| m.id == key_id) else {
+        return Ok(false);
+    };
+    let allowed = is_request_allowed(meta.restrictions.as_ref(), &url);
+    if !allowed {
+        log::warn!("Blocked map API key {} for request to {}", key_id, url);
+    }
+    Ok(allowed)
+}
+
+#[tauri::command]
+pub async fn record_map_api_key_use(
+    app: AppHandle,
+    key_id: String,
+) -> Result<MapApiKeyMeta, StorageError> {
+    let mut metas = load_meta(&app)?;
+    let meta = metas
+        .iter_mut()
+        .find(|m| m.id == key_id)
+        .ok_or_else(|| StorageError::Other(format!("Key {key_id} not found")))?;
+
+    let events = record_key_use(meta);
+    let updated = meta.clone();
+    save_meta(&app, &metas)?;
+
+    for event in events {
+        log::warn!(
+            "Map API key {} {:?} quota {:?}: {}/{}",
+            event.key_id,
+            event.period,
+            event.warning,
+            event.used,
+            event.limit
+        );
+        let _ = app.emit("map-key-quota-warning", event);
+    }
+    Ok(updated)
+}
+
+#[tauri::command]
+pub async fn reset_map_api_key_usage(
+    app: AppHandle,
+    key_id: String,
+) -> Result<MapApiKeyMeta, StorageError> {
+    let mut metas = load_meta(&app)?;
+    let meta = metas
+        .iter_mut()
+        .find(|m| m.id == key_id)
+        .ok_or_else(|| StorageError::Other(format!("Key {key_id} not found")))?;
+
+    reset_key_usage(meta);
+    let updated = meta.clone();
+    save_meta(&app, &metas)?;
+    Ok(updated)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta_with_quota(monthly: Option<u64>, used: u64) -> MapApiKeyMeta {
+        MapApiKeyMeta {
+            id: "key-1".to_string(),
+            provider: "mapbox".to_string(),
+            label: None,
+            is_default: Some(true),
+            is_active: Some(true),
+            quota: Some(MapApiKeyQuota {
+                daily: None,
+                monthly,
+                used: Some(used),
+                reset_date: None,
+                warning: None,
+                used_today: None,
+                usage_day: None,
+                daily_warning: None,
+            }),
+            restrictions: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            last_used: None,
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Domain Restriction Tests
+    // ------------------------------------------------------------------------
+
+    fn restricted_to(domains: &[&str]) -> MapApiKeyRestrictions {
+        MapApiKeyRestrictions {
+            domains: Some(domains.iter().map(|d| d.to_string()).collect()),
+            ips: None,
+            regions: None,
+        }
+    }
+
+    #[test]
+    fn test_request_to_allowed_host() {
+        let restrictions = restricted_to(&["api.mapbox.com"]);
+        assert!(is_request_allowed(
+            Some(&restrictions),
+            "https://api.mapbox.com/styles/v1/tiles/1/2/3?access_token=x"
+        ));
+        assert!(is_request_allowed(Some(&restrictions), "https://API.Mapbox.com/"));
+    }
+
+    #[test]
+    fn test_request_wildcard_match() {
+        let restrictions = restricted_to(&["*.tile.openstreetmap.org"]);
+        assert!(is_request_allowed(Some(&restrictions), "https://a.tile.openstreetmap.org/1/2/3.png"));
+        assert!(!is_request_allowed(Some(&restrictions), "https://tile.openstreetmap.org/1/2/3.png"));
+        assert!(!is_request_allowed(Some(&restrictions), "https://eviltile.openstreetmap.org/"));
+    }
+
+    #[test]
+    fn test_request_to_disallowed_host() {
+        let restrictions = restricted_to(&["api.mapbox.com"]);
+        assert!(!is_request_allowed(Some(&restrictions), "https://maps.googleapis.com/maps/api/js"));
+        assert!(!is_request_allowed(Some(&restrictions), "https://api.mapbox.com.evil.net/"));
+        assert!(!is_request_allowed(Some(&restrictions), "not a url"));
+    }
+
+    #[test]
+    fn test_request_without_domain_restrictions() {
+        assert!(is_request_allowed(None, "https://maps.googleapis.com/"));
+        assert!(is_request_allowed(Some(&restricted_to(&[])), "https://maps.googleapis.com/"));
+    }
+
+    #[test]
+    fn test_domain_restrictions_detected() {
+        assert!(has_domain_restrictions(Some(&restricted_to(&["api.mapbox.com"]))));
+        assert!(!has_domain_restrictions(Some(&restricted_to(&[]))));
+        assert!(!has_domain_restrictions(None));
+    }
+
+    // ------------------------------------------------------------------------
+    // Usage Tracking Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_record_use_increments_counter() {
+        let mut meta = meta_with_quota(Some(100), 0);
+        assert!(record_key_use(&mut meta).is_empty());
+        assert!(record_key_use(&mut meta).is_empty());
+        let quota = meta.quota.as_ref().unwrap();
+        assert_eq!(quota.used, Some(2));
+        assert_eq!(quota.used_today, Some(2));
+        assert!(meta.last_used.is_some());
+    }
+
+    #[test]
+    fn test_record_use_without_quota_only_touches_last_used() {
+        let mut meta = meta_with_quota(None, 0);
+        meta.quota = None;
+        assert!(record_key_use(&mut meta).is_empty());
+        assert!(meta.quota.is_none());
+        assert!(meta.last_used.is_some());
+    }
+
+    #[test]
+    fn test_crossing_thresholds_flags_warning_once() {
+        let mut meta = meta_with_quota(Some(10), 7);
+
+        let events = record_key_use(&mut meta);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].period, MapApiKeyQuotaPeriod::Monthly);
+        assert_eq!(events[0].warning, MapApiKeyQuotaWarning::Approaching);
+        assert_eq!(events[0].used, 8);
+        assert_eq!(meta.quota.as_ref().unwrap().warning, Some(MapApiKeyQuotaWarning::Approaching));
+
+        // Still approaching: no repeated event
+        assert!(record_key_use(&mut meta).is_empty());
+
+        let events = record_key_use(&mut meta);
+        assert_eq!(events[0].warning, MapApiKeyQuotaWarning::Exceeded);
+        assert_eq!(events[0].limit, 10);
+        assert!(record_key_use(&mut meta).is_empty());
+    }
+
+    #[test]
+    fn test_daily_limit_checks_daily_counter() {
+        // Plenty of earlier monthly usage must not count against today's limit
+        let mut meta = meta_with_quota(None, 500);
+        meta.quota.as_mut().unwrap().daily = Some(10);
+        for _ in 0..7 {
+            assert!(record_key_use(&mut meta).is_empty());
+        }
+
+        let events = record_key_use(&mut meta);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].period, MapApiKeyQuotaPeriod::Daily);
+        assert_eq!(events[0].warning, MapApiKeyQuotaWarning::Approaching);
+        assert_eq!(events[0].used, 8);
+        assert_eq!(events[0].limit, 10);
+    }
+
+    #[test]
+    fn test_new_day_restarts_daily_counter() {
+        let mut meta = meta_with_quota(Some(1000), 0);
+        {
+            let quota = meta.quota.as_mut().unwrap();
+            quota.daily = Some(10);
+            quota.used_today = Some(10);
+            quota.usage_day = Some("2000-01-01".to_string());
+            quota.daily_warning = Some(MapApiKeyQuotaWarning::Exceeded);
+        }
+
+        assert!(record_key_use(&mut meta).is_empty());
+        let quota = meta.quota.as_ref().unwrap();
+        assert_eq!(quota.used_today, Some(1));
+        assert!(quota.daily_warning.is_none());
+        assert_eq!(quota.used, Some(1));
+    }
+
+    #[test]
+    fn test_reset_usage_clears_counter_and_warning() {
+        let mut meta = meta_with_quota(Some(10), 9);
+        record_key_use(&mut meta);
+        reset_key_usage(&mut meta);
+
+        let quota = meta.quota.as_ref().unwrap();
+        assert_eq!(quota.used, Some(0));
+        assert_eq!(quota.used_today, Some(0));
+        assert!(quota.warning.is_none());
+        assert!(quota.reset_date.is_some());
+    }
+}
@@ -7,6 +7,7 @@
 //! - `cli`: Desktop CLI bridge helpers and commands
 //! - `updater`: Application update checking and installation
 //! - `plate_solver`: Astronomical plate solving integration
+//! - `operations`: Cancel-all across requests, solves and prefetches
 
 pub mod app_settings;
 pub mod app_control;
@@ -16,11 +17,14 @@ pub mod plate_solver;
 pub mod path_config;
 pub mod map_keys;
 pub mod secret_bootstrap;
+pub mod operations;
 
 pub use app_settings::{
-    AppSettings, RecentFile, SystemInfo, WindowState,
-    add_recent_file, clear_recent_files, get_system_info, load_app_settings,
-    open_path, restore_window_state, reveal_in_file_manager, save_app_settings, save_window_state,
+    AppSettings, RecentFile, ScreenRect, SettingsProfileList, Shortcuts, SystemInfo, WindowState,
+    add_recent_file, clear_recent_files, create_settings_profile, delete_settings_profile,
+    get_shortcuts, get_system_info, list_settings_profiles, load_app_settings, open_path,
+    restore_window_state, reveal_in_file_manager, save_app_settings, save_window_state,
+    set_shortcut, switch_settings_profile,
 };
 
 pub use app_control::{
@@ -34,39 +38,45 @@ pub use cli::{
 };
 
 pub use updater::{
-    UpdateInfo, UpdateProgress, UpdateStatus, UpdaterError,
+    PendingUpdateState, RollbackPoint, UpdateChannel, UpdateInfo, UpdateProgress, UpdateStatus,
+    UpdaterError,
     check_for_update, clear_pending_update, download_and_install_update, download_update,
-    get_current_version, has_pending_update, install_update,
+    create_update_rollback_point, get_current_version, has_pending_update, install_update,
+    rollback_update,
 };
 
 pub use path_config::{
     DirectoryValidation, MigrationResult, PathConfig, PathInfo,
-    get_path_config, migrate_cache_dir, migrate_data_dir, reset_paths_to_default,
+    available_disk_space, get_path_config, migrate_cache_dir, migrate_data_dir, reset_paths_to_default,
     set_custom_cache_dir, set_custom_data_dir, validate_directory,
 };
 
 pub use map_keys::{
-    MapApiKeyMeta, MapApiKeyQuota, MapApiKeyRecord, MapApiKeyRestrictions,
-    delete_map_api_key, get_map_api_key, list_map_api_keys_meta, save_map_api_key,
-    set_active_map_api_key,
+    MapApiKeyMeta, MapApiKeyQuota, MapApiKeyQuotaEvent, MapApiKeyQuotaPeriod, MapApiKeyQuotaWarning,
+    MapApiKeyRecord, MapApiKeyRestrictions,
+    delete_map_api_key, get_map_api_key, list_map_api_keys_meta, record_map_api_key_use,
+    reset_map_api_key_usage, save_map_api_key, set_active_map_api_key,
+    validate_map_api_key_request,
 };
 
+pub use operations::{ALL_OPERATIONS_CANCELLED_EVENT, CancelAllSummary, cancel_all_operations};
+
 pub use secret_bootstrap::{
     SecretVaultBootstrap,
     get_or_create_secret_vault_bootstrap,
 };
 
 pub use plate_solver::{
-    AstapDatabaseInfo, AstrometryIndex, DownloadableIndex, DownloadableIndexFull,
+    AstapDatabaseInfo, AstrometryIndex, DownloadableIndex, DownloadableIndexFull, FocusMetrics,
     ImageAnalysisResult, IndexDownloadProgress, IndexInfo, OnlineAnnotation,
     OnlineSolveConfig, OnlineSolveProgress, OnlineSolveResult,
     PlateSolveResult, PlateSolverConfig, PlateSolverError, PlateSolverType, ScaleRange,
-    SipCoefficients, SolveParameters, SolveResult, SolverConfig, SolverInfo,
+    QuadrantHfd, SipCoefficients, SolveParameters, SolveResult, SolverConfig, SolverInfo,
     StarDetection, WcsResult,
-    analyse_image, delete_index, detect_plate_solvers, download_index, extract_stars,
+    analyse_image, calculate_focus_metrics, delete_index, detect_plate_solvers, download_index, extract_stars,
     get_astap_databases, get_available_indexes, get_default_index_path,
     get_downloadable_indexes, get_installed_indexes, get_recommended_indexes,
-    cancel_online_solve, cancel_plate_solve, get_solver_indexes, get_solver_info, load_solver_config, plate_solve,
+    cancel_online_solve, cancel_plate_solve, clear_astrometry_session, get_solver_indexes, get_solver_info, load_solver_config, plate_solve,
     recommend_astap_database, save_solver_config, solve_image_local, solve_online,
-    validate_solver_path,
+    validate_solver_path, write_wcs_file,
 };
@@ -0,0 +1,139 @@
+//! Coordinated cancellation: stops HTTP requests, local and online plate
+//! solves, and cache prefetches with a single command
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+/// Event emitted after every subsystem has been asked to stop
+pub const ALL_OPERATIONS_CANCELLED_EVENT: &str = "all-operations-cancelled";
+
+/// What `cancel_all_operations` stopped
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CancelAllSummary {
+    pub http_requests: usize,
+    pub solver_killed: bool,
+    pub online_solves: usize,
+    pub prefetches: usize,
+}
+
+/// Subsystems with long-running work that can be cancelled
+trait CancellableSubsystems {
+    fn cancel_http_requests(&self) -> usize;
+    fn kill_solver(&self) -> bool;
+    fn cancel_online_solves(&self) -> usize;
+    fn abort_prefetches(&self) -> usize;
+}
+
+struct LiveSubsystems;
+
+impl CancellableSubsystems for LiveSubsystems {
+    fn cancel_http_requests(&self) -> usize {
+        crate::network::http_client::cancel_all_requests()
+    }
+
+    fn kill_solver(&self) -> bool {
+        super::plate_solver::kill_active_solver()
+    }
+
+    fn cancel_online_solves(&self) -> usize {
+        super::plate_solver::cancel_all_online_solves()
+    }
+
+    fn abort_prefetches(&self) -> usize {
+        crate::cache::unified::abort_prefetches()
+    }
+}
+
+fn cancel_all(subsystems: &impl CancellableSubsystems) -> CancelAllSummary {
+    CancelAllSummary {
+        http_requests: subsystems.cancel_http_requests(),
+        solver_killed: subsystems.kill_solver(),
+        online_solves: subsystems.cancel_online_solves(),
+        prefetches: subsystems.abort_prefetches(),
+    }
+}
+
+/// Cancel every request, plate solve and prefetch in progress
+#[tauri::command]
+pub async fn cancel_all_operations(app: AppHandle) -> CancelAllSummary {
+    let summary = cancel_all(&LiveSubsystems);
+    log::info!("Cancelled all operations: {:?}", summary);
+    let _ = app.emit(ALL_OPERATIONS_CANCELLED_EVENT, &summary);
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Default)]
+    struct StubSubsystems {
+        http_calls: Cell<usize>,
+        solver_calls: Cell<usize>,
+        online_calls: Cell<usize>,
+        prefetch_calls: Cell<usize>,
+    }
+
+    fn bump(counter: &Cell<usize>) {
+        counter.set(counter.get() + 1);
+    }
+
+    impl CancellableSubsystems for StubSubsystems {
+        fn cancel_http_requests(&self) -> usize {
+            bump(&self.http_calls);
+            3
+        }
+
+        fn kill_solver(&self) -> bool {
+            bump(&self.solver_calls);
+            true
+        }
+
+        fn cancel_online_solves(&self) -> usize {
+            bump(&self.online_calls);
+            1
+        }
+
+        fn abort_prefetches(&self) -> usize {
+            bump(&self.prefetch_calls);
+            4
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // cancel_all Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_cancel_all_invokes_each_subsystem_once() {
+        let stub = StubSubsystems::default();
+        cancel_all(&stub);
+
+        assert_eq!(stub.http_calls.get(), 1);
+        assert_eq!(stub.solver_calls.get(), 1);
+        assert_eq!(stub.online_calls.get(), 1);
+        assert_eq!(stub.prefetch_calls.get(), 1);
+    }
+
+    #[test]
+    fn test_cancel_all_reports_each_subsystem() {
+        let summary = cancel_all(&StubSubsystems::default());
+        assert_eq!(
+            summary,
+            CancelAllSummary {
+                http_requests: 3,
+                solver_killed: true,
+                online_solves: 1,
+                prefetches: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_serialization() {
+        let json = serde_json::to_value(CancelAllSummary::default()).unwrap();
+        assert_eq!(json["solver_killed"], false);
+        assert_eq!(json["prefetches"], 0);
+    }
+}
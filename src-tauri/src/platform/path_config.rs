@@ -4,11 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
 use tauri::{AppHandle, Manager};
 
-use crate::data::StorageError;
+use crate::data::{write_json_atomic, StorageError};
 
 // ============================================================================
 // Types
@@ -58,6 +58,9 @@ pub struct DirectoryValidation {
     pub exists: bool,
     pub writable: bool,
     pub available_bytes: Option<u64>,
+    /// Bytes the pending operation needs, when known
+    #[serde(default)]
+    pub required_bytes: Option<u64>,
     pub error: Option<String>,
 }
 
@@ -67,9 +70,34 @@ pub struct MigrationResult {
     pub success: bool,
     pub files_copied: usize,
     pub bytes_copied: u64,
+    /// Files already present from an interrupted run and left as-is
+    #[serde(default)]
+    pub files_skipped: usize,
     pub error: Option<String>,
 }
 
+/// Files and directories that make up the data directory
+const DATA_DIR_ENTRIES: &[&str] = &[
+    "stores",
+    "session",
+    "settings_profiles",
+    "app_settings.json",
+    "active_settings_profile.json",
+    "solver_config.json",
+    "map_keys_meta.json",
+    "secret-vault.hold",
+    "rate_limits.json",
+    "domain_rate_limits.json",
+];
+
+/// Counts gathered while staging a migration
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct StagedCopy {
+    files_copied: usize,
+    files_skipped: usize,
+    bytes: u64,
+}
+
 // ============================================================================
 // Global Config Cache
 // ============================================================================
@@ -123,8 +151,7 @@ fn load_config_from_disk(app: &AppHandle) -> Result<PathConfig, StorageError> {
 /// Save path config to disk
 fn save_config_to_disk(app: &AppHandle, config: &PathConfig) -> Result<(), StorageError> {
     let path = get_config_file_path(app)?;
-    let json = serde_json::to_string_pretty(config)?;
-    fs::write(&path, json)?;
+    write_json_atomic(&path, config)?;
     Ok(())
 }
 
@@ -155,9 +182,22 @@ fn update_config(app: &AppHandle, config: PathConfig) -> Result<(), StorageError
     Ok(())
 }
 
+/// Find the path itself or its closest ancestor that exists on disk
+fn nearest_existing_ancestor(path: &Path) -> Option<PathBuf> {
+    let mut current = Some(path);
+    while let Some(dir) = current {
+        if dir.exists() {
+            return Some(dir.to_path_buf());
+        }
+        current = dir.parent();
+    }
+    None
+}
+
 /// Validate a directory path without creating it (read-only check).
 /// Checks the parent directory for writability if the path doesn't exist yet.
-fn validate_dir(path: &str) -> DirectoryValidation {
+/// When `required_bytes` is given, the directory must also have that much free space.
+fn validate_dir(path: &str, required_bytes: Option<u64>) -> DirectoryValidation {
     let p = PathBuf::from(path);
 
     if path.is_empty() {
@@ -166,6 +206,7 @@ fn validate_dir(path: &str) -> DirectoryValidation {
             exists: false,
             writable: false,
             available_bytes: None,
+            required_bytes,
             error: Some("Path is empty".to_string()),
         };
     }
@@ -175,27 +216,17 @@ fn validate_dir(path: &str) -> DirectoryValidation {
     // Determine the directory to check for writability:
     // - If it exists, check the directory itself
     // - If not, check the nearest existing ancestor
-    let check_dir = if exists {
-        p.clone()
-    } else {
-        let mut ancestor = p.parent().map(|p| p.to_path_buf());
-        while let Some(ref dir) = ancestor {
-            if dir.exists() {
-                break;
-            }
-            ancestor = dir.parent().map(|p| p.to_path_buf());
-        }
-        match ancestor {
-            Some(dir) => dir,
-            None => {
-                return DirectoryValidation {
-                    valid: false,
-                    exists: false,
-                    writable: false,
-                    available_bytes: None,
-                    error: Some("No accessible parent directory found".to_string()),
-                };
-            }
+    let check_dir = match nearest_existing_ancestor(&p) {
+        Some(dir) => dir,
+        None => {
+            return DirectoryValidation {
+                valid: false,
+                exists: false,
+                writable: false,
+                available_bytes: None,
+                required_bytes,
+                error: Some("No accessible parent directory found".to_string()),
+            };
         }
     };
 
@@ -211,23 +242,57 @@ fn validate_dir(path: &str) -> DirectoryValidation {
 
     // Get available space (platform-specific)
     let available_bytes = get_available_space(&check_dir);
+    let enough_space = match (required_bytes, available_bytes) {
+        (Some(required), Some(available)) => available >= required,
+        _ => true,
+    };
+
+    let error = if !writable {
+        Some("Directory is not writable".to_string())
+    } else if !enough_space {
+        Some(format!(
+            "Insufficient disk space: {} bytes required, {} bytes available",
+            required_bytes.unwrap_or(0),
+            available_bytes.unwrap_or(0)
+        ))
+    } else {
+        None
+    };
 
     DirectoryValidation {
-        valid: writable,
+        valid: writable && enough_space,
         exists,
         writable,
         available_bytes,
-        error: if !writable {
-            Some("Directory is not writable".to_string())
-        } else {
-            None
-        },
+        required_bytes,
+        error,
+    }
+}
+
+/// Check that `required` bytes are free at `path` using the given space query.
+/// Proceeds when free space can't be determined.
+fn check_disk_space_with<F>(path: &Path, required: u64, space: F) -> Result<(), StorageError>
+where
+    F: Fn(&Path) -> Option<u64>,
+{
+    let dir = nearest_existing_ancestor(path).ok_or_else(|| {
+        StorageError::Other(format!("No accessible parent directory for '{}'", path.display()))
+    })?;
+    match space(&dir) {
+        Some(available) if available < required => {
+            Err(StorageError::InsufficientSpace { required, available })
+        }
+        Some(_) => Ok(()),
+        None => {
+            log::warn!("Could not determine free space for '{}'", dir.display());
+            Ok(())
+        }
     }
 }
 
 /// Get available disk space for a path using native OS APIs.
 /// Safe, fast, and cross-platform (no shell commands).
-fn get_available_space(path: &PathBuf) -> Option<u64> {
+fn get_available_space(path: &Path) -> Option<u64> {
     #[cfg(target_os = "windows")]
     {
         use std::os::windows::ffi::OsStrExt;
@@ -292,36 +357,179 @@ fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<(usize, u64), Stor
     Ok((files_copied, bytes_copied))
 }
 
+/// Staging directory next to the migration target
+fn staging_dir_for(target: &Path) -> Result<PathBuf, StorageError> {
+    let name = target
+        .file_name()
+        .ok_or_else(|| StorageError::Other(format!("Invalid migration target '{}'", target.display())))?;
+    Ok(target.with_file_name(format!(".{}.migrating", name.to_string_lossy())))
+}
+
+fn files_identical(a: &Path, b: &Path) -> bool {
+    match (fs::metadata(a), fs::metadata(b)) {
+        (Ok(ma), Ok(mb)) if ma.len() == mb.len() => {
+            matches!((fs::read(a), fs::read(b)), (Ok(x), Ok(y)) if x == y)
+        }
+        _ => false,
+    }
+}
+
+/// Copy a file or directory, skipping destination files that already match
+fn copy_resumable(src: &Path, dst: &Path, stats: &mut StagedCopy) -> Result<(), StorageError> {
+    if src.is_dir() {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            copy_resumable(&entry.path(), &dst.join(entry.file_name()), stats)?;
+        }
+    } else if src.is_file() {
+        let size = fs::metadata(src)?.len();
+        if files_identical(src, dst) {
+            stats.files_skipped += 1;
+        } else {
+            fs::copy(src, dst)?;
+            stats.files_copied += 1;
+        }
+        stats.bytes += size;
+    }
+    Ok(())
+}
+
+/// File count and total size of the named entries under `root`
+fn entries_manifest(root: &Path, entries: &[&str]) -> (usize, u64) {
+    let mut files = 0usize;
+    let mut bytes = 0u64;
+    let mut pending: Vec<PathBuf> = entries.iter().map(|e| root.join(e)).collect();
+    while let Some(path) = pending.pop() {
+        if path.is_dir() {
+            if let Ok(read) = fs::read_dir(&path) {
+                pending.extend(read.flatten().map(|e| e.path()));
+            }
+        } else if let Ok(meta) = fs::metadata(&path) {
+            files += 1;
+            bytes += meta.len();
+        }
+    }
+    (files, bytes)
+}
+
+/// Copy `entries` from `src_root` into a staging sibling of `target`, verify the
+/// copy, then move it into place. The source is never modified, and re-running
+/// after an interruption reuses files already staged. Entries already present
+/// in `target` are only replaced when `overwrite` is set.
+fn stage_and_swap(
+    src_root: &Path,
+    entries: &[&str],
+    target: &Path,
+    overwrite: bool,
+) -> Result<StagedCopy, StorageError> {
+    if !overwrite {
+        let existing: Vec<&str> = entries
+            .iter()
+            .copied()
+            .filter(|entry| src_root.join(entry).exists() && target.join(entry).exists())
+            .collect();
+        if !existing.is_empty() {
+            return Err(StorageError::Other(format!(
+                "Target '{}' already contains {}; choose an empty directory or confirm overwrite",
+                target.display(),
+                existing.join(", ")
+            )));
+        }
+    }
+
+    let staging = staging_dir_for(target)?;
+    fs::create_dir_all(&staging)?;
+
+    let mut stats = StagedCopy::default();
+    for entry in entries {
+        copy_resumable(&src_root.join(entry), &staging.join(entry), &mut stats)?;
+    }
+
+    let expected = entries_manifest(src_root, entries);
+    let staged = entries_manifest(&staging, entries);
+    if expected != staged {
+        return Err(StorageError::Other(format!(
+            "Migration verification failed: expected {} files ({} bytes), staged {} files ({} bytes)",
+            expected.0, expected.1, staged.0, staged.1
+        )));
+    }
+
+    if !target.exists() {
+        fs::rename(&staging, target)?;
+        return Ok(stats);
+    }
+
+    for entry in entries {
+        let from = staging.join(entry);
+        if !from.exists() {
+            continue;
+        }
+        let to = target.join(entry);
+        if to.is_dir() {
+            fs::remove_dir_all(&to)?;
+        } else if to.exists() {
+            fs::remove_file(&to)?;
+        }
+        fs::rename(&from, &to)?;
+    }
+    let _ = fs::remove_dir_all(&staging);
+    Ok(stats)
+}
+
+/// Delete migrated entries from the old location
+fn remove_migrated_entries(src_root: &Path, entries: &[&str]) {
+    for entry in entries {
+        let path = src_root.join(entry);
+        let result = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else if path.exists() {
+            fs::remove_file(&path)
+        } else {
+            Ok(())
+        };
+        if let Err(e) = result {
+            log::warn!("Failed to remove migrated '{}': {}", path.display(), e);
+        }
+    }
+}
+
 // ============================================================================
 // Public API (used by other Rust modules)
 // ============================================================================
 
-/// Resolve the effective data directory
-/// Returns custom_data_dir if set and valid, otherwise default
-pub fn resolve_data_dir(app: &AppHandle) -> Result<PathBuf, StorageError> {
-    let config = get_config(app)?;
-    if let Some(ref custom) = config.custom_data_dir {
+/// Fail with `StorageError::InsufficientSpace` unless `required` bytes are free at `path`
+pub fn ensure_disk_space(path: &Path, required: u64) -> Result<(), StorageError> {
+    check_disk_space_with(path, required, get_available_space)
+}
+
+/// Pick the custom directory when it is set and usable, otherwise fall back to the default
+pub(crate) fn resolve_dir_with<F>(custom: Option<&str>, default_dir: F) -> Result<PathBuf, StorageError>
+where
+    F: FnOnce() -> Result<PathBuf, StorageError>,
+{
+    if let Some(custom) = custom {
         let p = PathBuf::from(custom);
         if p.exists() || fs::create_dir_all(&p).is_ok() {
             return Ok(p);
         }
-        log::warn!("Custom data dir '{}' is not accessible, falling back to default", custom);
+        log::warn!("Custom directory '{}' is not accessible, falling back to default", custom);
     }
-    get_default_base_dir(app)
+    default_dir()
+}
+
+/// Resolve the effective data directory
+/// Returns custom_data_dir if set and valid, otherwise default
+pub fn resolve_data_dir(app: &AppHandle) -> Result<PathBuf, StorageError> {
+    let config = get_config(app)?;
+    resolve_dir_with(config.custom_data_dir.as_deref(), || get_default_base_dir(app))
 }
 
 /// Resolve the effective cache directory
 /// Returns custom_cache_dir if set and valid, otherwise default
 pub fn resolve_cache_dir(app: &AppHandle) -> Result<PathBuf, StorageError> {
     let config = get_config(app)?;
-    if let Some(ref custom) = config.custom_cache_dir {
-        let p = PathBuf::from(custom);
-        if p.exists() || fs::create_dir_all(&p).is_ok() {
-            return Ok(p);
-        }
-        log::warn!("Custom cache dir '{}' is not accessible, falling back to default", custom);
-    }
-    get_default_base_dir(app)
+    resolve_dir_with(config.custom_cache_dir.as_deref(), || get_default_base_dir(app))
 }
 
 // ============================================================================
@@ -349,7 +557,7 @@ pub async fn get_path_config(app: AppHandle) -> Result<PathInfo, StorageError> {
 /// Set custom data directory (does not migrate data)
 #[tauri::command]
 pub async fn set_custom_data_dir(app: AppHandle, path: String) -> Result<(), StorageError> {
-    let validation = validate_dir(&path);
+    let validation = validate_dir(&path, None);
     if !validation.valid {
         return Err(StorageError::Other(
             validation.error.unwrap_or_else(|| "Directory is not valid".to_string()),
@@ -366,7 +574,7 @@ pub async fn set_custom_data_dir(app: AppHandle, path: String) -> Result<(), Sto
 /// Set custom cache directory (does not migrate data)
 #[tauri::command]
 pub async fn set_custom_cache_dir(app: AppHandle, path: String) -> Result<(), StorageError> {
-    let validation = validate_dir(&path);
+    let validation = validate_dir(&path, None);
     if !validation.valid {
         return Err(StorageError::Other(
             validation.error.unwrap_or_else(|| "Directory is not valid".to_string()),
@@ -382,13 +590,18 @@ pub async fn set_custom_cache_dir(app: AppHandle, path: String) -> Result<(), St
 
 /// Migrate data to a new directory
 #[tauri::command]
-pub async fn migrate_data_dir(app: AppHandle, target_dir: String) -> Result<MigrationResult, StorageError> {
-    let validation = validate_dir(&target_dir);
+pub async fn migrate_data_dir(
+    app: AppHandle,
+    target_dir: String,
+    overwrite: Option<bool>,
+) -> Result<MigrationResult, StorageError> {
+    let validation = validate_dir(&target_dir, None);
     if !validation.valid {
         return Ok(MigrationResult {
             success: false,
             files_copied: 0,
             bytes_copied: 0,
+            files_skipped: 0,
             error: validation.error,
         });
     }
@@ -402,42 +615,32 @@ pub async fn migrate_data_dir(app: AppHandle, target_dir: String) -> Result<Migr
             success: true,
             files_copied: 0,
             bytes_copied: 0,
+            files_skipped: 0,
             error: None,
         });
     }
 
-    // Copy stores directory
-    let stores_src = current_data_dir.join("stores");
-    let stores_dst = target.join("stores");
-    let (mut total_files, mut total_bytes) = copy_dir_recursive(&stores_src, &stores_dst)?;
-
-    // Copy individual config files
-    let config_files = ["app_settings.json", "solver_config.json"];
-    for filename in &config_files {
-        let src = current_data_dir.join(filename);
-        if src.exists() {
-            let dst = target.join(filename);
-            let size = fs::metadata(&src).map(|m| m.len()).unwrap_or(0);
-            fs::copy(&src, &dst)?;
-            total_files += 1;
-            total_bytes += size;
-        }
-    }
+    let required = entries_manifest(&current_data_dir, DATA_DIR_ENTRIES).1;
+    ensure_disk_space(&target, required)?;
 
-    // Update config to use new directory
+    let staged = stage_and_swap(&current_data_dir, DATA_DIR_ENTRIES, &target, overwrite.unwrap_or(false))?;
+
+    // Update config to use new directory, then drop the old copy
     let mut config = get_config(&app)?;
     config.custom_data_dir = Some(target_dir.clone());
     update_config(&app, config)?;
+    remove_migrated_entries(&current_data_dir, DATA_DIR_ENTRIES);
 
     log::info!(
-        "Data migrated to '{}': {} files, {} bytes",
-        target_dir, total_files, total_bytes
+        "Data migrated to '{}': {} files copied, {} already present, {} bytes",
+        target_dir, staged.files_copied, staged.files_skipped, staged.bytes
     );
 
     Ok(MigrationResult {
         success: true,
-        files_copied: total_files,
-        bytes_copied: total_bytes,
+        files_copied: staged.files_copied,
+        bytes_copied: staged.bytes,
+        files_skipped: staged.files_skipped,
         error: None,
     })
 }
@@ -445,12 +648,13 @@ pub async fn migrate_data_dir(app: AppHandle, target_dir: String) -> Result<Migr
 /// Migrate cache to a new directory
 #[tauri::command]
 pub async fn migrate_cache_dir(app: AppHandle, target_dir: String) -> Result<MigrationResult, StorageError> {
-    let validation = validate_dir(&target_dir);
+    let validation = validate_dir(&target_dir, None);
     if !validation.valid {
         return Ok(MigrationResult {
             success: false,
             files_copied: 0,
             bytes_copied: 0,
+            files_skipped: 0,
             error: validation.error,
         });
     }
@@ -464,10 +668,17 @@ pub async fn migrate_cache_dir(app: AppHandle, target_dir: String) -> Result<Mig
             success: true,
             files_copied: 0,
             bytes_copied: 0,
+            files_skipped: 0,
             error: None,
         });
     }
 
+    let required = ["cache", "unified_cache"]
+        .iter()
+        .map(|d| crate::data::storage::dir_size(&current_cache_dir.join(d)))
+        .sum();
+    ensure_disk_space(&target, required)?;
+
     // Copy cache directory
     let cache_src = current_cache_dir.join("cache");
     let cache_dst = target.join("cache");
@@ -494,6 +705,7 @@ pub async fn migrate_cache_dir(app: AppHandle, target_dir: String) -> Result<Mig
         success: true,
         files_copied: total_files,
         bytes_copied: total_bytes,
+        files_skipped: 0,
         error: None,
     })
 }
@@ -506,10 +718,22 @@ pub async fn reset_paths_to_default(app: AppHandle) -> Result<(), StorageError>
     Ok(())
 }
 
-/// Validate a directory path
+/// Validate a directory path, optionally requiring free space for a pending operation
+#[tauri::command]
+pub async fn validate_directory(
+    path: String,
+    required_bytes: Option<u64>,
+) -> Result<DirectoryValidation, StorageError> {
+    Ok(validate_dir(&path, required_bytes))
+}
+
+/// Free bytes available at a path (or its nearest existing ancestor)
 #[tauri::command]
-pub async fn validate_directory(path: String) -> Result<DirectoryValidation, StorageError> {
-    Ok(validate_dir(&path))
+pub async fn available_disk_space(path: String) -> Result<u64, StorageError> {
+    let dir = nearest_existing_ancestor(Path::new(&path))
+        .ok_or_else(|| StorageError::Other(format!("No accessible parent directory for '{}'", path)))?;
+    get_available_space(&dir)
+        .ok_or_else(|| StorageError::Other(format!("Unable to determine free space for '{}'", path)))
 }
 
 // ============================================================================
@@ -567,6 +791,7 @@ mod tests {
             exists: true,
             writable: true,
             available_bytes: Some(1024 * 1024 * 1024),
+            required_bytes: None,
             error: None,
         };
         let json = serde_json::to_string(&v).unwrap();
@@ -579,6 +804,7 @@ mod tests {
             success: true,
             files_copied: 10,
             bytes_copied: 5000,
+            files_skipped: 0,
             error: None,
         };
         let json = serde_json::to_string(&r).unwrap();
@@ -587,7 +813,7 @@ mod tests {
 
     #[test]
     fn test_validate_empty_path() {
-        let v = validate_dir("");
+        let v = validate_dir("", None);
         assert!(!v.valid);
         assert!(v.error.is_some());
     }
@@ -598,14 +824,14 @@ mod tests {
         let temp = std::env::temp_dir().join("skymap_test_validate_nonexistent");
         // Ensure it doesn't exist before validation
         let _ = fs::remove_dir_all(&temp);
-        let v = validate_dir(temp.to_str().unwrap());
+        let v = validate_dir(temp.to_str().unwrap(), None);
         assert!(v.valid, "parent temp dir should be writable");
         assert!(v.writable);
         assert!(!v.exists, "validate_dir should not create the directory");
 
         // Also validate an existing directory
         fs::create_dir_all(&temp).unwrap();
-        let v2 = validate_dir(temp.to_str().unwrap());
+        let v2 = validate_dir(temp.to_str().unwrap(), None);
         assert!(v2.valid);
         assert!(v2.exists);
         // Cleanup
@@ -621,4 +847,155 @@ mod tests {
         assert_eq!(bytes, 0);
         let _ = fs::remove_dir_all(&dst);
     }
+
+    // ------------------------------------------------------------------------
+    // Disk Space Preflight Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_space_check_insufficient() {
+        let dir = std::env::temp_dir();
+        let result = check_disk_space_with(&dir, 1_000, |_| Some(10));
+        assert!(matches!(
+            result,
+            Err(StorageError::InsufficientSpace { required: 1_000, available: 10 })
+        ));
+    }
+
+    #[test]
+    fn test_space_check_sufficient_or_unknown() {
+        let target = std::env::temp_dir().join("skymap_test_space_missing/child");
+        assert!(check_disk_space_with(&target, 1_000, |_| Some(1_000)).is_ok());
+        assert!(check_disk_space_with(&target, 1_000, |_| None).is_ok());
+    }
+
+    #[test]
+    fn test_space_check_queries_existing_ancestor() {
+        let target = std::env::temp_dir().join("skymap_test_space_ancestor/a/b");
+        let _ = fs::remove_dir_all(std::env::temp_dir().join("skymap_test_space_ancestor"));
+        let queried = std::cell::RefCell::new(None);
+        check_disk_space_with(&target, 1, |dir| {
+            *queried.borrow_mut() = Some(dir.to_path_buf());
+            Some(1)
+        })
+        .unwrap();
+        assert_eq!(queried.into_inner(), Some(std::env::temp_dir()));
+    }
+
+    #[test]
+    fn test_validate_dir_reports_required_bytes() {
+        let temp = std::env::temp_dir();
+        let v = validate_dir(temp.to_str().unwrap(), Some(u64::MAX));
+        assert_eq!(v.required_bytes, Some(u64::MAX));
+        if v.available_bytes.is_some() {
+            assert!(!v.valid);
+            assert!(v.error.unwrap().contains("Insufficient disk space"));
+        }
+
+        let v = validate_dir(temp.to_str().unwrap(), Some(0));
+        assert!(v.valid);
+    }
+
+    // ------------------------------------------------------------------------
+    // Data Migration Tests
+    // ------------------------------------------------------------------------
+
+    fn migration_fixture() -> (PathBuf, PathBuf, PathBuf) {
+        let root = std::env::temp_dir().join(format!(
+            "skymap_migrate_{}",
+            crate::utils::generate_id("t")
+        ));
+        let src = root.join("old");
+        fs::create_dir_all(src.join("stores").join("nested")).unwrap();
+        fs::write(src.join("stores").join("a.json"), b"{\"a\":1}").unwrap();
+        fs::write(src.join("stores").join("nested").join("b.json"), b"[1,2,3]").unwrap();
+        fs::write(src.join("app_settings.json"), b"{\"theme\":\"dark\"}").unwrap();
+        fs::write(src.join("path_config.json"), b"{}").unwrap();
+        (root.clone(), src, root.join("new"))
+    }
+
+    #[test]
+    fn test_stage_and_swap_copies_tree() {
+        let (root, src, target) = migration_fixture();
+
+        let staged = stage_and_swap(&src, DATA_DIR_ENTRIES, &target, false).unwrap();
+        assert_eq!(staged.files_copied, 3);
+        assert_eq!(staged.files_skipped, 0);
+        assert_eq!(staged.bytes, entries_manifest(&src, DATA_DIR_ENTRIES).1);
+
+        assert_eq!(fs::read(target.join("stores/nested/b.json")).unwrap(), b"[1,2,3]");
+        assert!(!target.join("path_config.json").exists());
+        assert!(!staging_dir_for(&target).unwrap().exists());
+
+        remove_migrated_entries(&src, DATA_DIR_ENTRIES);
+        assert!(!src.join("stores").exists());
+        assert!(src.join("path_config.json").exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_interrupted_migration_keeps_source_and_resumes() {
+        let (root, src, target) = migration_fixture();
+
+        // Simulate a run that stopped part-way: one file fully staged, one truncated
+        let staging = staging_dir_for(&target).unwrap();
+        fs::create_dir_all(staging.join("stores")).unwrap();
+        fs::copy(src.join("stores/a.json"), staging.join("stores/a.json")).unwrap();
+        fs::write(staging.join("app_settings.json"), b"{\"th").unwrap();
+
+        assert!(!target.exists());
+        assert_eq!(fs::read(src.join("app_settings.json")).unwrap(), b"{\"theme\":\"dark\"}");
+        assert_eq!(entries_manifest(&src, DATA_DIR_ENTRIES).0, 3);
+
+        let staged = stage_and_swap(&src, DATA_DIR_ENTRIES, &target, false).unwrap();
+        assert_eq!(staged.files_skipped, 1);
+        assert_eq!(staged.files_copied, 2);
+        assert_eq!(fs::read(target.join("app_settings.json")).unwrap(), b"{\"theme\":\"dark\"}");
+        assert!(!staging.exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_stage_and_swap_refuses_occupied_target() {
+        let (root, src, target) = migration_fixture();
+        fs::create_dir_all(target.join("stores")).unwrap();
+        fs::write(target.join("stores/stale.json"), b"old").unwrap();
+
+        assert!(stage_and_swap(&src, DATA_DIR_ENTRIES, &target, false).is_err());
+        assert_eq!(fs::read(target.join("stores/stale.json")).unwrap(), b"old");
+        assert!(!target.join("stores/a.json").exists());
+        assert!(!staging_dir_for(&target).unwrap().exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_stage_and_swap_into_unrelated_target() {
+        let (root, src, target) = migration_fixture();
+        fs::create_dir_all(&target).unwrap();
+        fs::write(target.join("unrelated.txt"), b"keep").unwrap();
+
+        stage_and_swap(&src, DATA_DIR_ENTRIES, &target, false).unwrap();
+        assert!(target.join("stores/a.json").exists());
+        assert_eq!(fs::read(target.join("unrelated.txt")).unwrap(), b"keep");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_stage_and_swap_overwrites_existing_target() {
+        let (root, src, target) = migration_fixture();
+        fs::create_dir_all(target.join("stores")).unwrap();
+        fs::write(target.join("stores/stale.json"), b"old").unwrap();
+        fs::write(target.join("unrelated.txt"), b"keep").unwrap();
+
+        stage_and_swap(&src, DATA_DIR_ENTRIES, &target, true).unwrap();
+        assert!(!target.join("stores/stale.json").exists());
+        assert!(target.join("stores/a.json").exists());
+        assert_eq!(fs::read(target.join("unrelated.txt")).unwrap(), b"keep");
+
+        let _ = fs::remove_dir_all(&root);
+    }
 }
@@ -2,34 +2,145 @@
 //! Handles solving, INI/WCS parsing, database management, and image analysis.
 
 use std::fs;
+use std::io::{BufRead, BufReader, Read};
 use std::path::PathBuf;
-use std::process::{Command, Stdio};
+use std::process::{Command, Output, Stdio};
+use std::sync::Arc;
+
+use tauri::AppHandle;
 
 use super::fits::{parse_fits_header_from_bytes, parse_ini_value, parse_value};
 use super::helpers::{
     cleanup_local_solve_workspace, command_succeeds, create_local_solve_workspace, excerpt_output,
-    get_default_index_path_internal, resolve_preferred_executable,
+    get_default_index_path_internal, resolve_preferred_executable, solver_search_candidates,
 };
 use super::types::{
     AstapDatabaseInfo, AstrometryIndex, ImageAnalysisResult, IndexInfo, LocalInvocationDiagnostics,
-    LocalSolverProfileId, PlateSolveResult, PlateSolverConfig, PlateSolverError,
-    PlateSolverType, ScaleRange, SolverConfig, SolverInfo, StarDetection,
+    LocalSolverProfileId, PlateSolveResult, PlateSolverConfig, PlateSolverError, PlateSolverType,
+    ScaleRange, SolveProgressEvent, SolverConfig, SolverInfo, StarDetection,
 };
-use super::ACTIVE_SOLVE_PID;
+use super::{take_active_solve_pid, terminate_process, ActiveSolveGuard};
+
+/// Receives progress parsed from ASTAP's stdout while a solve runs
+pub(super) type AstapProgressCallback = Arc<dyn Fn(SolveProgressEvent) + Send + Sync>;
+
+/// Longest an ASTAP image analysis may run before it is killed
+const ANALYSE_TIMEOUT_SECS: u64 = 120;
+
+/// Progress band covered by parsed ASTAP output, between the caller's coarse
+/// "solving" (15%) and "parsing" (85%) events
+const ASTAP_PROGRESS_START: f64 = 20.0;
+const ASTAP_PROGRESS_END: f64 = 80.0;
+
+/// Turns ASTAP stdout lines into monotonic `solve-progress` events
+#[derive(Debug, Default)]
+struct AstapProgressTracker {
+    last_percent: f64,
+}
+
+impl AstapProgressTracker {
+    fn observe(&mut self, line: &str) -> Option<SolveProgressEvent> {
+        let (stage, percent) = parse_astap_progress_line(line)?;
+        if percent <= self.last_percent {
+            return None;
+        }
+        self.last_percent = percent;
+        Some(SolveProgressEvent {
+            stage: stage.to_string(),
+            progress: percent,
+            message: line.trim().to_string(),
+        })
+    }
+}
+
+/// Map a line of ASTAP output to a stage and overall percentage
+fn parse_astap_progress_line(line: &str) -> Option<(&'static str, f64)> {
+    let lower = line.trim().to_lowercase();
+    if lower.is_empty() {
+        return None;
+    }
+
+    if lower.starts_with("solution found") || lower.starts_with("solved in") {
+        return Some(("matching", ASTAP_PROGRESS_END));
+    }
+    if lower.contains("stars") && lower.contains("quads") {
+        return Some(("extracting", 35.0));
+    }
+    if lower.starts_with("search") {
+        // e.g. "Search 12 of 49" or "Search 12/49"
+        let numbers: Vec<f64> = lower
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|part| !part.is_empty())
+            .filter_map(|part| part.parse().ok())
+            .collect();
+        let fraction = match numbers.as_slice() {
+            [current, total, ..] if *total > 0.0 => (current / total).clamp(0.0, 1.0),
+            _ => 0.0,
+        };
+        return Some(("matching", 40.0 + fraction * (ASTAP_PROGRESS_END - 45.0)));
+    }
+    if lower.contains("database") || lower.contains("reading") || lower.contains("loading") {
+        return Some(("loading", ASTAP_PROGRESS_START));
+    }
+    None
+}
+
+/// Wait for the child while streaming stdout lines through the progress tracker
+fn wait_with_progress(
+    mut child: std::process::Child,
+    progress: Option<AstapProgressCallback>,
+) -> std::io::Result<Output> {
+    // Drain stderr on its own thread so a chatty child can't block on a full pipe
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut buffer = Vec::new();
+            let _ = stderr.read_to_end(&mut buffer);
+            buffer
+        })
+    });
+
+    let mut stdout_bytes = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        let mut tracker = AstapProgressTracker::default();
+        let mut reader = BufReader::new(stdout);
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            if let Some(callback) = &progress {
+                if let Some(event) = tracker.observe(&String::from_utf8_lossy(&line)) {
+                    callback(event);
+                }
+            }
+            stdout_bytes.extend_from_slice(&line);
+            line.clear();
+        }
+    }
+
+    let status = child.wait()?;
+    let stderr = stderr_reader
+        .and_then(|handle| handle.join().ok())
+        .unwrap_or_default();
+    Ok(Output {
+        status,
+        stdout: stdout_bytes,
+        stderr,
+    })
+}
 
 pub(super) async fn solve_with_astap(
     config: &PlateSolverConfig,
 ) -> Result<PlateSolveResult, PlateSolverError> {
-    solve_with_astap_enhanced(config, None).await
+    solve_with_astap_enhanced(config, None, None).await
 }
 
 pub(super) async fn solve_with_astap_enhanced(
     config: &PlateSolverConfig,
     solver_config: Option<&SolverConfig>,
+    progress: Option<AstapProgressCallback>,
 ) -> Result<PlateSolveResult, PlateSolverError> {
     let preferred_executable = solver_config.and_then(|sc| sc.executable_path.as_deref());
     let preferred_index_path = solver_config.and_then(|sc| sc.index_path.as_deref());
-    let astap = detect_astap_solver(preferred_executable, preferred_index_path)
+    let extra_paths = solver_config.map(|sc| sc.extra_solver_paths.as_slice()).unwrap_or(&[]);
+    let astap = detect_astap_solver(preferred_executable, preferred_index_path, extra_paths)
         .ok_or(PlateSolverError::SolverNotInstalled("ASTAP".to_string()))?;
     let workspace = create_local_solve_workspace("astap")?;
     let keep_wcs_file = solver_config.map(|sc| sc.keep_wcs_file).unwrap_or(true);
@@ -51,37 +162,17 @@ pub(super) async fn solve_with_astap_enhanced(
         tokio::task::spawn_blocking(move || {
             cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
             let child = cmd.spawn()?;
-            // Store PID for cancel support
-            {
-                let mut guard = ACTIVE_SOLVE_PID.lock().unwrap();
-                *guard = Some(child.id());
-            }
-            let result = child.wait_with_output();
-            // Clear PID after completion
-            {
-                let mut guard = ACTIVE_SOLVE_PID.lock().unwrap();
-                *guard = None;
-            }
-            result
+            // Publish PID for cancel support; cleared when `active` drops
+            let active = ActiveSolveGuard::register(child.id());
+            let output = wait_with_progress(child, progress)?;
+            Ok((output, active.was_cancelled()))
         }),
     )
     .await
     .map_err(|_| {
         // On timeout, also kill the process
-        let pid = ACTIVE_SOLVE_PID.lock().unwrap().take();
-        if let Some(pid) = pid {
-            #[cfg(target_os = "windows")]
-            {
-                let _ = Command::new("taskkill")
-                    .args(["/F", "/T", "/PID", &pid.to_string()])
-                    .output();
-            }
-            #[cfg(not(target_os = "windows"))]
-            {
-                unsafe {
-                    libc::kill(pid as i32, libc::SIGTERM);
-                }
-            }
+        if let Some(pid) = take_active_solve_pid() {
+            terminate_process(pid);
         }
         PlateSolverError::LocalInvocation(LocalInvocationDiagnostics {
             error_code: "timeout".to_string(),
@@ -97,6 +188,12 @@ pub(super) async fn solve_with_astap_enhanced(
     .map_err(|e| PlateSolverError::SolveFailed(format!("Task join error: {}", e)))?
     .map_err(PlateSolverError::Io)?;
 
+    let (output, cancelled) = output;
+    if cancelled {
+        cleanup_local_solve_workspace(&workspace);
+    }
+    cancelled_error_if(cancelled)?;
+
     // Try parsing INI output file first (more reliable than stdout)
     let ini_path = workspace.output_base.with_extension("ini");
     let wcs_path = workspace.wcs_file.clone();
@@ -245,9 +342,13 @@ pub fn validate_astap_executable(path: &str) -> Option<LocalSolverProfileId> {
 pub fn detect_astap_solver(
     preferred_executable: Option<&str>,
     preferred_index_path: Option<&str>,
+    extra_paths: &[String],
 ) -> Option<SolverInfo> {
-    let executable_path = resolve_preferred_executable(preferred_executable, &get_astap_paths())?;
-    let profile_id = validate_astap_executable(&executable_path)?;
+    let (executable_path, profile_id) = resolve_preferred_executable(
+        preferred_executable,
+        &solver_search_candidates(extra_paths, get_astap_paths()),
+        validate_astap_executable,
+    )?;
     let uses_custom_executable =
         preferred_executable.is_some_and(|path| !path.trim().is_empty() && path == executable_path);
     let index_path = preferred_index_path
@@ -274,6 +375,15 @@ pub fn detect_astap_solver(
     })
 }
 
+/// Map a solver child killed through `cancel_plate_solve` to `Cancelled`
+pub(super) fn cancelled_error_if(cancelled: bool) -> Result<(), PlateSolverError> {
+    if cancelled {
+        Err(PlateSolverError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
 /// Parse ASTAP .ini output file for solve results
 fn parse_astap_ini_file(ini_path: &PathBuf) -> Result<PlateSolveResult, PlateSolverError> {
     let content = fs::read_to_string(ini_path)
@@ -792,8 +902,21 @@ pub async fn recommend_astap_database(
 // Image Analysis (ASTAP)
 // ============================================================================
 
+/// ASTAP as detected with the saved solver config's paths
+async fn detect_configured_astap(app: AppHandle) -> Result<SolverInfo, PlateSolverError> {
+    let config = super::config::load_solver_config(app).await.unwrap_or_default();
+    let is_astap = config.solver_type == "astap";
+    detect_astap_solver(
+        is_astap.then_some(config.executable_path.as_deref()).flatten(),
+        is_astap.then_some(config.index_path.as_deref()).flatten(),
+        &config.extra_solver_paths,
+    )
+    .ok_or(PlateSolverError::SolverNotInstalled("ASTAP".to_string()))
+}
+
 #[tauri::command]
 pub async fn analyse_image(
+    app: AppHandle,
     image_path: String,
     snr_minimum: Option<f64>,
 ) -> Result<ImageAnalysisResult, PlateSolverError> {
@@ -804,25 +927,41 @@ pub async fn analyse_image(
         )));
     }
 
-    let astap = detect_astap_solver(None, None)
-        .ok_or(PlateSolverError::SolverNotInstalled("ASTAP".to_string()))?;
+    let astap = detect_configured_astap(app).await?;
 
-    let astap_path = astap.executable_path.clone();
     let snr = snr_minimum.unwrap_or(10.0);
-    let img_path = image_path.clone();
 
-    let output = tokio::task::spawn_blocking(move || {
-        Command::new(&astap_path)
-            .arg("-f")
-            .arg(&img_path)
-            .arg("-analyse")
-            .arg(format!("{}", snr))
-            .output()
-    })
-    .await
-    .map_err(|e| PlateSolverError::SolveFailed(format!("Task join error: {}", e)))?
-    .map_err(PlateSolverError::Io)?;
+    // -analyse2 reports background and noise in an .ini file at the -o output
+    // base, kept in a scratch workspace so a user's own .ini beside the image
+    // is never read or removed; older ASTAP builds lack it and only print to stdout
+    if astap_supports_analyse2(&astap.executable_path).await {
+        let workspace = create_local_solve_workspace("astap-analyse")?;
+        let analysed = run_astap_analyse(
+            &astap.executable_path,
+            &image_path,
+            "-analyse2",
+            snr,
+            Some(&workspace.output_base),
+        )
+        .await;
+        let report = fs::read_to_string(workspace.output_base.with_extension("ini")).ok();
+        cleanup_local_solve_workspace(&workspace);
+        analysed?;
+        return Ok(report
+            .as_deref()
+            .and_then(parse_astap_analyse2_report)
+            .unwrap_or_else(|| ImageAnalysisResult {
+                success: false,
+                median_hfd: None,
+                star_count: 0,
+                background: None,
+                noise: None,
+                stars: Vec::new(),
+                error_message: Some("ASTAP wrote no analysis report".to_string()),
+            }));
+    }
 
+    let output = run_astap_analyse(&astap.executable_path, &image_path, "-analyse", snr, None).await?;
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
 
     // Parse ASTAP analyse output
@@ -881,8 +1020,104 @@ pub async fn analyse_image(
     })
 }
 
+/// Whether this ASTAP build lists `-analyse2` in its help text
+async fn astap_supports_analyse2(astap_path: &str) -> bool {
+    run_astap_with_timeout(astap_path, vec!["-h".to_string()])
+        .await
+        .is_ok_and(|output| {
+            String::from_utf8_lossy(&output.stdout).contains("-analyse2")
+                || String::from_utf8_lossy(&output.stderr).contains("-analyse2")
+        })
+}
+
+async fn run_astap_analyse(
+    astap_path: &str,
+    image_path: &str,
+    flag: &'static str,
+    snr: f64,
+    output_base: Option<&std::path::Path>,
+) -> Result<Output, PlateSolverError> {
+    let mut args = vec!["-f".to_string(), image_path.to_string(), flag.to_string(), snr.to_string()];
+    if let Some(output_base) = output_base {
+        args.push("-o".to_string());
+        args.push(output_base.to_string_lossy().to_string());
+    }
+    run_astap_with_timeout(astap_path, args).await
+}
+
+/// Run ASTAP, killing it if it outlives [`ANALYSE_TIMEOUT_SECS`]
+async fn run_astap_with_timeout(
+    astap_path: &str,
+    args: Vec<String>,
+) -> Result<Output, PlateSolverError> {
+    let child = Command::new(astap_path)
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(PlateSolverError::Io)?;
+    let pid = child.id();
+
+    tokio::time::timeout(
+        std::time::Duration::from_secs(ANALYSE_TIMEOUT_SECS),
+        tokio::task::spawn_blocking(move || wait_with_progress(child, None)),
+    )
+    .await
+    .map_err(|_| {
+        terminate_process(pid);
+        PlateSolverError::SolveFailed(format!(
+            "ASTAP {} timed out after {}s",
+            args.join(" "),
+            ANALYSE_TIMEOUT_SECS
+        ))
+    })?
+    .map_err(|e| PlateSolverError::SolveFailed(format!("Task join error: {}", e)))?
+    .map_err(PlateSolverError::Io)
+}
+
+/// Parse the KEY=VALUE report ASTAP's -analyse2 writes at its output base,
+/// reading `HFD_MEDIAN`, `STARS`, `BACKGROUND` and `NOISE`. Returns None
+/// when the report has neither an HFD nor a star count.
+fn parse_astap_analyse2_report(content: &str) -> Option<ImageAnalysisResult> {
+    let mut median_hfd = None;
+    let mut star_count = None;
+    let mut background = None;
+    let mut noise = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let Some((key, _)) = trimmed.split_once('=') else {
+            continue;
+        };
+        let value = parse_ini_value(trimmed);
+        match key.trim() {
+            "HFD_MEDIAN" => median_hfd = value,
+            "STARS" => star_count = value.map(|v| v.max(0.0) as u32),
+            "BACKGROUND" => background = value,
+            "NOISE" => noise = value,
+            _ => {}
+        }
+    }
+
+    if median_hfd.is_none() && star_count.is_none() {
+        return None;
+    }
+
+    let star_count = star_count.unwrap_or(0);
+    Some(ImageAnalysisResult {
+        success: median_hfd.is_some() || star_count > 0,
+        median_hfd,
+        star_count,
+        background,
+        noise,
+        stars: Vec::new(),
+        error_message: None,
+    })
+}
+
 #[tauri::command]
 pub async fn extract_stars(
+    app: AppHandle,
     image_path: String,
     snr_minimum: Option<f64>,
     include_coordinates: bool,
@@ -894,8 +1129,7 @@ pub async fn extract_stars(
         )));
     }
 
-    let astap = detect_astap_solver(None, None)
-        .ok_or(PlateSolverError::SolverNotInstalled("ASTAP".to_string()))?;
+    let astap = detect_configured_astap(app).await?;
 
     let astap_path = astap.executable_path.clone();
     let snr = snr_minimum.unwrap_or(10.0);
@@ -920,45 +1154,9 @@ pub async fn extract_stars(
 
     // Parse the CSV output file
     let csv_path = PathBuf::from(&image_path).with_extension("csv");
-    let mut stars = Vec::new();
-
-    if csv_path.exists() {
-        if let Ok(csv_content) = fs::read_to_string(&csv_path) {
-            for (i, line) in csv_content.lines().enumerate() {
-                if i == 0 {
-                    continue;
-                } // skip header
-                let fields: Vec<&str> = line.split(',').collect();
-                if fields.len() >= 5 {
-                    let x = fields[0].trim().parse::<f64>().unwrap_or(0.0);
-                    let y = fields[1].trim().parse::<f64>().unwrap_or(0.0);
-                    let hfd = fields[2].trim().parse::<f64>().unwrap_or(0.0);
-                    let flux = fields[3].trim().parse::<f64>().unwrap_or(0.0);
-                    let snr_val = fields[4].trim().parse::<f64>().unwrap_or(0.0);
-
-                    let (ra, dec) = if include_coordinates && fields.len() >= 7 {
-                        (
-                            fields[5].trim().parse::<f64>().ok(),
-                            fields[6].trim().parse::<f64>().ok(),
-                        )
-                    } else {
-                        (None, None)
-                    };
-
-                    stars.push(StarDetection {
-                        x,
-                        y,
-                        hfd,
-                        flux,
-                        snr: snr_val,
-                        ra,
-                        dec,
-                        magnitude: None,
-                    });
-                }
-            }
-        }
-    }
+    let stars = fs::read_to_string(&csv_path)
+        .map(|content| parse_astap_star_csv(&content, include_coordinates))
+        .unwrap_or_default();
 
     // Calculate median HFD
     let median_hfd = if !stars.is_empty() {
@@ -986,6 +1184,118 @@ pub async fn extract_stars(
     })
 }
 
+/// Column indices of an ASTAP star CSV, resolved from its header row
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct StarCsvColumns {
+    x: Option<usize>,
+    y: Option<usize>,
+    hfd: Option<usize>,
+    flux: Option<usize>,
+    snr: Option<usize>,
+    ra: Option<usize>,
+    dec: Option<usize>,
+    magnitude: Option<usize>,
+}
+
+impl StarCsvColumns {
+    /// Legacy fixed layout: x, y, hfd, flux, snr[, ra, dec]
+    const POSITIONAL: StarCsvColumns = StarCsvColumns {
+        x: Some(0),
+        y: Some(1),
+        hfd: Some(2),
+        flux: Some(3),
+        snr: Some(4),
+        ra: Some(5),
+        dec: Some(6),
+        magnitude: None,
+    };
+
+    fn from_header(fields: &[&str]) -> Self {
+        let mut columns = StarCsvColumns {
+            x: None,
+            y: None,
+            hfd: None,
+            flux: None,
+            snr: None,
+            ra: None,
+            dec: None,
+            magnitude: None,
+        };
+        for (i, field) in fields.iter().enumerate() {
+            // Drop unit suffixes such as "ra[0..360]" or "flux (adu)"
+            let name = field
+                .split(['[', '('])
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_ascii_lowercase();
+            let slot = match name.as_str() {
+                "x" => &mut columns.x,
+                "y" => &mut columns.y,
+                "hfd" => &mut columns.hfd,
+                "flux" => &mut columns.flux,
+                "snr" => &mut columns.snr,
+                "ra" => &mut columns.ra,
+                "dec" => &mut columns.dec,
+                "mag" | "magn" | "magnitude" => &mut columns.magnitude,
+                _ => continue,
+            };
+            slot.get_or_insert(i);
+        }
+        columns
+    }
+}
+
+/// Parse the star list ASTAP writes for -extract/-extract2. Columns are
+/// mapped by header name; files without a header use the legacy order.
+fn parse_astap_star_csv(content: &str, include_coordinates: bool) -> Vec<StarDetection> {
+    let mut columns: Option<StarCsvColumns> = None;
+    let mut stars = Vec::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') || trimmed.starts_with("//") {
+            continue;
+        }
+        let fields: Vec<&str> = trimmed.split(',').map(str::trim).collect();
+
+        let cols = match columns {
+            Some(cols) => cols,
+            None => {
+                let is_data = fields.first().is_some_and(|f| f.parse::<f64>().is_ok());
+                let cols = match StarCsvColumns::from_header(&fields) {
+                    // Unrecognised header names: keep the legacy layout
+                    header if !is_data && header.x.is_some() && header.y.is_some() => header,
+                    _ => StarCsvColumns::POSITIONAL,
+                };
+                columns = Some(cols);
+                if !is_data {
+                    continue;
+                }
+                cols
+            }
+        };
+
+        let value = |index: Option<usize>| index.and_then(|i| fields.get(i)).and_then(|f| f.parse::<f64>().ok());
+        let (Some(x), Some(y)) = (value(cols.x), value(cols.y)) else {
+            continue;
+        };
+
+        stars.push(StarDetection {
+            x,
+            y,
+            hfd: value(cols.hfd).unwrap_or(0.0),
+            flux: value(cols.flux).unwrap_or(0.0),
+            snr: value(cols.snr).unwrap_or(0.0),
+            ra: if include_coordinates { value(cols.ra) } else { None },
+            dec: if include_coordinates { value(cols.dec) } else { None },
+            magnitude: value(cols.magnitude),
+        });
+    }
+
+    stars
+}
+
 fn extract_float_after(text: &str, keyword: &str) -> Option<f64> {
     if let Some(pos) = text.find(keyword) {
         let after = &text[pos + keyword.len()..];
@@ -1023,6 +1333,49 @@ fn extract_int_after(text: &str, keyword: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
+    // ------------------------------------------------------------------------
+    // ASTAP Progress Parsing Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_astap_progress_from_canned_stdout() {
+        let stdout = [
+            "ASTAP version 2024.05.01, Astrometric STAcking Program",
+            "Using star database D50",
+            "1843 stars, 1492 quads selected in the image. 2718 database stars, 2001 database quads required for the square search field of 1.3d.",
+            "Search 1 of 25",
+            "Search 5 of 25",
+            "Search 5 of 25",
+            "Search 25 of 25",
+            "Solution found: 05: 35 17.3  -05d 23 28",
+            "Solved in 2.1 sec.",
+        ];
+
+        let mut tracker = AstapProgressTracker::default();
+        let events: Vec<SolveProgressEvent> = stdout
+            .iter()
+            .filter_map(|line| tracker.observe(line))
+            .collect();
+        let stages: Vec<&str> = events.iter().map(|e| e.stage.as_str()).collect();
+
+        assert_eq!(
+            stages,
+            vec!["loading", "extracting", "matching", "matching", "matching", "matching"]
+        );
+        assert!(events.windows(2).all(|w| w[0].progress < w[1].progress));
+        assert_eq!(events.first().unwrap().progress, ASTAP_PROGRESS_START);
+        assert_eq!(events.last().unwrap().progress, ASTAP_PROGRESS_END);
+        assert!(events.last().unwrap().message.starts_with("Solution found"));
+    }
+
+    #[test]
+    fn test_astap_progress_ignores_unrecognised_output() {
+        let mut tracker = AstapProgressTracker::default();
+        assert!(tracker.observe("").is_none());
+        assert!(tracker.observe("Warning: image has no date-obs").is_none());
+        assert!(tracker.observe("No solution found!").is_none());
+    }
+
     const EPSILON: f64 = 1e-4;
 
     fn approx_eq(a: f64, b: f64) -> bool {
@@ -1089,6 +1442,7 @@ mod tests {
             auto_hints: true,
             retry_on_failure: false,
             max_retries: 2,
+            extra_solver_paths: Vec::new(),
         };
 
         let args = build_astap_command_args(
@@ -1205,6 +1559,75 @@ CDELT2  =       0.0001
         assert_eq!(result.flipped, Some(true));
     }
 
+    // ------------------------------------------------------------------------
+    // analyse2 Report Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_analyse2_report() {
+        let content = "HFD_MEDIAN=2.84\nSTARS=1342\nBACKGROUND=1021.5 // ADU\nNOISE=14.2\n";
+        let result = parse_astap_analyse2_report(content).unwrap();
+        assert!(result.success);
+        assert!(approx_eq(result.median_hfd.unwrap(), 2.84));
+        assert_eq!(result.star_count, 1342);
+        assert!(approx_eq(result.background.unwrap(), 1021.5));
+        assert!(approx_eq(result.noise.unwrap(), 14.2));
+    }
+
+    #[test]
+    fn test_parse_analyse2_report_without_metrics() {
+        assert!(parse_astap_analyse2_report("").is_none());
+        assert!(parse_astap_analyse2_report("PLTSOLVD=F\nWARNING=no stars").is_none());
+        assert!(parse_astap_analyse2_report("HFD=2.5\nSTAR_COUNT=100").is_none());
+    }
+
+    // ------------------------------------------------------------------------
+    // Star CSV Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_star_csv_reordered_columns() {
+        let content = "# ASTAP star list\nsnr,hfd,y,x,flux\n45.0,2.5,200.0,100.0,15000\n; skipped\n30.0,3.1,40.5,80.25,9000\n";
+        let stars = parse_astap_star_csv(content, false);
+        assert_eq!(stars.len(), 2);
+        assert!(approx_eq(stars[0].x, 100.0));
+        assert!(approx_eq(stars[0].y, 200.0));
+        assert!(approx_eq(stars[0].hfd, 2.5));
+        assert!(approx_eq(stars[0].flux, 15000.0));
+        assert!(approx_eq(stars[0].snr, 45.0));
+        assert!(approx_eq(stars[1].x, 80.25));
+        assert!(stars[0].magnitude.is_none());
+    }
+
+    #[test]
+    fn test_parse_star_csv_with_magnitude_and_coordinates() {
+        let content = "x,y,hfd,snr,flux,ra[0..360],dec[-90..90],magn\n10,20,2.0,50,12000,83.82,-5.39,9.75\n";
+        let stars = parse_astap_star_csv(content, true);
+        assert_eq!(stars.len(), 1);
+        assert!(approx_eq(stars[0].snr, 50.0));
+        assert!(approx_eq(stars[0].flux, 12000.0));
+        assert!(approx_eq(stars[0].ra.unwrap(), 83.82));
+        assert!(approx_eq(stars[0].dec.unwrap(), -5.39));
+        assert!(approx_eq(stars[0].magnitude.unwrap(), 9.75));
+
+        let without_coords = parse_astap_star_csv(content, false);
+        assert!(without_coords[0].ra.is_none());
+        assert!(approx_eq(without_coords[0].magnitude.unwrap(), 9.75));
+    }
+
+    #[test]
+    fn test_parse_star_csv_without_header_uses_legacy_order() {
+        let stars = parse_astap_star_csv("1.0,2.0,3.0,4000,25\n", false);
+        assert_eq!(stars.len(), 1);
+        assert!(approx_eq(stars[0].hfd, 3.0));
+
+        let stars = parse_astap_star_csv("col1,col2,col3,col4,col5\n1.0,2.0,3.0,4000,25\n", false);
+        assert_eq!(stars.len(), 1);
+        assert!(approx_eq(stars[0].hfd, 3.0));
+        assert!(approx_eq(stars[0].flux, 4000.0));
+        assert!(approx_eq(stars[0].snr, 25.0));
+    }
+
     // ------------------------------------------------------------------------
     // extract_float_after / extract_int_after Tests
     // ------------------------------------------------------------------------
@@ -9,7 +9,7 @@ use std::process::Command;
 use super::fits::{parse_fits_header_from_bytes, parse_value};
 use super::helpers::{
     cleanup_local_solve_workspace, command_succeeds, create_local_solve_workspace, excerpt_output,
-    get_default_index_path_internal, resolve_preferred_executable,
+    get_default_index_path_internal, resolve_preferred_executable, solver_search_candidates,
 };
 use super::index::parse_index_scale;
 use super::types::{
@@ -24,7 +24,8 @@ pub(super) async fn solve_with_local_astrometry(
 ) -> Result<PlateSolveResult, PlateSolverError> {
     let preferred_executable = solver_config.and_then(|sc| sc.executable_path.as_deref());
     let preferred_index_path = solver_config.and_then(|sc| sc.index_path.as_deref());
-    let astrometry = detect_astrometry_solver(preferred_executable, preferred_index_path).ok_or(
+    let extra_paths = solver_config.map(|sc| sc.extra_solver_paths.as_slice()).unwrap_or(&[]);
+    let astrometry = detect_astrometry_solver(preferred_executable, preferred_index_path, extra_paths).ok_or(
         PlateSolverError::SolverNotInstalled("Astrometry.net".to_string()),
     )?;
     let workspace = create_local_solve_workspace("astrometry")?;
@@ -243,10 +244,13 @@ pub fn validate_astrometry_executable(path: &str) -> Option<LocalSolverProfileId
 pub fn detect_astrometry_solver(
     preferred_executable: Option<&str>,
     preferred_index_path: Option<&str>,
+    extra_paths: &[String],
 ) -> Option<SolverInfo> {
-    let executable_path =
-        resolve_preferred_executable(preferred_executable, &get_astrometry_paths())?;
-    let profile_id = validate_astrometry_executable(&executable_path)?;
+    let (executable_path, profile_id) = resolve_preferred_executable(
+        preferred_executable,
+        &solver_search_candidates(extra_paths, get_astrometry_paths()),
+        validate_astrometry_executable,
+    )?;
     let uses_custom_executable =
         preferred_executable.is_some_and(|path| !path.trim().is_empty() && path == executable_path);
     let index_path = preferred_index_path
@@ -559,6 +563,7 @@ mod tests {
             auto_hints: true,
             retry_on_failure: false,
             max_retries: 2,
+            extra_solver_paths: Vec::new(),
         };
 
         let workspace = LocalSolveWorkspace {
@@ -582,6 +587,72 @@ mod tests {
         assert!(joined.contains("--overwrite"));
     }
 
+    #[test]
+    fn test_build_astrometry_command_args_from_saved_config() {
+        // Options as persisted by save_solver_config; hints come from the request
+        let solver_config: SolverConfig = serde_json::from_str(
+            r#"{
+                "solver_type": "astrometry_net",
+                "executable_path": null,
+                "index_path": null,
+                "timeout_seconds": 120,
+                "downsample": 0,
+                "search_radius": 10.0,
+                "use_sip": true,
+                "astap_database": null,
+                "astap_max_stars": 500,
+                "astap_tolerance": 0.007,
+                "astap_speed_mode": "auto",
+                "astap_min_star_size": 1.5,
+                "astap_equalise_background": false,
+                "astrometry_scale_low": 0.8,
+                "astrometry_scale_high": 1.6,
+                "astrometry_scale_units": "arcsec_per_pix",
+                "astrometry_depth": "10,20,30",
+                "astrometry_no_plots": false,
+                "astrometry_no_verify": false,
+                "astrometry_crpix_center": true,
+                "keep_wcs_file": false,
+                "auto_hints": true,
+                "retry_on_failure": false,
+                "max_retries": 1
+            }"#,
+        )
+        .unwrap();
+
+        let config = PlateSolverConfig {
+            solver_type: PlateSolverType::LocalAstrometry,
+            image_path: "/images/ngc7000.fit".to_string(),
+            ra_hint: Some(314.7),
+            dec_hint: Some(44.3),
+            radius_hint: None,
+            scale_low: None,
+            scale_high: None,
+            downsample: None,
+            timeout_seconds: None,
+        };
+        let workspace = LocalSolveWorkspace {
+            root_dir: PathBuf::from("/tmp/skymap-solve"),
+            output_base: PathBuf::from("/tmp/skymap-solve/result"),
+            wcs_file: PathBuf::from("/tmp/skymap-solve/result.wcs"),
+        };
+
+        let args = build_astrometry_command_args(&config, &solver_config, &workspace);
+        let joined = args.join(" ");
+
+        assert!(joined.contains("--ra 314.7"));
+        assert!(joined.contains("--dec 44.3"));
+        assert!(joined.contains("--radius 10"));
+        assert!(joined.contains("--scale-low 0.8"));
+        assert!(joined.contains("--scale-high 1.6"));
+        assert!(joined.contains("--scale-units arcsecperpix"));
+        assert!(joined.contains("--depth 10,20,30"));
+        assert!(joined.contains("--crpix-center"));
+        assert!(!args.contains(&"--no-verify".to_string()));
+        assert!(!args.contains(&"--no-plots".to_string()));
+        assert!(!args.contains(&"--downsample".to_string()));
+    }
+
     // ------------------------------------------------------------------------
     // parse_index_number Tests
     // ------------------------------------------------------------------------
@@ -1,12 +1,16 @@
 //! Solver configuration persistence: save and load solver config to/from disk.
+//! The config lives in the effective data directory, so it follows a custom
+//! data dir set through `path_config`.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use tauri::AppHandle;
 
 use super::types::{PlateSolverError, SolverConfig};
 
+const SOLVER_CONFIG_FILE: &str = "solver_config.json";
+
 fn get_config_path(app: &AppHandle) -> Result<PathBuf, PlateSolverError> {
     let dir = super::super::path_config::resolve_data_dir(app).map_err(|e| {
         PlateSolverError::Io(std::io::Error::new(
@@ -14,35 +18,32 @@ fn get_config_path(app: &AppHandle) -> Result<PathBuf, PlateSolverError> {
             e.to_string(),
         ))
     })?;
+    solver_config_path_in(&dir)
+}
+
+/// Solver config file inside a data directory, creating the directory if needed
+fn solver_config_path_in(dir: &Path) -> Result<PathBuf, PlateSolverError> {
     if !dir.exists() {
-        fs::create_dir_all(&dir)?;
+        fs::create_dir_all(dir)?;
     }
-    Ok(dir.join("solver_config.json"))
+    Ok(dir.join(SOLVER_CONFIG_FILE))
 }
 
-#[tauri::command]
-pub async fn save_solver_config(
-    app: AppHandle,
-    config: SolverConfig,
-) -> Result<(), PlateSolverError> {
-    let path = get_config_path(&app)?;
-    let json = serde_json::to_string_pretty(&config).map_err(|e| {
-        PlateSolverError::Io(std::io::Error::new(
+fn write_solver_config(path: &Path, config: &SolverConfig) -> Result<(), PlateSolverError> {
+    crate::data::write_json_atomic(path, config).map_err(|e| match e {
+        crate::data::StorageError::Io(io) => PlateSolverError::Io(io),
+        other => PlateSolverError::Io(std::io::Error::new(
             std::io::ErrorKind::InvalidData,
-            e.to_string(),
-        ))
-    })?;
-    fs::write(&path, json)?;
-    Ok(())
+            other.to_string(),
+        )),
+    })
 }
 
-#[tauri::command]
-pub async fn load_solver_config(app: AppHandle) -> Result<SolverConfig, PlateSolverError> {
-    let path = get_config_path(&app)?;
+fn read_solver_config(path: &Path) -> Result<SolverConfig, PlateSolverError> {
     if !path.exists() {
         return Ok(SolverConfig::default());
     }
-    let json = fs::read_to_string(&path)?;
+    let json = fs::read_to_string(path)?;
     serde_json::from_str(&json).map_err(|e| {
         PlateSolverError::Io(std::io::Error::new(
             std::io::ErrorKind::InvalidData,
@@ -51,6 +52,21 @@ pub async fn load_solver_config(app: AppHandle) -> Result<SolverConfig, PlateSol
     })
 }
 
+#[tauri::command]
+pub async fn save_solver_config(
+    app: AppHandle,
+    config: SolverConfig,
+) -> Result<(), PlateSolverError> {
+    let path = get_config_path(&app)?;
+    write_solver_config(&path, &config)
+}
+
+#[tauri::command]
+pub async fn load_solver_config(app: AppHandle) -> Result<SolverConfig, PlateSolverError> {
+    let path = get_config_path(&app)?;
+    read_solver_config(&path)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -86,6 +102,7 @@ mod tests {
             auto_hints: false,
             retry_on_failure: true,
             max_retries: 5,
+            extra_solver_paths: Vec::new(),
         };
 
         let json = serde_json::to_string_pretty(&config).unwrap();
@@ -156,4 +173,43 @@ mod tests {
         assert_eq!(config.timeout_seconds, 300);
         assert!(config.astrometry_scale_low.is_none());
     }
+
+    #[test]
+    fn test_custom_data_dir_redirects_solver_config() {
+        use crate::platform::path_config::resolve_dir_with;
+
+        let root = std::env::temp_dir().join(format!(
+            "skymap_solver_cfg_{}",
+            crate::utils::generate_id("t")
+        ));
+        let default_dir = root.join("default");
+        let custom_dir = root.join("custom");
+        let custom = custom_dir.to_string_lossy().to_string();
+
+        let dir = resolve_dir_with(Some(&custom), || Ok(default_dir.clone())).unwrap();
+        assert_eq!(dir, custom_dir);
+
+        let path = solver_config_path_in(&dir).unwrap();
+        let config = SolverConfig {
+            solver_type: "astrometry_net".to_string(),
+            timeout_seconds: 90,
+            ..SolverConfig::default()
+        };
+        write_solver_config(&path, &config).unwrap();
+
+        assert!(custom_dir.join(SOLVER_CONFIG_FILE).exists());
+        assert!(!default_dir.join(SOLVER_CONFIG_FILE).exists());
+
+        let loaded = read_solver_config(&solver_config_path_in(&dir).unwrap()).unwrap();
+        assert_eq!(loaded.solver_type, "astrometry_net");
+        assert_eq!(loaded.timeout_seconds, 90);
+
+        // Without a custom dir the default location is used and starts empty
+        let dir = resolve_dir_with(None, || Ok(default_dir.clone())).unwrap();
+        assert_eq!(dir, default_dir);
+        let loaded = read_solver_config(&solver_config_path_in(&dir).unwrap()).unwrap();
+        assert_eq!(loaded.solver_type, SolverConfig::default().solver_type);
+
+        let _ = fs::remove_dir_all(&root);
+    }
 }
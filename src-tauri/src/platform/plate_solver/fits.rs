@@ -1,8 +1,15 @@
 //! FITS header parsing, WCS extraction, and SIP distortion coefficient handling.
 
 use std::collections::HashMap;
+use std::io::Read;
 
-use super::types::{PlateSolverError, SipCoefficients, WcsResult};
+use super::types::{FitsHints, PlateSolverConfig, PlateSolverError, SipCoefficients, WcsResult};
+
+/// Upper bound on header bytes read when looking for solve hints (100 blocks)
+const MAX_HINT_HEADER_BYTES: u64 = 2880 * 100;
+
+/// Relative tolerance applied around a header-derived image scale
+const HINT_SCALE_TOLERANCE: f64 = 0.2;
 
 /// Parse FITS header cards from raw bytes into a string of "KEY = VALUE" lines
 pub fn parse_fits_header_from_bytes(data: &[u8]) -> String {
@@ -194,6 +201,14 @@ pub fn parse_sip_coefficients(header: &HashMap<String, String>) -> Option<SipCoe
     }
 }
 
+/// Image axis length, falling back to the IMAGEW/IMAGEH keys that
+/// Astrometry.net writes into its header-only (NAXIS = 0) WCS files
+fn image_dimension(header: &HashMap<String, String>, axis_key: &str, image_key: &str) -> Option<u32> {
+    parse_u32_header_value(header, axis_key)
+        .filter(|&n| n > 0)
+        .or_else(|| parse_u32_header_value(header, image_key).filter(|&n| n > 0))
+}
+
 /// Build a WcsResult from a parsed FITS header map
 pub fn wcs_from_header_map(header: &HashMap<String, String>) -> WcsResult {
     let sip = parse_sip_coefficients(header);
@@ -212,8 +227,8 @@ pub fn wcs_from_header_map(header: &HashMap<String, String>) -> WcsResult {
         cd2_2: parse_f64_header_value(header, "CD2_2"),
         ctype1: parse_string_header_value(header, "CTYPE1"),
         ctype2: parse_string_header_value(header, "CTYPE2"),
-        naxis1: parse_u32_header_value(header, "NAXIS1"),
-        naxis2: parse_u32_header_value(header, "NAXIS2"),
+        naxis1: image_dimension(header, "NAXIS1", "IMAGEW"),
+        naxis2: image_dimension(header, "NAXIS2", "IMAGEH"),
         sip,
     }
 }
@@ -232,6 +247,76 @@ pub fn parse_wcs_result_from_fits_bytes(data: &[u8]) -> Result<WcsResult, PlateS
     Ok(wcs)
 }
 
+/// Read RA/Dec and optics hints from the FITS header of `image_path`.
+/// Non-FITS or unreadable files yield empty hints.
+pub fn read_fits_hints(image_path: &str) -> FitsHints {
+    let mut data = Vec::new();
+    let read = std::fs::File::open(image_path)
+        .and_then(|file| file.take(MAX_HINT_HEADER_BYTES).read_to_end(&mut data));
+    if read.is_err() || !data.starts_with(b"SIMPLE") {
+        return FitsHints::default();
+    }
+    fits_hints_from_header(&parse_fits_header_map_from_bytes(&data))
+}
+
+pub fn fits_hints_from_header(header: &HashMap<String, String>) -> FitsHints {
+    // Mount pointing (RA/DEC, decimal degrees) is preferred over the target
+    // coordinates capture software writes as sexagesimal OBJCTRA/OBJCTDEC
+    let ra = ["RA", "OBJCTRA"].iter().find_map(|key| {
+        let value = parse_string_header_value(header, key)?;
+        let sexagesimal = value.contains(|c: char| c.is_whitespace() || c == ':');
+        if *key == "OBJCTRA" && !sexagesimal {
+            // Some writers store OBJCTRA as decimal hours
+            return value.parse::<f64>().ok().map(|hours| hours * 15.0);
+        }
+        crate::astronomy::parse_ra_hms(value).ok()
+    });
+    let dec = ["DEC", "OBJCTDEC"].iter().find_map(|key| {
+        crate::astronomy::parse_dec_dms(parse_string_header_value(header, key)?).ok()
+    });
+
+    FitsHints {
+        ra,
+        dec,
+        focal_length_mm: parse_f64_header_value(header, "FOCALLEN").filter(|v| *v > 0.0),
+        pixel_size_um: parse_f64_header_value(header, "XPIXSZ").filter(|v| *v > 0.0),
+        image_width: parse_u32_header_value(header, "NAXIS1").filter(|v| *v > 0),
+    }
+}
+
+/// Scale range (low, high) for the header's image scale, expressed in the
+/// solver's configured scale units
+pub fn fits_hint_scale_range(hints: &FitsHints, scale_units: &str) -> Option<(f64, f64)> {
+    let arcsec_per_pixel = hints.pixel_scale_arcsec()?;
+    let scale = match scale_units {
+        "arcsecperpix" | "arcsec_per_pix" => arcsec_per_pixel,
+        "arcminwidth" | "arcmin_width" => {
+            arcsec_per_pixel * f64::from(hints.image_width?) / 60.0
+        }
+        _ => arcsec_per_pixel * f64::from(hints.image_width?) / 3600.0,
+    };
+    Some((
+        scale * (1.0 - HINT_SCALE_TOLERANCE),
+        scale * (1.0 + HINT_SCALE_TOLERANCE),
+    ))
+}
+
+/// Fill position and scale hints the user left empty from the FITS header
+pub fn apply_fits_hints(config: &mut PlateSolverConfig, hints: &FitsHints, scale_units: &str) {
+    if config.ra_hint.is_none() && config.dec_hint.is_none() {
+        if let (Some(ra), Some(dec)) = (hints.ra, hints.dec) {
+            config.ra_hint = Some(ra);
+            config.dec_hint = Some(dec);
+        }
+    }
+    if config.scale_low.is_none() && config.scale_high.is_none() {
+        if let Some((low, high)) = fits_hint_scale_range(hints, scale_units) {
+            config.scale_low = Some(low);
+            config.scale_high = Some(high);
+        }
+    }
+}
+
 /// Calculate FOV width and height from WCS result
 pub fn calculate_fov_from_wcs(wcs: &WcsResult) -> (Option<f64>, Option<f64>) {
     let (Some(naxis1), Some(naxis2)) = (wcs.naxis1, wcs.naxis2) else {
@@ -331,6 +416,56 @@ mod tests {
         assert!(fov_h.unwrap() > 0.2 && fov_h.unwrap() < 0.3);
     }
 
+    #[test]
+    fn test_parse_astrometry_net_wcs_file() {
+        // Header-only WCS file as served by nova.astrometry.net /wcs_file/<job_id>
+        let fits_data = build_test_fits(&[
+            "SIMPLE  =                    T / Standard FITS file",
+            "BITPIX  =                    8 / ASCII or bytes array",
+            "NAXIS   =                    0 / Minimal header",
+            "EXTEND  =                    T / There may be FITS ext",
+            "WCSAXES =                    2 / no comment",
+            "CTYPE1  = 'RA---TAN-SIP' / TAN (gnomic) projection + SIP distortions",
+            "CTYPE2  = 'DEC--TAN-SIP' / TAN (gnomic) projection + SIP distortions",
+            "EQUINOX =               2000.0 / Equatorial coordinates definition (yr)",
+            "CRVAL1  =        83.8220766258 / RA  of reference point",
+            "CRVAL2  =       -5.39111530502 / DEC of reference point",
+            "CRPIX1  =        1024.46374512 / X reference pixel",
+            "CRPIX2  =        767.870330811 / Y reference pixel",
+            "CUNIT1  = 'deg     ' / X pixel scale units",
+            "CUNIT2  = 'deg     ' / Y pixel scale units",
+            "CD1_1   =    -0.00046432718941 / Transformation matrix",
+            "CD1_2   =    5.26451385838E-06 / no comment",
+            "CD2_1   =   -5.24609447457E-06 / no comment",
+            "CD2_2   =    -0.00046414812958 / no comment",
+            "IMAGEW  =                 2048 / Image width,  in pixels.",
+            "IMAGEH  =                 1536 / Image height, in pixels.",
+            "A_ORDER =                    2 / Polynomial order, axis 1",
+            "A_0_2   =   -2.52924620446E-07 / no comment",
+            "A_1_1   =    9.04463457628E-07 / no comment",
+            "A_2_0   =   -1.97470563364E-07 / no comment",
+            "B_ORDER =                    2 / Polynomial order, axis 2",
+            "B_0_2   =    2.97066063346E-07 / no comment",
+            "B_1_1   =   -1.23455282236E-07 / no comment",
+            "B_2_0   =    1.29519919312E-06 / no comment",
+        ]);
+
+        let wcs = parse_wcs_result_from_fits_bytes(&fits_data).unwrap();
+        assert!(approx_eq(wcs.crval1.unwrap(), 83.8220766258));
+        assert!(approx_eq(wcs.crval2.unwrap(), -5.39111530502));
+        assert_eq!(wcs.naxis1, Some(2048));
+        assert_eq!(wcs.naxis2, Some(1536));
+
+        let sip = wcs.sip.clone().expect("SIP terms should be parsed");
+        assert_eq!(sip.a_order, Some(2));
+        assert_eq!(sip.a_coeffs.len(), 3);
+        assert_eq!(sip.b_coeffs.len(), 3);
+
+        let (fov_w, fov_h) = calculate_fov_from_wcs(&wcs);
+        assert!((fov_w.unwrap() - 0.951).abs() < 0.01, "fov width {:?}", fov_w);
+        assert!((fov_h.unwrap() - 0.713).abs() < 0.01, "fov height {:?}", fov_h);
+    }
+
     // ------------------------------------------------------------------------
     // parse_ini_value Tests
     // ------------------------------------------------------------------------
@@ -573,6 +708,118 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // ------------------------------------------------------------------------
+    // FITS Solve Hint Tests
+    // ------------------------------------------------------------------------
+
+    fn hint_test_config() -> PlateSolverConfig {
+        PlateSolverConfig {
+            solver_type: super::super::types::PlateSolverType::LocalAstrometry,
+            image_path: "light.fits".to_string(),
+            ra_hint: None,
+            dec_hint: None,
+            radius_hint: None,
+            scale_low: None,
+            scale_high: None,
+            downsample: None,
+            timeout_seconds: None,
+        }
+    }
+
+    #[test]
+    fn test_fits_hints_from_header_with_hint_cards() {
+        let fits_data = build_test_fits(&[
+            "SIMPLE  =                    T",
+            "BITPIX  =                   16",
+            "NAXIS   =                    2",
+            "NAXIS1  =                 4656",
+            "NAXIS2  =                 3520",
+            "OBJCTRA = '05 35 17.3'         / Target right ascension",
+            "OBJCTDEC= '-05 23 28'          / Target declination",
+            "FOCALLEN=                 400. / Focal length in mm",
+            "XPIXSZ  =                 3.76 / Pixel width in microns",
+        ]);
+        let hints = fits_hints_from_header(&parse_fits_header_map_from_bytes(&fits_data));
+
+        assert!(approx_eq(hints.ra.unwrap(), 83.822_083));
+        assert!(approx_eq(hints.dec.unwrap(), -5.391_111));
+        assert_eq!(hints.focal_length_mm, Some(400.0));
+        assert_eq!(hints.pixel_size_um, Some(3.76));
+        assert_eq!(hints.image_width, Some(4656));
+        assert!(approx_eq(hints.pixel_scale_arcsec().unwrap(), 1.938_889));
+
+        let (low, high) = fits_hint_scale_range(&hints, "arcsecperpix").unwrap();
+        assert!(low < 1.94 && high > 1.94);
+        let (low, high) = fits_hint_scale_range(&hints, "deg_width").unwrap();
+        assert!(low < 2.507 && high > 2.507);
+
+        let mut config = hint_test_config();
+        apply_fits_hints(&mut config, &hints, "deg_width");
+        assert_eq!(config.ra_hint, hints.ra);
+        assert_eq!(config.dec_hint, hints.dec);
+        assert_eq!(config.scale_low, Some(low));
+        assert_eq!(config.scale_high, Some(high));
+    }
+
+    #[test]
+    fn test_fits_hints_prefer_mount_pointing_and_keep_user_hints() {
+        let fits_data = build_test_fits(&[
+            "SIMPLE  =                    T",
+            "RA      =            83.63308 / Mount RA in degrees",
+            "DEC     =            22.01450 / Mount Dec in degrees",
+            "OBJCTRA = '05 35 17.3'",
+            "OBJCTDEC= '-05 23 28'",
+        ]);
+        let hints = fits_hints_from_header(&parse_fits_header_map_from_bytes(&fits_data));
+        assert!(approx_eq(hints.ra.unwrap(), 83.63308));
+        assert!(approx_eq(hints.dec.unwrap(), 22.0145));
+        assert!(hints.pixel_scale_arcsec().is_none());
+
+        let mut config = hint_test_config();
+        config.ra_hint = Some(10.0);
+        config.dec_hint = Some(20.0);
+        apply_fits_hints(&mut config, &hints, "deg_width");
+        assert_eq!(config.ra_hint, Some(10.0));
+        assert_eq!(config.dec_hint, Some(20.0));
+        assert!(config.scale_low.is_none());
+    }
+
+    #[test]
+    fn test_fits_hints_without_hint_cards() {
+        let fits_data = build_test_fits(&[
+            "SIMPLE  =                    T",
+            "BITPIX  =                   16",
+            "NAXIS   =                    2",
+            "NAXIS1  =                 1024",
+            "NAXIS2  =                  768",
+        ]);
+        let hints = fits_hints_from_header(&parse_fits_header_map_from_bytes(&fits_data));
+        assert_eq!(
+            hints,
+            FitsHints {
+                image_width: Some(1024),
+                ..FitsHints::default()
+            }
+        );
+
+        let mut config = hint_test_config();
+        apply_fits_hints(&mut config, &hints, "deg_width");
+        assert!(config.ra_hint.is_none());
+        assert!(config.scale_low.is_none());
+    }
+
+    #[test]
+    fn test_read_fits_hints_ignores_non_fits_files() {
+        let path = std::env::temp_dir().join(format!(
+            "skymap_fits_hints_{}.jpg",
+            crate::utils::generate_id("t")
+        ));
+        std::fs::write(&path, b"\xFF\xD8\xFF\xE0 not a fits file").unwrap();
+        assert_eq!(read_fits_hints(&path.to_string_lossy()), FitsHints::default());
+        assert_eq!(read_fits_hints("/nonexistent/light.fits"), FitsHints::default());
+        let _ = std::fs::remove_file(&path);
+    }
+
     // ------------------------------------------------------------------------
     // build_test_fits helper (used by tests above)
     // ------------------------------------------------------------------------
@@ -5,8 +5,10 @@ use std::process::Command;
 
 use tauri::AppHandle;
 
-use super::types::{LocalSolveWorkspace, PlateSolverError, SolverInfo};
-use super::{astap, astrometry};
+use super::types::{
+    FocusMetrics, LocalSolveWorkspace, PlateSolverError, QuadrantHfd, SolverInfo, StarDetection,
+};
+use super::{astap, astrometry, watney};
 
 /// Get default index path for a given solver type (platform-specific)
 pub fn get_default_index_path_internal(solver_type: &str) -> Option<String> {
@@ -75,6 +77,7 @@ pub async fn validate_solver_path(
         "astrometry_net" | "astrometry" => {
             astrometry::validate_astrometry_executable(&resolved).is_some()
         }
+        "watney" => watney::validate_watney_executable(&resolved).is_some(),
         _ => false,
     })
 }
@@ -101,15 +104,50 @@ pub fn resolve_executable_path(candidate: &str) -> Option<String> {
         .map(|entry| entry.to_string_lossy().to_string())
 }
 
-pub fn resolve_preferred_executable(
+/// First executable accepted by `validate`, with what it returned. A resolvable
+/// `preferred` path is the only one tried; otherwise each candidate is checked
+/// in turn, so another solver's binary on a shared search path is skipped.
+pub fn resolve_preferred_executable<T>(
     preferred: Option<&str>,
     candidates: &[String],
-) -> Option<String> {
-    preferred.and_then(resolve_executable_path).or_else(|| {
-        candidates
+    validate: impl Fn(&str) -> Option<T>,
+) -> Option<(String, T)> {
+    let validated = |path: String| validate(&path).map(|value| (path, value));
+    match preferred.and_then(resolve_executable_path) {
+        Some(path) => validated(path),
+        None => candidates
             .iter()
-            .find_map(|candidate| resolve_executable_path(candidate))
-    })
+            .filter_map(|candidate| resolve_executable_path(candidate))
+            .find_map(validated),
+    }
+}
+
+fn is_bare_executable_name(candidate: &str) -> bool {
+    !candidate.contains('/') && !candidate.contains('\\') && !candidate.contains(std::path::MAIN_SEPARATOR)
+}
+
+/// Search order for a solver: user-configured paths first, then the built-in
+/// defaults. A configured directory is searched for the solver's executable
+/// names (the bare names in `defaults`, which also resolve through PATH).
+pub fn solver_search_candidates(extra_paths: &[String], defaults: Vec<String>) -> Vec<String> {
+    let executable_names: Vec<&String> = defaults.iter().filter(|c| is_bare_executable_name(c)).collect();
+    let mut candidates = Vec::new();
+
+    for extra in extra_paths.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        let path = Path::new(extra);
+        if path.is_dir() {
+            candidates.extend(
+                executable_names
+                    .iter()
+                    .map(|name| path.join(name.as_str()).to_string_lossy().to_string()),
+            );
+        } else {
+            candidates.push(extra.to_string());
+        }
+    }
+
+    candidates.extend(defaults);
+    candidates
 }
 
 pub fn command_succeeds(path: &str, args: &[&str]) -> bool {
@@ -177,6 +215,85 @@ pub fn format_dec_dms(dec_deg: f64) -> String {
     format!("{}{}° {:02}' {:05.2}\"", sign, d, m, s)
 }
 
+// ============================================================================
+// Focus Metrics
+// ============================================================================
+
+/// FWHM ≈ HFD × 0.7 for a Gaussian-like star profile
+const HFD_TO_FWHM: f64 = 0.7;
+
+/// Quadrant HFD spread (percent of median) above which tilt is flagged
+const TILT_WARNING_PERCENT: f64 = 10.0;
+
+/// Minimum stars per quadrant for the tilt comparison to be meaningful
+const MIN_QUADRANT_STARS: usize = 3;
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    Some(if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+/// Aggregate focus and tilt metrics from a detected star list
+#[tauri::command]
+pub fn calculate_focus_metrics(stars: Vec<StarDetection>) -> FocusMetrics {
+    let stars: Vec<&StarDetection> = stars.iter().filter(|s| s.hfd.is_finite() && s.hfd > 0.0).collect();
+    let mut hfds: Vec<f64> = stars.iter().map(|s| s.hfd).collect();
+    let median_hfd = median(&mut hfds);
+
+    let hfd_std_dev = (hfds.len() > 1).then(|| {
+        let mean = hfds.iter().sum::<f64>() / hfds.len() as f64;
+        (hfds.iter().map(|h| (h - mean).powi(2)).sum::<f64>() / (hfds.len() - 1) as f64).sqrt()
+    });
+
+    // Split the field at the middle of the star extents
+    let (mut min_x, mut max_x, mut min_y, mut max_y) = (f64::MAX, f64::MIN, f64::MAX, f64::MIN);
+    for star in &stars {
+        min_x = min_x.min(star.x);
+        max_x = max_x.max(star.x);
+        min_y = min_y.min(star.y);
+        max_y = max_y.max(star.y);
+    }
+    let (mid_x, mid_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+    let mut buckets: [Vec<f64>; 4] = Default::default();
+    for star in &stars {
+        let index = usize::from(star.x >= mid_x) + 2 * usize::from(star.y >= mid_y);
+        buckets[index].push(star.hfd);
+    }
+    let counts_ok = buckets.iter().all(|b| b.len() >= MIN_QUADRANT_STARS);
+    let [top_left, top_right, bottom_left, bottom_right] = buckets.map(|mut b| median(&mut b));
+    let quadrants = QuadrantHfd { top_left, top_right, bottom_left, bottom_right };
+
+    let tilt_percent = match (counts_ok, median_hfd) {
+        (true, Some(median_hfd)) => {
+            let values = [top_left, top_right, bottom_left, bottom_right].map(|v| v.unwrap_or(median_hfd));
+            let best = values.iter().cloned().fold(f64::MAX, f64::min);
+            let worst = values.iter().cloned().fold(f64::MIN, f64::max);
+            Some((worst - best) / median_hfd * 100.0)
+        }
+        _ => None,
+    };
+
+    FocusMetrics {
+        star_count: stars.len() as u32,
+        median_hfd,
+        hfd_std_dev,
+        fwhm_estimate: median_hfd.map(|hfd| hfd * HFD_TO_FWHM),
+        eccentricity: None,
+        quadrants,
+        tilt_warning: tilt_percent.is_some_and(|t| t > TILT_WARNING_PERCENT),
+        tilt_percent,
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -185,6 +302,118 @@ pub fn format_dec_dms(dec_deg: f64) -> String {
 mod tests {
     use super::*;
 
+    // ------------------------------------------------------------------------
+    // Solver Search Path Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_configured_solver_path_found_before_defaults() {
+        let root = std::env::temp_dir().join(format!("skymap_solver_paths_{}", crate::utils::generate_id("t")));
+        let custom_dir = root.join("custom");
+        let default_dir = root.join("default");
+        std::fs::create_dir_all(&custom_dir).unwrap();
+        std::fs::create_dir_all(&default_dir).unwrap();
+        std::fs::write(custom_dir.join("my-solver"), b"").unwrap();
+        std::fs::write(default_dir.join("my-solver"), b"").unwrap();
+
+        let default_exe = default_dir.join("my-solver").to_string_lossy().to_string();
+        let defaults = vec![default_exe.clone(), "my-solver".to_string()];
+        let missing = root.join("missing").join("my-solver").to_string_lossy().to_string();
+
+        // A configured directory is searched for the bare executable names
+        let extra = vec![missing.clone(), custom_dir.to_string_lossy().to_string()];
+        let candidates = solver_search_candidates(&extra, defaults.clone());
+        assert_eq!(candidates[0], missing);
+        assert_eq!(candidates.last().unwrap(), "my-solver");
+        let (found, _) = resolve_preferred_executable(None, &candidates, |_| Some(())).unwrap();
+        assert_eq!(PathBuf::from(found), custom_dir.join("my-solver"));
+
+        // Nonexistent configured paths are skipped in favour of the defaults
+        let candidates = solver_search_candidates(&[missing, "  ".to_string()], defaults);
+        assert_eq!(resolve_preferred_executable(None, &candidates, |_| Some(())), Some((default_exe, ())));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_shared_solver_path_skips_other_solvers_executable() {
+        let root = std::env::temp_dir().join(format!("skymap_solver_shared_{}", crate::utils::generate_id("t")));
+        std::fs::create_dir_all(&root).unwrap();
+        let astap = root.join("astap_cli").to_string_lossy().to_string();
+        let watney = root.join("watney-solve").to_string_lossy().to_string();
+        std::fs::write(&astap, b"").unwrap();
+        std::fs::write(&watney, b"").unwrap();
+
+        // Both files sit in the shared list; only the one the validator accepts wins
+        let candidates = solver_search_candidates(&[astap.clone(), watney.clone()], Vec::new());
+        let is_watney = |path: &str| path.ends_with("watney-solve").then_some(());
+        assert_eq!(resolve_preferred_executable(None, &candidates, is_watney), Some((watney, ())));
+        assert_eq!(resolve_preferred_executable(None, &[astap], is_watney), None);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    // ------------------------------------------------------------------------
+    // Focus Metrics Tests
+    // ------------------------------------------------------------------------
+
+    fn star_grid(hfd_at: impl Fn(f64, f64) -> f64) -> Vec<StarDetection> {
+        let mut stars = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                let (x, y) = (i as f64 * 400.0 + 100.0, j as f64 * 300.0 + 100.0);
+                stars.push(StarDetection {
+                    x,
+                    y,
+                    hfd: hfd_at(x, y),
+                    flux: 10000.0,
+                    snr: 40.0,
+                    ra: None,
+                    dec: None,
+                    magnitude: None,
+                });
+            }
+        }
+        stars
+    }
+
+    #[test]
+    fn test_focus_metrics_uniform_field() {
+        let metrics = calculate_focus_metrics(star_grid(|_, _| 2.5));
+        assert_eq!(metrics.star_count, 100);
+        assert!((metrics.median_hfd.unwrap() - 2.5).abs() < 1e-9);
+        assert!(metrics.hfd_std_dev.unwrap() < 1e-9);
+        assert!((metrics.fwhm_estimate.unwrap() - 1.75).abs() < 1e-9);
+        assert!(metrics.tilt_percent.unwrap() < 1e-9);
+        assert!(!metrics.tilt_warning);
+        assert!(metrics.eccentricity.is_none());
+    }
+
+    #[test]
+    fn test_focus_metrics_gradient_across_x_warns() {
+        // HFD grows from 2.0 on the left edge to ~3.5 on the right edge
+        let metrics = calculate_focus_metrics(star_grid(|x, _| 2.0 + x / 2500.0));
+        let q = &metrics.quadrants;
+        assert!(q.top_right.unwrap() > q.top_left.unwrap());
+        assert!(q.bottom_right.unwrap() > q.bottom_left.unwrap());
+        assert!(metrics.tilt_percent.unwrap() > 10.0, "tilt {:?}", metrics.tilt_percent);
+        assert!(metrics.tilt_warning);
+    }
+
+    #[test]
+    fn test_focus_metrics_empty_and_sparse() {
+        let metrics = calculate_focus_metrics(Vec::new());
+        assert_eq!(metrics.star_count, 0);
+        assert!(metrics.median_hfd.is_none());
+        assert!(metrics.tilt_percent.is_none());
+
+        let few: Vec<StarDetection> = star_grid(|_, _| 2.0).into_iter().take(4).collect();
+        let metrics = calculate_focus_metrics(few);
+        assert!(metrics.median_hfd.is_some());
+        assert!(metrics.tilt_percent.is_none());
+        assert!(!metrics.tilt_warning);
+    }
+
     // ------------------------------------------------------------------------
     // Coordinate Formatting Tests
     // ------------------------------------------------------------------------
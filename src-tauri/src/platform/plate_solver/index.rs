@@ -296,8 +296,32 @@ pub async fn download_index(
     index: DownloadableIndex,
     dest_path: String,
 ) -> Result<(), PlateSolverError> {
+    let result = download_index_to(&app, &index, &dest_path).await;
+    let size = result.as_ref().copied().unwrap_or(0);
+    crate::data::append_download_history(
+        &app,
+        vec![crate::data::DownloadEntry::now(&index.name, size, &index.url, result.is_ok())],
+    );
+    result.map(|_| ())
+}
+
+/// Stream an index file to disk, returning the number of bytes written
+async fn download_index_to(
+    app: &AppHandle,
+    index: &DownloadableIndex,
+    dest_path: &str,
+) -> Result<u64, PlateSolverError> {
     log::info!("Downloading index {} to {}", index.name, dest_path);
 
+    let required = index.size_mb * 1024 * 1024;
+    crate::platform::path_config::ensure_disk_space(std::path::Path::new(dest_path), required)
+        .map_err(|e| match e {
+            crate::data::StorageError::InsufficientSpace { required, available } => {
+                PlateSolverError::InsufficientSpace { required, available }
+            }
+            other => PlateSolverError::DownloadFailed(other.to_string()),
+        })?;
+
     let client = reqwest::Client::new();
     let response = client
         .get(&index.url)
@@ -310,7 +334,7 @@ pub async fn download_index(
         .unwrap_or(index.size_mb * 1024 * 1024);
     let mut downloaded = 0u64;
 
-    let mut file = std::fs::File::create(&dest_path)?;
+    let mut file = std::fs::File::create(dest_path)?;
     let mut stream = response.bytes_stream();
 
     use futures_util::StreamExt;
@@ -333,7 +357,7 @@ pub async fn download_index(
     }
 
     log::info!("Index {} downloaded successfully", index.name);
-    Ok(())
+    Ok(downloaded)
 }
 
 #[tauri::command]
@@ -1,5 +1,5 @@
 //! Plate solving module
-//! Integrates with ASTAP and Astrometry.net for astronomical plate solving
+//! Integrates with ASTAP, Astrometry.net and Watney for astronomical plate solving
 
 pub mod astap;
 pub mod astrometry;
@@ -9,6 +9,8 @@ pub mod helpers;
 pub mod index;
 pub mod online;
 pub mod types;
+pub mod watney;
+pub mod wcs;
 
 use std::path::PathBuf;
 use std::process::Command;
@@ -20,6 +22,39 @@ use types::SolveProgressEvent;
 
 static ACTIVE_SOLVE_PID: Mutex<Option<u32>> = Mutex::new(None);
 
+/// Take the running solver's PID so nothing else tries to kill or clear it
+fn take_active_solve_pid() -> Option<u32> {
+    ACTIVE_SOLVE_PID.lock().unwrap().take()
+}
+
+/// Publishes a solver child's PID in `ACTIVE_SOLVE_PID` for the lifetime of the
+/// guard and clears it on every exit path, unless it was already taken by
+/// `cancel_plate_solve` or the timeout handler
+pub(super) struct ActiveSolveGuard {
+    pid: u32,
+}
+
+impl ActiveSolveGuard {
+    pub(super) fn register(pid: u32) -> Self {
+        *ACTIVE_SOLVE_PID.lock().unwrap() = Some(pid);
+        Self { pid }
+    }
+
+    /// The PID was taken while the child ran, i.e. the child was killed on purpose
+    pub(super) fn was_cancelled(&self) -> bool {
+        *ACTIVE_SOLVE_PID.lock().unwrap() != Some(self.pid)
+    }
+}
+
+impl Drop for ActiveSolveGuard {
+    fn drop(&mut self) {
+        let mut guard = ACTIVE_SOLVE_PID.lock().unwrap();
+        if *guard == Some(self.pid) {
+            *guard = None;
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn detect_plate_solvers(app: AppHandle) -> Result<Vec<SolverInfo>, PlateSolverError> {
     let mut solvers = Vec::new();
@@ -32,6 +67,7 @@ pub async fn detect_plate_solvers(app: AppHandle) -> Result<Vec<SolverInfo>, Pla
         (config.solver_type == "astap")
             .then_some(config.index_path.as_deref())
             .flatten(),
+        &config.extra_solver_paths,
     ) {
         solvers.push(astap);
     }
@@ -43,10 +79,23 @@ pub async fn detect_plate_solvers(app: AppHandle) -> Result<Vec<SolverInfo>, Pla
         (config.solver_type == "astrometry_net")
             .then_some(config.index_path.as_deref())
             .flatten(),
+        &config.extra_solver_paths,
     ) {
         solvers.push(astrometry);
     }
 
+    if let Some(watney) = watney::detect_watney_solver(
+        (config.solver_type == "watney")
+            .then_some(config.executable_path.as_deref())
+            .flatten(),
+        (config.solver_type == "watney")
+            .then_some(config.index_path.as_deref())
+            .flatten(),
+        &config.extra_solver_paths,
+    ) {
+        solvers.push(watney);
+    }
+
     // Always add online Astrometry.net as fallback
     solvers.push(SolverInfo {
         solver_type: PlateSolverType::AstrometryNet,
@@ -67,31 +116,46 @@ pub async fn detect_plate_solvers(app: AppHandle) -> Result<Vec<SolverInfo>, Pla
 
 #[tauri::command]
 pub async fn cancel_plate_solve() -> Result<(), PlateSolverError> {
-    let pid = {
-        let mut guard = ACTIVE_SOLVE_PID.lock().unwrap();
-        guard.take()
+    kill_active_solver();
+    Ok(())
+}
+
+/// Terminate the running local solver process, if any; returns whether one
+/// was running
+pub(crate) fn kill_active_solver() -> bool {
+    let Some(pid) = take_active_solve_pid() else {
+        return false;
     };
-    if let Some(pid) = pid {
-        log::info!("Cancelling plate solve process with PID {}", pid);
-        #[cfg(target_os = "windows")]
-        {
-            let _ = Command::new("taskkill")
-                .args(["/F", "/T", "/PID", &pid.to_string()])
-                .output();
-        }
-        #[cfg(not(target_os = "windows"))]
-        {
-            unsafe {
-                libc::kill(pid as i32, libc::SIGTERM);
-            }
+    log::info!("Cancelling plate solve process with PID {}", pid);
+    terminate_process(pid);
+    true
+}
+
+/// Ask a child process to terminate (`taskkill /T` on Windows, SIGTERM elsewhere)
+pub(crate) fn terminate_process(pid: u32) {
+    #[cfg(target_os = "windows")]
+    {
+        let _ = Command::new("taskkill")
+            .args(["/F", "/T", "/PID", &pid.to_string()])
+            .output();
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        unsafe {
+            libc::kill(pid as i32, libc::SIGTERM);
         }
     }
-    Ok(())
+}
+
+/// The saved solver config when it was saved for `solver_type`; another
+/// solver's executable and index paths must not steer this one's detection
+fn saved_config_for<'a>(saved: &'a SolverConfig, solver_type: &str) -> Option<&'a SolverConfig> {
+    (saved.solver_type == solver_type).then_some(saved)
 }
 
 #[tauri::command]
 pub async fn plate_solve(
-    _app: AppHandle,
+    app: AppHandle,
     config: PlateSolverConfig,
 ) -> Result<PlateSolveResult, PlateSolverError> {
     let start = std::time::Instant::now();
@@ -104,10 +168,22 @@ pub async fn plate_solve(
         )));
     }
 
+    let saved = config::load_solver_config(app).await.unwrap_or_default();
+    let mut config = config;
+    if saved.auto_hints {
+        let hints = fits::read_fits_hints(&config.image_path);
+        fits::apply_fits_hints(&mut config, &hints, &saved.astrometry_scale_units);
+    }
+
     let result = match config.solver_type {
         PlateSolverType::Astap => astap::solve_with_astap(&config).await,
         PlateSolverType::LocalAstrometry => {
-            astrometry::solve_with_local_astrometry(&config, None).await
+            // Apply the saved depth/scale/verification options to the local solve
+            let saved = saved_config_for(&saved, "astrometry_net");
+            astrometry::solve_with_local_astrometry(&config, saved).await
+        }
+        PlateSolverType::Watney => {
+            watney::solve_with_watney(&config, saved_config_for(&saved, "watney")).await
         }
         PlateSolverType::AstrometryNet => online::solve_with_online_astrometry(&config).await,
     };
@@ -158,10 +234,11 @@ pub async fn solve_image_local(
         },
     );
 
-    let solver_config = PlateSolverConfig {
+    let mut solver_config = PlateSolverConfig {
         solver_type: match config.solver_type.as_str() {
             "astap" => PlateSolverType::Astap,
             "astrometry_net" => PlateSolverType::LocalAstrometry,
+            "watney" => PlateSolverType::Watney,
             _ => PlateSolverType::AstrometryNet,
         },
         image_path: params.image_path.clone(),
@@ -173,6 +250,10 @@ pub async fn solve_image_local(
         downsample: Some(config.downsample),
         timeout_seconds: Some(config.timeout_seconds),
     };
+    if config.auto_hints {
+        let hints = fits::read_fits_hints(&solver_config.image_path);
+        fits::apply_fits_hints(&mut solver_config, &hints, &config.astrometry_scale_units);
+    }
 
     // Emit: solving
     let _ = app.emit(
@@ -186,11 +267,16 @@ pub async fn solve_image_local(
 
     let result = match solver_config.solver_type {
         PlateSolverType::Astap => {
-            astap::solve_with_astap_enhanced(&solver_config, Some(&config)).await
+            let progress_app = app.clone();
+            let progress: astap::AstapProgressCallback = std::sync::Arc::new(move |event| {
+                let _ = progress_app.emit("solve-progress", event);
+            });
+            astap::solve_with_astap_enhanced(&solver_config, Some(&config), Some(progress)).await
         }
         PlateSolverType::LocalAstrometry => {
             astrometry::solve_with_local_astrometry(&solver_config, Some(&config)).await
         }
+        PlateSolverType::Watney => watney::solve_with_watney(&solver_config, Some(&config)).await,
         PlateSolverType::AstrometryNet => {
             online::solve_with_online_astrometry(&solver_config).await
         }
@@ -254,20 +340,75 @@ pub async fn solve_image_local(
 
 // Re-export all public types
 pub use types::{
-    AstapDatabaseInfo, AstrometryIndex, DownloadableIndex, DownloadableIndexFull,
-    ImageAnalysisResult, IndexDownloadProgress, IndexInfo, LocalInvocationDiagnostics,
+    AstapDatabaseInfo, AstrometryIndex, DownloadableIndex, DownloadableIndexFull, FitsHints,
+    FocusMetrics, ImageAnalysisResult, IndexDownloadProgress, IndexInfo, LocalInvocationDiagnostics,
     LocalSolveWorkspace, LocalSolverProfileId, OnlineAnnotation, OnlineSolveConfig,
     OnlineSolveProgress, OnlineSolveResult, PlateSolveResult, PlateSolverConfig, PlateSolverError,
     PlateSolverType, ScaleRange, SipCoefficients, SolveParameters, SolveResult, SolverConfig,
-    SolverInfo, StarDetection, WcsResult,
+    QuadrantHfd, SolverInfo, StarDetection, WcsResult,
 };
 
 // Re-export commands from submodules
 pub use astap::{analyse_image, extract_stars, get_astap_databases, recommend_astap_database};
 pub use config::{load_solver_config, save_solver_config};
-pub use helpers::{get_default_index_path, get_solver_info, validate_solver_path};
+pub use helpers::{calculate_focus_metrics, get_default_index_path, get_solver_info, validate_solver_path};
 pub use index::{
     delete_index, download_index, get_available_indexes, get_downloadable_indexes,
     get_installed_indexes, get_recommended_indexes, get_solver_indexes,
 };
-pub use online::{cancel_online_solve, solve_online};
+pub use online::{cancel_online_solve, clear_astrometry_session, solve_online};
+pub(crate) use online::cancel_all_online_solves;
+pub use wcs::write_wcs_file;
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ------------------------------------------------------------------------
+    // Saved Config Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_saved_astap_config_not_used_for_other_solvers() {
+        let saved = SolverConfig {
+            solver_type: "astap".to_string(),
+            executable_path: Some("/opt/astap/astap_cli".to_string()),
+            index_path: Some("/opt/astap/data".to_string()),
+            ..SolverConfig::default()
+        };
+        assert!(saved_config_for(&saved, "astrometry_net").is_none());
+        assert!(saved_config_for(&saved, "watney").is_none());
+
+        let saved = SolverConfig { solver_type: "astrometry_net".to_string(), ..saved };
+        let used = saved_config_for(&saved, "astrometry_net").unwrap();
+        assert_eq!(used.executable_path.as_deref(), Some("/opt/astap/astap_cli"));
+    }
+
+    // ------------------------------------------------------------------------
+    // ActiveSolveGuard Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_active_solve_guard_cancel_and_normal_exit() {
+        // Killed child: cancel takes the PID, the guard reports cancellation
+        let active = ActiveSolveGuard::register(424242);
+        assert_eq!(*ACTIVE_SOLVE_PID.lock().unwrap(), Some(424242));
+        assert_eq!(take_active_solve_pid(), Some(424242));
+        assert!(active.was_cancelled());
+        let err = astap::cancelled_error_if(active.was_cancelled()).unwrap_err();
+        assert!(matches!(err, types::PlateSolverError::Cancelled));
+        drop(active);
+        assert!(ACTIVE_SOLVE_PID.lock().unwrap().is_none());
+
+        // Normal exit: not cancelled and the PID is cleared on drop
+        let active = ActiveSolveGuard::register(424243);
+        assert!(!active.was_cancelled());
+        assert!(astap::cancelled_error_if(active.was_cancelled()).is_ok());
+        drop(active);
+        assert!(ACTIVE_SOLVE_PID.lock().unwrap().is_none());
+    }
+}
@@ -3,9 +3,11 @@
 
 use std::collections::HashMap;
 use std::fs;
+use std::future::Future;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use once_cell::sync::Lazy;
 use tauri::{AppHandle, Emitter};
@@ -28,6 +30,75 @@ pub(super) async fn solve_with_online_astrometry(
 static ACTIVE_ONLINE_SOLVES: Lazy<Mutex<HashMap<String, Arc<AtomicBool>>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 static ACTIVE_ONLINE_OPERATION_ID: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+/// Astrometry.net submission ids mapped to the operation that uploaded them
+static ONLINE_SUBMISSIONS: Lazy<Mutex<HashMap<u64, String>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Astrometry.net session keys by base URL and API key
+static ASTROMETRY_SESSIONS: Lazy<Mutex<HashMap<String, CachedSession>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+/// How long a cached session key is reused before logging in again
+const SESSION_VALIDITY: Duration = Duration::from_secs(30 * 60);
+
+struct CachedSession {
+    key: String,
+    issued_at: Instant,
+}
+
+/// Adaptive poll intervals: quick while a solve is young, slower as it drags on
+const ADAPTIVE_POLL_STEPS: [(u64, Duration); 3] = [
+    (0, Duration::from_secs(2)),
+    (30, Duration::from_secs(5)),
+    (120, Duration::from_secs(10)),
+];
+/// Granularity at which a poll wait notices cancellation
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+/// Consecutive failed polls after which the solve is abandoned
+const MAX_CONSECUTIVE_POLL_ERRORS: u32 = 5;
+/// Upper bound on the backoff between failed polls
+const MAX_POLL_BACKOFF: Duration = Duration::from_secs(60);
+
+/// How long to wait between Astrometry.net status polls
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PollSchedule {
+    Fixed(Duration),
+    /// Interval grows with elapsed time, see `ADAPTIVE_POLL_STEPS`
+    Adaptive,
+}
+
+impl PollSchedule {
+    fn from_config(poll_interval_seconds: Option<u32>) -> Self {
+        match poll_interval_seconds {
+            Some(seconds) if seconds > 0 => PollSchedule::Fixed(Duration::from_secs(seconds as u64)),
+            _ => PollSchedule::Adaptive,
+        }
+    }
+
+    fn interval(self, elapsed: Duration) -> Duration {
+        match self {
+            PollSchedule::Fixed(interval) => interval,
+            PollSchedule::Adaptive => ADAPTIVE_POLL_STEPS
+                .iter()
+                .rev()
+                .find(|(after_secs, _)| elapsed.as_secs() >= *after_secs)
+                .map_or(ADAPTIVE_POLL_STEPS[0].1, |(_, interval)| *interval),
+        }
+    }
+}
+
+/// Why a single status poll did not produce a value
+enum PollError {
+    /// Worth retrying, e.g. a dropped connection or a malformed response
+    Transient(PlateSolverError),
+    /// Ends the poll loop immediately
+    Fatal(PlateSolverError),
+}
+
+impl From<PlateSolverError> for PollError {
+    fn from(error: PlateSolverError) -> Self {
+        PollError::Fatal(error)
+    }
+}
 
 struct ActiveOnlineSolveGuard {
     operation_id: String,
@@ -44,6 +115,10 @@ impl Drop for ActiveOnlineSolveGuard {
                 *guard = None;
             }
         }
+
+        if let Ok(mut guard) = ONLINE_SUBMISSIONS.lock() {
+            guard.retain(|_, operation_id| *operation_id != self.operation_id);
+        }
     }
 }
 
@@ -60,6 +135,119 @@ fn ensure_not_cancelled(cancel_flag: &Arc<AtomicBool>) -> Result<(), PlateSolver
     Ok(())
 }
 
+fn session_cache_key(base_url: &str, api_key: &str) -> String {
+    format!("{}|{}", base_url.trim_end_matches('/'), api_key)
+}
+
+/// Cached session key for this account, logging in through `login` when
+/// there is none or it has outlived `SESSION_VALIDITY`
+async fn astrometry_session<F, Fut>(
+    base_url: &str,
+    api_key: &str,
+    login: F,
+) -> Result<String, PlateSolverError>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<String, PlateSolverError>>,
+{
+    let cache_key = session_cache_key(base_url, api_key);
+    let cached = ASTROMETRY_SESSIONS.lock().unwrap().get(&cache_key).and_then(|session| {
+        (session.issued_at.elapsed() < SESSION_VALIDITY).then(|| session.key.clone())
+    });
+    if let Some(key) = cached {
+        return Ok(key);
+    }
+
+    let key = login().await?;
+    ASTROMETRY_SESSIONS.lock().unwrap().insert(
+        cache_key,
+        CachedSession {
+            key: key.clone(),
+            issued_at: Instant::now(),
+        },
+    );
+    Ok(key)
+}
+
+fn invalidate_astrometry_session(base_url: &str, api_key: &str) {
+    ASTROMETRY_SESSIONS
+        .lock()
+        .unwrap()
+        .remove(&session_cache_key(base_url, api_key));
+}
+
+fn is_session_expired(error: &PlateSolverError) -> bool {
+    error.to_string().contains("session_expired")
+}
+
+/// Sleep for `duration`, waking early if the solve is cancelled
+async fn cancellable_sleep(cancel_flag: &Arc<AtomicBool>, duration: Duration) -> Result<(), PlateSolverError> {
+    let deadline = Instant::now() + duration;
+    loop {
+        ensure_not_cancelled(cancel_flag)?;
+        let now = Instant::now();
+        if now >= deadline {
+            return Ok(());
+        }
+        tokio::time::sleep(CANCEL_CHECK_INTERVAL.min(deadline - now)).await;
+    }
+}
+
+/// Wait before the next poll: `interval`, doubled for each consecutive failure
+fn poll_delay(interval: Duration, consecutive_errors: u32) -> Duration {
+    let factor = 1u32 << consecutive_errors.min(16);
+    interval.saturating_mul(factor).min(MAX_POLL_BACKOFF.max(interval))
+}
+
+/// Run `check` on `schedule` until it yields a value, stopping early on
+/// cancellation, once `timeout_secs` have passed since `started`, or after
+/// `MAX_CONSECUTIVE_POLL_ERRORS` transient failures in a row
+async fn poll_until<T, F, Fut>(
+    cancel_flag: &Arc<AtomicBool>,
+    started: Instant,
+    timeout_secs: u32,
+    schedule: PollSchedule,
+    mut check: F,
+) -> Result<T, PlateSolverError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<T>, PollError>>,
+{
+    let mut consecutive_errors = 0;
+    loop {
+        ensure_not_cancelled(cancel_flag)?;
+        if started.elapsed().as_secs() > timeout_secs as u64 {
+            return Err(PlateSolverError::SolveFailed(
+                "timeout: Online solve timed out".to_string(),
+            ));
+        }
+
+        let interval = schedule.interval(started.elapsed());
+        cancellable_sleep(cancel_flag, poll_delay(interval, consecutive_errors)).await?;
+
+        match check().await {
+            Ok(Some(value)) => return Ok(value),
+            Ok(None) => consecutive_errors = 0,
+            Err(PollError::Fatal(error)) => return Err(error),
+            Err(PollError::Transient(error)) => {
+                consecutive_errors += 1;
+                log::warn!(
+                    "Astrometry.net poll failed ({}/{}): {}",
+                    consecutive_errors,
+                    MAX_CONSECUTIVE_POLL_ERRORS,
+                    error
+                );
+                if consecutive_errors >= MAX_CONSECUTIVE_POLL_ERRORS {
+                    return Err(PlateSolverError::SolveFailed(format!(
+                        "network: Astrometry.net polling failed {} times in a row: {}",
+                        consecutive_errors, error
+                    )));
+                }
+            }
+        }
+    }
+}
+
 fn classify_error_code(error: &PlateSolverError) -> String {
     let message = error.to_string().to_lowercase();
 
@@ -188,7 +376,8 @@ pub async fn solve_online(
             None,
             None,
         );
-        let session_key = astrometry_login(&client, &base_url, &config.api_key).await?;
+        let login = || astrometry_login(&client, &base_url, &config.api_key);
+        let session_key = astrometry_session(&base_url, &config.api_key, login).await?;
         ensure_not_cancelled(&cancel_flag)?;
 
         // Step 2: Upload image
@@ -201,7 +390,15 @@ pub async fn solve_online(
             None,
             None,
         );
-        let sub_id = astrometry_upload(&client, &base_url, &session_key, &config).await?;
+        let sub_id = match astrometry_upload(&client, &base_url, &session_key, &config).await {
+            Err(e) if is_session_expired(&e) => {
+                log::info!("Astrometry.net session expired, logging in again");
+                invalidate_astrometry_session(&base_url, &config.api_key);
+                let session_key = astrometry_session(&base_url, &config.api_key, login).await?;
+                astrometry_upload(&client, &base_url, &session_key, &config).await?
+            }
+            result => result?,
+        };
 
         emit_progress(
             &app,
@@ -213,100 +410,87 @@ pub async fn solve_online(
             None,
         );
 
+        if let Ok(mut guard) = ONLINE_SUBMISSIONS.lock() {
+            guard.insert(sub_id, operation_id.clone());
+        }
+
         // Step 3: Poll submission status to get job_id
         let timeout = config.timeout_seconds.unwrap_or(300);
-        let poll_start = std::time::Instant::now();
-        let jid: u64 = loop {
-            ensure_not_cancelled(&cancel_flag)?;
-            if poll_start.elapsed().as_secs() > timeout as u64 {
-                return Err(PlateSolverError::SolveFailed(
-                    "timeout: Online solve timed out".to_string(),
-                ));
-            }
-
-            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
-            ensure_not_cancelled(&cancel_flag)?;
+        let schedule = PollSchedule::from_config(config.poll_interval_seconds);
+        let poll_start = Instant::now();
+        let (client_ref, base_url_ref, app_ref, operation_ref) = (&client, &base_url, &app, &operation_id);
 
-            match astrometry_check_submission(&client, &base_url, sub_id).await {
+        let jid: u64 = poll_until(&cancel_flag, poll_start, timeout, schedule, || async move {
+            match astrometry_check_submission(client_ref, base_url_ref, sub_id).await {
                 Ok(Some(job)) => {
                     emit_progress(
-                        &app,
-                        &operation_id,
+                        app_ref,
+                        operation_ref,
                         "solving",
                         50.0,
                         "Job started, solving...",
                         Some(sub_id),
                         Some(job),
                     );
-                    break job;
+                    Ok(Some(job))
                 }
                 Ok(None) => {
                     let elapsed = poll_start.elapsed().as_secs();
                     let progress = 30.0 + (elapsed as f64 / timeout as f64) * 20.0;
                     emit_progress(
-                        &app,
-                        &operation_id,
+                        app_ref,
+                        operation_ref,
                         "processing",
                         progress.min(49.0),
                         "Waiting for job...",
                         Some(sub_id),
                         None,
                     );
+                    Ok(None)
                 }
-                Err(e) => {
-                    log::warn!("Submission poll error: {}", e);
-                }
+                Err(e) => Err(PollError::Transient(e)),
             }
-        };
+        })
+        .await?;
 
         // Step 4: Poll job status
-        loop {
-            ensure_not_cancelled(&cancel_flag)?;
-            if poll_start.elapsed().as_secs() > timeout as u64 {
-                return Err(PlateSolverError::SolveFailed(
-                    "timeout: Online solve timed out".to_string(),
-                ));
-            }
-
-            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
-            ensure_not_cancelled(&cancel_flag)?;
-
-            match astrometry_check_job(&client, &base_url, jid).await {
+        poll_until(&cancel_flag, poll_start, timeout, schedule, || async move {
+            match astrometry_check_job(client_ref, base_url_ref, jid).await {
                 Ok(status) => match status.as_str() {
                     "success" => {
                         emit_progress(
-                            &app,
-                            &operation_id,
+                            app_ref,
+                            operation_ref,
                             "fetching",
                             80.0,
                             "Solve complete, fetching results...",
                             Some(sub_id),
                             Some(jid),
                         );
-                        break;
-                    }
-                    "failure" => {
-                        return Err(PlateSolverError::SolveFailed(
-                            "service_failed: Astrometry.net solve failed".to_string(),
-                        ));
+                        Ok(Some(()))
                     }
+                    "failure" => Err(PollError::Fatal(PlateSolverError::SolveFailed(
+                        "service_failed: Astrometry.net solve failed".to_string(),
+                    ))),
                     _ => {
                         let elapsed = poll_start.elapsed().as_secs();
                         let progress = 50.0 + (elapsed as f64 / timeout as f64) * 30.0;
                         emit_progress(
-                            &app,
-                            &operation_id,
+                            app_ref,
+                            operation_ref,
                             "solving",
                             progress.min(79.0),
                             &format!("Solving... ({})", status),
                             Some(sub_id),
                             Some(jid),
                         );
+                        Ok(None)
                     }
                 },
-                Err(e) => log::warn!("Job poll error: {}", e),
+                Err(e) => Err(PollError::Transient(e)),
             }
-        }
+        })
+        .await?;
 
         // Step 5: Get calibration results
         let calibration = astrometry_get_calibration(&client, &base_url, jid).await?;
@@ -366,11 +550,25 @@ pub async fn solve_online(
     }
 }
 
+/// Forget cached Astrometry.net session keys so the next solve logs in again
+#[tauri::command]
+pub async fn clear_astrometry_session() -> Result<(), PlateSolverError> {
+    ASTROMETRY_SESSIONS.lock().unwrap().clear();
+    Ok(())
+}
+
+/// Cancel an online solve by operation id or Astrometry.net submission id,
+/// falling back to the most recent solve when neither is given
 #[tauri::command]
-pub async fn cancel_online_solve(operation_id: Option<String>) -> Result<bool, PlateSolverError> {
-    let target_operation = match operation_id {
-        Some(id) => Some(id),
-        None => ACTIVE_ONLINE_OPERATION_ID.lock().unwrap().clone(),
+pub async fn cancel_online_solve(
+    operation_id: Option<String>,
+    sub_id: Option<u64>,
+) -> Result<bool, PlateSolverError> {
+    let by_submission = sub_id.and_then(|id| ONLINE_SUBMISSIONS.lock().unwrap().get(&id).cloned());
+    let target_operation = match (operation_id, sub_id) {
+        (Some(id), _) => Some(id),
+        (None, Some(_)) => by_submission,
+        (None, None) => ACTIVE_ONLINE_OPERATION_ID.lock().unwrap().clone(),
     };
 
     let Some(operation_id) = target_operation else {
@@ -390,6 +588,16 @@ pub async fn cancel_online_solve(operation_id: Option<String>) -> Result<bool, P
     Ok(false)
 }
 
+/// Set the cancel flag of every online solve in progress; returns how many
+/// solves were signalled
+pub(crate) fn cancel_all_online_solves() -> usize {
+    let guard = ACTIVE_ONLINE_SOLVES.lock().unwrap_or_else(|e| e.into_inner());
+    for flag in guard.values() {
+        flag.store(true, Ordering::Relaxed);
+    }
+    guard.len()
+}
+
 fn emit_progress(
     app: &AppHandle,
     operation_id: &str,
@@ -526,6 +734,12 @@ async fn astrometry_upload(
         .await
         .map_err(|e| PlateSolverError::SolveFailed(format!("Upload failed: {}", e)))?;
 
+    if resp.status() == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(PlateSolverError::SolveFailed(
+            "session_expired: Astrometry.net rejected the session key".to_string(),
+        ));
+    }
+
     let json: serde_json::Value = resp.json().await.map_err(|e| {
         PlateSolverError::SolveFailed(format!("Upload response parse failed: {}", e))
     })?;
@@ -539,10 +753,13 @@ async fn astrometry_upload(
             .get("errormessage")
             .and_then(|v| v.as_str())
             .unwrap_or("Unknown upload error");
-        Err(PlateSolverError::SolveFailed(format!(
-            "upload_failed: {}",
-            err
-        )))
+        // Astrometry.net answers "no session with key ..." once a session lapses
+        let code = if err.to_lowercase().contains("session") {
+            "session_expired"
+        } else {
+            "upload_failed"
+        };
+        Err(PlateSolverError::SolveFailed(format!("{}: {}", code, err)))
     }
 }
 
@@ -722,6 +939,293 @@ mod tests {
     use super::super::types::{PlateSolverConfig, PlateSolverType};
     use super::*;
 
+    // ------------------------------------------------------------------------
+    // Cancellation Tests
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_poll_loop_stops_on_immediate_cancel() {
+        let cancel_flag = Arc::new(AtomicBool::new(true));
+        let mut checks = 0;
+
+        let result: Result<u64, _> = poll_until(&cancel_flag, Instant::now(), 300, PollSchedule::Adaptive, || {
+            checks += 1;
+            async { Ok(Some(42)) }
+        })
+        .await;
+
+        let error = result.unwrap_err();
+        assert_eq!(classify_error_code(&error), "cancelled");
+        assert_eq!(checks, 0);
+    }
+
+    #[tokio::test]
+    async fn test_poll_loop_cancel_interrupts_sleep() {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let setter = Arc::clone(&cancel_flag);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            setter.store(true, Ordering::Relaxed);
+        });
+
+        let started = Instant::now();
+        let result: Result<(), _> = poll_until(&cancel_flag, started, 300, PollSchedule::Fixed(Duration::from_secs(60)), || async {
+            Ok(None)
+        })
+        .await;
+
+        assert_eq!(classify_error_code(&result.unwrap_err()), "cancelled");
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_poll_loop_returns_first_value() {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut checks = 0;
+        let value = poll_until(&cancel_flag, Instant::now(), 300, PollSchedule::Fixed(Duration::from_millis(1)), || {
+            checks += 1;
+            let ready = checks >= 3;
+            async move { Ok(ready.then_some(7u64)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(value, 7);
+        assert_eq!(checks, 3);
+    }
+
+    // ------------------------------------------------------------------------
+    // Poll Error Handling Tests
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_poll_loop_aborts_after_consecutive_errors() {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut checks = 0;
+        let started = Instant::now();
+        let result: Result<u64, _> = poll_until(&cancel_flag, started, 300, PollSchedule::Fixed(Duration::from_millis(1)), || {
+            checks += 1;
+            async {
+                Err(PollError::Transient(PlateSolverError::SolveFailed(
+                    "Submission check failed: connection reset".to_string(),
+                )))
+            }
+        })
+        .await;
+
+        let error = result.unwrap_err();
+        assert_eq!(checks, MAX_CONSECUTIVE_POLL_ERRORS);
+        assert_eq!(classify_error_code(&error), "network");
+        assert!(error.to_string().contains("connection reset"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_poll_loop_success_resets_error_count() {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut checks = 0u32;
+        // Fail every other poll: never reaches the consecutive limit
+        let value = poll_until(&cancel_flag, Instant::now(), 300, PollSchedule::Fixed(Duration::from_millis(1)), || {
+            checks += 1;
+            let n = checks;
+            async move {
+                match n {
+                    n if n >= 2 * MAX_CONSECUTIVE_POLL_ERRORS => Ok(Some(n)),
+                    n if n % 2 == 1 => Err(PollError::Transient(PlateSolverError::SolveFailed(
+                        "Job check failed".to_string(),
+                    ))),
+                    _ => Ok(None),
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(value, 2 * MAX_CONSECUTIVE_POLL_ERRORS);
+    }
+
+    #[tokio::test]
+    async fn test_poll_loop_fatal_error_stops_immediately() {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut checks = 0;
+        let result: Result<(), _> = poll_until(&cancel_flag, Instant::now(), 300, PollSchedule::Fixed(Duration::from_millis(1)), || {
+            checks += 1;
+            async {
+                Err(PollError::Fatal(PlateSolverError::SolveFailed(
+                    "service_failed: Astrometry.net solve failed".to_string(),
+                )))
+            }
+        })
+        .await;
+        assert_eq!(checks, 1);
+        assert_eq!(classify_error_code(&result.unwrap_err()), "service_failed");
+    }
+
+    #[test]
+    fn test_poll_delay_backs_off_exponentially() {
+        let interval = Duration::from_secs(5);
+        assert_eq!(poll_delay(interval, 0), interval);
+        assert_eq!(poll_delay(interval, 1), Duration::from_secs(10));
+        assert_eq!(poll_delay(interval, 2), Duration::from_secs(20));
+        assert_eq!(poll_delay(interval, 10), MAX_POLL_BACKOFF);
+        assert_eq!(poll_delay(interval, u32::MAX), MAX_POLL_BACKOFF);
+    }
+
+    // ------------------------------------------------------------------------
+    // Poll Schedule Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_poll_schedule_from_config() {
+        assert_eq!(
+            PollSchedule::from_config(Some(3)),
+            PollSchedule::Fixed(Duration::from_secs(3))
+        );
+        assert_eq!(PollSchedule::from_config(None), PollSchedule::Adaptive);
+        assert_eq!(PollSchedule::from_config(Some(0)), PollSchedule::Adaptive);
+
+        let fixed = PollSchedule::from_config(Some(3));
+        assert_eq!(fixed.interval(Duration::ZERO), Duration::from_secs(3));
+        assert_eq!(fixed.interval(Duration::from_secs(600)), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_adaptive_poll_interval_grows_over_time() {
+        let schedule = PollSchedule::Adaptive;
+        let early = schedule.interval(Duration::from_secs(5));
+        let middle = schedule.interval(Duration::from_secs(60));
+        let late = schedule.interval(Duration::from_secs(600));
+        assert!(early < middle && middle < late, "{:?} {:?} {:?}", early, middle, late);
+        assert_eq!(early, Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_poll_loop_honors_configured_interval() {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut checks = 0;
+        let started = Instant::now();
+        poll_until(&cancel_flag, started, 300, PollSchedule::Fixed(Duration::from_millis(30)), || {
+            checks += 1;
+            let ready = checks >= 3;
+            async move { Ok(ready.then_some(())) }
+        })
+        .await
+        .unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(90));
+    }
+
+    // ------------------------------------------------------------------------
+    // Session Cache Tests
+    // ------------------------------------------------------------------------
+
+    async fn counted_login(logins: &std::sync::atomic::AtomicU32) -> Result<String, PlateSolverError> {
+        let n = logins.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(format!("session-{}", n))
+    }
+
+    #[tokio::test]
+    async fn test_session_reused_within_validity() {
+        let logins = std::sync::atomic::AtomicU32::new(0);
+        let base = "https://reuse.example";
+        let first = astrometry_session(base, "key", || counted_login(&logins)).await.unwrap();
+        let second = astrometry_session(base, "key", || counted_login(&logins)).await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(logins.load(Ordering::SeqCst), 1);
+
+        // A different API key gets its own session
+        astrometry_session(base, "other", || counted_login(&logins)).await.unwrap();
+        assert_eq!(logins.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_invalidated_session_triggers_relogin() {
+        let logins = std::sync::atomic::AtomicU32::new(0);
+        let base = "https://invalidate.example";
+        let first = astrometry_session(base, "key", || counted_login(&logins)).await.unwrap();
+        invalidate_astrometry_session(base, "key");
+        let second = astrometry_session(base, "key", || counted_login(&logins)).await.unwrap();
+        assert_ne!(first, second);
+        assert_eq!(logins.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stale_session_triggers_relogin() {
+        let logins = std::sync::atomic::AtomicU32::new(0);
+        let base = "https://stale.example";
+        astrometry_session(base, "key", || counted_login(&logins)).await.unwrap();
+        if let Some(issued_at) = Instant::now().checked_sub(SESSION_VALIDITY) {
+            ASTROMETRY_SESSIONS
+                .lock()
+                .unwrap()
+                .get_mut(&session_cache_key(base, "key"))
+                .unwrap()
+                .issued_at = issued_at;
+            astrometry_session(base, "key", || counted_login(&logins)).await.unwrap();
+            assert_eq!(logins.load(Ordering::SeqCst), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_login_is_not_cached() {
+        let base = "https://failed.example";
+        let result = astrometry_session(base, "key", || async {
+            Err(PlateSolverError::SolveFailed("auth_failed: bad key".to_string()))
+        })
+        .await;
+        assert!(result.is_err());
+        assert!(!ASTROMETRY_SESSIONS
+            .lock()
+            .unwrap()
+            .contains_key(&session_cache_key(base, "key")));
+    }
+
+    #[test]
+    fn test_session_expired_detection() {
+        assert!(is_session_expired(&PlateSolverError::SolveFailed(
+            "session_expired: no session with key abc".to_string()
+        )));
+        assert!(!is_session_expired(&PlateSolverError::SolveFailed(
+            "upload_failed: file too large".to_string()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_online_solve_by_submission_id() {
+        let flag = Arc::new(AtomicBool::new(false));
+        ACTIVE_ONLINE_SOLVES
+            .lock()
+            .unwrap()
+            .insert("online-test-sub".to_string(), Arc::clone(&flag));
+        ONLINE_SUBMISSIONS
+            .lock()
+            .unwrap()
+            .insert(987_654, "online-test-sub".to_string());
+
+        assert!(!cancel_online_solve(None, Some(1)).await.unwrap());
+        assert!(cancel_online_solve(None, Some(987_654)).await.unwrap());
+        assert!(flag.load(Ordering::Relaxed));
+
+        drop(ActiveOnlineSolveGuard {
+            operation_id: "online-test-sub".to_string(),
+        });
+        assert!(!ONLINE_SUBMISSIONS.lock().unwrap().contains_key(&987_654));
+    }
+
+    #[test]
+    fn test_cancel_all_online_solves_sets_every_flag() {
+        let flags: Vec<_> = (0..2).map(|_| Arc::new(AtomicBool::new(false))).collect();
+        {
+            let mut guard = ACTIVE_ONLINE_SOLVES.lock().unwrap();
+            for (i, flag) in flags.iter().enumerate() {
+                guard.insert(format!("online-test-all-{}", i), Arc::clone(flag));
+            }
+        }
+
+        assert!(cancel_all_online_solves() >= 2);
+        assert!(flags.iter().all(|flag| flag.load(Ordering::Relaxed)));
+
+        let mut guard = ACTIVE_ONLINE_SOLVES.lock().unwrap();
+        guard.retain(|id, _| !id.starts_with("online-test-all-"));
+    }
+
     #[test]
     fn test_online_solve_config_serialization() {
         let config = OnlineSolveConfig {
@@ -742,6 +1246,7 @@ mod tests {
             crpix_center: Some(true),
             parity: None,
             timeout_seconds: Some(300),
+            poll_interval_seconds: Some(3),
             publicly_visible: Some(false),
         };
 
@@ -843,7 +1348,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_cancel_online_solve_without_active_task() {
-        let cancelled = cancel_online_solve(None).await.unwrap();
+        let cancelled = cancel_online_solve(None, None).await.unwrap();
         assert!(!cancelled);
     }
 
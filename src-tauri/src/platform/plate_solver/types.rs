@@ -18,8 +18,12 @@ pub enum PlateSolverError {
     Io(#[from] std::io::Error),
     #[error("Download failed: {0}")]
     DownloadFailed(String),
+    #[error("Insufficient disk space: {required} bytes required, {available} bytes available")]
+    InsufficientSpace { required: u64, available: u64 },
     #[error("Local invocation failed: {0:?}")]
     LocalInvocation(LocalInvocationDiagnostics),
+    #[error("Solve cancelled")]
+    Cancelled,
 }
 
 impl Serialize for PlateSolverError {
@@ -39,6 +43,8 @@ pub enum PlateSolverType {
     AstrometryNet,
     #[serde(rename = "astrometry_net")]
     LocalAstrometry,
+    #[serde(rename = "watney")]
+    Watney,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -47,6 +53,7 @@ pub enum LocalSolverProfileId {
     AstapGui,
     AstapCli,
     AstrometrySolveField,
+    WatneySolve,
 }
 
 impl LocalSolverProfileId {
@@ -55,6 +62,7 @@ impl LocalSolverProfileId {
             Self::AstapGui => "ASTAP",
             Self::AstapCli => "ASTAP CLI",
             Self::AstrometrySolveField => "solve-field",
+            Self::WatneySolve => "watney-solve",
         }
     }
 }
@@ -187,6 +195,30 @@ pub struct ImageAnalysisResult {
     pub error_message: Option<String>,
 }
 
+/// Median HFD of the stars in each image quadrant (image y grows downwards)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuadrantHfd {
+    pub top_left: Option<f64>,
+    pub top_right: Option<f64>,
+    pub bottom_left: Option<f64>,
+    pub bottom_right: Option<f64>,
+}
+
+/// Aggregate focus quality of a star list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusMetrics {
+    pub star_count: u32,
+    pub median_hfd: Option<f64>,
+    pub hfd_std_dev: Option<f64>,
+    pub fwhm_estimate: Option<f64>,
+    /// Star elongation; None when the star list carries no shape data
+    pub eccentricity: Option<f64>,
+    pub quadrants: QuadrantHfd,
+    /// Spread between best and worst quadrant as a percentage of median HFD
+    pub tilt_percent: Option<f64>,
+    pub tilt_warning: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AstapDatabaseInfo {
     pub name: String,
@@ -264,6 +296,8 @@ pub struct OnlineSolveConfig {
     pub crpix_center: Option<bool>,
     pub parity: Option<u32>,
     pub timeout_seconds: Option<u32>,
+    /// Fixed status poll interval; adaptive when unset
+    pub poll_interval_seconds: Option<u32>,
     pub publicly_visible: Option<bool>,
 }
 
@@ -353,6 +387,9 @@ pub struct SolverConfig {
     pub auto_hints: bool,
     pub retry_on_failure: bool,
     pub max_retries: u32,
+    /// Extra executables or directories searched before the built-in locations
+    #[serde(default)]
+    pub extra_solver_paths: Vec<String>,
 }
 
 impl Default for SolverConfig {
@@ -382,6 +419,29 @@ impl Default for SolverConfig {
             auto_hints: true,
             retry_on_failure: false,
             max_retries: 2,
+            extra_solver_paths: Vec::new(),
+        }
+    }
+}
+
+/// Pointing and optics hints read from an image's own FITS header
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FitsHints {
+    pub ra: Option<f64>,
+    pub dec: Option<f64>,
+    pub focal_length_mm: Option<f64>,
+    pub pixel_size_um: Option<f64>,
+    pub image_width: Option<u32>,
+}
+
+impl FitsHints {
+    /// Image scale in arcsec/pixel derived from pixel size and focal length
+    pub fn pixel_scale_arcsec(&self) -> Option<f64> {
+        match (self.pixel_size_um, self.focal_length_mm) {
+            (Some(pixel), Some(focal)) if pixel > 0.0 && focal > 0.0 => {
+                Some(206.264_806 * pixel / focal)
+            }
+            _ => None,
         }
     }
 }
@@ -463,6 +523,9 @@ mod tests {
 
         let online = serde_json::to_string(&PlateSolverType::AstrometryNet).unwrap();
         assert_eq!(online, "\"astrometry_net_online\"");
+
+        let watney = serde_json::to_string(&PlateSolverType::Watney).unwrap();
+        assert_eq!(watney, "\"watney\"");
     }
 
     // ------------------------------------------------------------------------
@@ -0,0 +1,444 @@
+//! Watney plate solver integration.
+//! Handles solving via the `watney-solve` CLI, JSON result parsing, and path detection.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use serde::Deserialize;
+
+use super::astap::cancelled_error_if;
+use super::helpers::{
+    cleanup_local_solve_workspace, command_succeeds, create_local_solve_workspace, excerpt_output,
+    resolve_preferred_executable, solver_search_candidates,
+};
+use super::types::{
+    LocalInvocationDiagnostics, LocalSolverProfileId, PlateSolveResult, PlateSolverConfig,
+    PlateSolverError, PlateSolverType, SolverConfig, SolverInfo,
+};
+use super::{kill_active_solver, ActiveSolveGuard};
+
+/// Blind solve field radius search range in degrees (Watney's own defaults)
+const WATNEY_BLIND_MIN_RADIUS: f64 = 0.25;
+const WATNEY_BLIND_MAX_RADIUS: f64 = 8.0;
+
+pub(super) async fn solve_with_watney(
+    config: &PlateSolverConfig,
+    solver_config: Option<&SolverConfig>,
+) -> Result<PlateSolveResult, PlateSolverError> {
+    let preferred_executable = solver_config.and_then(|sc| sc.executable_path.as_deref());
+    let preferred_index_path = solver_config.and_then(|sc| sc.index_path.as_deref());
+    let extra_paths = solver_config.map(|sc| sc.extra_solver_paths.as_slice()).unwrap_or(&[]);
+    let watney = detect_watney_solver(preferred_executable, preferred_index_path, extra_paths)
+        .ok_or(PlateSolverError::SolverNotInstalled("Watney".to_string()))?;
+    let workspace = create_local_solve_workspace("watney")?;
+    let result_file = workspace.root_dir.join("result.json");
+
+    let fallback = SolverConfig::default();
+    let mut cmd = Command::new(&watney.executable_path);
+    cmd.args(build_watney_command_args(
+        config,
+        solver_config.unwrap_or(&fallback),
+        &result_file,
+    ));
+
+    let timeout_secs = config.timeout_seconds.unwrap_or(120);
+    let executable_path = watney.executable_path.clone();
+    let workspace_path = workspace.root_dir.to_string_lossy().to_string();
+
+    let output = tokio::time::timeout(
+        std::time::Duration::from_secs(timeout_secs as u64),
+        tokio::task::spawn_blocking(move || {
+            cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+            let child = cmd.spawn()?;
+            // Publish PID for cancel support; cleared when `active` drops
+            let active = ActiveSolveGuard::register(child.id());
+            let output = child.wait_with_output()?;
+            Ok::<_, std::io::Error>((output, active.was_cancelled()))
+        }),
+    )
+    .await
+    .map_err(|_| {
+        // On timeout, also kill the process
+        kill_active_solver();
+        PlateSolverError::LocalInvocation(LocalInvocationDiagnostics {
+            error_code: "timeout".to_string(),
+            profile_id: watney.profile_id,
+            executable_path: Some(executable_path.clone()),
+            workspace_path: Some(workspace_path.clone()),
+            exit_code: None,
+            availability_reason: None,
+            stdout_excerpt: None,
+            stderr_excerpt: None,
+        })
+    })
+    .and_then(|joined| joined.map_err(|e| PlateSolverError::SolveFailed(format!("Task join error: {}", e))))
+    .and_then(|waited| waited.map_err(PlateSolverError::Io));
+
+    // Read the result before dropping the workspace, which goes on every
+    // outcome: success, failure, timeout and cancellation
+    let json = match &output {
+        Ok((_, false)) => fs::read_to_string(&result_file).ok(),
+        _ => None,
+    };
+    cleanup_local_solve_workspace(&workspace);
+    let (output, cancelled) = output?;
+    cancelled_error_if(cancelled)?;
+
+    match json {
+        Some(json) if output.status.success() => parse_watney_result(&json),
+        _ => Err(PlateSolverError::LocalInvocation(
+            LocalInvocationDiagnostics {
+                error_code: if output.status.success() {
+                    "result_missing".to_string()
+                } else {
+                    "nonzero_exit".to_string()
+                },
+                profile_id: watney.profile_id,
+                executable_path: Some(watney.executable_path.clone()),
+                workspace_path: Some(workspace_path),
+                exit_code: output.status.code(),
+                availability_reason: None,
+                stdout_excerpt: excerpt_output(&output.stdout),
+                stderr_excerpt: excerpt_output(&output.stderr),
+            },
+        )),
+    }
+}
+
+/// Solution fields written by `watney-solve --out-format json`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatneyJsonResult {
+    success: bool,
+    ra: Option<f64>,
+    dec: Option<f64>,
+    orientation: Option<f64>,
+    pix_scale: Option<f64>,
+    parity: Option<String>,
+    field_width: Option<f64>,
+    field_height: Option<f64>,
+}
+
+fn parse_watney_result(json: &str) -> Result<PlateSolveResult, PlateSolverError> {
+    let parsed: WatneyJsonResult = serde_json::from_str(json).map_err(|e| {
+        PlateSolverError::SolveFailed(format!("Failed to parse Watney output: {}", e))
+    })?;
+
+    if !parsed.success {
+        return Err(PlateSolverError::SolveFailed(
+            "Watney could not find a solution".to_string(),
+        ));
+    }
+
+    Ok(PlateSolveResult {
+        success: true,
+        ra: parsed.ra,
+        dec: parsed.dec,
+        rotation: parsed.orientation,
+        scale: parsed.pix_scale,
+        width_deg: parsed.field_width,
+        height_deg: parsed.field_height,
+        flipped: parsed
+            .parity
+            .map(|parity| parity.eq_ignore_ascii_case("flipped")),
+        error_message: None,
+        wcs_file: None,
+        solve_time_ms: 0,
+    })
+}
+
+/// Field radius (half diagonal, degrees) estimated from a field-width scale hint
+fn estimate_field_radius(config: &PlateSolverConfig, solver_config: &SolverConfig) -> Option<f64> {
+    if !matches!(
+        solver_config.astrometry_scale_units.as_str(),
+        "deg_width" | "degwidth"
+    ) {
+        return None;
+    }
+    let width = match (config.scale_low, config.scale_high) {
+        (Some(low), Some(high)) => (low + high) / 2.0,
+        (low, high) => low.or(high)?,
+    };
+    // Half diagonal of a 4:3 frame is 0.625 x width
+    (width > 0.0).then_some(width * 0.625)
+}
+
+fn build_watney_command_args(
+    config: &PlateSolverConfig,
+    solver_config: &SolverConfig,
+    result_file: &Path,
+) -> Vec<String> {
+    let field_radius = estimate_field_radius(config, solver_config);
+    let mut args = Vec::new();
+
+    match (config.ra_hint, config.dec_hint, field_radius) {
+        (Some(ra), Some(dec), Some(field_radius)) => {
+            args.push("nearby".to_string());
+            args.push("--manual".to_string());
+            args.push("--ra".to_string());
+            args.push(ra.to_string());
+            args.push("--dec".to_string());
+            args.push(dec.to_string());
+            args.push("--field-radius".to_string());
+            args.push(field_radius.to_string());
+            args.push("--search-radius".to_string());
+            args.push(
+                config
+                    .radius_hint
+                    .unwrap_or(solver_config.search_radius)
+                    .to_string(),
+            );
+        }
+        _ => {
+            args.push("blind".to_string());
+            args.push("--min-radius".to_string());
+            args.push(WATNEY_BLIND_MIN_RADIUS.to_string());
+            args.push("--max-radius".to_string());
+            args.push(WATNEY_BLIND_MAX_RADIUS.to_string());
+        }
+    }
+
+    args.push("--image".to_string());
+    args.push(config.image_path.clone());
+    args.push("--out".to_string());
+    args.push(result_file.to_string_lossy().to_string());
+    args.push("--out-format".to_string());
+    args.push("json".to_string());
+
+    let downsample = solver_config
+        .downsample
+        .max(config.downsample.unwrap_or_default());
+    if downsample > 1 {
+        args.push("--sampling".to_string());
+        args.push(downsample.to_string());
+    }
+
+    args
+}
+
+// ============================================================================
+// Watney Path and Version Helpers
+// ============================================================================
+
+pub fn get_watney_paths() -> Vec<String> {
+    #[cfg(target_os = "windows")]
+    {
+        vec![
+            r"C:\Program Files\watney\watney-solve.exe".to_string(),
+            r"C:\watney\watney-solve.exe".to_string(),
+            "watney-solve.exe".to_string(),
+        ]
+    }
+    #[cfg(target_os = "macos")]
+    {
+        vec![
+            "/Applications/watney/watney-solve".to_string(),
+            "/usr/local/bin/watney-solve".to_string(),
+            "/opt/homebrew/bin/watney-solve".to_string(),
+            "watney-solve".to_string(),
+        ]
+    }
+    #[cfg(target_os = "linux")]
+    {
+        vec![
+            "/usr/bin/watney-solve".to_string(),
+            "/usr/local/bin/watney-solve".to_string(),
+            "/opt/watney/watney-solve".to_string(),
+            "watney-solve".to_string(),
+        ]
+    }
+}
+
+pub fn get_watney_version(path: &str) -> Option<String> {
+    Command::new(path)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.lines().next().unwrap_or("").trim().to_string())
+        .filter(|version| !version.is_empty())
+}
+
+pub fn validate_watney_executable(path: &str) -> Option<LocalSolverProfileId> {
+    command_succeeds(path, &["--version"]).then_some(LocalSolverProfileId::WatneySolve)
+}
+
+pub fn detect_watney_solver(
+    preferred_executable: Option<&str>,
+    preferred_index_path: Option<&str>,
+    extra_paths: &[String],
+) -> Option<SolverInfo> {
+    let (executable_path, profile_id) = resolve_preferred_executable(
+        preferred_executable,
+        &solver_search_candidates(extra_paths, get_watney_paths()),
+        validate_watney_executable,
+    )?;
+    let uses_custom_executable =
+        preferred_executable.is_some_and(|path| !path.trim().is_empty() && path == executable_path);
+
+    Some(SolverInfo {
+        solver_type: PlateSolverType::Watney,
+        name: "Watney".to_string(),
+        version: get_watney_version(&executable_path),
+        executable_path,
+        is_available: true,
+        // Watney reads its quad database location from watney-solve-config.yml
+        index_path: preferred_index_path
+            .filter(|path| !path.trim().is_empty())
+            .map(|path| path.to_string()),
+        installed_indexes: Vec::new(),
+        profile_id: Some(profile_id),
+        profile_name: Some(profile_id.display_name().to_string()),
+        availability_reason: None,
+        uses_custom_executable,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watney_test_config() -> PlateSolverConfig {
+        PlateSolverConfig {
+            solver_type: PlateSolverType::Watney,
+            image_path: "/images/m42.fit".to_string(),
+            ra_hint: None,
+            dec_hint: None,
+            radius_hint: None,
+            scale_low: None,
+            scale_high: None,
+            downsample: None,
+            timeout_seconds: Some(60),
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Path Helper Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_get_watney_paths() {
+        let paths = get_watney_paths();
+        assert!(!paths.is_empty());
+        for path in &paths {
+            assert!(path.contains("watney-solve"));
+        }
+    }
+
+    #[test]
+    fn test_detect_watney_solver_missing_executable() {
+        assert!(validate_watney_executable("/nonexistent/watney-solve").is_none());
+        if let Some(info) = detect_watney_solver(Some("/nonexistent/watney-solve"), None, &[]) {
+            // Only a system install may be picked up, never the missing custom path
+            assert!(!info.uses_custom_executable);
+            assert_eq!(info.solver_type, PlateSolverType::Watney);
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Command Argument Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_build_watney_args_blind_without_hints() {
+        let args = build_watney_command_args(
+            &watney_test_config(),
+            &SolverConfig::default(),
+            Path::new("/tmp/result.json"),
+        );
+        assert_eq!(args[0], "blind");
+        assert!(args.windows(2).any(|w| w == ["--min-radius", "0.25"]));
+        assert!(args.windows(2).any(|w| w == ["--max-radius", "8"]));
+        assert!(args.windows(2).any(|w| w == ["--image", "/images/m42.fit"]));
+        assert!(args.windows(2).any(|w| w == ["--out-format", "json"]));
+        assert!(!args.contains(&"--sampling".to_string()));
+    }
+
+    #[test]
+    fn test_build_watney_args_nearby_with_hints() {
+        let mut config = watney_test_config();
+        config.ra_hint = Some(83.822);
+        config.dec_hint = Some(-5.391);
+        config.radius_hint = Some(10.0);
+        config.scale_low = Some(1.6);
+        config.scale_high = Some(2.4);
+        config.downsample = Some(2);
+
+        let args = build_watney_command_args(
+            &config,
+            &SolverConfig::default(),
+            Path::new("/tmp/result.json"),
+        );
+        assert_eq!(args[0], "nearby");
+        assert!(args.contains(&"--manual".to_string()));
+        assert!(args.windows(2).any(|w| w == ["--ra", "83.822"]));
+        assert!(args.windows(2).any(|w| w == ["--dec", "-5.391"]));
+        assert!(args.windows(2).any(|w| w == ["--field-radius", "1.25"]));
+        assert!(args.windows(2).any(|w| w == ["--search-radius", "10"]));
+        assert!(args.windows(2).any(|w| w == ["--sampling", "2"]));
+    }
+
+    #[test]
+    fn test_build_watney_args_falls_back_to_blind_without_field_size() {
+        let mut config = watney_test_config();
+        config.ra_hint = Some(83.822);
+        config.dec_hint = Some(-5.391);
+
+        let args = build_watney_command_args(
+            &config,
+            &SolverConfig::default(),
+            Path::new("/tmp/result.json"),
+        );
+        assert_eq!(args[0], "blind");
+    }
+
+    // ------------------------------------------------------------------------
+    // Result Parsing Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_watney_result_sample_output() {
+        let json = r#"{
+            "success": true,
+            "ra": 83.82208,
+            "dec": -5.39111,
+            "ra_hms": "05h 35m 17.30s",
+            "dec_dms": "-05° 23' 28.00\"",
+            "fieldRadius": 1.5682,
+            "orientation": 178.42,
+            "pixScale": 1.9389,
+            "parity": "flipped",
+            "timeSpent": "00:00:01.8420000",
+            "searchIterations": 12,
+            "starsDetected": 812,
+            "starsUsed": 300,
+            "quadMatches": 41,
+            "fieldWidth": 2.5077,
+            "fieldHeight": 1.8958
+        }"#;
+
+        let result = parse_watney_result(json).unwrap();
+        assert!(result.success);
+        assert_eq!(result.ra, Some(83.82208));
+        assert_eq!(result.dec, Some(-5.39111));
+        assert_eq!(result.rotation, Some(178.42));
+        assert_eq!(result.scale, Some(1.9389));
+        assert_eq!(result.width_deg, Some(2.5077));
+        assert_eq!(result.height_deg, Some(1.8958));
+        assert_eq!(result.flipped, Some(true));
+    }
+
+    #[test]
+    fn test_parse_watney_result_failed_solve() {
+        let result = parse_watney_result(r#"{ "success": false }"#);
+        assert!(matches!(result, Err(PlateSolverError::SolveFailed(_))));
+
+        let result = parse_watney_result("not json");
+        assert!(matches!(result, Err(PlateSolverError::SolveFailed(_))));
+    }
+}
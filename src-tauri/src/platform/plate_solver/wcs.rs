@@ -0,0 +1,227 @@
+//! WCS header writing: serializes a `WcsResult` into a standalone FITS
+//! header file (`.wcs`) that other tools can read. Parsing lives in `fits`.
+
+use std::collections::HashMap;
+
+use super::types::{PlateSolverError, WcsResult};
+
+const CARD_SIZE: usize = 80;
+const BLOCK_SIZE: usize = 2880;
+
+/// Write `wcs` to `path` as a header-only FITS file
+#[tauri::command]
+pub async fn write_wcs_file(wcs: WcsResult, path: String) -> Result<(), PlateSolverError> {
+    std::fs::write(&path, wcs_header_bytes(&wcs))?;
+    Ok(())
+}
+
+/// Header-only FITS bytes (NAXIS = 0) describing `wcs`, ending with an END
+/// card and padded to a whole 2880-byte block
+pub fn wcs_header_bytes(wcs: &WcsResult) -> Vec<u8> {
+    let mut cards = vec![
+        logical_card("SIMPLE", true),
+        int_card("BITPIX", 8),
+        int_card("NAXIS", 0),
+        int_card("WCSAXES", 2),
+    ];
+
+    let strings = [("CTYPE1", &wcs.ctype1), ("CTYPE2", &wcs.ctype2)];
+    cards.extend(strings.iter().filter_map(|(key, value)| value.as_deref().map(|v| string_card(key, v))));
+
+    let reals = [
+        ("CRVAL1", wcs.crval1),
+        ("CRVAL2", wcs.crval2),
+        ("CRPIX1", wcs.crpix1),
+        ("CRPIX2", wcs.crpix2),
+        ("CD1_1", wcs.cd1_1),
+        ("CD1_2", wcs.cd1_2),
+        ("CD2_1", wcs.cd2_1),
+        ("CD2_2", wcs.cd2_2),
+        ("CDELT1", wcs.cdelt1),
+        ("CDELT2", wcs.cdelt2),
+        ("CROTA1", wcs.crota1),
+        ("CROTA2", wcs.crota2),
+    ];
+    cards.extend(reals.iter().filter_map(|(key, value)| value.map(|v| real_card(key, v))));
+
+    // Astrometry.net convention for image size in header-only files
+    if let Some(width) = wcs.naxis1 {
+        cards.push(int_card("IMAGEW", width as i64));
+    }
+    if let Some(height) = wcs.naxis2 {
+        cards.push(int_card("IMAGEH", height as i64));
+    }
+
+    if let Some(sip) = &wcs.sip {
+        let orders = [
+            ("A_ORDER", sip.a_order, &sip.a_coeffs),
+            ("B_ORDER", sip.b_order, &sip.b_coeffs),
+            ("AP_ORDER", sip.ap_order, &sip.ap_coeffs),
+            ("BP_ORDER", sip.bp_order, &sip.bp_coeffs),
+        ];
+        for (order_key, order, coeffs) in orders {
+            if let Some(order) = order {
+                cards.push(int_card(order_key, order as i64));
+            }
+            cards.extend(sorted_coefficients(coeffs).map(|(key, value)| real_card(key, value)));
+        }
+    }
+
+    cards.push(pad_card("END".to_string()));
+
+    let mut bytes: Vec<u8> = cards.concat().into_bytes();
+    let remainder = bytes.len() % BLOCK_SIZE;
+    if remainder != 0 {
+        bytes.resize(bytes.len() + BLOCK_SIZE - remainder, b' ');
+    }
+    bytes
+}
+
+fn sorted_coefficients(coeffs: &HashMap<String, f64>) -> impl Iterator<Item = (&str, f64)> {
+    let mut entries: Vec<_> = coeffs.iter().map(|(key, value)| (key.as_str(), *value)).collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries.into_iter()
+}
+
+fn pad_card(mut card: String) -> String {
+    card.truncate(CARD_SIZE);
+    let padding = CARD_SIZE - card.len();
+    card.push_str(&" ".repeat(padding));
+    card
+}
+
+/// Fixed-format value card: keyword in columns 1-8, value right-aligned to column 30
+fn value_card(key: &str, value: &str) -> String {
+    pad_card(format!("{:<8}= {:>20}", key, value))
+}
+
+fn logical_card(key: &str, value: bool) -> String {
+    value_card(key, if value { "T" } else { "F" })
+}
+
+fn int_card(key: &str, value: i64) -> String {
+    value_card(key, &value.to_string())
+}
+
+/// Real values keep 17 significant digits so they survive a round trip
+fn real_card(key: &str, value: f64) -> String {
+    value_card(key, &format!("{:.16E}", value))
+}
+
+/// String values are quoted, with embedded quotes doubled and the content
+/// padded to the eight-character minimum
+fn string_card(key: &str, value: &str) -> String {
+    pad_card(format!("{:<8}= '{:<8}'", key, value.replace('\'', "''")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fits::parse_wcs_result_from_fits_bytes;
+    use super::super::types::SipCoefficients;
+    use super::*;
+
+    fn approx_eq(a: Option<f64>, b: Option<f64>) -> bool {
+        match (a, b) {
+            (Some(a), Some(b)) => (a - b).abs() <= 1e-12 * a.abs().max(1.0),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn sample_wcs() -> WcsResult {
+        let mut sip = SipCoefficients {
+            a_order: Some(2),
+            b_order: Some(2),
+            ..Default::default()
+        };
+        sip.a_coeffs.insert("A_0_2".to_string(), 1.234e-6);
+        sip.a_coeffs.insert("A_2_0".to_string(), -5.5e-7);
+        sip.b_coeffs.insert("B_1_1".to_string(), 3.3e-7);
+
+        WcsResult {
+            crpix1: Some(2048.5),
+            crpix2: Some(1536.5),
+            crval1: Some(83.822083333),
+            crval2: Some(-5.391111111),
+            cd1_1: Some(-2.9983456e-4),
+            cd1_2: Some(1.23456789e-6),
+            cd2_1: Some(-1.198765e-6),
+            cd2_2: Some(-2.9987654e-4),
+            ctype1: Some("RA---TAN-SIP".to_string()),
+            ctype2: Some("DEC--TAN-SIP".to_string()),
+            naxis1: Some(4096),
+            naxis2: Some(3072),
+            sip: Some(sip),
+            ..Default::default()
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Header Layout Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_header_is_block_padded_with_end_card() {
+        let bytes = wcs_header_bytes(&sample_wcs());
+        assert_eq!(bytes.len() % BLOCK_SIZE, 0);
+        assert!(bytes.iter().all(|b| (0x20..=0x7E).contains(b)));
+
+        let cards: Vec<&[u8]> = bytes.chunks(CARD_SIZE).collect();
+        assert!(cards[0].starts_with(b"SIMPLE  =                    T"));
+        let end = cards.iter().position(|card| card.starts_with(b"END     ")).unwrap();
+        assert!(cards[end + 1..].iter().all(|card| card.iter().all(|&b| b == b' ')));
+    }
+
+    #[test]
+    fn test_string_card_quotes_and_pads() {
+        assert_eq!(string_card("CTYPE1", "RA").trim_end(), "CTYPE1  = 'RA      '");
+        assert_eq!(string_card("OBJECT", "O'Neil").trim_end(), "OBJECT  = 'O''Neil '");
+    }
+
+    // ------------------------------------------------------------------------
+    // Round Trip Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_round_trip_reproduces_wcs() {
+        let original = sample_wcs();
+        let parsed = parse_wcs_result_from_fits_bytes(&wcs_header_bytes(&original)).unwrap();
+
+        assert!(approx_eq(parsed.crval1, original.crval1));
+        assert!(approx_eq(parsed.crval2, original.crval2));
+        assert!(approx_eq(parsed.crpix1, original.crpix1));
+        assert!(approx_eq(parsed.crpix2, original.crpix2));
+        assert!(approx_eq(parsed.cd1_1, original.cd1_1));
+        assert!(approx_eq(parsed.cd1_2, original.cd1_2));
+        assert!(approx_eq(parsed.cd2_1, original.cd2_1));
+        assert!(approx_eq(parsed.cd2_2, original.cd2_2));
+        assert_eq!(parsed.ctype1.as_deref(), Some("RA---TAN-SIP"));
+        assert_eq!(parsed.ctype2.as_deref(), Some("DEC--TAN-SIP"));
+        assert_eq!(parsed.naxis1, Some(4096));
+        assert_eq!(parsed.naxis2, Some(3072));
+
+        let sip = parsed.sip.unwrap();
+        assert_eq!(sip.a_order, Some(2));
+        assert_eq!(sip.b_order, Some(2));
+        assert!(approx_eq(sip.a_coeffs.get("A_0_2").copied(), Some(1.234e-6)));
+        assert!(approx_eq(sip.a_coeffs.get("A_2_0").copied(), Some(-5.5e-7)));
+        assert!(approx_eq(sip.b_coeffs.get("B_1_1").copied(), Some(3.3e-7)));
+        assert!(sip.ap_coeffs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_write_wcs_file_round_trip() {
+        let path = std::env::temp_dir().join(format!("skymap-wcs-{}.wcs", std::process::id()));
+        let wcs = WcsResult {
+            sip: None,
+            ..sample_wcs()
+        };
+        write_wcs_file(wcs, path.to_string_lossy().to_string()).await.unwrap();
+
+        let parsed = parse_wcs_result_from_fits_bytes(&std::fs::read(&path).unwrap()).unwrap();
+        assert!(approx_eq(parsed.crval1, Some(83.822083333)));
+        assert!(approx_eq(parsed.cd2_2, Some(-2.9987654e-4)));
+        assert!(parsed.sip.is_none());
+        let _ = std::fs::remove_file(&path);
+    }
+}
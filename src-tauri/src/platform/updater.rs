@@ -1,12 +1,61 @@
 //! Application updater module
 
+use semver::Version;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter, Runtime};
+use tauri::Url;
 use tauri_plugin_updater::{Update, UpdaterExt};
 use time::OffsetDateTime;
 
 use once_cell::sync::Lazy;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use tauri::Manager;
+
+const RELEASES_BASE_URL: &str = "https://github.com/AstroAir/skymap-test/releases";
+
+/// Release channel the updater follows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    Beta,
+    Nightly,
+}
+
+impl UpdateChannel {
+    /// Manifest endpoint published for this channel
+    pub fn manifest_url(self) -> String {
+        match self {
+            UpdateChannel::Stable => format!("{}/latest/download/latest.json", RELEASES_BASE_URL),
+            UpdateChannel::Beta => format!("{}/download/beta/latest.json", RELEASES_BASE_URL),
+            UpdateChannel::Nightly => format!("{}/download/nightly/latest.json", RELEASES_BASE_URL),
+        }
+    }
+
+    /// Whether a release with this version may be offered on the channel
+    fn allows(self, version: &Version) -> bool {
+        if version.pre.is_empty() {
+            return true;
+        }
+        let pre = version.pre.as_str();
+        match self {
+            UpdateChannel::Stable => false,
+            UpdateChannel::Beta => pre.starts_with("beta") || pre.starts_with("rc"),
+            UpdateChannel::Nightly => true,
+        }
+    }
+}
+
+/// Decide whether a remote release should be offered.
+///
+/// Downgrades are always refused: switching from nightly back to stable keeps
+/// the installed build until the stable channel catches up with it.
+fn should_offer_release(channel: UpdateChannel, current: &Version, remote: &Version) -> bool {
+    channel.allows(remote) && remote > current
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateInfo {
@@ -14,6 +63,8 @@ pub struct UpdateInfo {
     pub current_version: String,
     pub date: Option<String>,
     pub body: Option<String>,
+    #[serde(default)]
+    pub channel: UpdateChannel,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +74,25 @@ pub struct UpdateProgress {
     pub percent: f64,
 }
 
+/// Snapshot of the installed binary taken before an update is applied
+/// Only the executable is captured; bundled resources and installer-managed
+/// files (e.g. an AppImage or .app bundle contents) are not restored by a rollback
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RollbackPoint {
+    pub version: String,
+    pub executable_path: String,
+    pub backup_path: String,
+    pub created_at: String,
+}
+
+/// Pending update and rollback availability
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingUpdateState {
+    pub pending: bool,
+    pub rollback_available: bool,
+    pub rollback_version: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "status", content = "data")]
 pub enum UpdateStatus {
@@ -55,6 +125,10 @@ pub enum UpdaterError {
     DownloadFailed(String),
     #[error("Install failed: {0}")]
     InstallFailed(String),
+    #[error("Pending update belongs to the {0:?} channel; check for updates again")]
+    ChannelMismatch(UpdateChannel),
+    #[error("Rollback failed: {0}")]
+    RollbackFailed(String),
 }
 
 impl Serialize for UpdaterError {
@@ -66,6 +140,7 @@ impl Serialize for UpdaterError {
 
 struct PendingUpdate {
     update: Update,
+    channel: UpdateChannel,
     bytes: Option<Vec<u8>>,
 }
 
@@ -106,18 +181,98 @@ fn normalize_updater_error(raw: &str, phase: &'static str) -> String {
     format!("Update {} failed: {}", phase, raw)
 }
 
-fn extract_update_info(update: &Update) -> UpdateInfo {
+const ROLLBACK_DIR: &str = "update_rollback";
+const ROLLBACK_MANIFEST: &str = "rollback.json";
+
+fn get_rollback_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, UpdaterError> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(ROLLBACK_DIR))
+        .map_err(|e| UpdaterError::RollbackFailed(e.to_string()))
+}
+
+fn read_rollback_point(dir: &Path) -> Option<RollbackPoint> {
+    let content = fs::read_to_string(dir.join(ROLLBACK_MANIFEST)).ok()?;
+    let point: RollbackPoint = serde_json::from_str(&content).ok()?;
+    Path::new(&point.backup_path).exists().then_some(point)
+}
+
+/// Copy the executable into the rollback directory and record its version
+fn snapshot_executable(dir: &Path, executable: &Path, version: &str) -> Result<RollbackPoint, UpdaterError> {
+    let file_name = executable
+        .file_name()
+        .ok_or_else(|| UpdaterError::RollbackFailed("Executable path has no file name".to_string()))?;
+    let backup = dir.join(file_name);
+    fs::create_dir_all(dir).map_err(|e| UpdaterError::RollbackFailed(e.to_string()))?;
+    // Leftover from an earlier rollback whose replaced binary was still running
+    let _ = fs::remove_file(executable.with_extension("old"));
+    fs::copy(executable, &backup).map_err(|e| UpdaterError::RollbackFailed(e.to_string()))?;
+
+    let point = RollbackPoint {
+        version: version.to_string(),
+        executable_path: executable.to_string_lossy().to_string(),
+        backup_path: backup.to_string_lossy().to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+    crate::data::write_json_atomic(&dir.join(ROLLBACK_MANIFEST), &point)
+        .map_err(|e| UpdaterError::RollbackFailed(e.to_string()))?;
+    Ok(point)
+}
+
+/// Put the snapshotted executable back in place and discard the snapshot.
+/// Returns `None` when there is nothing to roll back to.
+fn restore_rollback_point(dir: &Path) -> Result<Option<RollbackPoint>, UpdaterError> {
+    let Some(point) = read_rollback_point(dir) else {
+        return Ok(None);
+    };
+    let target = PathBuf::from(&point.executable_path);
+    let staged = target.with_extension("rollback");
+    let replaced = target.with_extension("old");
+    if let Err(e) = fs::copy(&point.backup_path, &staged) {
+        let _ = fs::remove_file(&staged);
+        return Err(UpdaterError::RollbackFailed(e.to_string()));
+    }
+
+    // A running executable can't be overwritten on every platform, but it can be renamed
+    if target.exists() {
+        let _ = fs::rename(&target, &replaced);
+    }
+    if let Err(e) = fs::rename(&staged, &target) {
+        let _ = fs::remove_file(&staged);
+        let _ = fs::rename(&replaced, &target);
+        return Err(UpdaterError::RollbackFailed(e.to_string()));
+    }
+
+    // Fails on Windows while the old binary is still running; the next
+    // snapshot removes it then
+    let _ = fs::remove_file(&replaced);
+    let _ = fs::remove_dir_all(dir);
+    Ok(Some(point))
+}
+
+fn extract_update_info(update: &Update, channel: UpdateChannel) -> UpdateInfo {
     UpdateInfo {
         version: update.version.clone(),
         current_version: update.current_version.clone(),
         date: update.date.map(format_datetime),
         body: update.body.clone(),
+        channel,
     }
 }
 
 #[tauri::command]
-pub async fn check_for_update<R: Runtime>(app: AppHandle<R>) -> Result<UpdateStatus, UpdaterError> {
+pub async fn check_for_update(
+    app: AppHandle,
+    channel: Option<UpdateChannel>,
+) -> Result<UpdateStatus, UpdaterError> {
+    let channel = channel.unwrap_or_else(|| super::app_settings::persisted_update_channel(&app));
+    let endpoint = Url::parse(&channel.manifest_url())
+        .map_err(|e| UpdaterError::CheckFailed(normalize_updater_error(&e.to_string(), "check")))?;
+
     let updater = app.updater_builder()
+        .endpoints(vec![endpoint])
+        .map_err(|e| UpdaterError::CheckFailed(normalize_updater_error(&e.to_string(), "check")))?
+        .version_comparator(move |current, remote| should_offer_release(channel, &current, &remote.version))
         .on_before_exit(|| {
             log::info!("Updater: application exiting for update installation...");
         })
@@ -126,9 +281,9 @@ pub async fn check_for_update<R: Runtime>(app: AppHandle<R>) -> Result<UpdateSta
 
     match updater.check().await {
         Ok(Some(update)) => {
-            let info = extract_update_info(&update);
+            let info = extract_update_info(&update, channel);
             if let Ok(mut pending) = PENDING_UPDATE.lock() {
-                *pending = Some(PendingUpdate { update, bytes: None });
+                *pending = Some(PendingUpdate { update, channel, bytes: None });
             }
             Ok(UpdateStatus::Available(info))
         }
@@ -139,13 +294,13 @@ pub async fn check_for_update<R: Runtime>(app: AppHandle<R>) -> Result<UpdateSta
 
 #[tauri::command]
 pub async fn download_update<R: Runtime>(_app: AppHandle<R>, window: tauri::Window<R>) -> Result<UpdateStatus, UpdaterError> {
-    let update = {
+    let (update, channel) = {
         let pending = PENDING_UPDATE.lock().map_err(|_| UpdaterError::NoPendingUpdate)?;
         let p = pending.as_ref().ok_or(UpdaterError::NoPendingUpdate)?;
-        p.update.clone()
+        (p.update.clone(), p.channel)
     };
 
-    let info = extract_update_info(&update);
+    let info = extract_update_info(&update, channel);
     let window_clone = window.clone();
     let mut total_downloaded: u64 = 0;
 
@@ -162,7 +317,7 @@ pub async fn download_update<R: Runtime>(_app: AppHandle<R>, window: tauri::Wind
     ).await.map_err(|e| UpdaterError::DownloadFailed(normalize_updater_error(&e.to_string(), "download")))?;
 
     if let Ok(mut pending) = PENDING_UPDATE.lock() {
-        *pending = Some(PendingUpdate { update, bytes: Some(bytes) });
+        *pending = Some(PendingUpdate { update, channel, bytes: Some(bytes) });
     }
     Ok(UpdateStatus::Ready(info))
 }
@@ -178,6 +333,8 @@ pub async fn install_update<R: Runtime>(app: AppHandle<R>) -> Result<(), Updater
         "Update not downloaded yet. Call download_update first.".to_string()
     ))?;
 
+    try_create_update_rollback_point(&app);
+
     pending_data.update.install(bytes)
         .map_err(|e| UpdaterError::InstallFailed(normalize_updater_error(&e.to_string(), "install")))?;
 
@@ -186,13 +343,23 @@ pub async fn install_update<R: Runtime>(app: AppHandle<R>) -> Result<(), Updater
 }
 
 #[tauri::command]
-pub async fn download_and_install_update<R: Runtime>(app: AppHandle<R>, window: tauri::Window<R>) -> Result<(), UpdaterError> {
+pub async fn download_and_install_update(
+    app: AppHandle,
+    window: tauri::Window,
+    channel: Option<UpdateChannel>,
+) -> Result<(), UpdaterError> {
+    let channel = channel.unwrap_or_else(|| super::app_settings::persisted_update_channel(&app));
     let update = {
         let mut pending = PENDING_UPDATE.lock().map_err(|_| UpdaterError::NoPendingUpdate)?;
-        let p = pending.take().ok_or(UpdaterError::NoPendingUpdate)?;
-        p.update
+        let p = pending.as_ref().ok_or(UpdaterError::NoPendingUpdate)?;
+        if p.channel != channel {
+            return Err(UpdaterError::ChannelMismatch(p.channel));
+        }
+        pending.take().ok_or(UpdaterError::NoPendingUpdate)?.update
     };
 
+    try_create_update_rollback_point(&app);
+
     let window_clone = window.clone();
     let mut downloaded: u64 = 0;
 
@@ -223,8 +390,44 @@ pub fn clear_pending_update() -> Result<(), UpdaterError> {
 }
 
 #[tauri::command]
-pub fn has_pending_update() -> bool {
-    PENDING_UPDATE.lock().map(|p| p.is_some()).unwrap_or(false)
+pub fn has_pending_update<R: Runtime>(app: AppHandle<R>) -> PendingUpdateState {
+    let pending = PENDING_UPDATE.lock().map(|p| p.is_some()).unwrap_or(false);
+    let rollback = get_rollback_dir(&app).ok().and_then(|dir| read_rollback_point(&dir));
+    PendingUpdateState {
+        pending,
+        rollback_available: rollback.is_some(),
+        rollback_version: rollback.map(|point| point.version),
+    }
+}
+
+/// Best-effort rollback point before an install; a failed snapshot is logged
+/// rather than blocking the update
+fn try_create_update_rollback_point<R: Runtime>(app: &AppHandle<R>) {
+    if let Err(e) = create_update_rollback_point(app.clone()) {
+        log::warn!("Installing update without a rollback point: {}", e);
+    }
+}
+
+/// Snapshot the running binary so a broken update can be reverted
+/// Only the executable is captured; see [`RollbackPoint`]
+#[tauri::command]
+pub fn create_update_rollback_point<R: Runtime>(app: AppHandle<R>) -> Result<RollbackPoint, UpdaterError> {
+    let executable = std::env::current_exe().map_err(|e| UpdaterError::RollbackFailed(e.to_string()))?;
+    let version = app.package_info().version.to_string();
+    let point = snapshot_executable(&get_rollback_dir(&app)?, &executable, &version)?;
+    log::info!("Created update rollback point for version {}", point.version);
+    Ok(point)
+}
+
+/// Restore the binary recorded by the last rollback point and drop any pending update
+#[tauri::command]
+pub fn rollback_update<R: Runtime>(app: AppHandle<R>) -> Result<Option<RollbackPoint>, UpdaterError> {
+    let restored = restore_rollback_point(&get_rollback_dir(&app)?)?;
+    if let Ok(mut pending) = PENDING_UPDATE.lock() { *pending = None; }
+    if let Some(point) = &restored {
+        log::info!("Rolled back to version {}", point.version);
+    }
+    Ok(restored)
 }
 
 fn format_datetime(dt: OffsetDateTime) -> String {
@@ -234,7 +437,150 @@ fn format_datetime(dt: OffsetDateTime) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::normalize_updater_error;
+    use super::*;
+
+    // ------------------------------------------------------------------------
+    // Channel Selection Tests
+    // ------------------------------------------------------------------------
+
+    const STABLE_MANIFEST: &str = r#"{
+        "version": "1.4.0",
+        "notes": "Stable release",
+        "pub_date": "2026-09-01T12:00:00Z",
+        "platforms": {}
+    }"#;
+
+    const BETA_MANIFEST: &str = r#"{
+        "version": "1.5.0-beta.2",
+        "notes": "Beta release",
+        "pub_date": "2026-09-20T12:00:00Z",
+        "platforms": {}
+    }"#;
+
+    const NIGHTLY_MANIFEST: &str = r#"{
+        "version": "1.5.0-nightly.20261010",
+        "notes": "Nightly build",
+        "pub_date": "2026-10-10T03:00:00Z",
+        "platforms": {}
+    }"#;
+
+    fn manifest_version(manifest: &str) -> Version {
+        let value: serde_json::Value = serde_json::from_str(manifest).unwrap();
+        Version::parse(value["version"].as_str().unwrap()).unwrap()
+    }
+
+    fn offered(channel: UpdateChannel, current: &str, manifest: &str) -> bool {
+        should_offer_release(channel, &Version::parse(current).unwrap(), &manifest_version(manifest))
+    }
+
+    #[test]
+    fn channel_manifest_urls_are_distinct() {
+        let stable = UpdateChannel::Stable.manifest_url();
+        let beta = UpdateChannel::Beta.manifest_url();
+        let nightly = UpdateChannel::Nightly.manifest_url();
+        assert!(stable.ends_with("/releases/latest/download/latest.json"));
+        assert!(beta.contains("/download/beta/"));
+        assert!(nightly.contains("/download/nightly/"));
+        assert!(Url::parse(&beta).is_ok());
+    }
+
+    #[test]
+    fn each_channel_selects_its_own_manifest_version() {
+        assert!(offered(UpdateChannel::Stable, "1.3.2", STABLE_MANIFEST));
+        assert!(offered(UpdateChannel::Beta, "1.4.0", BETA_MANIFEST));
+        assert!(offered(UpdateChannel::Nightly, "1.5.0-beta.2", NIGHTLY_MANIFEST));
+    }
+
+    #[test]
+    fn channels_reject_releases_from_less_stable_channels() {
+        assert!(!offered(UpdateChannel::Stable, "1.3.2", BETA_MANIFEST));
+        assert!(!offered(UpdateChannel::Stable, "1.3.2", NIGHTLY_MANIFEST));
+        assert!(!offered(UpdateChannel::Beta, "1.3.2", NIGHTLY_MANIFEST));
+        assert!(offered(UpdateChannel::Beta, "1.3.2", STABLE_MANIFEST));
+    }
+
+    #[test]
+    fn switching_to_a_more_stable_channel_never_downgrades() {
+        // Running the nightly, the older stable manifest is refused
+        assert!(!offered(UpdateChannel::Stable, "1.5.0-nightly.20261010", STABLE_MANIFEST));
+        // Running the beta, an older stable release is refused too
+        assert!(!offered(UpdateChannel::Stable, "1.5.0-beta.2", STABLE_MANIFEST));
+        // Same version is not re-offered
+        assert!(!offered(UpdateChannel::Stable, "1.4.0", STABLE_MANIFEST));
+    }
+
+    #[test]
+    fn final_release_supersedes_its_prerelease() {
+        let current = Version::parse("1.5.0-beta.2").unwrap();
+        let remote = Version::parse("1.5.0").unwrap();
+        assert!(should_offer_release(UpdateChannel::Stable, &current, &remote));
+    }
+
+    #[test]
+    fn update_channel_serializes_lowercase() {
+        assert_eq!(serde_json::to_string(&UpdateChannel::Nightly).unwrap(), "\"nightly\"");
+        let channel: UpdateChannel = serde_json::from_str("\"beta\"").unwrap();
+        assert_eq!(channel, UpdateChannel::Beta);
+    }
+
+    // ------------------------------------------------------------------------
+    // Rollback Tests
+    // ------------------------------------------------------------------------
+
+    fn rollback_fixture() -> (PathBuf, PathBuf, PathBuf) {
+        let root = std::env::temp_dir().join(format!(
+            "skymap_rollback_{}",
+            crate::utils::generate_id("t")
+        ));
+        let install = root.join("install");
+        fs::create_dir_all(&install).unwrap();
+        let executable = install.join("skymap");
+        fs::write(&executable, b"binary-1.4.0").unwrap();
+        (root.clone(), root.join(ROLLBACK_DIR), executable)
+    }
+
+    #[test]
+    fn snapshot_records_version_before_install() {
+        let (root, dir, executable) = rollback_fixture();
+        assert!(read_rollback_point(&dir).is_none());
+
+        let point = snapshot_executable(&dir, &executable, "1.4.0").unwrap();
+        assert_eq!(point.version, "1.4.0");
+        assert_eq!(fs::read(&point.backup_path).unwrap(), b"binary-1.4.0");
+        assert_eq!(read_rollback_point(&dir), Some(point));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn rollback_restores_recorded_binary() {
+        let (root, dir, executable) = rollback_fixture();
+        snapshot_executable(&dir, &executable, "1.4.0").unwrap();
+
+        // Simulate the update replacing the binary
+        fs::write(&executable, b"binary-1.5.0").unwrap();
+
+        let restored = restore_rollback_point(&dir).unwrap().unwrap();
+        assert_eq!(restored.version, "1.4.0");
+        assert_eq!(fs::read(&executable).unwrap(), b"binary-1.4.0");
+        assert!(read_rollback_point(&dir).is_none());
+        assert!(!executable.with_extension("old").exists());
+        assert!(!executable.with_extension("rollback").exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn rollback_without_snapshot_is_noop() {
+        let (root, dir, executable) = rollback_fixture();
+        assert!(restore_rollback_point(&dir).unwrap().is_none());
+        assert_eq!(fs::read(&executable).unwrap(), b"binary-1.4.0");
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    // ------------------------------------------------------------------------
+    // Error Classification Tests
+    // ------------------------------------------------------------------------
 
     #[test]
     fn classifies_missing_configuration_errors() {
@@ -2,7 +2,8 @@
 //! Provides shared functionality for ID generation and path management
 
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Manager};
@@ -57,29 +58,53 @@ pub fn get_app_file_path(app: &AppHandle, subdir: &str, filename: &str) -> Resul
     Ok(dir.join(filename))
 }
 
-/// Perform atomic file write using temp file and rename
-/// This ensures data integrity even if the operation is interrupted
-pub fn atomic_write(path: &PathBuf, data: &[u8]) -> Result<(), PathError> {
-    let temp_path = path.with_extension("tmp");
+/// Counter that keeps concurrent atomic writes to the same file apart
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Sibling temp path for one atomic write of `path`, unique per process and
+/// call so concurrent writers never share a temp file
+pub fn atomic_temp_path(path: &Path) -> PathBuf {
+    let mut file_name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    path.with_file_name(file_name)
+}
 
+/// Perform atomic file write using temp file and rename
+/// Data is written and synced to a uniquely named temp file in the same
+/// directory, then renamed over the target, so an interrupted or concurrent
+/// write never leaves a truncated file behind
+pub fn atomic_write(path: &Path, data: &[u8]) -> Result<(), PathError> {
     // Ensure parent directory exists
     if let Some(parent) = path.parent() {
-        if !parent.exists() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
             fs::create_dir_all(parent)?;
         }
     }
 
-    // Write to temp file first
-    fs::write(&temp_path, data)?;
+    let temp_path = atomic_temp_path(path);
+    let written = fs::File::create(&temp_path).and_then(|mut file| {
+        file.write_all(data)?;
+        file.sync_all()
+    });
 
     // Atomic rename
-    fs::rename(&temp_path, path)?;
+    if let Err(e) = written.and_then(|_| fs::rename(&temp_path, path)) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e.into());
+    }
 
     Ok(())
 }
 
 /// Perform atomic JSON write with pretty formatting
-pub fn atomic_write_json<T: serde::Serialize>(path: &PathBuf, data: &T) -> Result<(), PathError> {
+pub fn atomic_write_json<T: serde::Serialize + ?Sized>(path: &Path, data: &T) -> Result<(), PathError> {
     let json = serde_json::to_string_pretty(data)
         .map_err(|e| PathError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
     atomic_write(path, json.as_bytes())
@@ -229,6 +254,41 @@ mod tests {
         let _ = fs::remove_dir_all(nested_path.parent().unwrap().parent().unwrap());
     }
 
+    #[test]
+    fn test_atomic_write_concurrent_writers_never_tear() {
+        let dir = env::temp_dir().join(format!("skymap_test_{}", generate_id("concurrent")));
+        let path = dir.join("store.json");
+        let payloads: Vec<Vec<u8>> = (0..8u8).map(|i| vec![b'a' + i; 64 * 1024]).collect();
+
+        std::thread::scope(|scope| {
+            for payload in &payloads {
+                let path = &path;
+                scope.spawn(move || {
+                    for _ in 0..10 {
+                        atomic_write(path, payload).unwrap();
+                    }
+                });
+            }
+        });
+
+        let content = fs::read(&path).unwrap();
+        assert!(payloads.contains(&content), "file holds a torn write");
+        let leftovers: Vec<_> = fs::read_dir(&dir).unwrap().map(|e| e.unwrap().file_name()).collect();
+        assert_eq!(leftovers, vec![std::ffi::OsString::from("store.json")]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_atomic_temp_path_is_unique_sibling() {
+        let path = PathBuf::from("/data/stores/targets.json");
+        let first = atomic_temp_path(&path);
+        let second = atomic_temp_path(&path);
+        assert_ne!(first, second);
+        assert_eq!(first.parent(), path.parent());
+        assert!(first.to_string_lossy().ends_with(".tmp"));
+    }
+
     #[test]
     fn test_atomic_write_overwrites_existing() {
         let temp_dir = env::temp_dir();